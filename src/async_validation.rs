@@ -0,0 +1,64 @@
+use crate::ParseError;
+use std::future::Future;
+
+/// Whether an input's value is valid, including checks that are run
+/// asynchronously, like asking a server whether a username is taken.
+/// See `start_async_validation` on inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationState<T> {
+    /// The value parsed, but an asynchronous check of it hasn't
+    /// finished yet.
+    Pending,
+    Valid(T),
+    Invalid(ParseError),
+}
+
+/// A check of an input's value which can't give an answer straight
+/// away, like a request to a server.
+///
+/// Any `Fn(&T) -> impl Future<Output = Result<(), ParseError>>` can be
+/// used as a check. Since the future can't borrow the value, clone
+/// whatever the future needs into it.
+pub trait AsyncValidate<T> {
+    type Future: Future<Output = Result<(), ParseError>>;
+
+    fn validate(&self, value: &T) -> Self::Future;
+}
+
+impl<T, F, Fut> AsyncValidate<T> for F
+where
+    F: Fn(&T) -> Fut,
+    Fut: Future<Output = Result<(), ParseError>>,
+{
+    type Future = Fut;
+
+    fn validate(&self, value: &T) -> Fut {
+        self(value)
+    }
+}
+
+/// An asynchronous check that has been started on an input, returned
+/// by `start_async_validation`. Pass it back to
+/// `finish_async_validation` with the check's result.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingValidation<T> {
+    /// The raw input that is being checked. If the input has changed
+    /// by the time the check finishes, its result is ignored.
+    pub input: String,
+    /// The parsed value that is being checked.
+    pub value: T,
+}
+
+impl<T> PendingValidation<T> {
+    /// Runs a check on the value, returning this together with the
+    /// result, ready to be sent back to the form. In the Elm
+    /// architecture, this would usually become a message like
+    /// `Msg::UsernameChecked(pending, result)`.
+    pub async fn check<V>(self, validator: &V) -> (PendingValidation<T>, Result<(), ParseError>)
+    where
+        V: AsyncValidate<T>,
+    {
+        let result = validator.validate(&self.value).await;
+        (self, result)
+    }
+}
@@ -0,0 +1,284 @@
+use crate::ParseError;
+use std::fmt;
+use std::str::FromStr;
+
+/// The length of an IBAN for each country that uses them.
+const IBAN_LENGTHS: &[(&str, usize)] = &[
+    ("AD", 24),
+    ("AE", 23),
+    ("AL", 28),
+    ("AT", 20),
+    ("AZ", 28),
+    ("BA", 20),
+    ("BE", 16),
+    ("BG", 22),
+    ("BH", 22),
+    ("BR", 29),
+    ("BY", 28),
+    ("CH", 21),
+    ("CR", 22),
+    ("CY", 28),
+    ("CZ", 24),
+    ("DE", 22),
+    ("DK", 18),
+    ("DO", 28),
+    ("EE", 20),
+    ("EG", 29),
+    ("ES", 24),
+    ("FI", 18),
+    ("FO", 18),
+    ("FR", 27),
+    ("GB", 22),
+    ("GE", 22),
+    ("GI", 23),
+    ("GL", 18),
+    ("GR", 27),
+    ("GT", 28),
+    ("HR", 21),
+    ("HU", 28),
+    ("IE", 22),
+    ("IL", 23),
+    ("IQ", 23),
+    ("IS", 26),
+    ("IT", 27),
+    ("JO", 30),
+    ("KW", 30),
+    ("KZ", 20),
+    ("LB", 28),
+    ("LC", 32),
+    ("LI", 21),
+    ("LT", 20),
+    ("LU", 20),
+    ("LV", 21),
+    ("MC", 27),
+    ("MD", 24),
+    ("ME", 22),
+    ("MK", 19),
+    ("MR", 27),
+    ("MT", 31),
+    ("MU", 30),
+    ("NL", 18),
+    ("NO", 15),
+    ("PK", 24),
+    ("PL", 28),
+    ("PS", 29),
+    ("PT", 25),
+    ("QA", 29),
+    ("RO", 24),
+    ("RS", 22),
+    ("SA", 24),
+    ("SC", 31),
+    ("SE", 24),
+    ("SI", 19),
+    ("SK", 24),
+    ("SM", 27),
+    ("ST", 25),
+    ("SV", 28),
+    ("TL", 23),
+    ("TN", 24),
+    ("TR", 26),
+    ("UA", 29),
+    ("VA", 22),
+    ("VG", 24),
+    ("XK", 20),
+];
+
+/// An International Bank Account Number.
+///
+/// This is validated against the IBAN length for its country, and
+/// the mod-97 check digits. Spaces are ignored and lower case
+/// letters are accepted when parsing. `Display` uses the grouped
+/// "print" format, like `GB82 WEST 1234 5698 7654 32`. Use
+/// `electronic` for the format without spaces.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Iban(String);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IbanError {
+    InvalidFormat,
+    UnsupportedCountry(String),
+    InvalidLength { country: String, length: usize },
+    InvalidChecksum,
+}
+
+impl fmt::Display for IbanError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            IbanError::InvalidFormat => {
+                write!(f, "Expected an IBAN like GB82 WEST 1234 5698 7654 32")
+            }
+            IbanError::UnsupportedCountry(country) => {
+                write!(f, "IBANs are not used in {}", country)
+            }
+            IbanError::InvalidLength { country, length } => {
+                write!(
+                    f,
+                    "Expected an IBAN from {} to have {} characters",
+                    country, length
+                )
+            }
+            IbanError::InvalidChecksum => write!(f, "This IBAN's check digits are not correct"),
+        }
+    }
+}
+
+impl std::error::Error for IbanError {}
+
+impl Iban {
+    /// The two letter country code at the start of the IBAN.
+    pub fn country(&self) -> &str {
+        &self.0[..2]
+    }
+
+    /// The IBAN without any spaces, as used in electronic payments.
+    pub fn electronic(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FromStr for Iban {
+    type Err = IbanError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let iban: String = s
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_ascii_uppercase();
+        let chars: Vec<char> = iban.chars().collect();
+        if chars.len() < 4
+            || !chars[..2].iter().all(|c| c.is_ascii_alphabetic())
+            || !chars[2..4].iter().all(|c| c.is_ascii_digit())
+            || !chars.iter().all(|c| c.is_ascii_alphanumeric())
+        {
+            return Err(IbanError::InvalidFormat);
+        }
+
+        let country = &iban[..2];
+        let length = IBAN_LENGTHS
+            .iter()
+            .find(|(iban_country, _)| *iban_country == country)
+            .map(|(_, length)| *length)
+            .ok_or_else(|| IbanError::UnsupportedCountry(country.to_string()))?;
+        if iban.len() != length {
+            return Err(IbanError::InvalidLength {
+                country: country.to_string(),
+                length,
+            });
+        }
+
+        let rearranged = iban[4..].chars().chain(iban[..4].chars());
+        let remainder = rearranged.fold(0, |remainder, c| {
+            let value = c.to_digit(36).unwrap_or(0);
+            if value < 10 {
+                (remainder * 10 + value) % 97
+            } else {
+                (remainder * 100 + value) % 97
+            }
+        });
+        if remainder != 1 {
+            return Err(IbanError::InvalidChecksum);
+        }
+
+        Ok(Iban(iban))
+    }
+}
+
+impl fmt::Display for Iban {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let groups: Vec<String> = self
+            .0
+            .chars()
+            .collect::<Vec<_>>()
+            .chunks(4)
+            .map(|group| group.iter().collect())
+            .collect();
+        write!(f, "{}", groups.join(" "))
+    }
+}
+
+/// The number of digits allowed in domestic bank account numbers for
+/// some countries that don't use IBANs.
+const BANK_ACCOUNT_LENGTHS: &[(&str, usize, usize)] = &[
+    ("AU", 6, 10),
+    ("CA", 7, 12),
+    ("GB", 8, 8),
+    ("IN", 9, 18),
+    ("NZ", 15, 16),
+    ("US", 4, 17),
+    ("ZA", 7, 11),
+];
+
+/// Checks a domestic bank account number for the given two letter
+/// country code, ignoring spaces and hyphens. Returns the account
+/// number with only its digits.
+///
+/// Countries without a specific rule accept between 4 and 34 digits.
+pub fn parse_bank_account_number(country: &str, value: &str) -> Result<String, ParseError> {
+    let (min, max) = BANK_ACCOUNT_LENGTHS
+        .iter()
+        .find(|(account_country, _, _)| account_country.eq_ignore_ascii_case(country))
+        .map(|(_, min, max)| (*min, *max))
+        .unwrap_or((4, 34));
+    let account_number: String = value
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect();
+    let required_type = if min == max {
+        format!("a {} digit account number", min)
+    } else {
+        format!("an account number of {} to {} digits", min, max)
+    };
+    if account_number.len() < min
+        || account_number.len() > max
+        || !account_number.chars().all(|c| c.is_ascii_digit())
+    {
+        Err(ParseError::InvalidFormat { required_type })
+    } else {
+        Ok(account_number)
+    }
+}
+
+/// Implements `ParseAndFormat<String> for $account_input<String>`, and
+/// also implements `ParseAndFormat<Option<String>> for
+/// $account_input<Option<String>>`, for domestic bank account numbers
+/// in `$country`.
+///
+/// This will parse using `structform::parse_bank_account_number`. If
+/// the input string is empty after trimming, then parse will return a
+/// `ParseError::Required` for the `ParseAndFormat<String>` case, and
+/// return `None` for the `ParseAndFormat<Option<String>>` case.
+#[macro_export]
+macro_rules! impl_bank_account_input {
+    ($account_input: ident, $country: literal) => {
+        impl structform::ParseAndFormat<String> for $account_input<String> {
+            fn parse(value: &str) -> Result<String, structform::ParseError> {
+                let trimmed = value.trim();
+                if trimmed.is_empty() {
+                    Err(structform::ParseError::Required)
+                } else {
+                    structform::parse_bank_account_number($country, trimmed)
+                }
+            }
+
+            fn format(value: &String) -> String {
+                value.clone()
+            }
+        }
+
+        impl structform::ParseAndFormat<Option<String>> for $account_input<Option<String>> {
+            fn parse(value: &str) -> Result<Option<String>, structform::ParseError> {
+                let trimmed = value.trim();
+                if trimmed.is_empty() {
+                    Ok(None)
+                } else {
+                    structform::parse_bank_account_number($country, trimmed).map(Option::Some)
+                }
+            }
+
+            fn format(value: &Option<String>) -> String {
+                value.clone().unwrap_or_default()
+            }
+        }
+    };
+}
@@ -0,0 +1,139 @@
+use crate::checksum::gs1_is_valid;
+use crate::{parse_query_string, to_query_string, StructForm, ValidationErrors};
+use std::fmt;
+use std::str::FromStr;
+
+/// A payload from a barcode or QR code scanner, for inventory and
+/// logistics forms. Most scanners type what they read into the focused
+/// input, so this is parsed from the text of an input.
+///
+/// - 13 digits are an EAN-13 barcode, and their check digit must be
+///   valid.
+/// - URL-encoded key-value pairs, like `sku=KTL-1&qty=3`, are the
+///   contents of a QR code. These can be used to fill in several
+///   fields at once with `fill_form`.
+/// - Any other ASCII text is a Code 128 barcode. Code 128 check
+///   digits aren't passed on by scanners, so they can't be checked.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Barcode {
+    Ean13(String),
+    Code128(String),
+    Qr(Vec<(String, String)>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BarcodeError {
+    Empty,
+    InvalidChecksum,
+    /// A character that can't be in a Code 128 barcode.
+    InvalidCharacter(char),
+}
+
+impl fmt::Display for BarcodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BarcodeError::Empty => write!(f, "Expected a barcode"),
+            BarcodeError::InvalidChecksum => write!(f, "This is not a valid EAN-13 barcode"),
+            BarcodeError::InvalidCharacter(c) => {
+                write!(f, "A barcode can't contain the character '{}'", c)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BarcodeError {}
+
+impl Barcode {
+    /// The value of a key in a QR code's contents. This is always
+    /// `None` for other barcodes.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        match self {
+            Barcode::Qr(pairs) => pairs
+                .iter()
+                .find(|(pair_key, _)| pair_key == key)
+                .map(|(_, value)| value.as_str()),
+            Barcode::Ean13(_) | Barcode::Code128(_) => None,
+        }
+    }
+
+    /// Sets inputs on a form from the key-value pairs of a QR code.
+    /// `map_key` picks the field that each key fills in, and keys that
+    /// it returns `None` for are ignored. Other barcodes don't set
+    /// anything.
+    ///
+    /// Returns the errors of the fields that were set, so that values
+    /// that don't parse can be pointed out. Other fields on the form
+    /// aren't checked.
+    pub fn fill_form<Form, Model, F>(
+        &self,
+        form: &mut Form,
+        mut map_key: F,
+    ) -> Result<(), ValidationErrors<Form::Field>>
+    where
+        Form: StructForm<Model>,
+        Form::Field: PartialEq,
+        F: FnMut(&str) -> Option<Form::Field>,
+    {
+        let mut fields = Vec::new();
+        if let Barcode::Qr(pairs) = self {
+            for (key, value) in pairs {
+                if let Some(field) = map_key(key) {
+                    form.set_input_at_path(&Form::field_path(&field), value.clone());
+                    fields.push(field);
+                }
+            }
+        }
+        let mut errors = ValidationErrors::new();
+        if let Err(form_errors) = form.validate() {
+            for (field, error) in form_errors {
+                if fields.contains(&field) {
+                    errors.push(field, error);
+                }
+            }
+        }
+        errors.into_result()
+    }
+}
+
+impl FromStr for Barcode {
+    type Err = BarcodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        if trimmed.is_empty() {
+            return Err(BarcodeError::Empty);
+        }
+
+        if trimmed.len() == 13 && trimmed.chars().all(|c| c.is_ascii_digit()) {
+            let digits: Vec<u32> = trimmed.chars().filter_map(|c| c.to_digit(10)).collect();
+            return if gs1_is_valid(&digits) {
+                Ok(Barcode::Ean13(trimmed.to_string()))
+            } else {
+                Err(BarcodeError::InvalidChecksum)
+            };
+        }
+
+        let is_key_value_pairs = trimmed.split('&').all(|pair| {
+            pair.find('=')
+                .map(|equals_index| equals_index > 0)
+                .unwrap_or(false)
+        });
+        if is_key_value_pairs {
+            return Ok(Barcode::Qr(parse_query_string(trimmed)));
+        }
+
+        match trimmed.chars().find(|c| !c.is_ascii()) {
+            Some(c) => Err(BarcodeError::InvalidCharacter(c)),
+            None => Ok(Barcode::Code128(trimmed.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Barcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Barcode::Ean13(code) | Barcode::Code128(code) => write!(f, "{}", code),
+            Barcode::Qr(pairs) => write!(f, "{}", to_query_string(pairs)),
+        }
+    }
+}
@@ -0,0 +1,75 @@
+/// Implements `ParseAndFormat<bool> for $bool_input<bool>`, and also
+/// implements `ParseAndFormat<Option<bool>> for
+/// $bool_input<Option<bool>>`, for checkboxes.
+///
+/// Inputs of `true`, `on`, `yes` or `1` parse as `true`, and `false`,
+/// `off`, `no` or `0` parse as `false`, ignoring case. An empty input
+/// is an unchecked checkbox, so it parses as `false` for the
+/// `ParseAndFormat<bool>` case, and `None` for the
+/// `ParseAndFormat<Option<bool>>` case, which is useful for "yes, no
+/// or not answered" questions. Anything else returns a
+/// `ParseError::InvalidFormat`.
+///
+/// Formatting is `true` or `false`.
+///
+/// This also adds `set_checked` and `is_checked` to both inputs, so
+/// that checkboxes can be set without going through a string.
+#[macro_export]
+macro_rules! impl_bool_input {
+    ($bool_input: ident) => {
+        impl structform::ParseAndFormat<bool> for $bool_input<bool> {
+            fn parse(value: &str) -> Result<bool, structform::ParseError> {
+                <$bool_input<Option<bool>> as structform::ParseAndFormat<Option<bool>>>::parse(
+                    value,
+                )
+                .map(|checked| checked.unwrap_or(false))
+            }
+
+            fn format(value: &bool) -> String {
+                value.to_string()
+            }
+        }
+
+        impl structform::ParseAndFormat<Option<bool>> for $bool_input<Option<bool>> {
+            fn parse(value: &str) -> Result<Option<bool>, structform::ParseError> {
+                match value.trim().to_lowercase().as_str() {
+                    "" => Ok(None),
+                    "true" | "on" | "yes" | "1" => Ok(Some(true)),
+                    "false" | "off" | "no" | "0" => Ok(Some(false)),
+                    _ => Err(structform::ParseError::InvalidFormat {
+                        required_type: "yes or no".to_string(),
+                    }),
+                }
+            }
+
+            fn format(value: &Option<bool>) -> String {
+                match value {
+                    None => "".to_string(),
+                    Some(checked) => checked.to_string(),
+                }
+            }
+        }
+
+        #[allow(dead_code)]
+        impl $bool_input<bool> {
+            pub fn set_checked(&mut self, checked: bool) {
+                self.set_input(checked.to_string());
+            }
+
+            pub fn is_checked(&self) -> bool {
+                matches!(self.value, Ok(true))
+            }
+        }
+
+        #[allow(dead_code)]
+        impl $bool_input<Option<bool>> {
+            pub fn set_checked(&mut self, checked: bool) {
+                self.set_input(checked.to_string());
+            }
+
+            pub fn is_checked(&self) -> bool {
+                matches!(self.value, Ok(Some(true)))
+            }
+        }
+    };
+}
@@ -0,0 +1,50 @@
+use crate::ParseError;
+
+/// A token from a third party captcha, like reCAPTCHA or hCaptcha,
+/// carried from the form through to the model.
+///
+/// On the form, annotate a `String` field with `#[structform(captcha)]`
+/// and set it from the captcha widget's callback. The model needs a
+/// `CaptchaToken` field with the same name, which is filled in on
+/// submit. Submitting without a token fails with
+/// `ParseError::CaptchaRequired`, and the token on the form is cleared
+/// whenever a submit fails, since tokens can only be verified once.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct CaptchaToken(String);
+
+impl CaptchaToken {
+    pub fn new(token: String) -> CaptchaToken {
+        CaptchaToken(token)
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Checks the token with the captcha provider, returning a
+    /// `ParseError::CaptchaFailed` if it isn't accepted. This is
+    /// intended to be called on the server before the rest of the
+    /// model is used.
+    pub fn verify<V: VerifyCaptcha>(&self, verifier: &V) -> Result<(), ParseError> {
+        if !self.0.is_empty() && verifier.verify_captcha(&self.0) {
+            Ok(())
+        } else {
+            Err(ParseError::CaptchaFailed)
+        }
+    }
+}
+
+/// Checks captcha tokens with a captcha provider. This is usually
+/// implemented by a server, by sending the token to the provider's
+/// verification API along with the server's secret key.
+///
+/// Any `Fn(&str) -> bool` can be used as a verifier.
+pub trait VerifyCaptcha {
+    fn verify_captcha(&self, token: &str) -> bool;
+}
+
+impl<F: Fn(&str) -> bool> VerifyCaptcha for F {
+    fn verify_captcha(&self, token: &str) -> bool {
+        self(token)
+    }
+}
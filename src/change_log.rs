@@ -0,0 +1,93 @@
+use crate::FormClock;
+use std::time::Duration;
+
+/// One change to an input, recorded by a `ChangeLog`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change {
+    /// The field path of the input, like `addresses[0].city`.
+    pub path: String,
+    pub old_value: String,
+    pub new_value: String,
+    /// When the change was made, according to the change log's clock,
+    /// or `None` if the time can't be read.
+    pub at: Option<Duration>,
+}
+
+/// The value recorded in place of the old and new values of inputs
+/// marked with `#[structform(sensitive)]`.
+pub const REDACTED: &str = "[redacted]";
+
+/// Records every change made to a form's inputs, for audit logs.
+///
+/// Annotate a field of this type with `#[structform(change_log)]`, and
+/// every `set_input` on the form (including those made through
+/// `set_input_at_path`) will record what changed. Values of inputs
+/// marked with `#[structform(sensitive)]` are replaced with
+/// `REDACTED`. Read the changes with `changes`, or use `take` at
+/// submit time to send them along with the model.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ChangeLog {
+    changes: Vec<Change>,
+    clock: FormClock,
+}
+
+impl ChangeLog {
+    /// Sets the clock that changes are timestamped with. This is
+    /// called by `StructForm::set_clock`.
+    pub fn set_clock(&mut self, clock: FormClock) {
+        self.clock = clock;
+    }
+
+    pub fn changes(&self) -> &[Change] {
+        &self.changes
+    }
+
+    /// Removes and returns all of the recorded changes.
+    pub fn take(&mut self) -> Vec<Change> {
+        std::mem::take(&mut self.changes)
+    }
+
+    /// Records the differences between two snapshots of a form's
+    /// inputs, as returned by `StructForm::inputs_by_path`. Inputs that
+    /// only appear in one of the snapshots are treated as empty in the
+    /// other.
+    pub fn record<F: Fn(&str) -> bool>(
+        &mut self,
+        before: &[(String, String)],
+        after: &[(String, String)],
+        is_sensitive_path: F,
+    ) {
+        let at = self.clock.now();
+        let find = |inputs: &[(String, String)], path: &str| {
+            inputs
+                .iter()
+                .find(|(input_path, _)| input_path == path)
+                .map(|(_, value)| value.clone())
+                .unwrap_or_default()
+        };
+        let removed_paths = before
+            .iter()
+            .map(|(path, _)| path)
+            .filter(|path| !after.iter().any(|(after_path, _)| &after_path == path));
+        let paths = after.iter().map(|(path, _)| path).chain(removed_paths);
+
+        for path in paths {
+            let old_value = find(before, path);
+            let new_value = find(after, path);
+            if old_value == new_value {
+                continue;
+            }
+            let (old_value, new_value) = if is_sensitive_path(path) {
+                (REDACTED.to_string(), REDACTED.to_string())
+            } else {
+                (old_value, new_value)
+            };
+            self.changes.push(Change {
+                path: path.clone(),
+                old_value,
+                new_value,
+                at,
+            });
+        }
+    }
+}
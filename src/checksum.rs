@@ -0,0 +1,41 @@
+/// Checks digits using the Luhn algorithm, where the last digit is the
+/// check digit.
+#[cfg(any(feature = "sa_id", feature = "vat"))]
+// `is_multiple_of` isn't available on the oldest Rust version that
+// StructForm supports.
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) fn luhn_is_valid(digits: &[u32]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| {
+            if i % 2 == 1 {
+                let doubled = d * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                *d
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+/// Checks digits using the GS1 algorithm of EAN and UPC barcodes, where
+/// the last digit is the check digit. Digits are weighted 3 and 1 in
+/// turn, starting from the one before the check digit.
+#[cfg(feature = "barcode")]
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) fn gs1_is_valid(digits: &[u32]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, d)| if i % 2 == 1 { d * 3 } else { *d })
+        .sum();
+    sum % 10 == 0
+}
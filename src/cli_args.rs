@@ -0,0 +1,91 @@
+use std::fmt;
+
+/// Why command line arguments couldn't be applied to a form. See
+/// `StructForm::apply_cli_args`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliArgsError {
+    /// A flag that doesn't match an input on the form.
+    UnknownFlag(String),
+    /// A flag at the end of the arguments without a value.
+    MissingValue(String),
+    /// An argument that isn't a flag, or the value of a flag.
+    UnexpectedArgument(String),
+}
+
+impl fmt::Display for CliArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliArgsError::UnknownFlag(flag) => write!(f, "Unknown flag {}.", flag),
+            CliArgsError::MissingValue(flag) => write!(f, "Expected a value after {}.", flag),
+            CliArgsError::UnexpectedArgument(arg) => write!(f, "Unexpected argument {}.", arg),
+        }
+    }
+}
+
+impl std::error::Error for CliArgsError {}
+
+/// Splits command line arguments, like `--addresses.0.city JHB` or
+/// `--username=joe`, into field paths and values. Numeric segments of
+/// a flag are list indexes, so `--addresses.0.city` is the field path
+/// `addresses[0].city`.
+///
+/// ```
+/// assert_eq!(
+///     structform::parse_cli_args(vec!["--addresses.0.city", "JHB", "--username=joe"]),
+///     Ok(vec![
+///         ("addresses[0].city".to_string(), "JHB".to_string()),
+///         ("username".to_string(), "joe".to_string()),
+///     ])
+/// );
+/// ```
+pub fn parse_cli_args<I, S>(args: I) -> Result<Vec<(String, String)>, CliArgsError>
+where
+    I: IntoIterator<Item = S>,
+    S: Into<String>,
+{
+    let mut args = args.into_iter().map(Into::into);
+    let mut pairs = Vec::new();
+    while let Some(arg) = args.next() {
+        let flag = match arg.strip_prefix("--") {
+            Some(flag) if !flag.is_empty() => flag,
+            _ => return Err(CliArgsError::UnexpectedArgument(arg)),
+        };
+        let (flag, value) = match flag.split_once('=') {
+            Some((flag, value)) => (flag, value.to_string()),
+            None => match args.next() {
+                Some(value) => (flag, value),
+                None => return Err(CliArgsError::MissingValue(arg)),
+            },
+        };
+        pairs.push((cli_flag_to_path(flag), value));
+    }
+    Ok(pairs)
+}
+
+/// The command line flag for a field path, like `--addresses.0.city`
+/// for `addresses[0].city`, for listing a form's flags in help
+/// output.
+///
+/// ```
+/// assert_eq!(structform::cli_flag("addresses[0].city"), "--addresses.0.city");
+/// ```
+pub fn cli_flag(path: &str) -> String {
+    format!("--{}", path.replace('[', ".").replace(']', ""))
+}
+
+fn cli_flag_to_path(flag: &str) -> String {
+    let mut path = String::new();
+    for segment in flag.split('.') {
+        if !segment.is_empty() && segment.bytes().all(|byte| byte.is_ascii_digit()) {
+            path.push('[');
+            path.push_str(segment);
+            path.push(']');
+        } else {
+            if !path.is_empty() {
+                path.push('.');
+            }
+            path.push_str(segment);
+        }
+    }
+    path
+}
@@ -0,0 +1,247 @@
+use crate::ParseError;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A source of the current time, as the duration since the Unix
+/// epoch.
+///
+/// Features that need the time, like rate limits and edit timestamps,
+/// read it from the `FormClock` that they were given with
+/// `StructForm::set_clock`. Implement this to use a time source other
+/// than the system clock.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Duration;
+}
+
+/// The clock used by a form's inputs, rate limits and change logs.
+///
+/// By default, this reads the system time, or the time from `WebClock`
+/// on `wasm32-unknown-unknown` with the `web` feature. Give a form
+/// another clock, like a `MockClock` in tests, with
+/// `StructForm::set_clock`.
+#[derive(Clone, Default)]
+pub struct FormClock {
+    clock: Option<Arc<dyn Clock>>,
+}
+
+impl FormClock {
+    pub fn new<C: Clock + 'static>(clock: C) -> FormClock {
+        FormClock {
+            clock: Some(Arc::new(clock)),
+        }
+    }
+
+    /// The current time, or `None` on `wasm32-unknown-unknown` without
+    /// the `web` feature, where the system time can't be read.
+    pub fn now(&self) -> Option<Duration> {
+        match &self.clock {
+            Some(clock) => Some(clock.now()),
+            None => system_now(),
+        }
+    }
+}
+
+impl fmt::Debug for FormClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.clock {
+            Some(_) => write!(f, "FormClock(custom)"),
+            None => write!(f, "FormClock(system)"),
+        }
+    }
+}
+
+/// Clocks are equal if they're both the system clock, or clones of the
+/// same custom clock.
+impl PartialEq for FormClock {
+    fn eq(&self, other: &FormClock) -> bool {
+        match (&self.clock, &other.clock) {
+            (Some(clock), Some(other_clock)) => Arc::ptr_eq(clock, other_clock),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for FormClock {}
+
+/// When an input was last changed, which inputs only record with the
+/// `edit-timestamps` feature. Without it, this is empty, so inputs
+/// don't carry a timestamp or a clock.
+///
+/// The time is read from the form's clock, which is the system clock
+/// unless the form was given another one with `StructForm::set_clock`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EditTimestamp {
+    #[cfg(feature = "edit-timestamps")]
+    last_edited_at: Option<Duration>,
+    #[cfg(feature = "edit-timestamps")]
+    clock: FormClock,
+}
+
+impl EditTimestamp {
+    /// Records that the input was changed now.
+    pub fn record(&mut self) {
+        #[cfg(feature = "edit-timestamps")]
+        {
+            self.last_edited_at = self.clock.now();
+        }
+    }
+
+    /// Forgets when the input was last changed, like when it's cleared.
+    pub fn clear(&mut self) {
+        #[cfg(feature = "edit-timestamps")]
+        {
+            self.last_edited_at = None;
+        }
+    }
+
+    pub fn set_clock(&mut self, _clock: &FormClock) {
+        #[cfg(feature = "edit-timestamps")]
+        {
+            self.clock = _clock.clone();
+        }
+    }
+
+    /// When the input was last changed, as the duration since the Unix
+    /// epoch. This is `None` if it hasn't been changed, or if the time
+    /// can't be read.
+    #[cfg(feature = "edit-timestamps")]
+    pub fn last_edited_at(&self) -> Option<Duration> {
+        self.last_edited_at
+    }
+
+    /// How long ago the input was last changed, for hints like "edited
+    /// 2 minutes ago".
+    #[cfg(feature = "edit-timestamps")]
+    pub fn time_since_edit(&self) -> Option<Duration> {
+        let last_edited_at = self.last_edited_at?;
+        self.clock
+            .now()
+            .map(|now| now.checked_sub(last_edited_at).unwrap_or_default())
+    }
+}
+
+/// A `Clock` that reads the system time.
+///
+/// This isn't available on `wasm32-unknown-unknown`, where the system
+/// time can't be read. Use `WebClock` there instead.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+impl Clock for SystemClock {
+    fn now(&self) -> Duration {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+    }
+}
+
+/// A `Clock` that reads the browser's time with JavaScript's
+/// `Date.now()`, which is available with the `web` feature.
+///
+/// This only works when running in a JavaScript environment, like a
+/// browser or Node.js.
+#[cfg(feature = "web")]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WebClock;
+
+#[cfg(feature = "web")]
+impl Clock for WebClock {
+    fn now(&self) -> Duration {
+        Duration::from_secs_f64(js_sys::Date::now().max(0.0) / 1000.0)
+    }
+}
+
+/// A `Clock` for tests, where time only passes when you call
+/// `advance`.
+///
+/// Clones share the same time, so you can give a form a clone with
+/// `StructForm::set_clock` and keep one to control it.
+#[derive(Debug, Clone, Default)]
+pub struct MockClock {
+    now: Arc<Mutex<Duration>>,
+}
+
+impl MockClock {
+    pub fn new(now: Duration) -> MockClock {
+        MockClock {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// Moves the time forward.
+    pub fn advance(&self, duration: Duration) {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner()) += duration;
+    }
+}
+
+impl Clock for MockClock {
+    fn now(&self) -> Duration {
+        *self.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Tracks when a form was last submitted, so that submits can be
+/// refused if they come too quickly after each other, like repeated
+/// attempts on a login form.
+///
+/// Annotate a field of this type with `#[structform(rate_limit_ms =
+/// "2000")]` and the derived submit functions will return a
+/// `ParseError::TooManyAttempts` until the interval has passed since
+/// the last accepted submit. The time is read from `clock`, which is
+/// the system clock unless the form was given another one with
+/// `StructForm::set_clock`. Submits are only left unlimited on
+/// `wasm32-unknown-unknown` without the `web` feature, where the
+/// system time can't be read.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubmitRateLimit {
+    pub last_attempt_at: Option<Duration>,
+    pub clock: FormClock,
+}
+
+impl SubmitRateLimit {
+    /// Records a submit attempt, or returns a
+    /// `ParseError::TooManyAttempts` if the last attempt was less than
+    /// `interval` ago.
+    pub fn check(&mut self, interval: Duration) -> Result<(), ParseError> {
+        self.peek(interval)?;
+        self.last_attempt_at = self.clock.now();
+        Ok(())
+    }
+
+    /// Whether `check` would accept a submit attempt, without recording
+    /// one.
+    pub fn peek(&self, interval: Duration) -> Result<(), ParseError> {
+        let now = match self.clock.now() {
+            Some(now) => now,
+            None => return Ok(()),
+        };
+        if let Some(last_attempt_at) = self.last_attempt_at {
+            let elapsed = now.checked_sub(last_attempt_at).unwrap_or_default();
+            if elapsed < interval {
+                return Err(ParseError::TooManyAttempts {
+                    retry_after: interval - elapsed,
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+fn system_now() -> Option<Duration> {
+    Some(SystemClock.now())
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown", feature = "web"))]
+fn system_now() -> Option<Duration> {
+    Some(WebClock.now())
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown", not(feature = "web")))]
+fn system_now() -> Option<Duration> {
+    None
+}
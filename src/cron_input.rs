@@ -0,0 +1,364 @@
+use std::fmt;
+use std::str::FromStr;
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const DAY_NAMES: [&str; 8] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// A standard five field cron expression (minute, hour, day of
+/// month, month and day of week).
+///
+/// Each field can be `*`, a number, a range like `1-5`, a step like
+/// `*/15` or `1-30/2`, or a comma separated list of those. Months
+/// and days of the week can also be given by their three letter
+/// English names (`JAN`, `MON`). The `@yearly`, `@annually`,
+/// `@monthly`, `@weekly`, `@daily`, `@midnight` and `@hourly`
+/// shorthands are also accepted.
+///
+/// `CronExpression` implements `FromStr` and `Display`, so it works
+/// with `impl_text_input_with_stringops`. Use `describe` to show a
+/// human readable summary of the schedule next to the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronExpression {
+    minute: CronField,
+    hour: CronField,
+    day_of_month: CronField,
+    month: CronField,
+    day_of_week: CronField,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronParseError(String);
+
+impl fmt::Display for CronParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for CronParseError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CronFieldKind {
+    Minute,
+    Hour,
+    DayOfMonth,
+    Month,
+    DayOfWeek,
+}
+
+impl CronFieldKind {
+    fn name(self) -> &'static str {
+        match self {
+            CronFieldKind::Minute => "minute",
+            CronFieldKind::Hour => "hour",
+            CronFieldKind::DayOfMonth => "day-of-month",
+            CronFieldKind::Month => "month",
+            CronFieldKind::DayOfWeek => "day-of-week",
+        }
+    }
+
+    fn plural(self) -> &'static str {
+        match self {
+            CronFieldKind::Minute => "minutes",
+            CronFieldKind::Hour => "hours",
+            CronFieldKind::DayOfMonth => "days",
+            CronFieldKind::Month => "months",
+            CronFieldKind::DayOfWeek => "days of the week",
+        }
+    }
+
+    fn range(self) -> (u8, u8) {
+        match self {
+            CronFieldKind::Minute => (0, 59),
+            CronFieldKind::Hour => (0, 23),
+            CronFieldKind::DayOfMonth => (1, 31),
+            CronFieldKind::Month => (1, 12),
+            CronFieldKind::DayOfWeek => (0, 7),
+        }
+    }
+
+    fn parse_value(self, value: &str) -> Result<u8, CronParseError> {
+        let named = match self {
+            CronFieldKind::Month => MONTH_NAMES
+                .iter()
+                .position(|name| name[..3].eq_ignore_ascii_case(value))
+                .map(|i| i as u8 + 1),
+            CronFieldKind::DayOfWeek => DAY_NAMES[..7]
+                .iter()
+                .position(|name| name[..3].eq_ignore_ascii_case(value))
+                .map(|i| i as u8),
+            _ => None,
+        };
+        let (min, max) = self.range();
+        named
+            .or_else(|| value.parse::<u8>().ok())
+            .filter(|value| *value >= min && *value <= max)
+            .ok_or_else(|| {
+                CronParseError(format!(
+                    "Expected the {} to be between {} and {}",
+                    self.name(),
+                    min,
+                    max
+                ))
+            })
+    }
+
+    fn value_name(self, value: u8) -> String {
+        match self {
+            CronFieldKind::Month => MONTH_NAMES[value as usize - 1].to_string(),
+            CronFieldKind::DayOfWeek => DAY_NAMES[value as usize].to_string(),
+            _ => value.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CronField {
+    kind: CronFieldKind,
+    parts: Vec<CronPart>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CronPart {
+    start: u8,
+    end: u8,
+    step: u8,
+}
+
+impl CronField {
+    fn parse(kind: CronFieldKind, field: &str) -> Result<CronField, CronParseError> {
+        let parts = field
+            .split(',')
+            .map(|part| CronPart::parse(kind, part))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(CronField { kind, parts })
+    }
+
+    fn is_any(&self) -> bool {
+        self.parts.len() == 1 && self.parts[0].is_full(self.kind) && self.parts[0].step == 1
+    }
+
+    fn single(&self) -> Option<u8> {
+        match self.parts.as_slice() {
+            [part] if part.start == part.end => Some(part.start),
+            _ => None,
+        }
+    }
+
+    fn every(&self) -> Option<u8> {
+        match self.parts.as_slice() {
+            [part] if part.is_full(self.kind) => Some(part.step),
+            _ => None,
+        }
+    }
+
+    fn describe(&self) -> String {
+        let descriptions: Vec<String> = self
+            .parts
+            .iter()
+            .map(|part| part.describe(self.kind))
+            .collect();
+        match descriptions.split_last() {
+            Some((last, init)) if !init.is_empty() => format!("{} and {}", init.join(", "), last),
+            Some((last, _)) => last.clone(),
+            None => String::new(),
+        }
+    }
+}
+
+impl CronPart {
+    fn parse(kind: CronFieldKind, part: &str) -> Result<CronPart, CronParseError> {
+        let (range, step) = match part.find('/') {
+            Some(i) => (&part[..i], Some(&part[i + 1..])),
+            None => (part, None),
+        };
+        let step = match step {
+            Some(step) => step
+                .parse::<u8>()
+                .ok()
+                .filter(|step| *step > 0)
+                .ok_or_else(|| {
+                    CronParseError(format!("Expected a positive step for the {}", kind.name()))
+                })?,
+            None => 1,
+        };
+        let (min, max) = kind.range();
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some(i) = range.find('-') {
+            let start = kind.parse_value(&range[..i])?;
+            let end = kind.parse_value(&range[i + 1..])?;
+            if start > end {
+                return Err(CronParseError(format!(
+                    "Expected the {} range to go from low to high",
+                    kind.name()
+                )));
+            }
+            (start, end)
+        } else {
+            let value = kind.parse_value(range)?;
+            if step == 1 {
+                (value, value)
+            } else {
+                (value, max)
+            }
+        };
+        Ok(CronPart { start, end, step })
+    }
+
+    fn is_full(&self, kind: CronFieldKind) -> bool {
+        (self.start, self.end) == kind.range()
+    }
+
+    fn describe(&self, kind: CronFieldKind) -> String {
+        if self.start == self.end {
+            kind.value_name(self.start)
+        } else if self.step == 1 {
+            format!(
+                "{} through {}",
+                kind.value_name(self.start),
+                kind.value_name(self.end)
+            )
+        } else if self.is_full(kind) {
+            format!("every {} {}", self.step, kind.plural())
+        } else {
+            format!(
+                "every {} {} from {} through {}",
+                self.step,
+                kind.plural(),
+                kind.value_name(self.start),
+                kind.value_name(self.end)
+            )
+        }
+    }
+}
+
+impl CronExpression {
+    /// A human readable summary of the schedule, like "At 09:00, on
+    /// Monday through Friday".
+    pub fn describe(&self) -> String {
+        let mut description = match (self.minute.single(), self.hour.single()) {
+            (Some(minute), Some(hour)) => format!("At {:02}:{:02}", hour, minute),
+            _ if self.hour.is_any() => match self.minute.every() {
+                Some(1) => "Every minute".to_string(),
+                Some(step) => format!("Every {} minutes", step),
+                None => format!("At minute {} past every hour", self.minute.describe()),
+            },
+            _ => format!(
+                "At minute {} past hour {}",
+                self.minute.describe(),
+                self.hour.describe()
+            ),
+        };
+
+        let day_of_month = if self.day_of_month.is_any() {
+            None
+        } else {
+            Some(format!("day-of-month {}", self.day_of_month.describe()))
+        };
+        let day_of_week = if self.day_of_week.is_any() {
+            None
+        } else {
+            Some(self.day_of_week.describe())
+        };
+        match (day_of_month, day_of_week) {
+            (Some(day_of_month), Some(day_of_week)) => {
+                description.push_str(&format!(", on {} or on {}", day_of_month, day_of_week))
+            }
+            (Some(day), None) | (None, Some(day)) => description.push_str(&format!(", on {}", day)),
+            (None, None) => {}
+        }
+
+        if !self.month.is_any() {
+            description.push_str(&format!(", in {}", self.month.describe()));
+        }
+        description
+    }
+}
+
+impl FromStr for CronExpression {
+    type Err = CronParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let expanded = match s.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            other => other,
+        };
+        let fields: Vec<&str> = expanded.split_whitespace().collect();
+        match fields.as_slice() {
+            [minute, hour, day_of_month, month, day_of_week] => Ok(CronExpression {
+                minute: CronField::parse(CronFieldKind::Minute, minute)?,
+                hour: CronField::parse(CronFieldKind::Hour, hour)?,
+                day_of_month: CronField::parse(CronFieldKind::DayOfMonth, day_of_month)?,
+                month: CronField::parse(CronFieldKind::Month, month)?,
+                day_of_week: CronField::parse(CronFieldKind::DayOfWeek, day_of_week)?,
+            }),
+            _ => Err(CronParseError(
+                "Expected a cron expression with 5 fields".to_string(),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for CronField {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let parts: Vec<String> = self
+            .parts
+            .iter()
+            .map(|part| {
+                let range = if part.is_full(self.kind) {
+                    "*".to_string()
+                } else if part.start == part.end
+                    || (part.step != 1 && part.end == self.kind.range().1)
+                {
+                    part.start.to_string()
+                } else {
+                    format!("{}-{}", part.start, part.end)
+                };
+                if part.step == 1 {
+                    range
+                } else {
+                    format!("{}/{}", range, part.step)
+                }
+            })
+            .collect();
+        write!(f, "{}", parts.join(","))
+    }
+}
+
+impl fmt::Display for CronExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {}",
+            self.minute, self.hour, self.day_of_month, self.month, self.day_of_week
+        )
+    }
+}
@@ -0,0 +1,226 @@
+use crate::{StructForm, ValidationErrors};
+use std::fmt;
+use std::io::Read;
+
+/// Why rows of a CSV file couldn't be imported into a list of
+/// subforms. See `CsvList::import_csv`.
+#[derive(Debug)]
+pub enum CsvImportError<Field> {
+    Read(std::io::Error),
+    /// A quoted value that is never closed.
+    UnterminatedQuote,
+    /// A column in the mapping that isn't in the file's header row.
+    MissingColumn(String),
+    /// The rows were imported, but some of their inputs don't parse.
+    /// Errors are keyed by the index of the row's subform in the list,
+    /// and the subform's field.
+    InvalidRows(ValidationErrors<(usize, Field)>),
+}
+
+impl<Field> fmt::Display for CsvImportError<Field> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvImportError::Read(error) => write!(f, "Couldn't read the file: {}.", error),
+            CsvImportError::UnterminatedQuote => {
+                write!(f, "The file has a quoted value that isn't closed.")
+            }
+            CsvImportError::MissingColumn(column) => {
+                write!(f, "The file doesn't have a {} column.", column)
+            }
+            CsvImportError::InvalidRows(errors) => write!(
+                f,
+                "{} value{} in the file {} not valid.",
+                errors.len(),
+                if errors.len() == 1 { "" } else { "s" },
+                if errors.len() == 1 { "is" } else { "are" }
+            ),
+        }
+    }
+}
+
+impl<Field: fmt::Debug> std::error::Error for CsvImportError<Field> {}
+
+/// Splits CSV text into rows of values, following RFC 4180. Values can
+/// be quoted with `"`, and quoted values can contain commas, line
+/// breaks and doubled `""` quotes. Rows can end with `\n` or `\r\n`,
+/// and empty lines are skipped. Returns `None` if a quoted value is
+/// never closed.
+///
+/// ```
+/// assert_eq!(
+///     structform::parse_csv("name,city\n\"Wernick, Justin\",JHB\n"),
+///     Some(vec![
+///         vec!["name".to_string(), "city".to_string()],
+///         vec!["Wernick, Justin".to_string(), "JHB".to_string()],
+///     ])
+/// );
+/// ```
+pub fn parse_csv(text: &str) -> Option<Vec<Vec<String>>> {
+    let mut rows = Vec::new();
+    let mut row = Vec::new();
+    let mut value = String::new();
+    let mut in_quotes = false;
+    let mut chars = text.trim_start_matches('\u{feff}').chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                chars.next();
+                value.push('"');
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if value.is_empty() => in_quotes = true,
+            (',', false) => row.push(std::mem::take(&mut value)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                row.push(std::mem::take(&mut value));
+                if row.len() == 1 && row[0].is_empty() {
+                    row.clear();
+                } else {
+                    rows.push(std::mem::take(&mut row));
+                }
+            }
+            (c, _) => value.push(c),
+        }
+    }
+    if in_quotes {
+        return None;
+    }
+    if !value.is_empty() || !row.is_empty() {
+        row.push(value);
+        rows.push(row);
+    }
+    Some(rows)
+}
+
+/// Joins rows of values into CSV text, following RFC 4180. Values that
+/// contain commas, quotes or line breaks are quoted, and every row ends
+/// with `\r\n`.
+///
+/// ```
+/// let rows = vec![vec!["Name".to_string(), "Wernick, Justin".to_string()]];
+/// assert_eq!(structform::to_csv(&rows), "Name,\"Wernick, Justin\"\r\n");
+/// ```
+pub fn to_csv(rows: &[Vec<String>]) -> String {
+    let mut csv = String::new();
+    for row in rows {
+        let values: Vec<String> = row
+            .iter()
+            .map(|value| {
+                if value.contains(&[',', '"', '\r', '\n'][..]) {
+                    format!("\"{}\"", value.replace('"', "\"\""))
+                } else {
+                    value.clone()
+                }
+            })
+            .collect();
+        csv.push_str(&values.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// CSV support for lists of subforms, for bulk entry like "upload your
+/// team".
+pub trait CsvList<Model> {
+    type Field;
+
+    /// Appends a subform to the list for every row of a CSV file. The
+    /// first row of the file is its header, and `mapping` pairs the
+    /// names of columns with the subform's fields that they fill in.
+    /// Column names are matched without case, and columns that aren't
+    /// in the mapping are ignored.
+    ///
+    /// If the file can't be read, or is missing a column, nothing is
+    /// added. Otherwise every row is added, even if some of its inputs
+    /// don't parse, so that they can be fixed on the form, and those
+    /// errors are returned as `CsvImportError::InvalidRows`.
+    fn import_csv<R: Read>(
+        &mut self,
+        reader: R,
+        mapping: &[(&str, Self::Field)],
+    ) -> Result<(), CsvImportError<Self::Field>>;
+
+    /// The list as a CSV file, with a row for every subform, for
+    /// offering "download what you entered". The header row has the
+    /// label of each input, like `summary`, and values are formatted
+    /// the same way. Inputs marked with `#[structform(sensitive)]` are
+    /// left out, as are optional and list subforms inside of the
+    /// subforms, since they don't have a fixed set of columns.
+    fn export_csv(&self) -> String;
+}
+
+impl<Form, Model> CsvList<Model> for Vec<Form>
+where
+    Form: StructForm<Model> + Default,
+    Form::Field: Clone,
+{
+    type Field = Form::Field;
+
+    fn import_csv<R: Read>(
+        &mut self,
+        mut reader: R,
+        mapping: &[(&str, Form::Field)],
+    ) -> Result<(), CsvImportError<Form::Field>> {
+        let mut text = String::new();
+        reader
+            .read_to_string(&mut text)
+            .map_err(CsvImportError::Read)?;
+        let mut rows = parse_csv(&text)
+            .ok_or(CsvImportError::UnterminatedQuote)?
+            .into_iter();
+        let header = rows.next().unwrap_or_default();
+        let columns = mapping
+            .iter()
+            .map(|(column, field)| {
+                header
+                    .iter()
+                    .position(|name| name.trim().eq_ignore_ascii_case(column.trim()))
+                    .map(|index| (index, field))
+                    .ok_or_else(|| CsvImportError::MissingColumn(column.to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut errors = ValidationErrors::new();
+        for row in rows {
+            let mut form = Form::default();
+            for (index, field) in &columns {
+                form.set_input(
+                    (*field).clone(),
+                    row.get(*index).cloned().unwrap_or_default(),
+                );
+            }
+            if let Err(form_errors) = form.validate() {
+                let list_index = self.len();
+                errors.extend_nested(form_errors, |field| (list_index, field));
+            }
+            self.push(form);
+        }
+        errors.into_result().map_err(CsvImportError::InvalidRows)
+    }
+
+    fn export_csv(&self) -> String {
+        let empty_form = Form::default();
+        let labels: Vec<String> = Form::labels_by_path()
+            .into_iter()
+            .filter(|(path, _)| !empty_form.is_sensitive_path(path))
+            .map(|(_, label)| label)
+            .collect();
+        let mut rows = vec![labels.clone()];
+        for form in self {
+            let summary = form.summary();
+            rows.push(
+                labels
+                    .iter()
+                    .map(|label| {
+                        summary
+                            .iter()
+                            .find(|(summary_label, _)| summary_label == label)
+                            .map(|(_, value)| value.clone())
+                            .unwrap_or_default()
+                    })
+                    .collect(),
+            );
+        }
+        to_csv(&rows)
+    }
+}
@@ -0,0 +1,543 @@
+use crate::{FormClock, ParseError};
+use std::convert::TryFrom;
+use time::{Date, Duration, Month, PrimitiveDateTime, Time, Weekday};
+
+/// Parses an ISO 8601 date, like `2021-12-31`, which is also the value
+/// of an HTML `<input type="date">`.
+///
+/// Returns `ParseError::Required` if the input is empty after
+/// trimming.
+///
+/// ```
+/// let date = structform::parse_date("2021-12-31").unwrap();
+/// assert_eq!(structform::format_date(&date), "2021-12-31");
+/// assert!(structform::parse_date("2021-02-30").is_err());
+/// ```
+pub fn parse_date(value: &str) -> Result<Date, ParseError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ParseError::Required);
+    }
+    parse_date_parts(value).ok_or_else(|| ParseError::InvalidFormat {
+        required_type: "a date, like 2021-12-31".to_string(),
+    })
+}
+
+pub fn format_date(value: &Date) -> String {
+    format!(
+        "{:04}-{:02}-{:02}",
+        value.year(),
+        u8::from(value.month()),
+        value.day()
+    )
+}
+
+/// Parses an ISO 8601 time, like `13:45` or `13:45:30`, which is also
+/// the value of an HTML `<input type="time">`. Fractions of a second,
+/// like `13:45:30.5`, are accepted too.
+///
+/// Returns `ParseError::Required` if the input is empty after
+/// trimming.
+///
+/// ```
+/// let time = structform::parse_time("13:45").unwrap();
+/// assert_eq!(structform::format_time(&time), "13:45");
+/// assert!(structform::parse_time("25:00").is_err());
+/// ```
+pub fn parse_time(value: &str) -> Result<Time, ParseError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ParseError::Required);
+    }
+    parse_time_parts(value).ok_or_else(|| ParseError::InvalidFormat {
+        required_type: "a time, like 13:45".to_string(),
+    })
+}
+
+/// Formats a time the way that browsers do for `<input
+/// type="time">`, leaving out the seconds when they are zero.
+pub fn format_time(value: &Time) -> String {
+    let mut formatted = format!("{:02}:{:02}", value.hour(), value.minute());
+    if value.second() != 0 || value.nanosecond() != 0 {
+        formatted.push_str(&format!(":{:02}", value.second()));
+    }
+    if value.nanosecond() != 0 {
+        let fraction = format!("{:09}", value.nanosecond());
+        formatted.push('.');
+        formatted.push_str(fraction.trim_end_matches('0'));
+    }
+    formatted
+}
+
+/// Parses an ISO 8601 date and time without a time zone, like
+/// `2021-12-31T13:45`, which is also the value of an HTML `<input
+/// type="datetime-local">`. A space can be used instead of the `T`.
+///
+/// Returns `ParseError::Required` if the input is empty after
+/// trimming.
+///
+/// ```
+/// let datetime = structform::parse_datetime("2021-12-31 13:45:30").unwrap();
+/// assert_eq!(structform::format_datetime(&datetime), "2021-12-31T13:45:30");
+/// ```
+pub fn parse_datetime(value: &str) -> Result<PrimitiveDateTime, ParseError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ParseError::Required);
+    }
+    value
+        .split_once(['T', 't', ' '])
+        .and_then(|(date, time)| {
+            Some(PrimitiveDateTime::new(
+                parse_date_parts(date)?,
+                parse_time_parts(time)?,
+            ))
+        })
+        .ok_or_else(|| ParseError::InvalidFormat {
+            required_type: "a date and time, like 2021-12-31T13:45".to_string(),
+        })
+}
+
+pub fn format_datetime(value: &PrimitiveDateTime) -> String {
+    format!(
+        "{}T{}",
+        format_date(&value.date()),
+        format_time(&value.time())
+    )
+}
+
+/// Parses a date in `format`, like `%d/%m/%Y` for `31/12/2021`. This is
+/// for apps that show dates in a local convention, rather than in an
+/// HTML `<input type="date">`, like egui or terminal apps.
+///
+/// The format may use `%Y` for the four digit year, `%m` for the month,
+/// `%d` for the day of the month, and `%%` for a `%`. Anything else has
+/// to match exactly. Months and days may be typed with one or two
+/// digits.
+///
+/// Returns `ParseError::Required` if the input is empty after
+/// trimming.
+///
+/// ```
+/// let date = structform::parse_date_with_format("1/2/2021", "%d/%m/%Y").unwrap();
+/// assert_eq!(structform::format_date_with_format(&date, "%d/%m/%Y"), "01/02/2021");
+/// assert_eq!(structform::format_date(&date), "2021-02-01");
+/// ```
+///
+/// # Panics
+///
+/// Panics if the format doesn't use each of `%Y`, `%m` and `%d`, or uses
+/// any other `%` specifier.
+pub fn parse_date_with_format(value: &str, format: &str) -> Result<Date, ParseError> {
+    let items = date_format_items(format);
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ParseError::Required);
+    }
+    parse_date_format_items(value, &items).ok_or_else(|| ParseError::InvalidFormat {
+        required_type: format!(
+            "a date, like {}",
+            format_date_items(&example_date(), &items)
+        ),
+    })
+}
+
+/// Formats a date in a format for `parse_date_with_format`. Months and
+/// days are always formatted with two digits.
+///
+/// # Panics
+///
+/// Panics if the format isn't one that `parse_date_with_format` takes.
+pub fn format_date_with_format(value: &Date, format: &str) -> String {
+    format_date_items(value, &date_format_items(format))
+}
+
+/// Parses a date like `parse_date`, but also accepts dates relative to
+/// today, for power users who'd rather type `+3d` than pick a date:
+///
+/// - `today`, `tomorrow` and `yesterday`.
+/// - A number of days or weeks from today, like `+3d`, `-1d` or `+2w`.
+/// - The next day of the week after today, like `next monday`.
+///
+/// Today is the date in UTC according to `clock`, so pass the form's
+/// clock to resolve relative dates against a `MockClock` in tests.
+/// Relative dates are invalid if the time can't be read.
+///
+/// Returns `ParseError::Required` if the input is empty after
+/// trimming.
+pub fn parse_relative_date(value: &str, clock: &FormClock) -> Result<Date, ParseError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(ParseError::Required);
+    }
+    parse_date_parts(value)
+        .or_else(|| parse_relative_date_parts(&value.to_lowercase(), today(clock)?))
+        .ok_or_else(|| ParseError::InvalidFormat {
+            required_type: "a date, like 2021-12-31 or +3d".to_string(),
+        })
+}
+
+/// Replaces a relative date that `parse_relative_date` accepts with the
+/// date that it means, formatted like `format_date`, so that it can be
+/// set on a date input. Date inputs then only ever hold absolute dates.
+/// Anything else is returned as it is, so that the input can show why
+/// it's invalid.
+///
+/// ```
+/// # use std::time::Duration;
+/// let clock = structform::FormClock::new(structform::MockClock::new(Duration::from_secs(
+///     1_640_908_800, // 2021-12-31
+/// )));
+/// assert_eq!(structform::resolve_relative_date("tomorrow", &clock), "2022-01-01");
+/// assert_eq!(structform::resolve_relative_date("soon", &clock), "soon");
+/// ```
+pub fn resolve_relative_date(value: &str, clock: &FormClock) -> String {
+    match parse_relative_date(value, clock) {
+        Ok(date) => format_date(&date),
+        Err(_) => value.to_string(),
+    }
+}
+
+/// The Julian day number of 1970-01-01, the Unix epoch.
+const UNIX_EPOCH_JULIAN_DAY: i64 = 2_440_588;
+
+fn today(clock: &FormClock) -> Option<Date> {
+    let days_since_epoch = (clock.now()?.as_secs() / 86_400) as i64;
+    Date::from_julian_day(i32::try_from(UNIX_EPOCH_JULIAN_DAY + days_since_epoch).ok()?).ok()
+}
+
+fn parse_relative_date_parts(value: &str, today: Date) -> Option<Date> {
+    let days = match value {
+        "today" => 0,
+        "tomorrow" => 1,
+        "yesterday" => -1,
+        _ => {
+            if let Some(weekday) = value.strip_prefix("next ") {
+                let weekday = parse_weekday(weekday.trim())?;
+                let days_ahead = (weekday.number_days_from_monday() + 7
+                    - today.weekday().number_days_from_monday())
+                    % 7;
+                if days_ahead == 0 {
+                    7
+                } else {
+                    i64::from(days_ahead)
+                }
+            } else {
+                let (sign, offset) = match value.strip_prefix('+') {
+                    Some(offset) => (1, offset),
+                    None => (-1, value.strip_prefix('-')?),
+                };
+                let (count, days_per_unit) = match offset.strip_suffix('d') {
+                    Some(count) => (count, 1),
+                    None => (offset.strip_suffix('w')?, 7),
+                };
+                sign * i64::from(parse_digits(count, 1..=5)?) * days_per_unit
+            }
+        }
+    };
+    today.checked_add(Duration::days(days))
+}
+
+fn parse_weekday(value: &str) -> Option<Weekday> {
+    Some(match value {
+        "monday" => Weekday::Monday,
+        "tuesday" => Weekday::Tuesday,
+        "wednesday" => Weekday::Wednesday,
+        "thursday" => Weekday::Thursday,
+        "friday" => Weekday::Friday,
+        "saturday" => Weekday::Saturday,
+        "sunday" => Weekday::Sunday,
+        _ => return None,
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateFormatItem {
+    Year,
+    Month,
+    Day,
+    Literal(char),
+}
+
+fn date_format_items(format: &str) -> Vec<DateFormatItem> {
+    let mut items = Vec::new();
+    let mut chars = format.chars();
+    while let Some(c) = chars.next() {
+        items.push(match c {
+            '%' => match chars.next() {
+                Some('Y') => DateFormatItem::Year,
+                Some('m') => DateFormatItem::Month,
+                Some('d') => DateFormatItem::Day,
+                Some('%') => DateFormatItem::Literal('%'),
+                _ => panic!(
+                    "The date format {:?} may only use %Y, %m, %d and %%",
+                    format
+                ),
+            },
+            c => DateFormatItem::Literal(c),
+        });
+    }
+    for item in &[
+        DateFormatItem::Year,
+        DateFormatItem::Month,
+        DateFormatItem::Day,
+    ] {
+        if !items.contains(item) {
+            panic!("The date format {:?} needs each of %Y, %m and %d", format);
+        }
+    }
+    items
+}
+
+fn parse_date_format_items(value: &str, items: &[DateFormatItem]) -> Option<Date> {
+    let (mut year, mut month, mut day) = (0, 0, 0);
+    let mut rest = value;
+    for item in items {
+        match item {
+            DateFormatItem::Literal(c) => rest = rest.strip_prefix(*c)?,
+            DateFormatItem::Year => {
+                let (digits, after) = split_digits(rest, 4);
+                year = parse_digits(digits, 4..=4)?;
+                rest = after;
+            }
+            DateFormatItem::Month => {
+                let (digits, after) = split_digits(rest, 2);
+                month = parse_digits(digits, 1..=2)?;
+                rest = after;
+            }
+            DateFormatItem::Day => {
+                let (digits, after) = split_digits(rest, 2);
+                day = parse_digits(digits, 1..=2)?;
+                rest = after;
+            }
+        }
+    }
+    if !rest.is_empty() {
+        return None;
+    }
+    Date::from_calendar_date(year as i32, Month::try_from(month as u8).ok()?, day as u8).ok()
+}
+
+fn format_date_items(value: &Date, items: &[DateFormatItem]) -> String {
+    let mut formatted = String::new();
+    for item in items {
+        match item {
+            DateFormatItem::Literal(c) => formatted.push(*c),
+            DateFormatItem::Year => formatted.push_str(&format!("{:04}", value.year())),
+            DateFormatItem::Month => formatted.push_str(&format!("{:02}", u8::from(value.month()))),
+            DateFormatItem::Day => formatted.push_str(&format!("{:02}", value.day())),
+        }
+    }
+    formatted
+}
+
+/// The date that error messages use to show the format that's needed.
+fn example_date() -> Date {
+    Date::from_calendar_date(2021, Month::December, 31).expect("2021-12-31 is a valid date")
+}
+
+/// Splits off up to `max_len` ASCII digits from the start of `value`.
+fn split_digits(value: &str, max_len: usize) -> (&str, &str) {
+    let len = value
+        .bytes()
+        .take(max_len)
+        .take_while(u8::is_ascii_digit)
+        .count();
+    value.split_at(len)
+}
+
+fn parse_date_parts(value: &str) -> Option<Date> {
+    let mut parts = value.splitn(3, '-');
+    let year = parse_digits(parts.next()?, 4..=4)?;
+    let month = parse_digits(parts.next()?, 2..=2)?;
+    let day = parse_digits(parts.next()?, 2..=2)?;
+    Date::from_calendar_date(year as i32, Month::try_from(month as u8).ok()?, day as u8).ok()
+}
+
+fn parse_time_parts(value: &str) -> Option<Time> {
+    let mut parts = value.splitn(3, ':');
+    let hour = parse_digits(parts.next()?, 2..=2)?;
+    let minute = parse_digits(parts.next()?, 2..=2)?;
+    let (second, nanosecond) = match parts.next() {
+        None => (0, 0),
+        Some(seconds) => match seconds.split_once('.') {
+            None => (parse_digits(seconds, 2..=2)?, 0),
+            Some((second, fraction)) => {
+                let nanosecond =
+                    parse_digits(fraction, 1..=9)? * 10u32.pow(9 - fraction.len() as u32);
+                (parse_digits(second, 2..=2)?, nanosecond)
+            }
+        },
+    };
+    Time::from_hms_nano(hour as u8, minute as u8, second as u8, nanosecond).ok()
+}
+
+fn parse_digits(value: &str, len: std::ops::RangeInclusive<usize>) -> Option<u32> {
+    if len.contains(&value.len()) && value.bytes().all(|byte| byte.is_ascii_digit()) {
+        value.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Implements `ParseAndFormat<Date> for $input<Date>`, and also
+/// implements `ParseAndFormat<Option<Date>> for $input<Option<Date>>`,
+/// for `<input type="date">`. The types are from version 0.3 of the `time`
+/// crate.
+///
+/// This will parse using `structform::parse_date`. If the input string
+/// is empty after trimming, then parse will return a
+/// `ParseError::Required` for the `ParseAndFormat<Date>` case, and
+/// return `None` for the `ParseAndFormat<Option<Date>>` case.
+///
+/// For targets without HTML date inputs, a format like `"%d/%m/%Y"` can
+/// be given after the input, to parse with
+/// `structform::parse_date_with_format` and format with
+/// `structform::format_date_with_format` instead.
+#[macro_export]
+macro_rules! impl_date_input {
+    ($date_input: ident) => {
+        structform::impl_date_input!(
+            @impl $date_input,
+            structform::parse_date,
+            structform::format_date
+        );
+    };
+    ($date_input: ident, $format: expr) => {
+        structform::impl_date_input!(
+            @impl $date_input,
+            |value| structform::parse_date_with_format(value, $format),
+            |value| structform::format_date_with_format(value, $format)
+        );
+    };
+    (@impl $date_input: ident, $parse: expr, $format: expr) => {
+        impl structform::ParseAndFormat<structform::__time::Date>
+            for $date_input<structform::__time::Date>
+        {
+            fn parse(value: &str) -> Result<structform::__time::Date, structform::ParseError> {
+                ($parse)(value)
+            }
+
+            fn format(value: &structform::__time::Date) -> String {
+                ($format)(value)
+            }
+        }
+
+        impl structform::ParseAndFormat<Option<structform::__time::Date>>
+            for $date_input<Option<structform::__time::Date>>
+        {
+            fn parse(
+                value: &str,
+            ) -> Result<Option<structform::__time::Date>, structform::ParseError> {
+                match ($parse)(value) {
+                    Ok(value) => Ok(Some(value)),
+                    Err(structform::ParseError::Required) => Ok(None),
+                    Err(e) => Err(e),
+                }
+            }
+
+            fn format(value: &Option<structform::__time::Date>) -> String {
+                value
+                    .as_ref()
+                    .map(|value| ($format)(value))
+                    .unwrap_or_default()
+            }
+        }
+    };
+}
+
+/// Implements `ParseAndFormat<Time> for $input<Time>`, and also
+/// implements `ParseAndFormat<Option<Time>> for $input<Option<Time>>`,
+/// for `<input type="time">`. The types are from version 0.3 of the `time`
+/// crate.
+///
+/// This will parse using `structform::parse_time`. If the input string
+/// is empty after trimming, then parse will return a
+/// `ParseError::Required` for the `ParseAndFormat<Time>` case, and
+/// return `None` for the `ParseAndFormat<Option<Time>>` case.
+#[macro_export]
+macro_rules! impl_time_input {
+    ($time_input: ident) => {
+        impl structform::ParseAndFormat<structform::__time::Time>
+            for $time_input<structform::__time::Time>
+        {
+            fn parse(value: &str) -> Result<structform::__time::Time, structform::ParseError> {
+                structform::parse_time(value)
+            }
+
+            fn format(value: &structform::__time::Time) -> String {
+                structform::format_time(value)
+            }
+        }
+
+        impl structform::ParseAndFormat<Option<structform::__time::Time>>
+            for $time_input<Option<structform::__time::Time>>
+        {
+            fn parse(
+                value: &str,
+            ) -> Result<Option<structform::__time::Time>, structform::ParseError> {
+                match structform::parse_time(value) {
+                    Ok(value) => Ok(Some(value)),
+                    Err(structform::ParseError::Required) => Ok(None),
+                    Err(e) => Err(e),
+                }
+            }
+
+            fn format(value: &Option<structform::__time::Time>) -> String {
+                value
+                    .as_ref()
+                    .map(structform::format_time)
+                    .unwrap_or_default()
+            }
+        }
+    };
+}
+
+/// Implements `ParseAndFormat<PrimitiveDateTime> for $input<PrimitiveDateTime>`, and also
+/// implements `ParseAndFormat<Option<PrimitiveDateTime>> for $input<Option<PrimitiveDateTime>>`,
+/// for `<input type="datetime-local">`. The types are from version 0.3 of the `time`
+/// crate.
+///
+/// This will parse using `structform::parse_datetime`. If the input string
+/// is empty after trimming, then parse will return a
+/// `ParseError::Required` for the `ParseAndFormat<PrimitiveDateTime>` case, and
+/// return `None` for the `ParseAndFormat<Option<PrimitiveDateTime>>` case.
+#[macro_export]
+macro_rules! impl_datetime_input {
+    ($datetime_input: ident) => {
+        impl structform::ParseAndFormat<structform::__time::PrimitiveDateTime>
+            for $datetime_input<structform::__time::PrimitiveDateTime>
+        {
+            fn parse(
+                value: &str,
+            ) -> Result<structform::__time::PrimitiveDateTime, structform::ParseError> {
+                structform::parse_datetime(value)
+            }
+
+            fn format(value: &structform::__time::PrimitiveDateTime) -> String {
+                structform::format_datetime(value)
+            }
+        }
+
+        impl structform::ParseAndFormat<Option<structform::__time::PrimitiveDateTime>>
+            for $datetime_input<Option<structform::__time::PrimitiveDateTime>>
+        {
+            fn parse(
+                value: &str,
+            ) -> Result<Option<structform::__time::PrimitiveDateTime>, structform::ParseError> {
+                match structform::parse_datetime(value) {
+                    Ok(value) => Ok(Some(value)),
+                    Err(structform::ParseError::Required) => Ok(None),
+                    Err(e) => Err(e),
+                }
+            }
+
+            fn format(value: &Option<structform::__time::PrimitiveDateTime>) -> String {
+                value
+                    .as_ref()
+                    .map(structform::format_datetime)
+                    .unwrap_or_default()
+            }
+        }
+    };
+}
@@ -0,0 +1,79 @@
+/// The UI state of an optional subform that is shown as an expandable
+/// section, like in an accordion.
+///
+/// Whether the subform exists and whether its section is expanded are
+/// separate. Collapsing a section hides the subform's inputs without
+/// losing what was typed into them, while removing it toggles the
+/// subform off. Methods that need the subform to be toggled on or off
+/// return the event to send with `StructForm::set_input`, so that the
+/// form's hooks, like `change_log`, still see the change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disclosure<Field> {
+    /// The optional subform's toggle field, like
+    /// `UserFormField::TogglePostalAddress`.
+    pub toggle: Field,
+    expanded: bool,
+}
+
+impl<Field: Clone> Disclosure<Field> {
+    /// A collapsed section for the optional subform toggled by
+    /// `toggle`.
+    pub fn new(toggle: Field) -> Disclosure<Field> {
+        Disclosure {
+            toggle,
+            expanded: false,
+        }
+    }
+
+    /// Whether the section should show its subform's inputs. This is
+    /// only true if the section is expanded and the subform is toggled
+    /// on.
+    pub fn is_expanded<Subform>(&self, subform: &Option<Subform>) -> bool {
+        self.expanded && subform.is_some()
+    }
+
+    /// Expands the section, returning the event that toggles the
+    /// subform on if it isn't already.
+    pub fn expand<Subform>(&mut self, subform: &Option<Subform>) -> Option<(Field, String)> {
+        self.expanded = true;
+        match subform {
+            Some(_) => None,
+            None => Some((self.toggle.clone(), String::new())),
+        }
+    }
+
+    /// Collapses the section. The subform, and what was typed into it,
+    /// is kept.
+    pub fn collapse(&mut self) {
+        self.expanded = false;
+    }
+
+    /// Expands the section if it is collapsed, and collapses it if it
+    /// is expanded, for when its header is clicked. See `expand`.
+    pub fn click<Subform>(&mut self, subform: &Option<Subform>) -> Option<(Field, String)> {
+        if self.is_expanded(subform) {
+            self.collapse();
+            None
+        } else {
+            self.expand(subform)
+        }
+    }
+
+    /// Collapses the section, returning the event that toggles the
+    /// subform off if it is on.
+    pub fn remove<Subform>(&mut self, subform: &Option<Subform>) -> Option<(Field, String)> {
+        self.collapse();
+        subform
+            .as_ref()
+            .map(|_| (self.toggle.clone(), String::new()))
+    }
+
+    /// The value of the section header's `aria-expanded` attribute.
+    pub fn aria_expanded<Subform>(&self, subform: &Option<Subform>) -> &'static str {
+        if self.is_expanded(subform) {
+            "true"
+        } else {
+            "false"
+        }
+    }
+}
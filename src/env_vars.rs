@@ -0,0 +1,70 @@
+use crate::{FieldKind, FieldMeta};
+
+/// The field path that an environment variable sets, like
+/// `smtp_port` for `MYAPP_SMTP_PORT` with the prefix `MYAPP`, or
+/// `relays[0].host` for `MYAPP_RELAYS_0_HOST`. Variable names are
+/// matched against a form's `FIELDS` table without case, so field
+/// names that contain underscores still resolve. Returns `None` if the
+/// variable doesn't have the prefix, or doesn't match an input.
+///
+/// ```
+/// use structform::{env_var_path, FieldKind, FieldMeta};
+///
+/// const FIELDS: &[FieldMeta] = &[FieldMeta {
+///     name: "smtp_port",
+///     label: "Smtp port",
+///     sensitive: false,
+///     kind: FieldKind::Input,
+///     value_type: "u16",
+/// }];
+///
+/// assert_eq!(env_var_path(FIELDS, "MYAPP", "MYAPP_SMTP_PORT"), Some("smtp_port".to_string()));
+/// assert_eq!(env_var_path(FIELDS, "MYAPP", "OTHER_SMTP_PORT"), None);
+/// ```
+pub fn env_var_path(fields: &'static [FieldMeta], prefix: &str, name: &str) -> Option<String> {
+    let prefix = prefix.trim_end_matches('_');
+    let rest = strip_prefix_ignore_case(name, prefix)?.strip_prefix('_')?;
+    resolve(fields, rest)
+}
+
+/// The field paths and values of the environment variables that match
+/// a form's inputs. See `env_var_path`.
+pub fn env_vars_by_path<I>(
+    fields: &'static [FieldMeta],
+    prefix: &str,
+    vars: I,
+) -> Vec<(String, String)>
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    vars.into_iter()
+        .filter_map(|(name, value)| env_var_path(fields, prefix, &name).map(|path| (path, value)))
+        .collect()
+}
+
+fn resolve(fields: &'static [FieldMeta], name: &str) -> Option<String> {
+    fields.iter().find_map(|field| {
+        let rest = strip_prefix_ignore_case(name, field.name)?;
+        match field.kind {
+            FieldKind::Input if rest.is_empty() => Some(field.name.to_string()),
+            FieldKind::Subform(inner_fields) | FieldKind::OptionalSubform(inner_fields) => {
+                let inner = resolve(inner_fields, rest.strip_prefix('_')?)?;
+                Some(format!("{}.{}", field.name, inner))
+            }
+            FieldKind::ListSubform(inner_fields) => {
+                let (index, rest) = rest.strip_prefix('_')?.split_once('_')?;
+                let index: usize = index.parse().ok()?;
+                let inner = resolve(inner_fields, rest)?;
+                Some(format!("{}[{}].{}", field.name, index, inner))
+            }
+            _ => None,
+        }
+    })
+}
+
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    match s.get(..prefix.len()) {
+        Some(start) if start.eq_ignore_ascii_case(prefix) => Some(&s[prefix.len()..]),
+        _ => None,
+    }
+}
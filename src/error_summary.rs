@@ -0,0 +1,83 @@
+use crate::html::escape_html;
+use crate::{field_id, field_label, FieldMeta, ParseError, Theme};
+use std::fmt::Write;
+
+/// The "there is a problem" box at the top of a form, listing every
+/// error on the form with a link to its input.
+///
+/// Build one from a form's `FIELDS` table and its `errors_by_path`,
+/// or from errors given by a server. Each entry has the label of its
+/// input, and the `id` that the input should be rendered with, so
+/// that the entry's link jumps to it. `to_html` renders the summary
+/// for server rendered pages, and apps using a frontend framework can
+/// render the entries themselves. With the `web` feature,
+/// `focus_field` moves the focus to an entry's input when it is
+/// clicked.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ErrorSummary {
+    pub entries: Vec<ErrorSummaryEntry>,
+}
+
+/// An error in an `ErrorSummary`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorSummaryEntry {
+    /// The field path of the input with the error.
+    pub path: String,
+    /// The `id` of the input with the error. See `field_id`.
+    pub id: String,
+    /// See `field_label`. Paths that aren't in the form's `FIELDS`
+    /// table use the path as the label.
+    pub label: String,
+    pub error: ParseError,
+}
+
+impl ErrorSummary {
+    pub fn new<I>(fields: &'static [FieldMeta], errors: I) -> ErrorSummary
+    where
+        I: IntoIterator<Item = (String, ParseError)>,
+    {
+        ErrorSummary {
+            entries: errors
+                .into_iter()
+                .map(|(path, error)| ErrorSummaryEntry {
+                    id: field_id(&path),
+                    label: field_label(fields, &path).unwrap_or_else(|| path.clone()),
+                    path,
+                    error,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Renders the summary as a list of links to the inputs with
+    /// errors, using the theme's `error_class`. This is empty if there
+    /// are no errors, so it can always be put at the top of the form.
+    pub fn to_html(&self, theme: &dyn Theme) -> String {
+        let mut html = String::new();
+        if self.is_empty() {
+            return html;
+        }
+        writeln!(
+            html,
+            "<ul class=\"{}\" role=\"alert\">",
+            escape_html(theme.error_class())
+        )
+        .unwrap();
+        for entry in &self.entries {
+            writeln!(
+                html,
+                "<li><a href=\"#{}\">{}: {}</a></li>",
+                escape_html(&entry.id),
+                escape_html(&entry.label),
+                escape_html(&entry.error.to_string())
+            )
+            .unwrap();
+        }
+        html.push_str("</ul>\n");
+        html
+    }
+}
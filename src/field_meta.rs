@@ -0,0 +1,178 @@
+use crate::{split_field_path, split_map_key};
+
+/// The table of a form's fields, which `#[derive(StructForm)]`
+/// implements for every form. It is a trait, rather than a constant on
+/// the form itself, so that it can't clash with the form's own items.
+pub trait FormFields {
+    /// The form's inputs and subforms, in the order that they are
+    /// declared. See `FieldMeta`.
+    const FIELDS: &'static [FieldMeta];
+}
+
+/// The variants of a form over an enum model, which
+/// `#[derive(StructForm)]` implements for enum forms, for rendering
+/// the variant selector. Like `FormFields`, it's a trait so that it
+/// can't clash with the form's own items.
+pub trait FormVariants {
+    /// The value and label of every variant, in the order that they
+    /// are declared.
+    const VARIANTS: &'static [(&'static str, &'static str)];
+
+    /// The value of the selected variant, which is set with the
+    /// `Variant` field.
+    fn variant(&self) -> &'static str;
+
+    /// Switches to the variant with `value` as its value, starting it
+    /// with an empty subform. Selecting the variant that's already
+    /// selected keeps its inputs. Returns false if no variant has that
+    /// value.
+    fn select_variant(&mut self, value: &str) -> bool;
+}
+
+/// A field of a form, from its `FormFields::FIELDS` table. The table is
+/// a `static` slice, so looking up labels while rendering doesn't
+/// allocate.
+///
+/// Only inputs and subforms are listed. Fields like honeypots and
+/// change logs aren't part of the form's model, so they are left
+/// out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldMeta {
+    /// The field's name, which is its segment of a field path.
+    pub name: &'static str,
+    /// See `StructForm::summary` for how labels are chosen.
+    pub label: &'static str,
+    /// Whether the field is marked with `#[structform(sensitive)]`.
+    pub sensitive: bool,
+    pub kind: FieldKind,
+    /// The Rust type that an input parses into, as it's written on the
+    /// form without spaces, like `u32` or `Option<String>`. This is
+    /// empty for subforms.
+    pub value_type: &'static str,
+}
+
+/// What kind of field a `FieldMeta` describes. Subforms carry the
+/// `FIELDS` table of their form. Map subforms are keyed by a segment
+/// of the path, like `translations.en.title`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Input,
+    Subform(&'static [FieldMeta]),
+    OptionalSubform(&'static [FieldMeta]),
+    ListSubform(&'static [FieldMeta]),
+    MapSubform(&'static [FieldMeta]),
+}
+
+/// Finds the field at a field path, like `addresses[0].city`, in a
+/// form's `FIELDS` table. Returns `None` if the path doesn't match a
+/// field.
+///
+/// ```
+/// use structform::{find_field, FieldKind, FieldMeta};
+///
+/// const ADDRESS_FIELDS: &[FieldMeta] = &[FieldMeta {
+///     name: "city",
+///     label: "City",
+///     sensitive: false,
+///     kind: FieldKind::Input,
+///     value_type: "String",
+/// }];
+/// const FIELDS: &[FieldMeta] = &[FieldMeta {
+///     name: "addresses",
+///     label: "Addresses",
+///     sensitive: false,
+///     kind: FieldKind::ListSubform(ADDRESS_FIELDS),
+///     value_type: "",
+/// }];
+///
+/// assert_eq!(find_field(FIELDS, "addresses[0].city"), Some(&ADDRESS_FIELDS[0]));
+/// assert_eq!(find_field(FIELDS, "addresses.city"), None);
+/// ```
+pub fn find_field(fields: &'static [FieldMeta], path: &str) -> Option<&'static FieldMeta> {
+    let (name, index, rest) = split_field_path(path)?;
+    let field = fields.iter().find(|field| field.name == name)?;
+    match (field.kind, index, rest) {
+        (_, None, "") => Some(field),
+        (FieldKind::ListSubform(inner_fields), Some(_), rest) if !rest.is_empty() => {
+            find_field(inner_fields, rest)
+        }
+        (FieldKind::Subform(inner_fields), None, rest)
+        | (FieldKind::OptionalSubform(inner_fields), None, rest) => find_field(inner_fields, rest),
+        (FieldKind::MapSubform(inner_fields), None, rest) => {
+            let (_, rest) = split_map_key::<String>(rest)?;
+            find_field(inner_fields, rest)
+        }
+        _ => None,
+    }
+}
+
+/// The label of the field at a field path, for showing the path to a
+/// user. The labels of the subforms on the path come first, like
+/// `Billing address - City`, list entries are numbered from one, like
+/// `Addresses 2 - City`, and map entries are named by their key, like
+/// `Translations en - Title`. Returns `None` if the path doesn't match
+/// a field.
+///
+/// ```
+/// use structform::{field_label, FieldKind, FieldMeta};
+///
+/// const ADDRESS_FIELDS: &[FieldMeta] = &[FieldMeta {
+///     name: "city",
+///     label: "City",
+///     sensitive: false,
+///     kind: FieldKind::Input,
+///     value_type: "String",
+/// }];
+/// const FIELDS: &[FieldMeta] = &[FieldMeta {
+///     name: "addresses",
+///     label: "Addresses",
+///     sensitive: false,
+///     kind: FieldKind::ListSubform(ADDRESS_FIELDS),
+///     value_type: "",
+/// }];
+///
+/// assert_eq!(field_label(FIELDS, "addresses[1].city"), Some("Addresses 2 - City".to_string()));
+/// assert_eq!(field_label(FIELDS, "addresses.city"), None);
+/// ```
+pub fn field_label(fields: &'static [FieldMeta], path: &str) -> Option<String> {
+    let (name, index, rest) = split_field_path(path)?;
+    let field = fields.iter().find(|field| field.name == name)?;
+    let label = match index {
+        Some(i) => format!("{} {}", field.label, i + 1),
+        None => field.label.to_string(),
+    };
+    let inner_label = match (field.kind, index, rest) {
+        (_, None, "") => return Some(label),
+        (FieldKind::ListSubform(inner_fields), Some(_), rest) if !rest.is_empty() => {
+            field_label(inner_fields, rest)
+        }
+        (FieldKind::Subform(inner_fields), None, rest)
+        | (FieldKind::OptionalSubform(inner_fields), None, rest) => field_label(inner_fields, rest),
+        (FieldKind::MapSubform(inner_fields), None, rest) => {
+            let (key, rest) = split_map_key::<String>(rest)?;
+            let inner_label = field_label(inner_fields, rest)?;
+            return Some(format!("{} {} - {}", label, key, inner_label));
+        }
+        _ => None,
+    }?;
+    Some(format!("{} - {}", label, inner_label))
+}
+
+/// A field of a form, from `StructForm::fields`, for renderers that
+/// walk a form's structure instead of hand coding every field.
+///
+/// Inputs are listed with their field, including the inputs of
+/// subforms and optional subforms, nested under the subform's field.
+/// An optional subform is listed with its toggle field before its
+/// inputs, which only exist while it's toggled on. A list of subforms
+/// is listed with the field that adds an entry. The inputs of its
+/// entries depend on how many there are, so they aren't listed, but
+/// can be found with the `fields` function of the list's subform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormField<Field> {
+    /// The field path, like `address.city`.
+    pub path: String,
+    pub field: Field,
+    /// The field's entry in its form's `FIELDS` table.
+    pub meta: &'static FieldMeta,
+}
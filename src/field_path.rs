@@ -0,0 +1,207 @@
+/// Splits the first segment off of a field path, like
+/// `addresses[0].city`.
+///
+/// Returns the field name, the list index if the segment has one, and
+/// the rest of the path after the segment (which is empty for the
+/// last segment). Returns `None` if the path is malformed. A `.` or
+/// `[` escaped with a `\`, like in a map key from `escape_map_key`,
+/// doesn't end the segment, and is left escaped.
+///
+/// ```
+/// assert_eq!(structform::split_field_path("username"), Some(("username", None, "")));
+/// assert_eq!(structform::split_field_path("addresses[0].city"), Some(("addresses", Some(0), "city")));
+/// assert_eq!(structform::split_field_path("billing.city"), Some(("billing", None, "city")));
+/// assert_eq!(structform::split_field_path(r"en\.us.title"), Some((r"en\.us", None, "title")));
+/// ```
+pub fn split_field_path(path: &str) -> Option<(&str, Option<usize>, &str)> {
+    let name_end = find_unescaped(path, &['.', '[']).unwrap_or(path.len());
+    let name = &path[..name_end];
+    if name.is_empty() {
+        return None;
+    }
+
+    let mut rest = &path[name_end..];
+    let mut index = None;
+    if let Some(after_bracket) = rest.strip_prefix('[') {
+        let index_end = after_bracket.find(']')?;
+        index = Some(after_bracket[..index_end].parse().ok()?);
+        rest = &after_bracket[index_end + 1..];
+    }
+
+    if rest.is_empty() {
+        Some((name, index, rest))
+    } else {
+        rest.strip_prefix('.')
+            .filter(|rest| !rest.is_empty())
+            .map(|rest| (name, index, rest))
+    }
+}
+
+/// The byte index of the first of `chars` in `path` that isn't escaped
+/// with a `\`.
+fn find_unescaped(path: &str, chars: &[char]) -> Option<usize> {
+    let mut escaped = false;
+    for (i, c) in path.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if chars.contains(&c) {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// Splits the key of a map entry off of the rest of a field path, like
+/// `en.title` in `translations.en.title`, and parses it. Returns
+/// `None` if the key doesn't parse, or if there is nothing after it.
+/// Keys escaped with `escape_map_key` are unescaped before they're
+/// parsed.
+///
+/// ```
+/// assert_eq!(structform::split_map_key::<String>("en.title"), Some(("en".to_string(), "title")));
+/// assert_eq!(structform::split_map_key::<String>(r"en\.us.title"), Some(("en.us".to_string(), "title")));
+/// assert_eq!(structform::split_map_key::<u32>("en.title"), None);
+/// assert_eq!(structform::split_map_key::<String>("en"), None);
+/// ```
+pub fn split_map_key<K: std::str::FromStr>(path: &str) -> Option<(K, &str)> {
+    match split_field_path(path)? {
+        (key, None, rest) if !rest.is_empty() => Some((unescape_map_key(key).parse().ok()?, rest)),
+        _ => None,
+    }
+}
+
+/// Writes a map key for a field path, like the `en` in
+/// `translations.en.title`. A `.`, `[`, `]` or `\` in the key is
+/// escaped with a `\`, so that keys like `en.us` can be split off
+/// again by `split_map_key`.
+///
+/// ```
+/// assert_eq!(structform::escape_map_key("en"), "en");
+/// assert_eq!(structform::escape_map_key("en.us"), r"en\.us");
+/// ```
+pub fn escape_map_key<K: std::fmt::Display + ?Sized>(key: &K) -> String {
+    let key = key.to_string();
+    let mut escaped = String::with_capacity(key.len());
+    for c in key.chars() {
+        if matches!(c, '.' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// The reverse of `escape_map_key`.
+pub(crate) fn unescape_map_key(key: &str) -> String {
+    let mut unescaped = String::with_capacity(key.len());
+    let mut chars = key.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            c => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+/// An HTML `id` for the input at a field path, so that links and
+/// labels can refer to it. Closing brackets are dropped, and other
+/// characters that aren't letters, digits, `-` or `_` become `-`. The
+/// id is prefixed with `structform-` so that it doesn't clash with the
+/// app's own ids.
+///
+/// ```
+/// assert_eq!(structform::field_id("addresses[0].city"), "structform-addresses-0-city");
+/// ```
+pub fn field_id(path: &str) -> String {
+    let mut id = "structform-".to_string();
+    id.extend(path.chars().filter(|c| *c != ']').map(|c| {
+        if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+            c
+        } else {
+            '-'
+        }
+    }));
+    id
+}
+
+/// Splits an `application/x-www-form-urlencoded` string, like a URL's
+/// query string, into decoded keys and values. A leading `?` is
+/// ignored.
+pub fn parse_query_string(query: &str) -> Vec<(String, String)> {
+    query
+        .trim_start_matches('?')
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let mut parts = pair.splitn(2, '=');
+            let key = parts.next().unwrap_or("");
+            let value = parts.next().unwrap_or("");
+            (decode_query_component(key), decode_query_component(value))
+        })
+        .collect()
+}
+
+/// Encodes keys and values as an `application/x-www-form-urlencoded`
+/// string, which can be used as a URL's query string (without the
+/// leading `?`).
+pub fn to_query_string<'a, I>(pairs: I) -> String
+where
+    I: IntoIterator<Item = &'a (String, String)>,
+{
+    pairs
+        .into_iter()
+        .map(|(key, value)| {
+            format!(
+                "{}={}",
+                encode_query_component(key),
+                encode_query_component(value)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+fn encode_query_component(component: &str) -> String {
+    let mut encoded = String::with_capacity(component.len());
+    for byte in component.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            b' ' => encoded.push('+'),
+            byte => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+fn decode_query_component(component: &str) -> String {
+    let bytes = component.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'+' => decoded.push(b' '),
+            b'%' => {
+                match bytes
+                    .get(i + 1..i + 3)
+                    .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                {
+                    Some(byte) => {
+                        decoded.push(byte);
+                        i += 2;
+                    }
+                    None => decoded.push(b'%'),
+                }
+            }
+            byte => decoded.push(byte),
+        }
+        i += 1;
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
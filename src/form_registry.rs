@@ -0,0 +1,133 @@
+use crate::{DynStructForm, ErasedForm, StructForm};
+use std::collections::BTreeMap;
+
+/// Forms that are opened and closed while an app runs, stored by key,
+/// like edit panels for rows of a table that are edited inline.
+///
+/// Forms are created from their model with `open`, and `dirty_keys`
+/// lists the ones that have been changed since they were opened or
+/// last marked as saved.
+pub struct FormRegistry<K> {
+    forms: BTreeMap<K, RegisteredForm>,
+}
+
+struct RegisteredForm {
+    form: Box<dyn DynStructForm>,
+    saved_inputs: Vec<(String, String)>,
+}
+
+impl<K> Default for FormRegistry<K>
+where
+    K: Ord,
+{
+    fn default() -> FormRegistry<K> {
+        FormRegistry {
+            forms: BTreeMap::new(),
+        }
+    }
+}
+
+impl<K> FormRegistry<K>
+where
+    K: Ord,
+{
+    pub fn new() -> FormRegistry<K> {
+        FormRegistry::default()
+    }
+
+    /// Creates a form from `model` under `key`. If a form is already
+    /// open under `key`, it is left as it is, so that edits aren't
+    /// lost. Returns true if a new form was created.
+    pub fn open<F, Model>(&mut self, key: K, model: &Model) -> bool
+    where
+        F: StructForm<Model> + Clone + 'static,
+        Model: 'static,
+    {
+        if self.forms.contains_key(&key) {
+            return false;
+        }
+        let form = ErasedForm::<F, Model>::boxed(F::new(model));
+        let saved_inputs = form.inputs_by_path();
+        self.forms
+            .insert(key, RegisteredForm { form, saved_inputs });
+        true
+    }
+
+    /// Removes the form under `key`, discarding any changes. Returns
+    /// true if there was a form to remove.
+    pub fn close(&mut self, key: &K) -> bool {
+        self.forms.remove(key).is_some()
+    }
+
+    pub fn contains(&self, key: &K) -> bool {
+        self.forms.contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.forms.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.forms.is_empty()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.forms.keys()
+    }
+
+    pub fn get(&self, key: &K) -> Option<&dyn DynStructForm> {
+        self.forms
+            .get(key)
+            .map(|registered| registered.form.as_ref())
+    }
+
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut dyn DynStructForm> {
+        match self.forms.get_mut(key) {
+            Some(registered) => Some(registered.form.as_mut()),
+            None => None,
+        }
+    }
+
+    /// The form under `key`, if it has the type `F`.
+    pub fn form<F: 'static>(&self, key: &K) -> Option<&F> {
+        self.get(key)
+            .and_then(|form| form.as_any().downcast_ref::<F>())
+    }
+
+    /// The form under `key`, if it has the type `F`.
+    pub fn form_mut<F: 'static>(&mut self, key: &K) -> Option<&mut F> {
+        self.get_mut(key)
+            .and_then(|form| form.as_any_mut().downcast_mut::<F>())
+    }
+
+    /// Whether the form under `key` has inputs that changed since it
+    /// was opened or last marked as saved.
+    pub fn is_dirty(&self, key: &K) -> bool {
+        self.forms
+            .get(key)
+            .map(RegisteredForm::is_dirty)
+            .unwrap_or(false)
+    }
+
+    /// The keys of every form with changes that haven't been saved.
+    pub fn dirty_keys(&self) -> impl Iterator<Item = &K> {
+        self.forms
+            .iter()
+            .filter(|(_, registered)| registered.is_dirty())
+            .map(|(key, _)| key)
+    }
+
+    /// Records that the form under `key` has been saved, so it is no
+    /// longer dirty until its next change.
+    pub fn mark_saved(&mut self, key: &K) {
+        if let Some(registered) = self.forms.get_mut(key) {
+            registered.saved_inputs = registered.form.inputs_by_path();
+        }
+    }
+}
+
+impl RegisteredForm {
+    fn is_dirty(&self) -> bool {
+        self.form.inputs_by_path() != self.saved_inputs
+    }
+}
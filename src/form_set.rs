@@ -0,0 +1,202 @@
+use crate::{ParseError, StructForm};
+use std::any::Any;
+use std::marker::PhantomData;
+
+/// The parts of `StructForm` that don't depend on the model type, so
+/// that forms for different models can be kept together, like in a
+/// `FormSet`.
+pub trait DynStructForm {
+    fn set_input_at_path(&mut self, path: &str, value: String) -> bool;
+    fn set_error_at_path(&mut self, path: &str, error: ParseError) -> bool;
+    fn inputs_by_path(&self) -> Vec<(String, String)>;
+    fn is_empty(&self) -> bool;
+    /// The first error that submitting the form would return, without
+    /// showing validation messages on the form.
+    fn validation_error(&self) -> Option<ParseError>;
+    /// Submits the form, returning the model as a `Box<dyn Any>`.
+    fn submit_any(&mut self) -> Result<Box<dyn Any>, ParseError>;
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+/// Wraps a form to implement `DynStructForm`. This is needed because a
+/// form type could implement `StructForm` for more than one model.
+pub struct ErasedForm<F, Model> {
+    form: F,
+    model: PhantomData<fn() -> Model>,
+}
+
+impl<F, Model> ErasedForm<F, Model>
+where
+    F: StructForm<Model> + 'static,
+    Model: 'static,
+{
+    pub fn new(form: F) -> ErasedForm<F, Model> {
+        ErasedForm {
+            form,
+            model: PhantomData,
+        }
+    }
+
+    pub fn boxed(form: F) -> Box<dyn DynStructForm> {
+        Box::new(ErasedForm::new(form))
+    }
+}
+
+impl<F, Model> DynStructForm for ErasedForm<F, Model>
+where
+    F: StructForm<Model> + 'static,
+    Model: 'static,
+{
+    fn set_input_at_path(&mut self, path: &str, value: String) -> bool {
+        self.form.set_input_at_path(path, value)
+    }
+
+    fn set_error_at_path(&mut self, path: &str, error: ParseError) -> bool {
+        self.form.set_error_at_path(path, error)
+    }
+
+    fn inputs_by_path(&self) -> Vec<(String, String)> {
+        self.form.inputs_by_path()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.form.is_empty()
+    }
+
+    fn validation_error(&self) -> Option<ParseError> {
+        self.form.parse().err()
+    }
+
+    fn submit_any(&mut self) -> Result<Box<dyn Any>, ParseError> {
+        self.form
+            .submit()
+            .map(|model| Box::new(model) as Box<dyn Any>)
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        &self.form
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        &mut self.form
+    }
+}
+
+/// Several independent forms on the same page, which are saved
+/// together, like a settings page with profile, password and
+/// notification forms and one save button.
+///
+/// Each form is added with a name. Field paths on the set start with
+/// the form's name, like `profile.display_name`.
+#[derive(Default)]
+pub struct FormSet {
+    forms: Vec<(String, Box<dyn DynStructForm>)>,
+}
+
+impl FormSet {
+    pub fn new() -> FormSet {
+        FormSet::default()
+    }
+
+    pub fn add<F, Model>(&mut self, name: &str, form: F)
+    where
+        F: StructForm<Model> + 'static,
+        Model: 'static,
+    {
+        self.forms
+            .push((name.to_string(), ErasedForm::<F, Model>::boxed(form)));
+    }
+
+    /// The form added as `name`, if it has the type `F`.
+    pub fn form<F: 'static>(&self, name: &str) -> Option<&F> {
+        self.find(name)
+            .and_then(|form| form.as_any().downcast_ref::<F>())
+    }
+
+    /// The form added as `name`, if it has the type `F`.
+    pub fn form_mut<F: 'static>(&mut self, name: &str) -> Option<&mut F> {
+        self.forms
+            .iter_mut()
+            .find(|(form_name, _)| form_name == name)
+            .and_then(|(_, form)| form.as_any_mut().downcast_mut::<F>())
+    }
+
+    /// Sets an input using a field path that starts with the form's
+    /// name, like `profile.display_name`.
+    pub fn set_input_at_path(&mut self, path: &str, value: String) -> bool {
+        match crate::split_field_path(path) {
+            Some((name, None, rest)) if !rest.is_empty() => self
+                .forms
+                .iter_mut()
+                .find(|(form_name, _)| form_name == name)
+                .map(|(_, form)| form.set_input_at_path(rest, value))
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.forms
+            .iter()
+            .all(|(_, form)| form.validation_error().is_none())
+    }
+
+    /// The first validation error of each form that isn't valid, keyed
+    /// by the form's name.
+    pub fn errors(&self) -> Vec<(String, ParseError)> {
+        self.forms
+            .iter()
+            .filter_map(|(name, form)| form.validation_error().map(|error| (name.clone(), error)))
+            .collect()
+    }
+
+    /// Submits every form, so that they all show their validation
+    /// messages. If they are all valid, the models can be taken from
+    /// the result with `SubmittedForms::take`. Otherwise, the error of
+    /// each form that failed is returned, keyed by the form's name.
+    pub fn submit_all(&mut self) -> Result<SubmittedForms, Vec<(String, ParseError)>> {
+        let mut models = Vec::new();
+        let mut errors = Vec::new();
+        for (name, form) in &mut self.forms {
+            match form.submit_any() {
+                Ok(model) => models.push((name.clone(), model)),
+                Err(error) => errors.push((name.clone(), error)),
+            }
+        }
+        if errors.is_empty() {
+            Ok(SubmittedForms { models })
+        } else {
+            Err(errors)
+        }
+    }
+
+    fn find(&self, name: &str) -> Option<&dyn DynStructForm> {
+        self.forms
+            .iter()
+            .find(|(form_name, _)| form_name == name)
+            .map(|(_, form)| form.as_ref())
+    }
+}
+
+/// The models from `FormSet::submit_all`.
+pub struct SubmittedForms {
+    models: Vec<(String, Box<dyn Any>)>,
+}
+
+impl SubmittedForms {
+    /// Takes the model of the form added as `name`, if it has the type
+    /// `Model`.
+    pub fn take<Model: 'static>(&mut self, name: &str) -> Option<Model> {
+        let index = self
+            .models
+            .iter()
+            .position(|(model_name, model)| model_name == name && model.is::<Model>())?;
+        self.models
+            .remove(index)
+            .1
+            .downcast::<Model>()
+            .ok()
+            .map(|model| *model)
+    }
+}
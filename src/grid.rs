@@ -0,0 +1,82 @@
+use crate::StructForm;
+
+/// A column of a `Grid`, which is one input of the list's subform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridColumn {
+    /// The field path of the input within each row, like `quantity`.
+    pub path: String,
+    pub label: String,
+}
+
+/// A list subform laid out as a table, for generating inline editing
+/// UIs like data tables. Each row is an entry in the list, and each
+/// column is an input of the subform.
+///
+/// Inputs are edited with `set_input_at_path` using `cell_path`, and
+/// rows are added, removed and moved with the list's `Add`, `Remove`
+/// and `Move` field variants.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid {
+    field: String,
+    columns: Vec<GridColumn>,
+    rows: Vec<Vec<String>>,
+}
+
+impl Grid {
+    /// Lays out the list subform `field`, like `line_items`, whose
+    /// entries are `rows`.
+    pub fn new<F, Model>(field: &str, rows: &[F]) -> Grid
+    where
+        F: StructForm<Model>,
+    {
+        let columns: Vec<GridColumn> = F::labels_by_path()
+            .into_iter()
+            .map(|(path, label)| GridColumn { path, label })
+            .collect();
+        let rows = rows
+            .iter()
+            .map(|row| {
+                let inputs = row.inputs_by_path();
+                columns
+                    .iter()
+                    .map(|column| {
+                        inputs
+                            .iter()
+                            .find(|(path, _)| path == &column.path)
+                            .map(|(_, input)| input.clone())
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect();
+        Grid {
+            field: field.to_string(),
+            columns,
+            rows,
+        }
+    }
+
+    pub fn columns(&self) -> &[GridColumn] {
+        &self.columns
+    }
+
+    pub fn row_count(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// The raw input in a cell, or `None` if the cell is out of range.
+    pub fn cell_input(&self, row: usize, column: usize) -> Option<&str> {
+        self.rows
+            .get(row)
+            .and_then(|cells| cells.get(column))
+            .map(String::as_str)
+    }
+
+    /// The field path of a cell on the whole form, like
+    /// `line_items[2].quantity`, for use with `set_input_at_path`.
+    pub fn cell_path(&self, row: usize, column: usize) -> Option<String> {
+        self.columns
+            .get(column)
+            .map(|column| format!("{}[{}].{}", self.field, row, column.path))
+    }
+}
@@ -0,0 +1,116 @@
+use crate::{escape_map_key, split_field_path, unescape_map_key};
+use serde_json::{Map, Value};
+
+/// Nests values keyed by field path into a JSON object that mirrors
+/// the structure of the form. Subforms and map entries become
+/// objects, and lists become arrays, so `addresses[0].city` becomes
+/// `{"addresses": [{"city": ...}]}`. Map keys that were escaped with
+/// `escape_map_key` are unescaped. Paths that are malformed are left
+/// out.
+///
+/// ```
+/// let json = structform::nest_field_paths(vec![
+///     ("username".to_string(), "joe".to_string()),
+///     ("addresses[0].city".to_string(), "JHB".to_string()),
+/// ]);
+/// assert_eq!(json.to_string(), r#"{"addresses":[{"city":"JHB"}],"username":"joe"}"#);
+///
+/// let json = structform::nest_field_paths(vec![
+///     (r"translations.en\.us.title".to_string(), "Kettle".to_string()),
+/// ]);
+/// assert_eq!(json.to_string(), r#"{"translations":{"en.us":{"title":"Kettle"}}}"#);
+/// assert_eq!(
+///     structform::flatten_field_paths(&json),
+///     vec![(r"translations.en\.us.title".to_string(), "Kettle".to_string())]
+/// );
+/// ```
+pub fn nest_field_paths<I>(values: I) -> Value
+where
+    I: IntoIterator<Item = (String, String)>,
+{
+    let mut root = Value::Object(Map::new());
+    for (path, value) in values {
+        insert_at_path(&mut root, &path, value);
+    }
+    root
+}
+
+fn insert_at_path(target: &mut Value, path: &str, value: String) {
+    let (name, index, rest) = match split_field_path(path) {
+        Some(segment) => segment,
+        None => return,
+    };
+    let object = match target {
+        Value::Object(object) => object,
+        _ => return,
+    };
+    let mut child = object.entry(unescape_map_key(name)).or_insert(Value::Null);
+
+    if let Some(index) = index {
+        if !child.is_array() {
+            *child = Value::Array(Vec::new());
+        }
+        let entries = child
+            .as_array_mut()
+            .expect("Just made sure this is an array");
+        if entries.len() <= index {
+            entries.resize(index + 1, Value::Null);
+        }
+        child = &mut entries[index];
+    }
+
+    if rest.is_empty() {
+        *child = Value::String(value);
+    } else {
+        if !child.is_object() {
+            *child = Value::Object(Map::new());
+        }
+        insert_at_path(child, rest, value);
+    }
+}
+
+/// The reverse of `nest_field_paths`. Flattens a JSON object into
+/// values keyed by field path. Strings are used as they are, and
+/// numbers and booleans are written the way JSON writes them, so
+/// hand-written files don't need to quote them. Object keys are
+/// escaped with `escape_map_key`, so that map keys with a `.` in them
+/// keep their place. `null` values are left out.
+///
+/// ```
+/// let json = serde_json::json!({"addresses": [{"city": "JHB"}], "age": 42});
+/// assert_eq!(
+///     structform::flatten_field_paths(&json),
+///     vec![
+///         ("addresses[0].city".to_string(), "JHB".to_string()),
+///         ("age".to_string(), "42".to_string()),
+///     ]
+/// );
+/// ```
+pub fn flatten_field_paths(json: &Value) -> Vec<(String, String)> {
+    let mut values = Vec::new();
+    if let Value::Object(object) = json {
+        for (name, child) in object {
+            flatten_into(child, escape_map_key(name), &mut values);
+        }
+    }
+    values
+}
+
+fn flatten_into(json: &Value, path: String, values: &mut Vec<(String, String)>) {
+    match json {
+        Value::Null => {}
+        Value::String(value) => values.push((path, value.clone())),
+        Value::Bool(value) => values.push((path, value.to_string())),
+        Value::Number(value) => values.push((path, value.to_string())),
+        Value::Array(entries) => {
+            for (index, entry) in entries.iter().enumerate() {
+                flatten_into(entry, format!("{}[{}]", path, index), values);
+            }
+        }
+        Value::Object(object) => {
+            for (name, child) in object {
+                flatten_into(child, format!("{}.{}", path, escape_map_key(name)), values);
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::FieldMeta;
+
+/// Where a form's fields go when it is rendered, so that renderers
+/// don't each hard code the layout of every form.
+///
+/// A layout is a grid with a number of columns, split into sections.
+/// Each section is a list of rows, and each row is a list of cells,
+/// which hold a field path and how many columns the cell spans.
+///
+/// Every derived form implements `FormLayout`. Its layout is one field
+/// per row, in the order the fields are declared, unless a function
+/// returning the layout is given with `#[structform(layout = "...")]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "manifest", derive(serde::Serialize))]
+pub struct Layout {
+    pub columns: u16,
+    pub sections: Vec<Section>,
+}
+
+/// A group of rows in a `Layout`, which may have a title.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "manifest", derive(serde::Serialize))]
+pub struct Section {
+    pub title: Option<String>,
+    pub rows: Vec<Vec<Cell>>,
+}
+
+/// A field in a row of a `Layout`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "manifest", derive(serde::Serialize))]
+pub struct Cell {
+    /// The field path of an input or subform, like `first_name` or
+    /// `address.city`.
+    pub path: String,
+    /// How many of the layout's columns the cell spans.
+    pub span: u16,
+}
+
+impl Layout {
+    /// An empty layout with the given number of columns.
+    pub fn new(columns: u16) -> Layout {
+        Layout {
+            columns,
+            sections: Vec::new(),
+        }
+    }
+
+    /// A single column layout with each field on its own row, in the
+    /// order of a form's `FIELDS` table.
+    pub fn one_field_per_row(fields: &[FieldMeta]) -> Layout {
+        let mut section = Section::untitled();
+        for field in fields {
+            section = section.row(vec![Cell::new(field.name)]);
+        }
+        Layout::new(1).section(section)
+    }
+
+    pub fn section(mut self, section: Section) -> Layout {
+        self.sections.push(section);
+        self
+    }
+
+    /// Every cell in the layout, in order.
+    pub fn cells(&self) -> impl Iterator<Item = &Cell> {
+        self.sections
+            .iter()
+            .flat_map(|section| section.rows.iter())
+            .flat_map(|row| row.iter())
+    }
+
+    /// The names of fields in a form's `FIELDS` table that the layout
+    /// doesn't place anywhere. A field counts as placed if a cell has
+    /// its path, or a path inside of it when it's a subform. This is
+    /// useful in a test, to catch fields that were added to a form but
+    /// not to its layout.
+    pub fn missing_fields(&self, fields: &[FieldMeta]) -> Vec<&'static str> {
+        fields
+            .iter()
+            .map(|field| field.name)
+            .filter(|name| {
+                !self.cells().any(|cell| {
+                    cell.path == *name
+                        || matches!(
+                            cell.path.strip_prefix(name),
+                            Some(rest) if rest.starts_with('.') || rest.starts_with('[')
+                        )
+                })
+            })
+            .collect()
+    }
+}
+
+impl Section {
+    pub fn new(title: &str) -> Section {
+        Section {
+            title: Some(title.to_string()),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn untitled() -> Section {
+        Section::default()
+    }
+
+    pub fn row(mut self, cells: Vec<Cell>) -> Section {
+        self.rows.push(cells);
+        self
+    }
+}
+
+impl Cell {
+    /// A cell which spans one column.
+    pub fn new(path: &str) -> Cell {
+        Cell {
+            path: path.to_string(),
+            span: 1,
+        }
+    }
+
+    pub fn span(mut self, span: u16) -> Cell {
+        self.span = span;
+        self
+    }
+}
+
+/// Forms that have a `Layout`. This is implemented by
+/// `#[derive(StructForm)]`, so renderers can lay out any derived form.
+pub trait FormLayout {
+    fn layout() -> Layout;
+}
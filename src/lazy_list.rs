@@ -0,0 +1,172 @@
+use crate::{FormClock, ParseError, StructForm};
+use std::borrow::Cow;
+
+/// A list subform whose entries are only turned into forms when they
+/// are edited, for models with thousands of list entries where
+/// building every subform up front would be too slow.
+///
+/// Use it in place of a `Vec` of subforms, and annotate the field with
+/// `#[structform(lazy)]`:
+///
+/// ```ignore
+/// #[structform(lazy)]
+/// line_items: structform::LazyList<LineItemForm, LineItem>,
+/// ```
+///
+/// Entries that are never edited are submitted as the model they
+/// started as. Reading an entry that hasn't been edited, like with
+/// `get` or `iter`, builds a temporary form for it.
+#[derive(Debug, Clone)]
+pub struct LazyList<F, Model> {
+    entries: Vec<LazyEntry<F, Model>>,
+    clock: FormClock,
+}
+
+/// An entry of a `LazyList`.
+#[derive(Debug, Clone)]
+pub enum LazyEntry<F, Model> {
+    /// An entry which hasn't been edited yet.
+    Model(Model),
+    Form(F),
+}
+
+impl<F, Model> Default for LazyList<F, Model> {
+    fn default() -> LazyList<F, Model> {
+        LazyList {
+            entries: Vec::new(),
+            clock: FormClock::default(),
+        }
+    }
+}
+
+impl<F, Model> LazyList<F, Model>
+where
+    F: StructForm<Model> + Clone,
+    Model: Clone,
+{
+    pub fn from_models(models: &[Model]) -> LazyList<F, Model> {
+        LazyList {
+            entries: models.iter().cloned().map(LazyEntry::Model).collect(),
+            clock: FormClock::default(),
+        }
+    }
+
+    /// Gives every entry's form a clock, including entries that are
+    /// turned into forms or added later. See `StructForm::set_clock`.
+    pub fn set_clock(&mut self, clock: &FormClock) {
+        self.clock = clock.clone();
+        for entry in &mut self.entries {
+            if let LazyEntry::Form(form) = entry {
+                form.set_clock(clock);
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The number of entries that have been turned into forms.
+    pub fn materialized_len(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| matches!(entry, LazyEntry::Form(_)))
+            .count()
+    }
+
+    pub fn get(&self, index: usize) -> Option<Cow<'_, F>> {
+        self.entries.get(index).map(LazyEntry::form)
+    }
+
+    /// The form for an entry, turning it into a form if it hasn't been
+    /// edited yet.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut F> {
+        let entry = self.entries.get_mut(index)?;
+        if let LazyEntry::Model(model) = entry {
+            let mut form = F::new(model);
+            form.set_clock(&self.clock);
+            *entry = LazyEntry::Form(form);
+        }
+        match entry {
+            LazyEntry::Form(form) => Some(form),
+            LazyEntry::Model(_) => None,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Cow<'_, F>> {
+        self.entries.iter().map(LazyEntry::form)
+    }
+
+    /// The entries that have been turned into forms, with their
+    /// indices.
+    pub fn materialized(&self) -> impl Iterator<Item = (usize, &F)> {
+        self.entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| match entry {
+                LazyEntry::Form(form) => Some((i, form)),
+                LazyEntry::Model(_) => None,
+            })
+    }
+
+    pub fn push(&mut self, mut form: F) {
+        form.set_clock(&self.clock);
+        self.entries.push(LazyEntry::Form(form));
+    }
+
+    pub fn insert(&mut self, index: usize, entry: LazyEntry<F, Model>) {
+        self.entries.insert(index, entry);
+    }
+
+    pub fn remove(&mut self, index: usize) -> LazyEntry<F, Model> {
+        self.entries.remove(index)
+    }
+
+    /// Submits every entry. Entries that haven't been edited are
+    /// returned as they are, and the others are submitted as updates
+    /// to the model at the same index in `models`.
+    pub fn submit_update(&mut self, models: &[Model]) -> Result<Vec<Model>, ParseError> {
+        self.entries
+            .iter_mut()
+            .enumerate()
+            .map(|(i, entry)| match entry {
+                LazyEntry::Model(model) => Ok(model.clone()),
+                LazyEntry::Form(form) => models
+                    .get(i)
+                    .map(|model| form.submit_update(model.clone()))
+                    .unwrap_or_else(|| form.submit()),
+            })
+            .collect()
+    }
+
+    /// What `submit_update` would return, without changing any entries.
+    pub fn parse_update(&self, models: &[Model]) -> Result<Vec<Model>, ParseError> {
+        self.entries
+            .iter()
+            .enumerate()
+            .map(|(i, entry)| match entry {
+                LazyEntry::Model(model) => Ok(model.clone()),
+                LazyEntry::Form(form) => models
+                    .get(i)
+                    .map(|model| form.parse_update(model.clone()))
+                    .unwrap_or_else(|| form.parse()),
+            })
+            .collect()
+    }
+}
+
+impl<F, Model> LazyEntry<F, Model>
+where
+    F: StructForm<Model> + Clone,
+{
+    fn form(&self) -> Cow<'_, F> {
+        match self {
+            LazyEntry::Model(model) => Cow::Owned(F::new(model)),
+            LazyEntry::Form(form) => Cow::Borrowed(form),
+        }
+    }
+}
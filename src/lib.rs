@@ -1,14 +1,168 @@
 use std::fmt;
 
+// Lets the macros that this crate exports, which refer to
+// `structform::...`, also be used inside of this crate.
+extern crate self as structform;
+
+mod async_validation;
+#[cfg(feature = "banking")]
+mod banking_input;
+#[cfg(feature = "barcode")]
+mod barcode_input;
+#[cfg(feature = "bool")]
+mod bool_input;
+mod captcha;
+mod change_log;
+#[cfg(any(feature = "barcode", feature = "sa_id", feature = "vat"))]
+mod checksum;
+mod cli_args;
+mod clock;
+#[cfg(feature = "cron")]
+mod cron_input;
+mod csv;
+#[cfg(feature = "time")]
+mod date_input;
+mod disclosure;
+mod env_vars;
+mod error_summary;
+mod field_meta;
+mod field_path;
+mod form_registry;
+mod form_set;
+mod grid;
+mod html;
+#[cfg(feature = "json")]
+mod json;
+mod layout;
+mod lazy_list;
+mod list_window;
+#[cfg(feature = "manifest")]
+mod manifest;
+#[cfg(feature = "markdown")]
+mod markdown;
+#[cfg(feature = "measurement")]
+mod measurement_input;
+#[cfg(feature = "name")]
+mod name_input;
+#[cfg(feature = "network")]
+mod network_input;
+#[cfg(feature = "numeric")]
 mod numeric_input;
+#[cfg(feature = "openapi")]
+mod openapi;
+#[cfg(feature = "otp")]
+mod otp_input;
+mod password_input;
+mod paste;
+#[cfg(feature = "postal_code")]
+mod postal_code_input;
+#[cfg(feature = "sa_id")]
+mod sa_id_input;
+#[cfg(feature = "select")]
+mod select_input;
+mod shortcuts;
+#[cfg(feature = "slug")]
+mod slug_input;
+mod string_or;
+#[cfg(feature = "temperature")]
+mod temperature_input;
+#[cfg(feature = "text")]
 mod text_input;
+#[cfg(feature = "textarea")]
+mod textarea_input;
+mod theme;
+#[cfg(feature = "ts-export")]
+mod ts_export;
+mod unsaved_changes;
+mod validation_errors;
+#[cfg(feature = "vat")]
+mod vat_input;
+#[cfg(feature = "web")]
+mod web;
 
+pub use async_validation::*;
+#[cfg(feature = "banking")]
+pub use banking_input::*;
+#[cfg(feature = "barcode")]
+pub use barcode_input::*;
+pub use captcha::*;
+pub use change_log::*;
+pub use cli_args::*;
+pub use clock::*;
+#[cfg(feature = "cron")]
+pub use cron_input::*;
+pub use csv::*;
+#[cfg(feature = "time")]
+pub use date_input::*;
+pub use disclosure::*;
+pub use env_vars::*;
+pub use error_summary::*;
+pub use field_meta::*;
+pub use field_path::*;
+pub use form_registry::*;
+pub use form_set::*;
+pub use grid::*;
+#[cfg(feature = "json")]
+pub use json::*;
+pub use layout::*;
+pub use lazy_list::*;
+pub use list_window::*;
+#[cfg(feature = "manifest")]
+pub use manifest::*;
+#[cfg(feature = "markdown")]
+pub use markdown::*;
+#[cfg(feature = "measurement")]
+pub use measurement_input::*;
+#[cfg(feature = "name")]
+pub use name_input::*;
+#[cfg(feature = "network")]
+pub use network_input::*;
+#[cfg(feature = "numeric")]
 pub use numeric_input::*;
+pub use password_input::*;
+pub use paste::*;
+#[cfg(feature = "postal_code")]
+pub use postal_code_input::*;
+#[cfg(feature = "sa_id")]
+pub use sa_id_input::*;
+pub use shortcuts::*;
+#[cfg(feature = "slug")]
+pub use slug_input::*;
+pub use string_or::*;
+#[cfg(feature = "temperature")]
+pub use temperature_input::*;
+#[cfg(feature = "text")]
 pub use text_input::*;
+#[cfg(feature = "textarea")]
+pub use textarea_input::*;
+pub use theme::*;
+pub use unsaved_changes::*;
+pub use validation_errors::*;
+#[cfg(feature = "vat")]
+pub use vat_input::*;
+#[cfg(feature = "web")]
+pub use web::*;
 
 // Re-export this, so users don't need to explicitly depend on both crates.
 pub use structform_derive::*;
 
+/// The types from `time` that the date and time input macros refer to.
+/// Apps depend on `time` themselves, rather than through StructForm.
+#[cfg(feature = "time")]
+#[doc(hidden)]
+pub mod __time {
+    pub use time::{Date, PrimitiveDateTime, Time};
+}
+
+/// Without the `manifest` feature, derived forms don't get a
+/// `manifest` function.
+#[cfg(not(feature = "manifest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_form_manifest {
+    ($vis: vis, $form: ident) => {};
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ParseError {
     Required,
@@ -21,6 +175,42 @@ pub enum ParseError {
         min: String,
         max: String,
     },
+    PasswordTooWeak {
+        min: PasswordStrength,
+    },
+    TooLong {
+        max: usize,
+    },
+    WrongTotal {
+        field: String,
+        expected: String,
+        actual: String,
+    },
+    DuplicateEntry {
+        field: String,
+        subfield: String,
+        first_index: usize,
+        index: usize,
+    },
+    AtLeastOneRequired {
+        fields: Vec<String>,
+    },
+    OutOfOrder {
+        field: String,
+        at_least: String,
+    },
+    Spam,
+    TooManyAttempts {
+        retry_after: std::time::Duration,
+    },
+    CaptchaRequired,
+    CaptchaFailed,
+    /// An error from outside of the form, like a server checking
+    /// whether a username is taken. The message is shown as is.
+    External(String),
+    /// An asynchronous check of the input, like a server checking
+    /// whether a username is taken, hasn't finished yet.
+    ValidationPending,
 }
 
 impl fmt::Display for ParseError {
@@ -34,50 +224,737 @@ impl fmt::Display for ParseError {
                 min,
                 max,
             } => write!(f, "Expected {} between {} and {}.", required_type, min, max),
+            ParseError::PasswordTooWeak { min } => {
+                write!(f, "Expected a password with at least {} strength.", min)
+            }
+            ParseError::TooLong { max } => write!(f, "Expected at most {} characters.", max),
+            ParseError::WrongTotal {
+                field,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Expected {} to add up to {}, but they add up to {}.",
+                field, expected, actual
+            ),
+            ParseError::DuplicateEntry {
+                field,
+                subfield,
+                first_index,
+                index,
+            } => write!(
+                f,
+                "Expected each of the {} to have a different {}, but entries {} and {} are the same.",
+                field,
+                subfield,
+                first_index + 1,
+                index + 1
+            ),
+            ParseError::AtLeastOneRequired { fields } => write!(
+                f,
+                "Expected at least one of {} to be filled in.",
+                fields.join(", ")
+            ),
+            ParseError::OutOfOrder { field, at_least } => {
+                write!(f, "Expected {} to be at least {}.", field, at_least)
+            }
+            ParseError::Spam => write!(f, "This form could not be submitted."),
+            ParseError::External(message) => write!(f, "{}", message),
+            ParseError::ValidationPending => write!(f, "This value is still being checked."),
+            ParseError::CaptchaRequired => write!(f, "Please complete the captcha."),
+            ParseError::CaptchaFailed => write!(
+                f,
+                "The captcha could not be verified. Please complete it again."
+            ),
+            ParseError::TooManyAttempts { retry_after } => {
+                let seconds = retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0);
+                write!(
+                    f,
+                    "Too many attempts. Please wait {} second{} and try again.",
+                    seconds,
+                    if seconds == 1 { "" } else { "s" }
+                )
+            }
         }
     }
 }
 
+/// Checks that values add up to `expected`, for the `sum_of` and
+/// `sum_to` annotations. `field` is the name of the list that the
+/// values come from, for the `ParseError::WrongTotal` error.
+///
+/// The values can be of any type that can be summed and compared, like
+/// integers or a decimal type. Floating point values are compared
+/// exactly, so they are best avoided for totals like percentages.
+///
+/// ```
+/// use structform::{check_total, ParseError};
+///
+/// assert_eq!(check_total("allocations", [60u8, 40].iter(), 100), Ok(()));
+/// assert_eq!(
+///     check_total("allocations", [60u8, 30].iter(), 100),
+///     Err(ParseError::WrongTotal {
+///         field: "allocations".to_string(),
+///         expected: "100".to_string(),
+///         actual: "90".to_string(),
+///     })
+/// );
+/// ```
+pub fn check_total<'a, T, I>(field: &str, values: I, expected: T) -> Result<(), ParseError>
+where
+    T: 'a + std::iter::Sum<&'a T> + PartialEq + fmt::Display,
+    I: Iterator<Item = &'a T>,
+{
+    let total: T = values.sum();
+    if total == expected {
+        Ok(())
+    } else {
+        Err(ParseError::WrongTotal {
+            field: field.to_string(),
+            expected: expected.to_string(),
+            actual: total.to_string(),
+        })
+    }
+}
+
+/// Whether a value on a model counts as filled in, for the
+/// `at_least_one_of` annotation, which checks that at least one of a
+/// group of fields is. `None`, empty strings and empty `Vec`s aren't
+/// filled in. Implement this for other types that can be used in the
+/// group.
+///
+/// ```
+/// use structform::FilledIn;
+///
+/// assert!(Some(0).is_filled_in());
+/// assert!(!None::<String>.is_filled_in());
+/// assert!(!String::new().is_filled_in());
+/// ```
+pub trait FilledIn {
+    fn is_filled_in(&self) -> bool;
+}
+
+impl<T> FilledIn for Option<T> {
+    fn is_filled_in(&self) -> bool {
+        self.is_some()
+    }
+}
+
+impl FilledIn for String {
+    fn is_filled_in(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T> FilledIn for Vec<T> {
+    fn is_filled_in(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
 pub trait StructForm<Model> {
     type Field;
 
     fn new(model: &Model) -> Self;
     fn set_input(&mut self, field: Self::Field, value: String);
 
+    /// The same as `set_input`, for callers that have a `&str`, like
+    /// an input event's value. The text is copied into a buffer that
+    /// the input keeps for this, so typing usually doesn't allocate.
+    fn set_input_str(&mut self, field: Self::Field, value: &str);
+
+    /// Sets a checkbox, like one using `impl_bool_input`, without
+    /// going through a string. This is the same as `set_input` with
+    /// `"true"` or `"false"`.
+    fn set_checked(&mut self, field: Self::Field, checked: bool) {
+        self.set_input(field, checked.to_string());
+    }
+
+    /// Sets many inputs at once, like when restoring a draft. This is
+    /// the same as calling `set_input` for each event, except that
+    /// inputs with a `depends_on` annotation are only revalidated once
+    /// at the end, and a `change_log` records one change per input
+    /// for the whole batch.
+    fn apply_events<I>(&mut self, events: I)
+    where
+        I: IntoIterator<Item = (Self::Field, String)>;
+
+    /// Sets an input using its field path, like `username` or
+    /// `addresses[0].city`. Optional subforms on the path are turned
+    /// on, and a list subform gets a new entry if the index is one
+    /// past its end. Returns false if the path doesn't match an input.
+    fn set_input_at_path(&mut self, path: &str, value: String) -> bool;
+
+    /// The current raw input of every input on the form, keyed by
+    /// field path. See `set_input_at_path`.
+    fn inputs_by_path(&self) -> Vec<(String, String)>;
+
+    /// The current value of every input on the form, formatted with
+    /// `ParseAndFormat::format` and keyed by field path. Inputs that
+    /// don't parse show their raw input instead.
+    fn values_by_path(&self) -> Vec<(String, String)>;
+
+    /// Shows an error on the input at a field path, until the input is
+    /// changed. Returns false if the path doesn't match an input.
+    fn set_error_at_path(&mut self, path: &str, error: ParseError) -> bool;
+
+    /// Shows errors from outside of the form, like a server's
+    /// response, on the inputs at their field paths. Errors for paths
+    /// that don't match an input are ignored.
+    fn apply_external_errors<I>(&mut self, errors: I)
+    where
+        I: IntoIterator<Item = (String, ParseError)>,
+    {
+        for (path, error) in errors {
+            self.set_error_at_path(&path, error);
+        }
+    }
+
+    /// Submits the form, and then checks the model with a
+    /// `ValidationService`, like a server that pre-validates the model
+    /// before it is saved. Any errors from the service are shown on
+    /// their inputs, and the first one is returned.
+    ///
+    /// For asynchronous services, `submit` the form, send the model to
+    /// the service, and then call `apply_external_errors` with the
+    /// response.
+    fn validate_with_service<S>(&mut self, service: &S) -> Result<Model, ParseError>
+    where
+        S: ValidationService<Model>,
+    {
+        let model = self.submit()?;
+        let errors = service.validate(&model);
+        match errors.first() {
+            Some((_, error)) => {
+                let error = error.clone();
+                self.apply_external_errors(errors);
+                Err(error)
+            }
+            None => Ok(model),
+        }
+    }
+
+    /// Submits the form, and then checks the model for things that are
+    /// allowed but look unusual, for an "are you sure?" step before
+    /// saving.
+    ///
+    /// Errors block the submit as usual. If the form is valid but
+    /// `warnings` returns any warnings, the model is returned with them
+    /// as `NeedsConfirmation`. If the user confirms, the model can be
+    /// used as is.
+    ///
+    /// Any `Fn(&Model) -> Vec<FieldWarning>` can be used to check for
+    /// warnings.
+    fn submit_checked<W>(&mut self, warnings: W) -> CheckedSubmit<Model>
+    where
+        W: Fn(&Model) -> Vec<FieldWarning>,
+    {
+        match self.submit() {
+            Err(e) => CheckedSubmit::Err(e),
+            Ok(model) => {
+                let warnings = warnings(&model);
+                if warnings.is_empty() {
+                    CheckedSubmit::Ok(model)
+                } else {
+                    CheckedSubmit::NeedsConfirmation(model, warnings)
+                }
+            }
+        }
+    }
+
+    /// A label and formatted value for every input on the form, for
+    /// rendering "review before submitting" pages. Inputs that parse
+    /// successfully are formatted from their value, and others show
+    /// their raw input.
+    ///
+    /// Labels default to the field name in sentence case, and can be
+    /// set with `#[structform(label = "...")]`. Inputs and subforms
+    /// marked with `#[structform(sensitive)]`, like passwords, are
+    /// left out.
+    fn summary(&self) -> Vec<(String, String)>;
+
+    /// The label of every input on the form, keyed by field path. See
+    /// `summary` for how labels are chosen. Optional and list subforms
+    /// are left out, since they have no inputs until they are added.
+    fn labels_by_path() -> Vec<(String, String)>
+    where
+        Self: Sized;
+
+    /// Every field of the form, including the fields of its subforms,
+    /// for renderers that walk the form's structure at runtime. See
+    /// `FormField`.
+    fn fields() -> std::vec::IntoIter<FormField<Self::Field>>
+    where
+        Self: Sized;
+
+    /// Whether the input at a field path is marked with
+    /// `#[structform(sensitive)]`, or is inside a subform that is.
+    fn is_sensitive_path(&self, path: &str) -> bool;
+
+    /// Sets inputs from a query string, like
+    /// `?username=joe&addresses[0].city=JHB`, using
+    /// `set_input_at_path`. Keys that don't match an input are
+    /// ignored. This is useful for deep links that arrive with some
+    /// fields already filled in.
+    fn apply_query_string(&mut self, query: &str) {
+        for (path, value) in parse_query_string(query) {
+            self.set_input_at_path(&path, value);
+        }
+    }
+
+    /// Sets inputs from an `application/x-www-form-urlencoded` string,
+    /// like one produced by `to_urlencoded`.
+    fn apply_urlencoded(&mut self, urlencoded: &str) {
+        self.apply_query_string(urlencoded);
+    }
+
+    /// Sets inputs from the environment variables that start with a
+    /// prefix, like `MYAPP_SMTP_PORT` for the input `smtp_port` with the
+    /// prefix `MYAPP`. Variables that don't match an input are ignored.
+    /// See `env_var_path`.
+    fn apply_env(&mut self, prefix: &str)
+    where
+        Self: FormFields + Sized,
+    {
+        self.apply_env_vars(prefix, std::env::vars());
+    }
+
+    /// Like `apply_env`, but with the variables given as names and
+    /// values, rather than read from the environment.
+    fn apply_env_vars<I>(&mut self, prefix: &str, vars: I)
+    where
+        Self: FormFields + Sized,
+        I: IntoIterator<Item = (String, String)>,
+    {
+        for (path, value) in env_vars_by_path(Self::FIELDS, prefix, vars) {
+            self.set_input_at_path(&path, value);
+        }
+    }
+
+    /// Sets inputs from command line flags, like
+    /// `--username joe --addresses.0.city JHB`, for scripts that fill
+    /// in the same forms that people fill in interactively. See
+    /// `parse_cli_args` for the format of the flags, and
+    /// `set_input_at_path` for how they are applied.
+    ///
+    /// Every flag must match an input on the form. If any argument
+    /// can't be applied, none of them are, so a typo can't leave the
+    /// form half filled in.
+    fn apply_cli_args<I, S>(&mut self, args: I) -> Result<(), CliArgsError>
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+        Self: Clone,
+    {
+        let mut form = self.clone();
+        for (path, value) in parse_cli_args(args)? {
+            if !form.set_input_at_path(&path, value) {
+                return Err(CliArgsError::UnknownFlag(cli_flag(&path)));
+            }
+        }
+        *self = form;
+        Ok(())
+    }
+
+    /// Fills in several fields from one pasted value, like a row copied
+    /// from a spreadsheet, or `Justin <justin@example.com>`. `split`
+    /// breaks the pasted value into pieces, like `split_pasted_row`,
+    /// and each piece is set on the field at the same position in
+    /// `fields`. Fields can be inside list entries, and the entry right
+    /// after the end of a list is added, like with `set_input_at_path`.
+    /// Extra pieces are ignored.
+    ///
+    /// Returns the errors of the pasted fields, so that pieces that
+    /// don't parse can be pointed out. Other fields on the form aren't
+    /// checked.
+    fn apply_paste<F>(
+        &mut self,
+        pasted: &str,
+        fields: &[Self::Field],
+        split: F,
+    ) -> Result<(), ValidationErrors<Self::Field>>
+    where
+        F: Fn(&str) -> Vec<String>,
+        Self: Sized,
+        Self::Field: PartialEq,
+    {
+        for (field, piece) in fields.iter().zip(split(pasted)) {
+            self.set_input_at_path(&Self::field_path(field), piece);
+        }
+        let mut errors = ValidationErrors::new();
+        if let Err(form_errors) = self.validate() {
+            for (field, error) in form_errors {
+                if fields.contains(&field) {
+                    errors.push(field, error);
+                }
+            }
+        }
+        errors.into_result()
+    }
+
+    /// Encodes every non-empty input as
+    /// `application/x-www-form-urlencoded`, keyed by field path. This
+    /// can be used as a query string to share the form as a link, and
+    /// read back with `apply_urlencoded`.
+    ///
+    /// Since empty inputs are left out, optional subforms and list
+    /// entries that are completely empty won't be recreated. Inputs
+    /// marked with `#[structform(sensitive)]`, like passwords, are
+    /// also left out, so that they don't end up in links, browser
+    /// history or server logs.
+    fn to_urlencoded(&self) -> String {
+        to_query_string(
+            self.inputs_by_path()
+                .iter()
+                .filter(|(path, input)| !input.is_empty() && !self.is_sensitive_path(path)),
+        )
+    }
+
+    /// The raw input of every input on the form, as a JSON object that
+    /// mirrors the structure of the model, like
+    /// `{"username": "joe", "addresses": [{"city": "JHB"}]}`. Every
+    /// value is the input's raw string, whether or not it parses, so
+    /// an unfinished form can be saved for support tooling or picked
+    /// up on another device with `apply_json_values`.
+    ///
+    /// Inputs marked with `#[structform(sensitive)]`, like passwords,
+    /// are left out.
+    #[cfg(feature = "json")]
+    fn to_json_values(&self) -> serde_json::Value {
+        nest_field_paths(
+            self.inputs_by_path()
+                .into_iter()
+                .filter(|(path, _)| !self.is_sensitive_path(path)),
+        )
+    }
+
+    /// Sets inputs from a JSON object like the one produced by
+    /// `to_json_values`, using `set_input_at_path`. Values that don't
+    /// match an input are ignored. See `flatten_field_paths` for how
+    /// values that aren't strings are read.
+    #[cfg(feature = "json")]
+    fn apply_json_values(&mut self, json: &serde_json::Value) {
+        for (path, value) in flatten_field_paths(json) {
+            self.set_input_at_path(&path, value);
+        }
+    }
+
+    /// A new form with its inputs set from a TOML file, like an
+    /// existing config file that is about to be edited. Tables are
+    /// read as subforms, and arrays of tables as lists of subforms.
+    /// See `apply_json_values` for how values are applied.
+    #[cfg(feature = "toml")]
+    fn from_toml_str(toml: &str) -> Result<Self, toml::de::Error>
+    where
+        Self: Default + Sized,
+    {
+        let mut form = Self::default();
+        form.apply_json_values(&toml::from_str(toml)?);
+        Ok(form)
+    }
+
+    /// The raw inputs of the form as a TOML file, nested the same way
+    /// as `to_json_values`.
+    ///
+    /// The file is written from scratch. Comments, formatting and keys
+    /// that aren't inputs on the form aren't kept from the file that
+    /// was read with `from_toml_str`, and every value is written as a
+    /// string. If the file is also edited by hand, write the submitted
+    /// model with its own serde implementation instead, or merge the
+    /// changed inputs into the original file with a format-preserving
+    /// editor like `toml_edit`.
+    #[cfg(feature = "toml")]
+    fn to_toml_string(&self) -> Result<String, toml::ser::Error> {
+        toml::to_string(&self.to_json_values())
+    }
+
+    /// A new form with its inputs set from a YAML file. See
+    /// `from_toml_str`.
+    #[cfg(feature = "yaml")]
+    fn from_yaml_str(yaml: &str) -> Result<Self, serde_yaml::Error>
+    where
+        Self: Default + Sized,
+    {
+        let mut form = Self::default();
+        form.apply_json_values(&serde_yaml::from_str(yaml)?);
+        Ok(form)
+    }
+
+    /// The raw inputs of the form as a YAML file. Like
+    /// `to_toml_string`, the file is written from scratch, so comments
+    /// and keys that aren't inputs on the form aren't kept.
+    #[cfg(feature = "yaml")]
+    fn to_yaml_string(&self) -> Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(&self.to_json_values())
+    }
+
     fn submit(&mut self) -> Result<Model, ParseError>;
+
+    /// Checks every input on the form, including those in subforms and
+    /// lists, without submitting it. Unlike `submit`, which stops at the
+    /// first error, this returns the error of every input that doesn't
+    /// parse, so that they can all be shown at once.
+    ///
+    /// Once every input parses, the rules on the assembled model are
+    /// checked as well. See `validate_model`.
+    fn validate(&self) -> Result<(), ValidationErrors<Self::Field>>;
+
+    /// Checks the rules that apply to the assembled model rather than
+    /// to a single input, like `unique_by`, `sum_of`, `at_least` and
+    /// `at_least_one_of`. Errors are keyed by the field that they
+    /// should be shown on. Rules on a whole list are keyed by the
+    /// field that adds to the list, which has the list's field path.
+    ///
+    /// `submit`, `parse` and `validate` already check these rules, so
+    /// this only needs to be called for a model that was built some
+    /// other way.
+    fn validate_model(&self, _model: &Model) -> Result<(), ValidationErrors<Self::Field>> {
+        Ok(())
+    }
+
+    /// The field path of a field, like `addresses[0].city`. Fields that
+    /// aren't inputs, like `AddAddresses` or `RemoveAddresses(0)`, have
+    /// the path of the subform or list entry that they act on.
+    fn field_path(field: &Self::Field) -> String
+    where
+        Self: Sized;
+
+    /// The errors from `validate`, keyed by field path, like the errors
+    /// given to `apply_external_errors`.
+    fn errors_by_path(&self) -> Vec<(String, ParseError)>
+    where
+        Self: Sized,
+    {
+        match self.validate() {
+            Ok(()) => Vec::new(),
+            Err(errors) => errors
+                .into_iter()
+                .map(|(field, error)| (Self::field_path(&field), error))
+                .collect(),
+        }
+    }
+
     fn submit_update(&mut self, model: Model) -> Result<Model, ParseError>;
+
+    /// What `submit` would return, without changing the form. Inputs
+    /// aren't marked as edited, `submit_attempted` isn't set, and rate
+    /// limits don't record an attempt, so this can be called as often
+    /// as needed, like on every input event.
+    ///
+    /// Forms with a `submit_with` function are cloned to be submitted,
+    /// so they need to implement `Clone`.
+    fn parse(&self) -> Result<Model, ParseError>;
+
+    /// What `submit_update` would return, without changing the form.
+    /// See `parse`.
+    fn parse_update(&self, model: Model) -> Result<Model, ParseError>;
+
     fn submit_attempted(&self) -> bool;
+
+    /// Clears the form without replacing it, so that anything else
+    /// kept in the form's struct isn't lost. Every input is cleared,
+    /// optional subforms are turned off, lists of subforms are
+    /// emptied, and `submit_attempted` is set back to false. Enum forms
+    /// go back to their first variant. Rate limits and change logs are
+    /// kept.
+    fn reset(&mut self);
+
+    /// Gives the form's inputs, rate limits and change logs, including
+    /// those on subforms, a clock to read the time from in place of
+    /// the system clock, like a `MockClock` in tests. Each form has its
+    /// own clock, so forms on different threads or in different tests
+    /// don't affect each other.
+    ///
+    /// Subforms that are added afterwards, like new entries in a list
+    /// of subforms, start with the system clock. Lazy lists are the
+    /// exception, since they keep the clock for their new entries.
+    fn set_clock(&mut self, clock: &FormClock);
+
+    /// Every input whose raw input differs from what it started with,
+    /// including inputs on subforms, for highlighting modified inputs.
+    /// Unlike `has_unsaved_changes`, this only compares the raw inputs,
+    /// so it doesn't need the pristine model or a submit.
+    fn dirty_fields(&self) -> Vec<Self::Field>;
+
+    /// Whether any input on the form, including inputs on subforms, has
+    /// an asynchronous check that hasn't finished yet. While this is
+    /// true, `submit` fails with `ParseError::ValidationPending`, so a
+    /// view can disable its submit button or show a spinner.
+    fn has_pending_validations(&self) -> bool;
     fn is_empty(&self) -> bool;
 
     fn has_unsaved_changes(&self, pristine: &Model) -> bool
     where
-        Self: Clone,
         Model: Clone + PartialEq,
     {
-        let mut tmp_form = self.clone();
-        let updated_model = tmp_form.submit_update(pristine.clone());
-        match updated_model {
+        match self.parse_update(pristine.clone()) {
             Ok(updated_model) => *pristine != updated_model,
             Err(_) => true,
         }
     }
 
-    fn validation_error(&self) -> Option<ParseError>
+    /// Lists the inputs whose values differ from `pristine`, for
+    /// confirmations like "you changed these 3 settings" before
+    /// saving. Values are compared after formatting, so reformatting
+    /// an input (like adding spaces around a number) isn't a change.
+    /// Values of sensitive inputs are replaced with `REDACTED`.
+    fn diff(&self, pristine: &Model) -> Vec<FieldDiff>
     where
-        Self: Clone,
+        Self: Sized,
     {
-        // This is not an efficient implementation because it clones
-        // the whole form. It would be better if we had a separate
-        // immutable parse vs submit, or have some caching built into
-        // the form (model: Option<Result<Model>> updated on each
-        // input event?). It could be better to move this over to
-        // structform_derive.
+        let pristine_values = Self::new(pristine).values_by_path();
+        let current_values = self.values_by_path();
+        let find = |values: &[(String, String)], path: &str| -> String {
+            values
+                .iter()
+                .find(|(value_path, _)| value_path == path)
+                .map(|(_, value)| value.clone())
+                .unwrap_or_default()
+        };
+
+        let mut paths: Vec<&String> = pristine_values.iter().map(|(path, _)| path).collect();
+        for (path, _) in &current_values {
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+        paths
+            .into_iter()
+            .filter_map(|path| {
+                let old_value = find(&pristine_values, path);
+                let new_value = find(&current_values, path);
+                if old_value == new_value {
+                    None
+                } else if self.is_sensitive_path(path) {
+                    Some(FieldDiff {
+                        path: path.clone(),
+                        old_value: REDACTED.to_string(),
+                        new_value: REDACTED.to_string(),
+                    })
+                } else {
+                    Some(FieldDiff {
+                        path: path.clone(),
+                        old_value,
+                        new_value,
+                    })
+                }
+            })
+            .collect()
+    }
+
+    fn validation_error(&self) -> Option<ParseError> {
         if self.submit_attempted() {
-            self.clone().submit().err()
+            self.parse().err()
         } else {
             None
         }
     }
+
+    /// Merges two drafts of a form that were both edited from the same
+    /// `base` draft, like two browser tabs editing the same record.
+    ///
+    /// Inputs are compared by field path. Inputs which were only
+    /// changed in one of the drafts take that change. Inputs which were
+    /// changed differently in both drafts keep the value from `mine`,
+    /// and their field paths are reported as conflicts. List entries
+    /// added in `theirs` are added, but removing list entries isn't
+    /// merged.
+    fn merge(base: &Self, mine: &Self, theirs: &Self) -> MergedForm<Self>
+    where
+        Self: Clone,
+    {
+        let base_inputs = base.inputs_by_path();
+        let mine_inputs = mine.inputs_by_path();
+        let theirs_inputs = theirs.inputs_by_path();
+        let find = |inputs: &[(String, String)], path: &str| -> String {
+            inputs
+                .iter()
+                .find(|(input_path, _)| input_path == path)
+                .map(|(_, input)| input.clone())
+                .unwrap_or_default()
+        };
+
+        let mut merged = mine.clone();
+        let mut conflicts = Vec::new();
+        let mut paths: Vec<&String> = mine_inputs.iter().map(|(path, _)| path).collect();
+        for (path, _) in &theirs_inputs {
+            if !paths.contains(&path) {
+                paths.push(path);
+            }
+        }
+        for path in paths {
+            let base_input = find(&base_inputs, path);
+            let mine_input = find(&mine_inputs, path);
+            let theirs_input = find(&theirs_inputs, path);
+            if mine_input == theirs_input || theirs_input == base_input {
+                continue;
+            } else if mine_input == base_input {
+                merged.set_input_at_path(path, theirs_input);
+            } else {
+                conflicts.push(path.clone());
+            }
+        }
+
+        MergedForm { merged, conflicts }
+    }
+}
+
+/// Checks a model outside of the form, returning errors keyed by the
+/// field path of the input that they belong to. See
+/// `StructForm::validate_with_service`.
+///
+/// Any `Fn(&Model) -> Vec<(String, ParseError)>` can be used as a
+/// service.
+pub trait ValidationService<Model> {
+    fn validate(&self, model: &Model) -> Vec<(String, ParseError)>;
+}
+
+impl<Model, F> ValidationService<Model> for F
+where
+    F: Fn(&Model) -> Vec<(String, ParseError)>,
+{
+    fn validate(&self, model: &Model) -> Vec<(String, ParseError)> {
+        self(model)
+    }
+}
+
+/// An input whose value differs from the pristine model. See
+/// `StructForm::diff`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldDiff {
+    pub path: String,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+/// Something about an input that is allowed, but looks unusual enough
+/// that the user should confirm it. See `StructForm::submit_checked`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldWarning {
+    pub path: String,
+    pub message: String,
+}
+
+/// The result of `StructForm::submit_checked`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckedSubmit<Model> {
+    /// The form is valid, and there is nothing to confirm.
+    Ok(Model),
+    /// The form is valid, but the user should confirm the warnings
+    /// before the model is saved.
+    NeedsConfirmation(Model, Vec<FieldWarning>),
+    /// The form is not valid.
+    Err(ParseError),
+}
+
+/// The result of `StructForm::merge`.
+#[derive(Debug, Clone)]
+pub struct MergedForm<Form> {
+    pub merged: Form,
+    /// The field paths of inputs which were changed differently in
+    /// both drafts. The merged form keeps the value from `mine` for
+    /// these inputs.
+    pub conflicts: Vec<String>,
 }
 
 /// Trait used to tie strongly typed models into form
@@ -88,18 +965,47 @@ pub trait StructForm<Model> {
 pub trait ParseAndFormat<T> {
     fn parse(value: &str) -> Result<T, ParseError>;
     fn format(value: &T) -> String;
+
+    /// Cleans up raw input as it is typed, before it is stored in the
+    /// input and parsed. This can be used to drop characters that
+    /// could never be valid. By default, input is stored exactly as
+    /// typed.
+    fn sanitize(value: String) -> String {
+        value
+    }
 }
 
+/// A rule added to an input with `add_rule`. Rules are shared between
+/// clones of the input, and are `Send + Sync` so that forms can still
+/// be sent between threads.
+pub type InputRule<T> = std::sync::Arc<dyn Fn(&T) -> Result<(), ParseError> + Send + Sync>;
+
 /// Creates a new form input to be used in a StructForm.
 #[macro_export]
 macro_rules! derive_form_input {
     ($input:ident) => {
         #[derive(Clone)]
+        #[allow(missing_docs)]
         pub struct $input<T> {
             pub initial_input: String,
             pub input: String,
             pub value: Result<T, structform::ParseError>,
             pub is_edited: bool,
+            /// When the input was last changed, which is only recorded
+            /// with the `edit-timestamps` feature.
+            pub edit_timestamp: structform::EditTimestamp,
+            /// Extra rules added with `add_rule`, which are checked in
+            /// order after the input is parsed.
+            pub rules: Vec<structform::InputRule<T>>,
+            /// The raw input that an asynchronous check was started on
+            /// with `start_async_validation`, until it finishes or the
+            /// input changes.
+            pub pending_validation: Option<String>,
+            // A buffer that `set_input_str` sanitizes new input into
+            // before comparing it with `input`, and which takes the old
+            // buffer once it is replaced. Its contents aren't
+            // meaningful, so it's private to the input.
+            spare_input: String,
         }
 
         impl<T> Default for $input<T>
@@ -112,10 +1018,15 @@ macro_rules! derive_form_input {
                     input: String::new(),
                     value: $input::parse(""),
                     is_edited: false,
+                    edit_timestamp: Default::default(),
+                    rules: Vec::new(),
+                    pending_validation: None,
+                    spare_input: String::new(),
                 }
             }
         }
 
+        #[allow(missing_docs)]
         impl<T> $input<T> {
             pub fn show_validation_msg(&self) -> bool {
                 self.is_edited && self.value.is_err()
@@ -131,9 +1042,57 @@ macro_rules! derive_form_input {
             pub fn is_empty(&self) -> bool {
                 self.input.is_empty()
             }
+
+            /// Whether an asynchronous check of the input is still
+            /// running. See `start_async_validation`.
+            pub fn is_pending(&self) -> bool {
+                self.pending_validation.is_some()
+            }
+
+            pub fn validation_state(&self) -> structform::ValidationState<&T> {
+                match &self.value {
+                    Err(e) => structform::ValidationState::Invalid(e.clone()),
+                    Ok(_) if self.is_pending() => structform::ValidationState::Pending,
+                    Ok(value) => structform::ValidationState::Valid(value),
+                }
+            }
+
+            /// Finishes an asynchronous check started with
+            /// `start_async_validation`. If the check failed, its error
+            /// is shown on the input. If the input has changed since the
+            /// check was started, the result is ignored.
+            pub fn finish_async_validation(
+                &mut self,
+                pending: structform::PendingValidation<T>,
+                result: Result<(), structform::ParseError>,
+            ) {
+                if self.pending_validation.as_ref() != Some(&pending.input) {
+                    return;
+                }
+                self.pending_validation = None;
+                if let Err(e) = result {
+                    self.set_error(e);
+                }
+            }
+
+            /// Shows an error from outside of the form, until the input
+            /// is changed.
+            pub fn set_error(&mut self, error: structform::ParseError) {
+                self.value = Err(error);
+                self.is_edited = true;
+            }
+
+            /// Checks a parsed value against the rules added with
+            /// `add_rule`, stopping at the first one that fails.
+            pub fn check_rules(&self, value: T) -> Result<T, structform::ParseError> {
+                for rule in &self.rules {
+                    rule(&value)?;
+                }
+                Ok(value)
+            }
         }
 
-        #[allow(dead_code)]
+        #[allow(dead_code, missing_docs)]
         impl<T> $input<T>
         where
             $input<T>: structform::ParseAndFormat<T>,
@@ -146,24 +1105,109 @@ macro_rules! derive_form_input {
                     input: initial_input,
                     value: Ok(value.clone()),
                     is_edited: false,
+                    edit_timestamp: Default::default(),
+                    rules: Vec::new(),
+                    pending_validation: None,
+                    spare_input: String::new(),
+                }
+            }
+
+            /// Adds a rule that the parsed value must pass, on top of
+            /// what `parse` checks. This is for rules that depend on
+            /// context which is only known after the form is built,
+            /// like limits fetched from a server, so the rule may be a
+            /// closure that captures them. The current value is checked
+            /// against the new rule straight away.
+            pub fn add_rule<F>(&mut self, rule: F)
+            where
+                F: Fn(&T) -> Result<(), structform::ParseError> + Send + Sync + 'static,
+            {
+                if let Ok(value) = &self.value {
+                    if let Err(e) = rule(value) {
+                        self.value = Err(e);
+                    }
                 }
+                self.rules.push(std::sync::Arc::new(rule));
+            }
+
+            /// Starts an asynchronous check of the input's value, like
+            /// asking a server whether a username is taken. Until it is
+            /// finished with `finish_async_validation`, the input is
+            /// pending, and submitting it fails with
+            /// `ParseError::ValidationPending`. Returns `None` if the
+            /// value doesn't parse, since there's nothing to check.
+            pub fn start_async_validation(&mut self) -> Option<structform::PendingValidation<T>> {
+                let value = self.value.as_ref().ok()?.clone();
+                self.pending_validation = Some(self.input.clone());
+                Some(structform::PendingValidation {
+                    input: self.input.clone(),
+                    value,
+                })
             }
 
             pub fn submit(&mut self) -> Result<T, structform::ParseError> {
                 self.is_edited = true;
+                if !self.rules.is_empty() {
+                    self.value = self.value.clone().and_then(|value| self.check_rules(value));
+                }
+                if self.is_pending() && self.value.is_ok() {
+                    return Err(structform::ParseError::ValidationPending);
+                }
                 self.value.clone()
             }
 
+            /// What `submit` would return, without marking the input as
+            /// edited, so that no validation message is shown for it.
+            pub fn peek_submit(&self) -> Result<T, structform::ParseError> {
+                let value = self.value.clone().and_then(|value| self.check_rules(value));
+                if self.is_pending() && value.is_ok() {
+                    return Err(structform::ParseError::ValidationPending);
+                }
+                value
+            }
+
+            /// Sets the input as typed, and parses it. If the input hasn't
+            /// changed, it isn't parsed again.
             pub fn set_input(&mut self, value: String) {
-                self.value = Self::parse(&value);
-                self.input = value;
+                let value = Self::sanitize(value);
+                if value != self.input {
+                    self.value = Self::parse(&value).and_then(|value| self.check_rules(value));
+                    self.input = value;
+                    self.edit_timestamp.record();
+                    self.pending_validation = None;
+                }
+                self.is_edited = true;
+            }
+
+            /// The same as `set_input`, but copies `value` into the
+            /// existing input buffer instead of replacing it.
+            pub fn set_input_str(&mut self, value: &str) {
+                // Like `set_input`, the new input is sanitized before it
+                // is compared, since sanitizing can undo a change, like
+                // a character that isn't allowed being typed.
+                let mut input = std::mem::take(&mut self.spare_input);
+                input.clear();
+                input.push_str(value);
+                let input = Self::sanitize(input);
+                if input == self.input {
+                    self.spare_input = input;
+                    self.is_edited = true;
+                    return;
+                }
+                self.value = Self::parse(&input).and_then(|value| self.check_rules(value));
+                self.spare_input = std::mem::replace(&mut self.input, input);
                 self.is_edited = true;
+                self.edit_timestamp.record();
+                self.pending_validation = None;
             }
 
             pub fn clear(&mut self) {
                 self.initial_input = "".to_string();
-                self.set_input("".to_string());
+                self.input.clear();
+                self.value = Self::parse("").and_then(|value| self.check_rules(value));
                 self.is_edited = false;
+                self.edit_timestamp.clear();
+                self.pending_validation = None;
             }
         }
     };
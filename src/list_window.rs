@@ -0,0 +1,70 @@
+use crate::StructForm;
+use std::ops::Range;
+
+/// A window over a list subform, like one page of a list with hundreds
+/// of entries, so that only the visible entries need to be rendered.
+///
+/// Entries keep their index in the whole list, so field paths and the
+/// form's field enum variants work the same as without a window.
+/// Submitting the form still validates every entry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ListWindow {
+    pub offset: usize,
+    pub limit: usize,
+}
+
+impl ListWindow {
+    pub fn new(offset: usize, limit: usize) -> ListWindow {
+        ListWindow { offset, limit }
+    }
+
+    /// The window for a page of `page_size` entries, counting pages
+    /// from 0.
+    pub fn page(page: usize, page_size: usize) -> ListWindow {
+        ListWindow::new(page * page_size, page_size)
+    }
+
+    /// The indexes of the entries in the window, for a list with `len`
+    /// entries.
+    pub fn range(&self, len: usize) -> Range<usize> {
+        let start = self.offset.min(len);
+        let end = self.offset.saturating_add(self.limit).min(len);
+        start..end
+    }
+
+    /// The entries in the window, along with their index in the whole
+    /// list.
+    pub fn entries<'a, F>(&self, entries: &'a [F]) -> impl Iterator<Item = (usize, &'a F)> {
+        let range = self.range(entries.len());
+        let offset = range.start;
+        entries[range]
+            .iter()
+            .enumerate()
+            .map(move |(i, entry)| (offset + i, entry))
+    }
+}
+
+/// The number of pages needed to show `len` entries, `page_size` at a
+/// time.
+pub fn page_count(len: usize, page_size: usize) -> usize {
+    match len.checked_div(page_size) {
+        Some(full_pages) => full_pages + usize::from(full_pages * page_size < len),
+        None => 0,
+    }
+}
+
+/// The number of entries on each page that would fail to submit, for
+/// showing which pages of a long list need attention.
+pub fn invalid_entries_per_page<F, Model>(entries: &[F], page_size: usize) -> Vec<usize>
+where
+    F: StructForm<Model>,
+{
+    (0..page_count(entries.len(), page_size))
+        .map(|page| {
+            ListWindow::page(page, page_size)
+                .entries(entries)
+                .filter(|(_, entry)| entry.parse().is_err())
+                .count()
+        })
+        .collect()
+}
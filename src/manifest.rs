@@ -0,0 +1,93 @@
+use crate::{FieldKind, FieldMeta, Layout};
+use serde::Serialize;
+
+/// A description of a whole form, which can be serialized with serde
+/// for clients that aren't written in Rust, like a TypeScript
+/// frontend, to render an equivalent form.
+///
+/// With the `manifest` feature, every derived form has a `manifest`
+/// function returning one, built from its `FIELDS` table and its
+/// `FormLayout`. The rules that inputs check when they parse are
+/// written in Rust, so they aren't part of the manifest. Clients
+/// should still send the form's inputs to be checked with
+/// `apply_urlencoded` and `submit`, or `errors_by_path`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FormManifest {
+    pub fields: Vec<FieldManifest>,
+    pub layout: Layout,
+}
+
+/// A field in a `FormManifest`. See `FieldMeta`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct FieldManifest {
+    pub name: &'static str,
+    pub label: &'static str,
+    pub sensitive: bool,
+    pub kind: FieldManifestKind,
+    /// See `FieldMeta::value_type`. This is left out for subforms.
+    #[serde(skip_serializing_if = "str::is_empty")]
+    pub value_type: &'static str,
+    /// The fields of a subform, which is empty for inputs.
+    pub fields: Vec<FieldManifest>,
+}
+
+/// What kind of field a `FieldManifest` describes. See `FieldKind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FieldManifestKind {
+    Input,
+    Subform,
+    OptionalSubform,
+    ListSubform,
+    MapSubform,
+}
+
+impl FormManifest {
+    pub fn new(fields: &'static [FieldMeta], layout: Layout) -> FormManifest {
+        FormManifest {
+            fields: fields.iter().map(FieldManifest::new).collect(),
+            layout,
+        }
+    }
+}
+
+impl FieldManifest {
+    pub fn new(field: &FieldMeta) -> FieldManifest {
+        let (kind, inner_fields) = match field.kind {
+            FieldKind::Input => (FieldManifestKind::Input, &[][..]),
+            FieldKind::Subform(fields) => (FieldManifestKind::Subform, fields),
+            FieldKind::OptionalSubform(fields) => (FieldManifestKind::OptionalSubform, fields),
+            FieldKind::ListSubform(fields) => (FieldManifestKind::ListSubform, fields),
+            FieldKind::MapSubform(fields) => (FieldManifestKind::MapSubform, fields),
+        };
+        FieldManifest {
+            name: field.name,
+            label: field.label,
+            sensitive: field.sensitive,
+            kind,
+            value_type: field.value_type,
+            fields: inner_fields.iter().map(FieldManifest::new).collect(),
+        }
+    }
+}
+
+/// Used by `#[derive(StructForm)]` to add a `manifest` function to
+/// forms when the `manifest` feature is on.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_form_manifest {
+    ($vis: vis, $form: ident) => {
+        #[automatically_derived]
+        impl $form {
+            /// A description of the form for clients that aren't
+            /// written in Rust. See `structform::FormManifest`.
+            #[allow(dead_code)]
+            $vis fn manifest() -> structform::FormManifest {
+                structform::FormManifest::new(
+                    <Self as structform::FormFields>::FIELDS,
+                    <Self as structform::FormLayout>::layout(),
+                )
+            }
+        }
+    };
+}
@@ -0,0 +1,41 @@
+use pulldown_cmark::{html, Event, Parser, Tag, TagEnd};
+
+/// Renders a preview of Markdown as HTML, using
+/// [pulldown-cmark](https://crates.io/crates/pulldown-cmark) to parse
+/// CommonMark.
+///
+/// Any HTML in the input is escaped rather than rendered, and links
+/// and images are only rendered for `http`, `https` and `mailto` URLs
+/// and relative paths. Otherwise, only the link's text is kept.
+pub fn markdown_to_html(markdown: &str) -> String {
+    // Whether each link or image that's open was rendered, so that
+    // their ends can match.
+    let mut open_links = Vec::new();
+    let events = Parser::new(markdown).filter_map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Some(Event::Text(raw)),
+        Event::Start(Tag::Link { ref dest_url, .. })
+        | Event::Start(Tag::Image { ref dest_url, .. }) => {
+            let is_safe = is_safe_url(dest_url);
+            open_links.push(is_safe);
+            Some(event).filter(|_| is_safe)
+        }
+        Event::End(TagEnd::Link) | Event::End(TagEnd::Image) => {
+            Some(event).filter(|_| open_links.pop().unwrap_or(true))
+        }
+        event => Some(event),
+    });
+
+    let mut html = String::new();
+    html::push_html(&mut html, events);
+    html
+}
+
+fn is_safe_url(url: &str) -> bool {
+    let lowercase = url.trim().to_ascii_lowercase();
+    match lowercase.find(':') {
+        Some(_) => ["http://", "https://", "mailto:"]
+            .iter()
+            .any(|scheme| lowercase.starts_with(scheme)),
+        None => true,
+    }
+}
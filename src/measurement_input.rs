@@ -0,0 +1,176 @@
+use crate::ParseError;
+
+/// Units of mass, converted to kilograms.
+pub const MASS_UNITS: &[(&str, f64)] = &[
+    ("kg", 1.0),
+    ("g", 0.001),
+    ("mg", 0.000_001),
+    ("t", 1000.0),
+    ("lb", 0.453_592_37),
+    ("oz", 0.028_349_523_125),
+    ("st", 6.350_293_18),
+];
+
+/// Units of length, converted to metres.
+pub const LENGTH_UNITS: &[(&str, f64)] = &[
+    ("m", 1.0),
+    ("mm", 0.001),
+    ("cm", 0.01),
+    ("km", 1000.0),
+    ("in", 0.0254),
+    ("ft", 0.3048),
+    ("yd", 0.9144),
+    ("mi", 1609.344),
+];
+
+/// Units of volume, converted to litres.
+pub const VOLUME_UNITS: &[(&str, f64)] = &[
+    ("l", 1.0),
+    ("ml", 0.001),
+    ("m3", 1000.0),
+    ("gal", 3.785_411_784),
+    ("qt", 0.946_352_946),
+    ("pt", 0.473_176_473),
+    ("floz", 0.029_573_529_562_5),
+];
+
+fn find_unit(units: &[(&str, f64)], unit: &str) -> Option<f64> {
+    units
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+        .map(|(_, factor)| *factor)
+}
+
+/// Parses a measurement like "12.5 kg" or "27lb", and converts it to
+/// the canonical unit of `units`. `units` is a table of unit names and
+/// how many canonical units each one is. Unit names are matched case
+/// insensitively.
+///
+/// If no unit is given, the number is assumed to be in `default_unit`.
+pub fn parse_measurement(
+    value: &str,
+    units: &[(&str, f64)],
+    default_unit: &str,
+) -> Result<f64, ParseError> {
+    let value = value.trim();
+    let number_end = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(value.len());
+    let number = &value[..number_end];
+    let unit = match value[number_end..].trim() {
+        "" => default_unit,
+        unit => unit,
+    };
+
+    let required_type = || {
+        format!(
+            "a measurement in {}",
+            units
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ")
+        )
+    };
+    let number: f64 = number.parse().map_err(|_| ParseError::InvalidFormat {
+        required_type: required_type(),
+    })?;
+    let factor = find_unit(units, unit).ok_or_else(|| ParseError::InvalidFormat {
+        required_type: required_type(),
+    })?;
+    Ok(number * factor)
+}
+
+/// Formats a measurement in the canonical unit of `units` as `unit`,
+/// like "27 lb". The number is rounded to at most 2 decimal places.
+///
+/// If `unit` isn't in `units`, the measurement is formatted in the
+/// canonical unit instead.
+pub fn format_measurement(value: f64, units: &[(&str, f64)], unit: &str) -> String {
+    let (unit, factor) = units
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+        .or_else(|| units.iter().find(|(_, factor)| *factor == 1.0))
+        .map(|(name, factor)| (*name, *factor))
+        .unwrap_or(("", 1.0));
+    let number = format!("{:.2}", value / factor);
+    let number = number.trim_end_matches('0').trim_end_matches('.');
+    let number = if number == "-0" { "0" } else { number };
+    if unit.is_empty() {
+        number.to_string()
+    } else {
+        format!("{} {}", number, unit)
+    }
+}
+
+/// Implements `ParseAndFormat<f64> for $measurement_input<f64>`, and
+/// also implements `ParseAndFormat<Option<f64>> for
+/// $measurement_input<Option<f64>>`.
+///
+/// `$units` is a conversion table, like `structform::MASS_UNITS`,
+/// listing each unit name and how many canonical units it is. Inputs
+/// like "12.5 kg" or "27 lb" are parsed using
+/// `structform::parse_measurement`, and the value stored in the model
+/// is always in the canonical unit. Numbers without a unit are
+/// assumed to be in `$preferred_unit`, and values are formatted back
+/// in `$preferred_unit`.
+///
+/// If the input string is empty after trimming, then parse will return
+/// a `ParseError::Required` for the `ParseAndFormat<f64>` case, and
+/// return `None` for the `ParseAndFormat<Option<f64>>` case.
+///
+/// This also adds a `format_in` function to the input, for showing the
+/// current value in a different unit.
+#[macro_export]
+macro_rules! impl_measurement_input {
+    ($measurement_input: ident, $units: expr, $preferred_unit: expr) => {
+        impl structform::ParseAndFormat<f64> for $measurement_input<f64> {
+            fn parse(value: &str) -> Result<f64, structform::ParseError> {
+                if value.trim().is_empty() {
+                    Err(structform::ParseError::Required)
+                } else {
+                    structform::parse_measurement(value, $units, $preferred_unit)
+                }
+            }
+
+            fn format(value: &f64) -> String {
+                structform::format_measurement(*value, $units, $preferred_unit)
+            }
+        }
+
+        impl structform::ParseAndFormat<Option<f64>> for $measurement_input<Option<f64>> {
+            fn parse(value: &str) -> Result<Option<f64>, structform::ParseError> {
+                if value.trim().is_empty() {
+                    Ok(None)
+                } else {
+                    structform::parse_measurement(value, $units, $preferred_unit).map(Option::Some)
+                }
+            }
+
+            fn format(value: &Option<f64>) -> String {
+                value
+                    .map(|value| structform::format_measurement(value, $units, $preferred_unit))
+                    .unwrap_or_default()
+            }
+        }
+
+        impl $measurement_input<f64> {
+            pub fn format_in(&self, unit: &str) -> Option<String> {
+                self.value
+                    .as_ref()
+                    .ok()
+                    .map(|value| structform::format_measurement(*value, $units, unit))
+            }
+        }
+
+        impl $measurement_input<Option<f64>> {
+            pub fn format_in(&self, unit: &str) -> Option<String> {
+                self.value
+                    .as_ref()
+                    .ok()
+                    .and_then(|value| *value)
+                    .map(|value| structform::format_measurement(value, $units, unit))
+            }
+        }
+    };
+}
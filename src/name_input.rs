@@ -0,0 +1,53 @@
+/// Splits a full name into a first name and a last name.
+///
+/// The first word is taken as the first name, and the rest as the
+/// last name, so that surnames like "van der Merwe" are kept
+/// together. Extra whitespace between words is collapsed. Returns
+/// `None` unless there are at least two words.
+pub fn split_full_name(value: &str) -> Option<(String, String)> {
+    let mut words = value.split_whitespace();
+    let first_name = words.next()?.to_string();
+    let last_name = words.collect::<Vec<_>>().join(" ");
+    if last_name.is_empty() {
+        None
+    } else {
+        Some((first_name, last_name))
+    }
+}
+
+/// Implements `ParseAndFormat<(String, String)> for
+/// $name_input<(String, String)>`, for a single input where a user
+/// types their full name, which is stored as a first name and a last
+/// name.
+///
+/// This will parse using `structform::split_full_name`. If the input
+/// string is empty after trimming, then parse will return a
+/// `ParseError::Required`. If only one name is given, it will return
+/// a `ParseError::InvalidFormat`.
+///
+/// Formatting joins the two names with a space.
+///
+/// Use `#[structform(model_fields = "first_name, last_name")]` on
+/// the field to store the two names in separate fields on your model.
+#[macro_export]
+macro_rules! impl_full_name_input {
+    ($name_input: ident) => {
+        impl structform::ParseAndFormat<(String, String)> for $name_input<(String, String)> {
+            fn parse(value: &str) -> Result<(String, String), structform::ParseError> {
+                if value.trim().is_empty() {
+                    Err(structform::ParseError::Required)
+                } else {
+                    structform::split_full_name(value).ok_or_else(|| {
+                        structform::ParseError::InvalidFormat {
+                            required_type: "a first name and a last name".to_string(),
+                        }
+                    })
+                }
+            }
+
+            fn format(value: &(String, String)) -> String {
+                format!("{} {}", value.0, value.1).trim().to_string()
+            }
+        }
+    };
+}
@@ -0,0 +1,184 @@
+use crate::ParseError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Well known service names, and the port that each one runs on by
+/// default. This is used by `impl_port_input_with_stringops` to let
+/// users type a service name like "https" in place of a port number.
+pub const SERVICE_PORTS: &[(&str, u16)] = &[
+    ("ftp", 21),
+    ("ssh", 22),
+    ("telnet", 23),
+    ("smtp", 25),
+    ("dns", 53),
+    ("http", 80),
+    ("pop3", 110),
+    ("ntp", 123),
+    ("imap", 143),
+    ("snmp", 161),
+    ("ldap", 389),
+    ("https", 443),
+    ("smtps", 465),
+    ("submission", 587),
+    ("ldaps", 636),
+    ("imaps", 993),
+    ("pop3s", 995),
+    ("mssql", 1433),
+    ("mysql", 3306),
+    ("rdp", 3389),
+    ("postgresql", 5432),
+    ("amqp", 5672),
+    ("redis", 6379),
+    ("http-alt", 8080),
+    ("mongodb", 27017),
+];
+
+/// Looks up the default port for a service name in
+/// `SERVICE_PORTS`. The lookup ignores case.
+pub fn port_from_service_name(name: &str) -> Option<u16> {
+    SERVICE_PORTS
+        .iter()
+        .find(|(service, _)| service.eq_ignore_ascii_case(name))
+        .map(|(_, port)| *port)
+}
+
+/// Parses a port that may either be given as a number or as a
+/// service name from `SERVICE_PORTS`. The input is expected to
+/// already be trimmed and non-empty. Port 0 isn't a port that can be
+/// connected to, so numbers start at 1.
+pub fn parse_port(trimmed: &str) -> Result<u16, ParseError> {
+    if trimmed.chars().all(|c| c.is_ascii_digit()) {
+        trimmed
+            .parse::<u16>()
+            .ok()
+            .filter(|port| *port >= 1)
+            .ok_or_else(|| ParseError::NumberOutOfRange {
+                required_type: "a port".to_string(),
+                min: "1".to_string(),
+                max: u16::MAX.to_string(),
+            })
+    } else {
+        port_from_service_name(trimmed).ok_or_else(|| ParseError::InvalidFormat {
+            required_type: "a port number or service name".to_string(),
+        })
+    }
+}
+
+/// Implements `ParseAndFormat<u16> for $port_input<u16>`, and also
+/// implements `ParseAndFormat<Option<u16>> for $port_input<Option<u16>>`.
+///
+/// This will parse by trimming the string input, and then either
+/// parsing it as a number or looking it up as a service name (like
+/// "https") in `structform::SERVICE_PORTS`. If the input string is
+/// empty after trimming, then parse will return a
+/// `ParseError::Required` for the `ParseAndFormat<u16>` case, and
+/// return `None` for the `ParseAndFormat<Option<u16>>` case.
+///
+/// Formatting is done using `std::string::ToString`, so a service
+/// name will be shown as its port number once the form is
+/// initialized from a model.
+#[macro_export]
+macro_rules! impl_port_input_with_stringops {
+    ($port_input: ident) => {
+        impl structform::ParseAndFormat<u16> for $port_input<u16> {
+            fn parse(value: &str) -> Result<u16, structform::ParseError> {
+                let trimmed = value.trim();
+                if trimmed.is_empty() {
+                    Err(structform::ParseError::Required)
+                } else {
+                    structform::parse_port(trimmed)
+                }
+            }
+
+            fn format(value: &u16) -> String {
+                value.to_string()
+            }
+        }
+
+        impl structform::ParseAndFormat<Option<u16>> for $port_input<Option<u16>> {
+            fn parse(value: &str) -> Result<Option<u16>, structform::ParseError> {
+                let trimmed = value.trim();
+                if trimmed.is_empty() {
+                    Ok(None)
+                } else {
+                    structform::parse_port(trimmed).map(Option::Some)
+                }
+            }
+
+            fn format(value: &Option<u16>) -> String {
+                match value {
+                    None => "".to_string(),
+                    Some(inner) => inner.to_string(),
+                }
+            }
+        }
+    };
+}
+
+/// A 48 bit MAC address.
+///
+/// This can be parsed from six hex octets separated by colons
+/// (`aa:bb:cc:dd:ee:ff`), separated by hyphens (`AA-BB-CC-DD-EE-FF`)
+/// or with no separators at all (`aabbccddeeff`). Upper and lower
+/// case are both accepted. It is always formatted in lower case with
+/// colons, so it can be used with `impl_text_input_with_stringops` to
+/// canonicalize whatever the user typed.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct MacAddress(pub [u8; 6]);
+
+impl MacAddress {
+    pub fn octets(&self) -> [u8; 6] {
+        self.0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MacAddressParseError;
+
+impl fmt::Display for MacAddressParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Expected a MAC address like aa:bb:cc:dd:ee:ff")
+    }
+}
+
+impl std::error::Error for MacAddressParseError {}
+
+impl FromStr for MacAddress {
+    type Err = MacAddressParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let octets: Vec<&str> = if s.contains(':') {
+            s.split(':').collect()
+        } else if s.contains('-') {
+            s.split('-').collect()
+        } else if s.len() == 12 && s.is_ascii() {
+            (0..6).map(|i| &s[i * 2..i * 2 + 2]).collect()
+        } else {
+            return Err(MacAddressParseError);
+        };
+
+        if octets.len() != 6 {
+            return Err(MacAddressParseError);
+        }
+
+        let mut result = [0; 6];
+        for (byte, octet) in result.iter_mut().zip(octets) {
+            if octet.len() != 2 || !octet.chars().all(|c| c.is_ascii_hexdigit()) {
+                return Err(MacAddressParseError);
+            }
+            *byte = u8::from_str_radix(octet, 16).map_err(|_e| MacAddressParseError)?;
+        }
+        Ok(MacAddress(result))
+    }
+}
+
+impl fmt::Display for MacAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(
+            f,
+            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+            a, b, c, d, e, g
+        )
+    }
+}
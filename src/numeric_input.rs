@@ -1,3 +1,33 @@
+use crate::ParseError;
+use std::fmt;
+use std::str::FromStr;
+
+/// Parses a number typed into a numeric input, and checks that it is
+/// between `min` and `max`. Numbers that don't parse, or that are out
+/// of range, fail with a `ParseError::NumberOutOfRange`.
+///
+/// This is used by the numeric input macros when they're given
+/// `min = ...` and `max = ...` bounds.
+pub fn parse_number_in_range<N>(
+    trimmed: &str,
+    type_name: &str,
+    min: N,
+    max: N,
+) -> Result<N, ParseError>
+where
+    N: FromStr + PartialOrd + fmt::Display,
+{
+    trimmed
+        .parse::<N>()
+        .ok()
+        .filter(|number| *number >= min && *number <= max)
+        .ok_or_else(|| ParseError::NumberOutOfRange {
+            required_type: type_name.to_string(),
+            min: min.to_string(),
+            max: max.to_string(),
+        })
+}
+
 /// Implements `ParseAndFormat<$type> for $numeric_input<$type>`, and also
 /// implements `ParseAndFormat<Option<$type>>> for $numeric_input<Option<$type>>`.
 ///
@@ -10,45 +40,41 @@
 /// return `None` for the `ParseAndFormat<Option<$type>>` case.
 ///
 /// Formatting is done using `std::string::ToString`.
+///
+/// `$min` and `$max` are only used in the error message for input
+/// that isn't a number. To also check the range, pass them as
+/// `min = ..., max = ...` instead. They are then evaluated every time
+/// the input is parsed, so they can be runtime expressions, like
+/// limits read from a tenant's configuration:
+///
+/// ```ignore
+/// impl_numeric_input_with_stringops!(FormNumberInput, "a quantity", u32, u32, min = 1, max = max_quantity());
+/// ```
 #[macro_export]
 macro_rules! impl_numeric_input_with_stringops {
-    ($numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty) => {
-        impl_numeric_input_with_stringops!(
-            $numeric_input,
-            $type_name,
-            $type,
-            $underlying_numeric_type,
-            <$type>::MIN,
-            <$type>::MAX
-        );
+    (@parse $trimmed: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty, $min: expr, $max: expr, checked) => {
+        structform::parse_number_in_range::<$underlying_numeric_type>(
+            $trimmed, $type_name, $min, $max,
+        )
+        .and_then(|via| {
+            <$type>::try_from(via)
+                .map_err(|e| structform::ParseError::FromStrError(e.to_string()))
+        })
     };
-    ($numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty, $min: expr, $max: expr) => {
-        impl structform::ParseAndFormat<$type> for $numeric_input<$type> {
-            fn parse(value: &str) -> Result<$type, ParseError> {
-                use std::convert::TryFrom;
-                let trimmed = value.trim();
-                if trimmed.is_empty() {
-                    Err(ParseError::Required)
-                } else {
-                    trimmed
-                        .parse::<$underlying_numeric_type>()
-                        .map_err(|_e| ParseError::NumberOutOfRange {
-                            required_type: $type_name.to_string(),
-                            min: $min.to_string(),
-                            max: $max.to_string(),
-                        })
-                        .and_then(|via| {
-                            <$type>::try_from(via)
-                                .map_err(|e| ParseError::FromStrError(e.to_string()))
-                        })
-                }
-            }
-
-            fn format(value: &$type) -> String {
-                value.to_string()
-            }
-        }
-
+    (@parse $trimmed: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty, $min: expr, $max: expr, unchecked) => {
+        $trimmed
+            .parse::<$underlying_numeric_type>()
+            .map_err(|_e| structform::ParseError::NumberOutOfRange {
+                required_type: $type_name.to_string(),
+                min: $min.to_string(),
+                max: $max.to_string(),
+            })
+            .and_then(|via| {
+                <$type>::try_from(via)
+                    .map_err(|e| structform::ParseError::FromStrError(e.to_string()))
+            })
+    };
+    (@optional $numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty, $min: expr, $max: expr, $range: ident) => {
         impl structform::ParseAndFormat<Option<$type>> for $numeric_input<Option<$type>> {
             fn parse(value: &str) -> Result<Option<$type>, structform::ParseError> {
                 use std::convert::TryFrom;
@@ -57,18 +83,10 @@ macro_rules! impl_numeric_input_with_stringops {
                 if trimmed.is_empty() {
                     Ok(None)
                 } else {
-                    trimmed
-                        .parse::<$underlying_numeric_type>()
-                        .map_err(|_e| structform::ParseError::NumberOutOfRange {
-                            required_type: $type_name.to_string(),
-                            min: $min.to_string(),
-                            max: $max.to_string(),
-                        })
-                        .and_then(|via| {
-                            <$type>::try_from(via)
-                                .map_err(|e| structform::ParseError::FromStrError(e.to_string()))
-                        })
-                        .map(Option::Some)
+                    $crate::impl_numeric_input_with_stringops!(
+                        @parse trimmed, $type_name, $type, $underlying_numeric_type, $min, $max, $range
+                    )
+                    .map(Option::Some)
                 }
             }
 
@@ -80,16 +98,32 @@ macro_rules! impl_numeric_input_with_stringops {
             }
         }
     };
-}
+    (@required $numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty, $min: expr, $max: expr, $range: ident) => {
+        impl structform::ParseAndFormat<$type> for $numeric_input<$type> {
+            fn parse(value: &str) -> Result<$type, structform::ParseError> {
+                use std::convert::TryFrom;
+                let trimmed = value.trim();
+                if trimmed.is_empty() {
+                    Err(structform::ParseError::Required)
+                } else {
+                    $crate::impl_numeric_input_with_stringops!(
+                        @parse trimmed, $type_name, $type, $underlying_numeric_type, $min, $max, $range
+                    )
+                }
+            }
 
-/// Implements `ParseAndFormat<$type> for $numeric_input<$type>`.
-///
-/// This works the same as `impl_numeric_input_with_stringops`, except if input
-/// string is empty after trimming, then parse will return $type::default().
-#[macro_export]
-macro_rules! impl_numeric_input_with_default_with_stringops {
+            fn format(value: &$type) -> String {
+                value.to_string()
+            }
+        }
+
+        $crate::impl_numeric_input_with_stringops!(@optional $numeric_input, $type_name, $type, $underlying_numeric_type, $min, $max, $range);
+    };
+    ($numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty, min = $min: expr, max = $max: expr) => {
+        $crate::impl_numeric_input_with_stringops!(@required $numeric_input, $type_name, $type, $underlying_numeric_type, $min, $max, checked);
+    };
     ($numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty) => {
-        impl_numeric_input_with_default_with_stringops!(
+        $crate::impl_numeric_input_with_stringops!(
             $numeric_input,
             $type_name,
             $type,
@@ -99,24 +133,40 @@ macro_rules! impl_numeric_input_with_default_with_stringops {
         );
     };
     ($numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty, $min: expr, $max: expr) => {
+        $crate::impl_numeric_input_with_stringops!(@required $numeric_input, $type_name, $type, $underlying_numeric_type, $min, $max, unchecked);
+    };
+}
+
+/// Implements `ParseAndFormat<$type> for $numeric_input<$type>`.
+///
+/// This works the same as `impl_numeric_input_with_stringops`, except if input
+/// string is empty after trimming, then parse will return $type::default().
+///
+/// Like `impl_numeric_input_with_stringops`, the range is checked at
+/// parse time if it is given as `min = ..., max = ...`.
+///
+/// `ParseAndFormat<Option<$type>>` is only implemented if `optional`
+/// is passed as the last argument, so existing code that implements
+/// it separately doesn't clash. An empty optional input is still
+/// `None`, so the two macros can be swapped without breaking optional
+/// fields:
+///
+/// ```ignore
+/// impl_numeric_input_with_default_with_stringops!(FormNumberInput, "a quantity", u32, u32, optional);
+/// ```
+#[macro_export]
+macro_rules! impl_numeric_input_with_default_with_stringops {
+    (@required $numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty, $min: expr, $max: expr, $range: ident) => {
         impl structform::ParseAndFormat<$type> for $numeric_input<$type> {
-            fn parse(value: &str) -> Result<$type, ParseError> {
+            fn parse(value: &str) -> Result<$type, structform::ParseError> {
                 use std::convert::TryFrom;
                 let trimmed = value.trim();
                 if trimmed.is_empty() {
                     Ok(<$type>::default())
                 } else {
-                    trimmed
-                        .parse::<$underlying_numeric_type>()
-                        .map_err(|_e| ParseError::NumberOutOfRange {
-                            required_type: $type_name.to_string(),
-                            min: $min.to_string(),
-                            max: $max.to_string(),
-                        })
-                        .and_then(|via| {
-                            <$type>::try_from(via)
-                                .map_err(|e| ParseError::FromStrError(e.to_string()))
-                        })
+                    $crate::impl_numeric_input_with_stringops!(
+                        @parse trimmed, $type_name, $type, $underlying_numeric_type, $min, $max, $range
+                    )
                 }
             }
 
@@ -125,4 +175,39 @@ macro_rules! impl_numeric_input_with_default_with_stringops {
             }
         }
     };
+    ($numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty, min = $min: expr, max = $max: expr, optional) => {
+        $crate::impl_numeric_input_with_default_with_stringops!(@required $numeric_input, $type_name, $type, $underlying_numeric_type, $min, $max, checked);
+        $crate::impl_numeric_input_with_stringops!(@optional $numeric_input, $type_name, $type, $underlying_numeric_type, $min, $max, checked);
+    };
+    ($numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty, min = $min: expr, max = $max: expr) => {
+        $crate::impl_numeric_input_with_default_with_stringops!(@required $numeric_input, $type_name, $type, $underlying_numeric_type, $min, $max, checked);
+    };
+    ($numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty, optional) => {
+        $crate::impl_numeric_input_with_default_with_stringops!(
+            $numeric_input,
+            $type_name,
+            $type,
+            $underlying_numeric_type,
+            <$type>::MIN,
+            <$type>::MAX,
+            optional
+        );
+    };
+    ($numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty) => {
+        $crate::impl_numeric_input_with_default_with_stringops!(
+            $numeric_input,
+            $type_name,
+            $type,
+            $underlying_numeric_type,
+            <$type>::MIN,
+            <$type>::MAX
+        );
+    };
+    ($numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty, $min: expr, $max: expr, optional) => {
+        $crate::impl_numeric_input_with_default_with_stringops!(@required $numeric_input, $type_name, $type, $underlying_numeric_type, $min, $max, unchecked);
+        $crate::impl_numeric_input_with_stringops!(@optional $numeric_input, $type_name, $type, $underlying_numeric_type, $min, $max, unchecked);
+    };
+    ($numeric_input: ident, $type_name: literal, $type: ty, $underlying_numeric_type: ty, $min: expr, $max: expr) => {
+        $crate::impl_numeric_input_with_default_with_stringops!(@required $numeric_input, $type_name, $type, $underlying_numeric_type, $min, $max, unchecked);
+    };
 }
@@ -0,0 +1,94 @@
+use crate::{FieldManifest, FieldManifestKind, FormManifest};
+use serde_json::{json, Map, Value};
+
+impl FormManifest {
+    /// An OpenAPI schema for the model that the form submits, for
+    /// adding to the `components.schemas` of an API's documentation.
+    ///
+    /// Properties are named after the form's fields, and have the
+    /// field's label as their title. Inputs of `Option` types,
+    /// optional subforms and the variants of enum forms aren't
+    /// required, and are nullable. Sensitive inputs, like passwords,
+    /// are `writeOnly`.
+    ///
+    /// Types are worked out from each input's `value_type`. Numbers,
+    /// `bool`, `String` and `Vec`s of them are described fully. Other
+    /// types are serialized however their own serde implementation
+    /// chooses, so their schema only has an `x-rust-type` extension
+    /// naming the type. The rules that inputs check when they parse,
+    /// like the bounds of numeric inputs, are written in Rust and
+    /// can't be described.
+    pub fn to_openapi_schema(&self) -> Value {
+        object_schema(&self.fields)
+    }
+}
+
+fn object_schema(fields: &[FieldManifest]) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for field in fields {
+        let (mut schema, is_required) = match field.kind {
+            FieldManifestKind::Input => match option_inner_type(field.value_type) {
+                Some(inner_type) => (value_type_schema(inner_type), false),
+                None => (value_type_schema(field.value_type), true),
+            },
+            FieldManifestKind::Subform => (object_schema(&field.fields), true),
+            FieldManifestKind::OptionalSubform => (object_schema(&field.fields), false),
+            FieldManifestKind::ListSubform => (
+                json!({ "type": "array", "items": object_schema(&field.fields) }),
+                true,
+            ),
+            FieldManifestKind::MapSubform => (
+                json!({ "type": "object", "additionalProperties": object_schema(&field.fields) }),
+                true,
+            ),
+        };
+        let schema_fields = schema.as_object_mut().expect("Schemas are objects");
+        schema_fields.insert("title".to_string(), json!(field.label));
+        if !is_required {
+            schema_fields.insert("nullable".to_string(), json!(true));
+        }
+        if field.sensitive {
+            schema_fields.insert("writeOnly".to_string(), json!(true));
+        }
+        if is_required {
+            required.push(json!(field.name));
+        }
+        properties.insert(field.name.to_string(), schema);
+    }
+
+    let mut schema = json!({ "type": "object", "properties": properties });
+    if !required.is_empty() {
+        schema["required"] = Value::Array(required);
+    }
+    schema
+}
+
+fn option_inner_type(value_type: &str) -> Option<&str> {
+    value_type
+        .strip_prefix("Option<")
+        .or_else(|| value_type.strip_prefix("std::option::Option<"))
+        .and_then(|inner_type| inner_type.strip_suffix('>'))
+}
+
+fn value_type_schema(value_type: &str) -> Value {
+    if let Some(item_type) = value_type
+        .strip_prefix("Vec<")
+        .and_then(|item_type| item_type.strip_suffix('>'))
+    {
+        return json!({ "type": "array", "items": value_type_schema(item_type) });
+    }
+    match value_type {
+        "i8" | "i16" | "i32" => json!({ "type": "integer", "format": "int32" }),
+        "i64" | "isize" => json!({ "type": "integer", "format": "int64" }),
+        "u8" | "u16" => json!({ "type": "integer", "format": "int32", "minimum": 0 }),
+        "u32" | "u64" | "usize" => json!({ "type": "integer", "format": "int64", "minimum": 0 }),
+        "i128" => json!({ "type": "integer" }),
+        "u128" => json!({ "type": "integer", "minimum": 0 }),
+        "f32" => json!({ "type": "number", "format": "float" }),
+        "f64" => json!({ "type": "number", "format": "double" }),
+        "bool" => json!({ "type": "boolean" }),
+        "String" | "std::string::String" => json!({ "type": "string" }),
+        _ => json!({ "x-rust-type": value_type }),
+    }
+}
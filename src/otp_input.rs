@@ -0,0 +1,59 @@
+/// Implements `ParseAndFormat<String> for $otp_input<String>`, for
+/// one-time codes made up of exactly `$length` digits.
+///
+/// Anything other than digits is dropped as it is typed, and the
+/// input is cut off at `$length` digits, so pasting a code like
+/// "123 456" works as expected. If the input is empty, then parse will
+/// return a `ParseError::Required`. If it is shorter than `$length`,
+/// parse will return a `ParseError::InvalidFormat`.
+///
+/// This also adds accessors for the individual digits, for rendering
+/// the code as a row of single character boxes.
+#[macro_export]
+macro_rules! impl_otp_input {
+    ($otp_input: ident, $length: expr) => {
+        impl structform::ParseAndFormat<String> for $otp_input<String> {
+            fn parse(value: &str) -> Result<String, structform::ParseError> {
+                if value.is_empty() {
+                    Err(structform::ParseError::Required)
+                } else if value.len() != $length || !value.chars().all(|c| c.is_ascii_digit()) {
+                    Err(structform::ParseError::InvalidFormat {
+                        required_type: format!("a {} digit code", $length),
+                    })
+                } else {
+                    Ok(value.to_string())
+                }
+            }
+
+            fn format(value: &String) -> String {
+                value.clone()
+            }
+
+            fn sanitize(value: String) -> String {
+                value
+                    .chars()
+                    .filter(|c| c.is_ascii_digit())
+                    .take($length)
+                    .collect()
+            }
+        }
+
+        impl $otp_input<String> {
+            /// The number of digits in a complete code.
+            pub fn code_length(&self) -> usize {
+                $length
+            }
+
+            /// The digit at `index`, if it has been entered yet.
+            pub fn digit(&self, index: usize) -> Option<char> {
+                self.input.chars().nth(index)
+            }
+
+            /// Every digit position in the code, with `None` for
+            /// digits that haven't been entered yet.
+            pub fn digits(&self) -> Vec<Option<char>> {
+                (0..$length).map(|index| self.digit(index)).collect()
+            }
+        }
+    };
+}
@@ -0,0 +1,171 @@
+use std::fmt;
+
+/// A rough estimate of how hard a password would be to guess. See
+/// `estimate_password_strength`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PasswordStrength {
+    VeryWeak,
+    Weak,
+    Fair,
+    Strong,
+    VeryStrong,
+}
+
+impl fmt::Display for PasswordStrength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PasswordStrength::VeryWeak => write!(f, "very weak"),
+            PasswordStrength::Weak => write!(f, "weak"),
+            PasswordStrength::Fair => write!(f, "fair"),
+            PasswordStrength::Strong => write!(f, "strong"),
+            PasswordStrength::VeryStrong => write!(f, "very strong"),
+        }
+    }
+}
+
+#[cfg(feature = "password")]
+const COMMON_PASSWORDS: &[&str] = &[
+    "123456",
+    "12345678",
+    "123456789",
+    "password",
+    "password1",
+    "qwerty",
+    "qwerty123",
+    "letmein",
+    "welcome",
+    "admin",
+    "iloveyou",
+    "monkey",
+    "dragon",
+    "abc123",
+    "111111",
+    "000000",
+];
+
+/// Estimates the strength of a password using heuristics on its
+/// length and the kinds of characters it uses.
+///
+/// This is intended for realtime feedback while someone is typing a
+/// new password. It is not a replacement for a proper password
+/// policy on your server.
+#[cfg(feature = "password")]
+pub fn estimate_password_strength(password: &str) -> PasswordStrength {
+    if COMMON_PASSWORDS
+        .iter()
+        .any(|common| common.eq_ignore_ascii_case(password))
+    {
+        return PasswordStrength::VeryWeak;
+    }
+
+    let has = |predicate: fn(&char) -> bool| password.chars().any(|c| predicate(&c));
+    let mut charset_size = 0;
+    if has(char::is_ascii_lowercase) {
+        charset_size += 26;
+    }
+    if has(char::is_ascii_uppercase) {
+        charset_size += 26;
+    }
+    if has(char::is_ascii_digit) {
+        charset_size += 10;
+    }
+    if has(char::is_ascii_punctuation) || has(|c| *c == ' ') {
+        charset_size += 33;
+    }
+    if has(|c| !c.is_ascii()) {
+        charset_size += 100;
+    }
+
+    // Repeating the same character doesn't make a password much
+    // harder to guess, so only distinct neighbouring characters
+    // count towards the length.
+    let mut effective_length = 0;
+    let mut previous = None;
+    for c in password.chars() {
+        if previous != Some(c) {
+            effective_length += 1;
+        }
+        previous = Some(c);
+    }
+
+    let entropy_bits = effective_length as f64 * f64::from(charset_size.max(1)).log2();
+    if entropy_bits < 28.0 {
+        PasswordStrength::VeryWeak
+    } else if entropy_bits < 36.0 {
+        PasswordStrength::Weak
+    } else if entropy_bits < 60.0 {
+        PasswordStrength::Fair
+    } else if entropy_bits < 128.0 {
+        PasswordStrength::Strong
+    } else {
+        PasswordStrength::VeryStrong
+    }
+}
+
+/// Implements `ParseAndFormat<String> for $password_input<String>`,
+/// and also implements `ParseAndFormat<Option<String>> for
+/// $password_input<Option<String>>`.
+///
+/// Unlike the text input macros, the input is not trimmed, since
+/// leading and trailing whitespace may be part of a password. If the
+/// input is empty, then parse will return a `ParseError::Required` for
+/// the `ParseAndFormat<String>` case, and return `None` for the
+/// `ParseAndFormat<Option<String>>` case.
+///
+/// If a `$min_strength` is given, passwords which
+/// `estimate_password_strength` rates as weaker than it will fail to
+/// parse with `ParseError::PasswordTooWeak`.
+///
+/// This also adds a `strength` function to the input, for showing a
+/// strength meter while the user is typing.
+#[cfg(feature = "password")]
+#[macro_export]
+macro_rules! impl_password_input {
+    ($password_input: ident) => {
+        impl_password_input!($password_input, structform::PasswordStrength::VeryWeak);
+    };
+    ($password_input: ident, $min_strength: expr) => {
+        impl structform::ParseAndFormat<String> for $password_input<String> {
+            fn parse(value: &str) -> Result<String, structform::ParseError> {
+                if value.is_empty() {
+                    Err(structform::ParseError::Required)
+                } else if structform::estimate_password_strength(value) < $min_strength {
+                    Err(structform::ParseError::PasswordTooWeak { min: $min_strength })
+                } else {
+                    Ok(value.to_string())
+                }
+            }
+
+            fn format(value: &String) -> String {
+                value.clone()
+            }
+        }
+
+        impl structform::ParseAndFormat<Option<String>> for $password_input<Option<String>> {
+            fn parse(value: &str) -> Result<Option<String>, structform::ParseError> {
+                if value.is_empty() {
+                    Ok(None)
+                } else {
+                    <$password_input<String> as structform::ParseAndFormat<String>>::parse(value)
+                        .map(Option::Some)
+                }
+            }
+
+            fn format(value: &Option<String>) -> String {
+                value.clone().unwrap_or_default()
+            }
+        }
+
+        impl $password_input<String> {
+            pub fn strength(&self) -> structform::PasswordStrength {
+                structform::estimate_password_strength(&self.input)
+            }
+        }
+
+        impl $password_input<Option<String>> {
+            pub fn strength(&self) -> structform::PasswordStrength {
+                structform::estimate_password_strength(&self.input)
+            }
+        }
+    };
+}
@@ -0,0 +1,16 @@
+/// Splits a pasted row into pieces, for `StructForm::apply_paste`.
+/// Rows copied from a spreadsheet are split on tabs. Anything else is
+/// split on commas. Pieces are trimmed, and a trailing line break is
+/// ignored.
+///
+/// ```
+/// assert_eq!(structform::split_pasted_row("Justin\t30\n"), vec!["Justin", "30"]);
+/// assert_eq!(structform::split_pasted_row("Justin, 30"), vec!["Justin", "30"]);
+/// ```
+pub fn split_pasted_row(pasted: &str) -> Vec<String> {
+    let row = pasted.trim_end_matches(['\r', '\n']);
+    let separator = if row.contains('\t') { '\t' } else { ',' };
+    row.split(separator)
+        .map(|piece| piece.trim().to_string())
+        .collect()
+}
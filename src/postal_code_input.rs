@@ -0,0 +1,184 @@
+use crate::ParseError;
+
+/// Postal code formats for some common countries. In each pattern,
+/// `9` is any digit, `A` is any letter, and other characters must
+/// appear as written. Spaces in the patterns are optional when
+/// parsing, and are added back when formatting.
+const POSTAL_CODE_PATTERNS: &[(&str, &[&str])] = &[
+    ("AT", &["9999"]),
+    ("AU", &["9999"]),
+    ("BE", &["9999"]),
+    ("BR", &["99999-999"]),
+    ("CA", &["A9A 9A9"]),
+    ("CH", &["9999"]),
+    ("DE", &["99999"]),
+    ("DK", &["9999"]),
+    ("ES", &["99999"]),
+    ("FR", &["99999"]),
+    (
+        "GB",
+        &[
+            "A9 9AA", "A99 9AA", "A9A 9AA", "AA9 9AA", "AA99 9AA", "AA9A 9AA",
+        ],
+    ),
+    ("IN", &["999999"]),
+    ("IT", &["99999"]),
+    ("JP", &["999-9999"]),
+    ("NL", &["9999 AA"]),
+    ("NO", &["9999"]),
+    ("NZ", &["9999"]),
+    ("PL", &["99-999"]),
+    ("PT", &["9999-999"]),
+    ("SE", &["999 99"]),
+    ("US", &["99999", "99999-9999"]),
+    ("ZA", &["9999"]),
+];
+
+/// Cleans up a postal code as typed, by trimming it, converting it to
+/// upper case and collapsing any runs of whitespace into a single
+/// space.
+///
+/// Any postal code from 2 to 10 letters, digits, spaces and hyphens
+/// is accepted, since the format depends on the country. Use
+/// `validate_postal_code` to check it against a specific country's
+/// format.
+pub fn parse_postal_code(value: &str) -> Result<String, ParseError> {
+    let postal_code = value
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_ascii_uppercase();
+    if postal_code.is_empty() {
+        Err(ParseError::Required)
+    } else if postal_code.len() < 2
+        || postal_code.len() > 10
+        || !postal_code
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '-')
+    {
+        Err(ParseError::InvalidFormat {
+            required_type: "a postal code".to_string(),
+        })
+    } else {
+        Ok(postal_code)
+    }
+}
+
+/// Checks a postal code against the format used in the given two
+/// letter country code, and returns it in that country's usual
+/// format. For example, `sw1a1aa` in `GB` becomes `SW1A 1AA`.
+///
+/// Countries without a built in format accept any postal code that
+/// `parse_postal_code` accepts.
+///
+/// This is intended to be used as the `validate_with` function of a
+/// postal code field which `depends_on` a country field:
+///
+/// ```ignore
+/// #[structform(depends_on = "country", validate_with = "structform::validate_postal_code")]
+/// postal_code: FormPostalCodeInput<String>,
+/// ```
+pub fn validate_postal_code(country: &str, postal_code: String) -> Result<String, ParseError> {
+    let patterns = match POSTAL_CODE_PATTERNS
+        .iter()
+        .find(|(pattern_country, _)| pattern_country.eq_ignore_ascii_case(country.trim()))
+    {
+        Some((_, patterns)) => patterns,
+        None => return parse_postal_code(&postal_code),
+    };
+
+    let without_spaces: Vec<char> = postal_code.chars().filter(|c| *c != ' ').collect();
+    patterns
+        .iter()
+        .find_map(|pattern| format_with_pattern(&without_spaces, pattern))
+        .ok_or_else(|| ParseError::InvalidFormat {
+            required_type: format!(
+                "a postal code like {}",
+                patterns
+                    .iter()
+                    .map(|p| p.replace('A', "X"))
+                    .collect::<Vec<_>>()
+                    .join(" or ")
+            ),
+        })
+}
+
+/// The same as `validate_postal_code`, for optional postal code
+/// fields. Empty postal codes are always valid.
+pub fn validate_optional_postal_code(
+    country: &str,
+    postal_code: Option<String>,
+) -> Result<Option<String>, ParseError> {
+    postal_code
+        .map(|postal_code| validate_postal_code(country, postal_code))
+        .transpose()
+}
+
+fn format_with_pattern(postal_code: &[char], pattern: &str) -> Option<String> {
+    let mut formatted = String::with_capacity(pattern.len());
+    let mut chars = postal_code.iter();
+    for pattern_char in pattern.chars() {
+        if pattern_char == ' ' {
+            formatted.push(' ');
+            continue;
+        }
+        let c = *chars.next()?;
+        let matches = match pattern_char {
+            '9' => c.is_ascii_digit(),
+            'A' => c.is_ascii_alphabetic(),
+            literal => c == literal,
+        };
+        if !matches {
+            return None;
+        }
+        formatted.push(c.to_ascii_uppercase());
+    }
+    if chars.next().is_some() {
+        None
+    } else {
+        Some(formatted)
+    }
+}
+
+/// Implements `ParseAndFormat<String> for $postal_code_input<String>`,
+/// and also implements `ParseAndFormat<Option<String>> for
+/// $postal_code_input<Option<String>>`.
+///
+/// This will parse using `structform::parse_postal_code`, which
+/// accepts postal codes from any country. If the input string is
+/// empty after trimming, then parse will return a
+/// `ParseError::Required` for the `ParseAndFormat<String>` case, and
+/// return `None` for the `ParseAndFormat<Option<String>>` case.
+///
+/// To check the postal code against the format of a country chosen
+/// elsewhere on the form, annotate the field with
+/// `#[structform(depends_on = "country", validate_with =
+/// "structform::validate_postal_code")]`.
+#[macro_export]
+macro_rules! impl_postal_code_input {
+    ($postal_code_input: ident) => {
+        impl structform::ParseAndFormat<String> for $postal_code_input<String> {
+            fn parse(value: &str) -> Result<String, structform::ParseError> {
+                structform::parse_postal_code(value)
+            }
+
+            fn format(value: &String) -> String {
+                value.clone()
+            }
+        }
+
+        impl structform::ParseAndFormat<Option<String>> for $postal_code_input<Option<String>> {
+            fn parse(value: &str) -> Result<Option<String>, structform::ParseError> {
+                match structform::parse_postal_code(value) {
+                    Ok(value) => Ok(Some(value)),
+                    Err(structform::ParseError::Required) => Ok(None),
+                    Err(e) => Err(e),
+                }
+            }
+
+            fn format(value: &Option<String>) -> String {
+                value.clone().unwrap_or_default()
+            }
+        }
+    };
+}
@@ -0,0 +1,160 @@
+use crate::checksum::luhn_is_valid;
+use std::fmt;
+use std::str::FromStr;
+
+/// A South African identity number.
+///
+/// These are 13 digits, in the form `YYMMDD SSSS C A Z`:
+///
+/// - `YYMMDD` is the holder's date of birth.
+/// - `SSSS` is a sequence number, where 0000 to 4999 is female and
+///   5000 to 9999 is male.
+/// - `C` is 0 for South African citizens, 1 for permanent residents
+///   and 2 for refugees.
+/// - `A` is historically used for race, and is now usually 8.
+/// - `Z` is a Luhn check digit.
+///
+/// Spaces are ignored when parsing. `Display` writes all 13 digits
+/// without spaces.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SaIdNumber(String);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Gender {
+    Female,
+    Male,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Citizenship {
+    Citizen,
+    PermanentResident,
+    Refugee,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaIdNumberError {
+    InvalidFormat,
+    InvalidDateOfBirth,
+    InvalidCitizenship,
+    InvalidChecksum,
+}
+
+impl fmt::Display for SaIdNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaIdNumberError::InvalidFormat => write!(f, "Expected a 13 digit ID number"),
+            SaIdNumberError::InvalidDateOfBirth => {
+                write!(
+                    f,
+                    "This ID number does not start with a valid date of birth"
+                )
+            }
+            SaIdNumberError::InvalidCitizenship => {
+                write!(f, "This ID number's citizenship digit is not valid")
+            }
+            SaIdNumberError::InvalidChecksum => write!(f, "This is not a valid ID number"),
+        }
+    }
+}
+
+impl std::error::Error for SaIdNumberError {}
+
+impl SaIdNumber {
+    fn digits(&self) -> Vec<u32> {
+        self.0.chars().filter_map(|c| c.to_digit(10)).collect()
+    }
+
+    fn number_at(&self, start: usize, end: usize) -> u32 {
+        self.0[start..end].parse().unwrap_or(0)
+    }
+
+    /// The holder's date of birth, as `(year, month, day)`.
+    ///
+    /// ID numbers only include the last two digits of the year. Years
+    /// that would be after `current_year` are assumed to be from the
+    /// previous century.
+    pub fn date_of_birth(&self, current_year: i32) -> (i32, u32, u32) {
+        (
+            full_birth_year(self.number_at(0, 2) as i32, current_year),
+            self.number_at(2, 4),
+            self.number_at(4, 6),
+        )
+    }
+
+    pub fn gender(&self) -> Gender {
+        if self.number_at(6, 10) < 5000 {
+            Gender::Female
+        } else {
+            Gender::Male
+        }
+    }
+
+    pub fn citizenship(&self) -> Citizenship {
+        match self.digits()[10] {
+            0 => Citizenship::Citizen,
+            1 => Citizenship::PermanentResident,
+            _ => Citizenship::Refugee,
+        }
+    }
+}
+
+impl FromStr for SaIdNumber {
+    type Err = SaIdNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let id: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        if id.len() != 13 || !id.chars().all(|c| c.is_ascii_digit()) {
+            return Err(SaIdNumberError::InvalidFormat);
+        }
+        let id = SaIdNumber(id);
+
+        // The century isn't known without the current year, but it only
+        // matters for whether the 29th of February is a valid date.
+        let (two_digit_year, month, day) =
+            (id.number_at(0, 2), id.number_at(2, 4), id.number_at(4, 6));
+        let max_day = days_in_month(1900 + two_digit_year, month)
+            .max(days_in_month(2000 + two_digit_year, month));
+        if !(1..=12).contains(&month) || day < 1 || day > max_day {
+            return Err(SaIdNumberError::InvalidDateOfBirth);
+        }
+        if id.digits()[10] > 2 {
+            return Err(SaIdNumberError::InvalidCitizenship);
+        }
+        if !luhn_is_valid(&id.digits()) {
+            return Err(SaIdNumberError::InvalidChecksum);
+        }
+        Ok(id)
+    }
+}
+
+impl fmt::Display for SaIdNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+fn full_birth_year(two_digit_year: i32, current_year: i32) -> i32 {
+    let century = current_year.div_euclid(100) * 100;
+    if century + two_digit_year > current_year {
+        century - 100 + two_digit_year
+    } else {
+        century + two_digit_year
+    }
+}
+
+// `is_multiple_of` isn't available on the oldest Rust version that
+// StructForm supports.
+#[allow(clippy::manual_is_multiple_of)]
+fn is_leap_year(year: u32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: u32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
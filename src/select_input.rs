@@ -0,0 +1,79 @@
+/// Implements `ParseAndFormat<$enum> for $select_input<$enum>`, and
+/// also implements `ParseAndFormat<Option<$enum>> for
+/// $select_input<Option<$enum>>`, for dropdowns that pick a variant of
+/// an enum without fields.
+///
+/// Each variant is listed with the label to show for it. The value of
+/// each option is the variant's name. If the input is empty, then
+/// parse will return a `ParseError::Required` for the
+/// `ParseAndFormat<$enum>` case, and return `None` for the
+/// `ParseAndFormat<Option<$enum>>` case. Any other value that isn't a
+/// variant's name returns a `ParseError::InvalidFormat`.
+///
+/// This also adds a `variants` function to both inputs, which returns
+/// the value and label of every variant, for rendering the dropdown's
+/// options.
+///
+/// For enums in your own crate, `#[derive(FormSelect)]` does the same
+/// thing without listing the variants. This macro is for enums from
+/// other crates, which can't be derived for.
+///
+/// ```ignore
+/// impl_select_input_for_enum!(FormSelectInput, Colour, [
+///     Red => "Red",
+///     DarkBlue => "Dark blue",
+/// ]);
+/// ```
+#[macro_export]
+macro_rules! impl_select_input_for_enum {
+    ($select_input: ident, $enum: ident, [$($variant: ident => $label: literal),+ $(,)?]) => {
+        $crate::impl_select_input_for_enum!(@variants $select_input, $enum, [$($variant => $label),+]);
+        $crate::impl_select_input_for_enum!(@variants $select_input, Option<$enum>, [$($variant => $label),+]);
+
+        impl structform::ParseAndFormat<$enum> for $select_input<$enum> {
+            fn parse(value: &str) -> Result<$enum, structform::ParseError> {
+                match value.trim() {
+                    "" => Err(structform::ParseError::Required),
+                    $(stringify!($variant) => Ok($enum::$variant),)+
+                    _ => Err(structform::ParseError::InvalidFormat {
+                        required_type: format!("one of {}", [$($label),+].join(", ")),
+                    }),
+                }
+            }
+
+            fn format(value: &$enum) -> String {
+                match value {
+                    $($enum::$variant => stringify!($variant).to_string(),)+
+                }
+            }
+        }
+
+        impl structform::ParseAndFormat<Option<$enum>> for $select_input<Option<$enum>> {
+            fn parse(value: &str) -> Result<Option<$enum>, structform::ParseError> {
+                if value.trim().is_empty() {
+                    Ok(None)
+                } else {
+                    <$select_input<$enum> as structform::ParseAndFormat<$enum>>::parse(value)
+                        .map(Option::Some)
+                }
+            }
+
+            fn format(value: &Option<$enum>) -> String {
+                match value {
+                    None => "".to_string(),
+                    Some(inner) => <$select_input<$enum> as structform::ParseAndFormat<$enum>>::format(inner),
+                }
+            }
+        }
+    };
+    (@variants $select_input: ident, $type: ty, [$($variant: ident => $label: literal),+]) => {
+        #[allow(dead_code)]
+        impl $select_input<$type> {
+            /// The value and label of every variant, in the order that
+            /// they were listed.
+            pub fn variants() -> &'static [(&'static str, &'static str)] {
+                &[$((stringify!($variant), $label)),+]
+            }
+        }
+    };
+}
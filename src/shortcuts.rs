@@ -0,0 +1,112 @@
+/// A key that was pressed, along with the modifier keys that were held
+/// down. Keys are named the way that the DOM's `KeyboardEvent.key`
+/// names them, like `"Enter"`, `"Escape"` or `"z"`, so that the same
+/// `ShortcutMap` works wherever the form is embedded.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeyPress {
+    pub key: String,
+    pub ctrl: bool,
+    pub shift: bool,
+    pub alt: bool,
+    /// The Command key on macOS, or the Windows key elsewhere.
+    pub meta: bool,
+}
+
+impl KeyPress {
+    /// A key pressed without any modifier keys.
+    pub fn new(key: &str) -> KeyPress {
+        KeyPress {
+            key: key.to_string(),
+            ..KeyPress::default()
+        }
+    }
+
+    pub fn with_ctrl(mut self) -> KeyPress {
+        self.ctrl = true;
+        self
+    }
+
+    pub fn with_shift(mut self) -> KeyPress {
+        self.shift = true;
+        self
+    }
+
+    pub fn with_alt(mut self) -> KeyPress {
+        self.alt = true;
+        self
+    }
+
+    pub fn with_meta(mut self) -> KeyPress {
+        self.meta = true;
+        self
+    }
+
+    /// Letters are matched without case, since holding shift changes
+    /// the case of the key that is reported.
+    fn matches(&self, other: &KeyPress) -> bool {
+        self.key.eq_ignore_ascii_case(&other.key)
+            && self.ctrl == other.ctrl
+            && self.shift == other.shift
+            && self.alt == other.alt
+            && self.meta == other.meta
+    }
+}
+
+/// Something that a keyboard shortcut asks the form to do. The app
+/// decides what each of these means for its form, like resetting it to
+/// the model that it was opened with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormAction {
+    Submit,
+    Reset,
+    Undo,
+}
+
+/// Translates key presses into `FormAction`s, so that forms respond to
+/// the same shortcuts everywhere that they are embedded.
+///
+/// The default map submits on Enter, resets on Escape, and undoes on
+/// Ctrl+Z or Cmd+Z. Use `ShortcutMap::new` to start with no shortcuts
+/// instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShortcutMap {
+    bindings: Vec<(KeyPress, FormAction)>,
+}
+
+impl Default for ShortcutMap {
+    fn default() -> ShortcutMap {
+        let mut shortcuts = ShortcutMap::new();
+        shortcuts.bind(KeyPress::new("Enter"), FormAction::Submit);
+        shortcuts.bind(KeyPress::new("Escape"), FormAction::Reset);
+        shortcuts.bind(KeyPress::new("z").with_ctrl(), FormAction::Undo);
+        shortcuts.bind(KeyPress::new("z").with_meta(), FormAction::Undo);
+        shortcuts
+    }
+}
+
+impl ShortcutMap {
+    pub fn new() -> ShortcutMap {
+        ShortcutMap {
+            bindings: Vec::new(),
+        }
+    }
+
+    /// Binds a key press to an action, replacing any action that it was
+    /// already bound to.
+    pub fn bind(&mut self, key: KeyPress, action: FormAction) {
+        self.unbind(&key);
+        self.bindings.push((key, action));
+    }
+
+    pub fn unbind(&mut self, key: &KeyPress) {
+        self.bindings.retain(|(bound, _)| !bound.matches(key));
+    }
+
+    /// The action that a key press is bound to, if any.
+    pub fn action(&self, key: &KeyPress) -> Option<FormAction> {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| bound.matches(key))
+            .map(|(_, action)| *action)
+    }
+}
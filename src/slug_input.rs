@@ -0,0 +1,78 @@
+/// Converts text into a URL friendly slug, like "Hello, World!" to
+/// "hello-world".
+///
+/// ASCII letters and digits are kept (in lower case), and any runs of
+/// other characters are replaced with a single hyphen. Leading and
+/// trailing hyphens are removed.
+pub fn slugify(value: &str) -> String {
+    let mut slug = String::with_capacity(value.len());
+    for c in value.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    if slug.ends_with('-') {
+        slug.pop();
+    }
+    slug
+}
+
+/// Checks if `value` is already a valid slug, made up of lower case
+/// ASCII letters, digits and single hyphens between them.
+pub fn is_slug(value: &str) -> bool {
+    !value.is_empty() && slugify(value) == value
+}
+
+/// Implements `ParseAndFormat<String> for $slug_input<String>`, and
+/// also implements `ParseAndFormat<Option<String>> for
+/// $slug_input<Option<String>>`.
+///
+/// This will parse by trimming the string input and checking that
+/// it's a valid slug (see `structform::is_slug`). Invalid slugs
+/// return a `ParseError::InvalidFormat`. If the input string is empty
+/// after trimming, then parse will return a `ParseError::Required`
+/// for the `ParseAndFormat<String>` case, and return `None` for the
+/// `ParseAndFormat<Option<String>>` case.
+///
+/// Slug inputs can be filled in automatically from another field on
+/// the form with `#[structform(slug_of = "other_field")]`.
+#[macro_export]
+macro_rules! impl_slug_input {
+    ($slug_input: ident) => {
+        impl structform::ParseAndFormat<String> for $slug_input<String> {
+            fn parse(value: &str) -> Result<String, structform::ParseError> {
+                let trimmed = value.trim();
+                if trimmed.is_empty() {
+                    Err(structform::ParseError::Required)
+                } else if structform::is_slug(trimmed) {
+                    Ok(trimmed.to_string())
+                } else {
+                    Err(structform::ParseError::InvalidFormat {
+                        required_type: "lower case letters, numbers and hyphens".to_string(),
+                    })
+                }
+            }
+
+            fn format(value: &String) -> String {
+                value.clone()
+            }
+        }
+
+        impl structform::ParseAndFormat<Option<String>> for $slug_input<Option<String>> {
+            fn parse(value: &str) -> Result<Option<String>, structform::ParseError> {
+                if value.trim().is_empty() {
+                    Ok(None)
+                } else {
+                    <$slug_input<String> as structform::ParseAndFormat<String>>::parse(value)
+                        .map(Option::Some)
+                }
+            }
+
+            fn format(value: &Option<String>) -> String {
+                value.clone().unwrap_or_default()
+            }
+        }
+    };
+}
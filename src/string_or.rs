@@ -0,0 +1,113 @@
+use std::fmt;
+
+/// A model value which is either a valid `T`, or the raw input that
+/// didn't parse as one. This is for import and review workflows, where
+/// a draft needs to be saved before every value in it is valid.
+///
+/// See `impl_string_or_input` for using it in a form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StringOr<T> {
+    Value(T),
+    Raw(String),
+}
+
+impl<T> StringOr<T> {
+    /// The value, if the input was valid.
+    pub fn value(&self) -> Option<&T> {
+        match self {
+            StringOr::Value(value) => Some(value),
+            StringOr::Raw(_) => None,
+        }
+    }
+
+    pub fn is_raw(&self) -> bool {
+        matches!(self, StringOr::Raw(_))
+    }
+
+    /// The value, or the raw input if it wasn't valid.
+    pub fn into_result(self) -> Result<T, String> {
+        match self {
+            StringOr::Value(value) => Ok(value),
+            StringOr::Raw(raw) => Err(raw),
+        }
+    }
+}
+
+impl<T> Default for StringOr<T> {
+    fn default() -> StringOr<T> {
+        StringOr::Raw(String::new())
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for StringOr<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StringOr::Value(value) => value.fmt(f),
+            StringOr::Raw(raw) => f.write_str(raw),
+        }
+    }
+}
+
+/// Forms with a draft model, from `#[structform(draft_model = "...")]`.
+/// This is implemented by `#[derive(StructForm)]`, and lets a form's
+/// draft include the drafts of its subforms.
+pub trait FormDraft {
+    /// The generated struct with a `StringOr` for each input.
+    type Draft;
+
+    /// Takes the value of every input that parses, and the raw input
+    /// of every input that doesn't, for saving a draft of the form.
+    /// Unlike `submit`, this never fails, and doesn't show any
+    /// validation errors.
+    fn submit_draft(&self) -> Self::Draft;
+
+    /// Sets every input from a draft saved with `submit_draft`.
+    /// Optional subforms and lists of subforms are replaced by the
+    /// ones in the draft.
+    fn restore_draft(&mut self, draft: &Self::Draft);
+}
+
+/// Implements `ParseAndFormat<StringOr<T>> for $input<StringOr<T>>`,
+/// for every `T` that `$input` already parses.
+///
+/// Input is parsed the same way as `$input<T>`, but invalid input
+/// never fails to parse. It is kept as `StringOr::Raw` instead, so the
+/// form can always be submitted. Use the input's `draft_error` function
+/// to show why a raw value isn't valid yet.
+#[macro_export]
+macro_rules! impl_string_or_input {
+    ($input: ident) => {
+        impl<T> structform::ParseAndFormat<structform::StringOr<T>>
+            for $input<structform::StringOr<T>>
+        where
+            $input<T>: structform::ParseAndFormat<T>,
+        {
+            fn parse(value: &str) -> Result<structform::StringOr<T>, structform::ParseError> {
+                Ok(<$input<T> as structform::ParseAndFormat<T>>::parse(value)
+                    .map(structform::StringOr::Value)
+                    .unwrap_or_else(|_| structform::StringOr::Raw(value.to_string())))
+            }
+
+            fn format(value: &structform::StringOr<T>) -> String {
+                match value {
+                    structform::StringOr::Value(value) => {
+                        <$input<T> as structform::ParseAndFormat<T>>::format(value)
+                    }
+                    structform::StringOr::Raw(raw) => raw.clone(),
+                }
+            }
+        }
+
+        impl<T> $input<structform::StringOr<T>>
+        where
+            $input<T>: structform::ParseAndFormat<T>,
+        {
+            /// Why the input isn't a valid value yet, or `None` if it
+            /// is.
+            #[allow(dead_code)]
+            pub fn draft_error(&self) -> Option<structform::ParseError> {
+                <$input<T> as structform::ParseAndFormat<T>>::parse(&self.input).err()
+            }
+        }
+    };
+}
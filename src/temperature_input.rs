@@ -0,0 +1,144 @@
+use crate::ParseError;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TemperatureUnit {
+    Celsius,
+    Fahrenheit,
+}
+
+impl TemperatureUnit {
+    /// Converts a temperature in this unit into `unit`.
+    pub fn convert(self, value: f64, unit: TemperatureUnit) -> f64 {
+        match (self, unit) {
+            (TemperatureUnit::Celsius, TemperatureUnit::Fahrenheit) => value * 9.0 / 5.0 + 32.0,
+            (TemperatureUnit::Fahrenheit, TemperatureUnit::Celsius) => (value - 32.0) * 5.0 / 9.0,
+            _ => value,
+        }
+    }
+
+    fn from_suffix(suffix: &str) -> Option<TemperatureUnit> {
+        let suffix = suffix.trim_start_matches(&['°', 'º'][..]);
+        if suffix.eq_ignore_ascii_case("c") || suffix.eq_ignore_ascii_case("celsius") {
+            Some(TemperatureUnit::Celsius)
+        } else if suffix.eq_ignore_ascii_case("f") || suffix.eq_ignore_ascii_case("fahrenheit") {
+            Some(TemperatureUnit::Fahrenheit)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for TemperatureUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemperatureUnit::Celsius => write!(f, "°C"),
+            TemperatureUnit::Fahrenheit => write!(f, "°F"),
+        }
+    }
+}
+
+/// Parses a temperature like "21.5", "21.5°C" or "70 F", and converts
+/// it to `unit`. Numbers without a unit are assumed to already be in
+/// `unit`.
+///
+/// The result must be between `min` and `max` (inclusive), which are
+/// also in `unit`.
+pub fn parse_temperature(
+    value: &str,
+    unit: TemperatureUnit,
+    min: f64,
+    max: f64,
+) -> Result<f64, ParseError> {
+    let value = value.trim();
+    let number_end = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-' || c == '+'))
+        .unwrap_or(value.len());
+    let required_type = || "a temperature".to_string();
+
+    let number: f64 = value[..number_end]
+        .parse()
+        .map_err(|_| ParseError::InvalidFormat {
+            required_type: required_type(),
+        })?;
+    let input_unit = match value[number_end..].trim() {
+        "" => unit,
+        suffix => {
+            TemperatureUnit::from_suffix(suffix).ok_or_else(|| ParseError::InvalidFormat {
+                required_type: required_type(),
+            })?
+        }
+    };
+
+    let temperature = input_unit.convert(number, unit);
+    if temperature < min || temperature > max {
+        Err(ParseError::NumberOutOfRange {
+            required_type: required_type(),
+            min: format_temperature(min, unit),
+            max: format_temperature(max, unit),
+        })
+    } else {
+        Ok(temperature)
+    }
+}
+
+/// Formats a temperature like "21.5°C". The number is rounded to at
+/// most 1 decimal place.
+pub fn format_temperature(value: f64, unit: TemperatureUnit) -> String {
+    let number = format!("{:.1}", value);
+    let number = number.trim_end_matches('0').trim_end_matches('.');
+    let number = if number == "-0" { "0" } else { number };
+    format!("{}{}", number, unit)
+}
+
+/// Implements `ParseAndFormat<f64> for $temperature_input<f64>`, and
+/// also implements `ParseAndFormat<Option<f64>> for
+/// $temperature_input<Option<f64>>`.
+///
+/// Values are stored in `$unit`, which is a
+/// `structform::TemperatureUnit`. Users may type a temperature in
+/// either Celsius or Fahrenheit by adding a suffix like "°C" or "F",
+/// and it will be converted using `structform::parse_temperature`.
+/// Temperatures outside of `$min` to `$max` (in `$unit`) return a
+/// `ParseError::NumberOutOfRange`.
+///
+/// If the input string is empty after trimming, then parse will return
+/// a `ParseError::Required` for the `ParseAndFormat<f64>` case, and
+/// return `None` for the `ParseAndFormat<Option<f64>>` case.
+#[macro_export]
+macro_rules! impl_temperature_input {
+    ($temperature_input: ident, $unit: expr) => {
+        impl_temperature_input!($temperature_input, $unit, f64::MIN, f64::MAX);
+    };
+    ($temperature_input: ident, $unit: expr, $min: expr, $max: expr) => {
+        impl structform::ParseAndFormat<f64> for $temperature_input<f64> {
+            fn parse(value: &str) -> Result<f64, structform::ParseError> {
+                if value.trim().is_empty() {
+                    Err(structform::ParseError::Required)
+                } else {
+                    structform::parse_temperature(value, $unit, $min, $max)
+                }
+            }
+
+            fn format(value: &f64) -> String {
+                structform::format_temperature(*value, $unit)
+            }
+        }
+
+        impl structform::ParseAndFormat<Option<f64>> for $temperature_input<Option<f64>> {
+            fn parse(value: &str) -> Result<Option<f64>, structform::ParseError> {
+                if value.trim().is_empty() {
+                    Ok(None)
+                } else {
+                    structform::parse_temperature(value, $unit, $min, $max).map(Option::Some)
+                }
+            }
+
+            fn format(value: &Option<f64>) -> String {
+                value
+                    .map(|value| structform::format_temperature(value, $unit))
+                    .unwrap_or_default()
+            }
+        }
+    };
+}
@@ -1,3 +1,7 @@
+use crate::{ParseAndFormat, ParseError};
+use std::fmt;
+use std::str::FromStr;
+
 /// Implements `ParseAndFormat<$type> for $text_input<$type>`, and also
 /// implements `ParseAndFormat<Option<$type>>> for $text_input<Option<$type>>`.
 ///
@@ -11,7 +15,7 @@
 #[macro_export]
 macro_rules! impl_text_input_with_stringops {
     ($text_input: ident, $type_name: literal, $type: ty) => {
-        impl_text_input_with_stringops!(
+        $crate::impl_text_input_with_stringops!(
             $text_input,
             |_e| structform::ParseError::InvalidFormat {
                 required_type: $type_name.to_string()
@@ -20,7 +24,7 @@ macro_rules! impl_text_input_with_stringops {
         );
     };
     ($text_input: ident, $type: ty) => {
-        impl_text_input_with_stringops!(
+        $crate::impl_text_input_with_stringops!(
             $text_input,
             |e| structform::ParseError::FromStrError(e.to_string()),
             $type
@@ -75,10 +79,11 @@ macro_rules! impl_text_input_with_stringops {
 /// of the `Vec` and then joining them with a comma.
 ///
 /// Note: This is not a good idea of your value might contain commas.
+#[cfg(feature = "vec")]
 #[macro_export]
 macro_rules! impl_vec_text_input_with_stringops {
     ($text_input: ident, $type_name: literal, $type: ty) => {
-        impl_vec_text_input_with_stringops!(
+        $crate::impl_vec_text_input_with_stringops!(
             $text_input,
             |_e| structform::ParseError::InvalidFormat {
                 required_type: $type_name.to_string()
@@ -87,7 +92,7 @@ macro_rules! impl_vec_text_input_with_stringops {
         );
     };
     ($text_input: ident, $type: ty) => {
-        impl_vec_text_input_with_stringops!(
+        $crate::impl_vec_text_input_with_stringops!(
             $text_input,
             |e| structform::ParseError::FromStrError(e.to_string()),
             $type
@@ -115,3 +120,59 @@ macro_rules! impl_vec_text_input_with_stringops {
         }
     };
 }
+
+crate::derive_form_input! {StdTextInput}
+
+/// Any type with `FromStr` and `Display` can be used in a
+/// `StdTextInput` without calling a macro. It parses and formats the
+/// same way as `impl_text_input_with_stringops`.
+impl<T> ParseAndFormat<T> for StdTextInput<T>
+where
+    T: FromStr + fmt::Display,
+    T::Err: fmt::Display,
+{
+    fn parse(value: &str) -> Result<T, ParseError> {
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            Err(ParseError::Required)
+        } else {
+            trimmed
+                .parse::<T>()
+                .map_err(|e| ParseError::FromStrError(e.to_string()))
+        }
+    }
+
+    fn format(value: &T) -> String {
+        value.to_string()
+    }
+}
+
+crate::impl_string_or_input!(StdTextInput);
+
+crate::derive_form_input! {StdOptionalTextInput}
+
+/// The same as `StdTextInput`, for optional values. An empty input
+/// parses to `None`. This is a separate input because `StdTextInput`
+/// already accepts any `FromStr` type, and the standard library could
+/// implement `FromStr` for `Option` one day.
+impl<T> ParseAndFormat<Option<T>> for StdOptionalTextInput<Option<T>>
+where
+    T: FromStr + fmt::Display,
+    T::Err: fmt::Display,
+{
+    fn parse(value: &str) -> Result<Option<T>, ParseError> {
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            Ok(None)
+        } else {
+            trimmed
+                .parse::<T>()
+                .map(Some)
+                .map_err(|e| ParseError::FromStrError(e.to_string()))
+        }
+    }
+
+    fn format(value: &Option<T>) -> String {
+        value.as_ref().map(T::to_string).unwrap_or_default()
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::HashSet;
+
+/// Removes HTML tags from `value`, other than those listed in
+/// `allowed_tags`, so that it's safe to show as HTML.
+///
+/// The value is parsed the way a browser would parse it, using
+/// [ammonia](https://docs.rs/ammonia). Allowed tags are kept without
+/// any of their attributes, so `<b onclick="...">` becomes `<b>`, and
+/// links lose their URLs. The text inside disallowed tags is kept,
+/// except for `<script>` and `<style>` tags, which are removed along
+/// with their contents. Any `<`, `>` or `&` left in the text is
+/// escaped, like `&lt;`. Tag names are matched case insensitively.
+pub fn sanitize_html(value: &str, allowed_tags: &[&str]) -> String {
+    let allowed_tags: Vec<String> = allowed_tags
+        .iter()
+        .map(|tag| tag.to_ascii_lowercase())
+        .filter(|tag| tag != "script" && tag != "style")
+        .collect();
+    let mut builder = ammonia::Builder::empty();
+    builder
+        .tags(
+            allowed_tags
+                .iter()
+                .map(String::as_str)
+                .collect::<HashSet<_>>(),
+        )
+        .clean_content_tags(["script", "style"].iter().copied().collect())
+        .generic_attributes(HashSet::new())
+        .link_rel(None);
+    builder.clean(value).to_string()
+}
+
+/// Reverses the escaping done by `sanitize_html`, to put sanitized
+/// text back into an input. Any HTML that this brings back is removed
+/// again when the input is parsed.
+pub fn unescape_html(value: &str) -> String {
+    value
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&nbsp;", "\u{a0}")
+        .replace("&amp;", "&")
+}
+
+/// Implements `ParseAndFormat<String> for $textarea_input<String>`,
+/// and also implements `ParseAndFormat<Option<String>> for
+/// $textarea_input<Option<String>>`, for long free text like
+/// descriptions or bios.
+///
+/// This will parse by trimming the string input, and then removing
+/// any HTML tags which aren't in `$allowed_tags` using
+/// `sanitize_html`. If no allowed tags are given, all HTML is
+/// removed. If the result is empty, then parse will return a
+/// `ParseError::Required` for the `ParseAndFormat<String>` case, and
+/// return `None` for the `ParseAndFormat<Option<String>>` case. If
+/// the result is longer than `$max_length` characters, parse will
+/// return a `ParseError::TooLong`. Escaped characters, like `&amp;`,
+/// count as one character. Formatting unescapes the value, so the
+/// input shows what the user typed.
+///
+/// With the `markdown` feature enabled, `structform::markdown_to_html`
+/// can be used to render a preview of the input.
+#[macro_export]
+macro_rules! impl_textarea_input {
+    ($textarea_input: ident, $max_length: expr) => {
+        impl_textarea_input!($textarea_input, $max_length, &[]);
+    };
+    ($textarea_input: ident, $max_length: expr, $allowed_tags: expr) => {
+        impl structform::ParseAndFormat<String> for $textarea_input<String> {
+            fn parse(value: &str) -> Result<String, structform::ParseError> {
+                let sanitized = structform::sanitize_html(value.trim(), $allowed_tags);
+                let sanitized = sanitized.trim();
+                if sanitized.is_empty() {
+                    Err(structform::ParseError::Required)
+                } else if structform::unescape_html(sanitized).chars().count() > $max_length {
+                    Err(structform::ParseError::TooLong { max: $max_length })
+                } else {
+                    Ok(sanitized.to_string())
+                }
+            }
+
+            fn format(value: &String) -> String {
+                structform::unescape_html(value)
+            }
+        }
+
+        impl structform::ParseAndFormat<Option<String>> for $textarea_input<Option<String>> {
+            fn parse(value: &str) -> Result<Option<String>, structform::ParseError> {
+                match <$textarea_input<String> as structform::ParseAndFormat<String>>::parse(value)
+                {
+                    Ok(value) => Ok(Some(value)),
+                    Err(structform::ParseError::Required) => Ok(None),
+                    Err(e) => Err(e),
+                }
+            }
+
+            fn format(value: &Option<String>) -> String {
+                value
+                    .as_deref()
+                    .map(structform::unescape_html)
+                    .unwrap_or_default()
+            }
+        }
+    };
+}
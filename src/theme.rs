@@ -0,0 +1,44 @@
+/// The CSS classes that a renderer puts on the markup it generates for
+/// a form, so that the same renderer can match Tailwind, Bootstrap or
+/// an app's own design system.
+///
+/// Every method has a default, using `structform-` prefixed classes,
+/// so a theme only needs to override the classes that it changes.
+/// `DefaultTheme` doesn't override any of them.
+pub trait Theme {
+    /// The class of every `<input>`, `<select>` and `<textarea>`.
+    fn input_class(&self) -> &str {
+        "structform-input"
+    }
+
+    fn label_class(&self) -> &str {
+        "structform-label"
+    }
+
+    /// The class of the message shown for an input's validation error.
+    fn error_class(&self) -> &str {
+        "structform-error"
+    }
+
+    /// The class added to an input, alongside `input_class`, when its
+    /// value is invalid.
+    fn invalid_class(&self) -> &str {
+        "structform-invalid"
+    }
+
+    /// The full `class` attribute for an input, depending on whether
+    /// its value is invalid.
+    fn input_classes(&self, is_invalid: bool) -> String {
+        if is_invalid && !self.invalid_class().is_empty() {
+            format!("{} {}", self.input_class(), self.invalid_class())
+        } else {
+            self.input_class().to_string()
+        }
+    }
+}
+
+/// A `Theme` using all of the default classes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefaultTheme;
+
+impl Theme for DefaultTheme {}
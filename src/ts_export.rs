@@ -0,0 +1,75 @@
+use crate::{FieldManifest, FieldManifestKind, FormManifest};
+use std::fmt::Write;
+
+impl FormManifest {
+    /// TypeScript definitions for a form, for frontends that are
+    /// partly written in TypeScript. Call this from a build script or
+    /// a test, and write the result to a `.d.ts` or `.ts` file so that
+    /// both sides of the frontend stay in sync.
+    ///
+    /// Two types are exported, named after `name`:
+    ///
+    /// - `{name}Path`, a union of the field paths of every input, like
+    ///   `"address.city"`. List entries use a template literal, like
+    ///   `` `addresses[${number}].city` ``, and map entries are keyed
+    ///   by any string, like `` `translations.${string}.title` ``.
+    /// - `{name}RawValues`, the raw inputs keyed by field path, like
+    ///   `StructForm::inputs_by_path` gives and
+    ///   `StructForm::set_input_at_path` takes.
+    pub fn to_typescript(&self, name: &str) -> String {
+        let mut paths = Vec::new();
+        push_input_paths(&self.fields, "", &mut paths);
+
+        let mut ts = String::new();
+        writeln!(ts, "// Generated by structform. Do not edit.").unwrap();
+        writeln!(ts).unwrap();
+        if paths.is_empty() {
+            writeln!(ts, "export type {}Path = never;", name).unwrap();
+        } else {
+            writeln!(ts, "export type {}Path =", name).unwrap();
+            for (i, path) in paths.iter().enumerate() {
+                let end = if i + 1 == paths.len() { ";" } else { "" };
+                writeln!(ts, "  | {}{}", path, end).unwrap();
+            }
+        }
+        writeln!(ts).unwrap();
+        writeln!(
+            ts,
+            "export type {name}RawValues = {{ [Path in {name}Path]?: string }};",
+            name = name
+        )
+        .unwrap();
+        ts
+    }
+}
+
+/// Adds the TypeScript string type of the path of every input in
+/// `fields` to `paths`. `prefix` is the path of the subform that the
+/// fields are in, ending with a `.`, as it's written inside of a
+/// TypeScript template literal.
+fn push_input_paths(fields: &[FieldManifest], prefix: &str, paths: &mut Vec<String>) {
+    for field in fields {
+        match field.kind {
+            FieldManifestKind::Input => {
+                let path = format!("{}{}", prefix, field.name);
+                paths.push(if path.contains("${") {
+                    format!("`{}`", path)
+                } else {
+                    format!("\"{}\"", path)
+                });
+            }
+            FieldManifestKind::Subform | FieldManifestKind::OptionalSubform => {
+                let prefix = format!("{}{}.", prefix, field.name);
+                push_input_paths(&field.fields, &prefix, paths);
+            }
+            FieldManifestKind::ListSubform => {
+                let prefix = format!("{}{}[${{number}}].", prefix, field.name);
+                push_input_paths(&field.fields, &prefix, paths);
+            }
+            FieldManifestKind::MapSubform => {
+                let prefix = format!("{}{}.${{string}}.", prefix, field.name);
+                push_input_paths(&field.fields, &prefix, paths);
+            }
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use crate::StructForm;
+
+/// Keeps a form together with the model it was last saved as, so that
+/// navigation away from a form with unsaved changes can be blocked.
+///
+/// Hook `should_block_navigation` into your router's "before leave"
+/// check, or the browser's `beforeunload` event.
+#[derive(Debug, Clone)]
+pub struct UnsavedChangesGuard<F, Model> {
+    pub form: F,
+    pristine: Model,
+}
+
+impl<F, Model> UnsavedChangesGuard<F, Model>
+where
+    F: StructForm<Model> + Clone,
+    Model: Clone + PartialEq,
+{
+    /// Creates the form from the saved model.
+    pub fn new(pristine: Model) -> UnsavedChangesGuard<F, Model> {
+        UnsavedChangesGuard {
+            form: F::new(&pristine),
+            pristine,
+        }
+    }
+
+    pub fn pristine(&self) -> &Model {
+        &self.pristine
+    }
+
+    /// Whether the form has changes that haven't been saved. See
+    /// `StructForm::has_unsaved_changes`.
+    pub fn should_block_navigation(&self) -> bool {
+        self.form.has_unsaved_changes(&self.pristine)
+    }
+
+    /// Records that the form has been saved as `model`, so navigation
+    /// is allowed again until the next change.
+    pub fn saved(&mut self, model: Model) {
+        self.pristine = model;
+    }
+}
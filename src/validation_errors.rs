@@ -0,0 +1,83 @@
+use crate::ParseError;
+
+/// The errors of every input on a form that doesn't parse, keyed by
+/// the input's field. See `StructForm::validate`.
+///
+/// Errors are kept in the order that the fields are declared, with the
+/// errors of subforms nested under the subform's field, like
+/// `UserFormField::Addresses(0, AddressFormField::City)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationErrors<Field> {
+    errors: Vec<(Field, ParseError)>,
+}
+
+impl<Field> Default for ValidationErrors<Field> {
+    fn default() -> ValidationErrors<Field> {
+        ValidationErrors { errors: Vec::new() }
+    }
+}
+
+impl<Field> ValidationErrors<Field> {
+    pub fn new() -> ValidationErrors<Field> {
+        ValidationErrors::default()
+    }
+
+    pub fn push(&mut self, field: Field, error: ParseError) {
+        self.errors.push((field, error));
+    }
+
+    /// Adds the errors of a subform, nesting each of its fields under
+    /// this form's field with `to_field`.
+    pub fn extend_nested<Inner, F>(&mut self, inner: ValidationErrors<Inner>, to_field: F)
+    where
+        F: Fn(Inner) -> Field,
+    {
+        self.errors.extend(
+            inner
+                .errors
+                .into_iter()
+                .map(|(field, error)| (to_field(field), error)),
+        );
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, (Field, ParseError)> {
+        self.errors.iter()
+    }
+
+    /// The error of the input for `field`, if it has one.
+    pub fn get(&self, field: &Field) -> Option<&ParseError>
+    where
+        Field: PartialEq,
+    {
+        self.errors
+            .iter()
+            .find(|(error_field, _)| error_field == field)
+            .map(|(_, error)| error)
+    }
+
+    /// `Ok` if there are no errors, or else `Err` with the errors.
+    pub fn into_result(self) -> Result<(), ValidationErrors<Field>> {
+        if self.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl<Field> IntoIterator for ValidationErrors<Field> {
+    type Item = (Field, ParseError);
+    type IntoIter = std::vec::IntoIter<(Field, ParseError)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.errors.into_iter()
+    }
+}
@@ -0,0 +1,267 @@
+use crate::checksum::luhn_is_valid;
+use crate::ParseError;
+use std::fmt;
+use std::str::FromStr;
+
+/// A VAT number, validated according to the rules of the country that
+/// issued it.
+///
+/// The EU member states and South Africa are supported. Every
+/// country's VAT numbers are checked for the right length and
+/// characters. Where a country uses a check digit that can be
+/// verified offline (BE, DE, DK, FI, FR, HR, IT, LU, PL, PT, SE and
+/// SK), that is checked too.
+///
+/// When parsed from a string, the VAT number must start with its two
+/// letter country prefix, like `DE123456789` (Greece uses `EL`). If
+/// your form has a separate country field, use `validate_vat_number`
+/// as the `validate_with` function of a field that `depends_on` it
+/// instead.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VatNumber {
+    country: String,
+    number: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VatNumberError {
+    UnsupportedCountry(String),
+    InvalidFormat { country: String },
+    InvalidChecksum { country: String },
+}
+
+impl fmt::Display for VatNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VatNumberError::UnsupportedCountry(country) => {
+                write!(f, "VAT numbers from {} are not supported", country)
+            }
+            VatNumberError::InvalidFormat { country } => {
+                write!(f, "Expected a {} VAT number", country)
+            }
+            VatNumberError::InvalidChecksum { country } => {
+                write!(f, "This is not a valid {} VAT number", country)
+            }
+        }
+    }
+}
+
+impl std::error::Error for VatNumberError {}
+
+impl VatNumber {
+    /// Validates a VAT number for the given two letter country
+    /// code. The number may include the country prefix, spaces,
+    /// hyphens and dots.
+    pub fn new(country: &str, number: &str) -> Result<VatNumber, VatNumberError> {
+        let country = match country.trim().to_ascii_uppercase().as_str() {
+            "GR" => "EL".to_string(),
+            country => country.to_string(),
+        };
+        let mut number: String = number
+            .chars()
+            .filter(|c| !c.is_whitespace() && *c != '-' && *c != '.')
+            .collect::<String>()
+            .to_ascii_uppercase();
+        if number.starts_with(&country) {
+            number = number[country.len()..].to_string();
+        }
+        // The format rules below slice the number by bytes.
+        if !number.is_ascii() {
+            return Err(VatNumberError::InvalidFormat { country });
+        }
+
+        let format_ok = is_valid_format(&country, &number)
+            .ok_or_else(|| VatNumberError::UnsupportedCountry(country.clone()))?;
+        if !format_ok {
+            return Err(VatNumberError::InvalidFormat { country });
+        }
+        if !is_valid_checksum(&country, &number) {
+            return Err(VatNumberError::InvalidChecksum { country });
+        }
+        Ok(VatNumber { country, number })
+    }
+
+    /// The two letter country prefix. Note that Greece uses `EL`.
+    pub fn country(&self) -> &str {
+        &self.country
+    }
+
+    /// The VAT number, without its country prefix.
+    pub fn number(&self) -> &str {
+        &self.number
+    }
+}
+
+impl FromStr for VatNumber {
+    type Err = VatNumberError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let country: String = trimmed.chars().take(2).collect();
+        if country.len() == 2 && country.chars().all(|c| c.is_ascii_alphabetic()) {
+            VatNumber::new(&country, trimmed)
+        } else {
+            Err(VatNumberError::InvalidFormat {
+                country: "country prefixed".to_string(),
+            })
+        }
+    }
+}
+
+impl fmt::Display for VatNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.country, self.number)
+    }
+}
+
+/// Checks a VAT number against the rules of the given two letter
+/// country code, and returns it cleaned up and without its country
+/// prefix. For example, `0403.170.701` in `BE` becomes `0403170701`.
+///
+/// This is intended to be used as the `validate_with` function of a
+/// VAT number field which `depends_on` a country field:
+///
+/// ```ignore
+/// #[structform(depends_on = "country", validate_with = "structform::validate_vat_number")]
+/// vat_number: FormTextInput<String>,
+/// ```
+pub fn validate_vat_number(country: &str, vat_number: String) -> Result<String, ParseError> {
+    VatNumber::new(country, &vat_number)
+        .map(|vat_number| vat_number.number)
+        .map_err(|e| ParseError::FromStrError(e.to_string()))
+}
+
+/// The same as `validate_vat_number`, for optional VAT number fields.
+/// Empty VAT numbers are always valid.
+pub fn validate_optional_vat_number(
+    country: &str,
+    vat_number: Option<String>,
+) -> Result<Option<String>, ParseError> {
+    vat_number
+        .map(|vat_number| validate_vat_number(country, vat_number))
+        .transpose()
+}
+
+fn digits(number: &str) -> Vec<u32> {
+    number.chars().filter_map(|c| c.to_digit(10)).collect()
+}
+
+fn all_digits(number: &str, lengths: &[usize]) -> bool {
+    lengths.contains(&number.len()) && number.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Returns `None` if the country isn't supported.
+fn is_valid_format(country: &str, number: &str) -> Option<bool> {
+    let chars: Vec<char> = number.chars().collect();
+    let valid = match country {
+        "AT" => chars.len() == 9 && chars[0] == 'U' && all_digits(&number[1..], &[8]),
+        "BE" => all_digits(number, &[10]) && (chars[0] == '0' || chars[0] == '1'),
+        "BG" => all_digits(number, &[9, 10]),
+        "CY" => {
+            chars.len() == 9 && all_digits(&number[..8], &[8]) && chars[8].is_ascii_alphabetic()
+        }
+        "CZ" => all_digits(number, &[8, 9, 10]),
+        "DE" | "EE" | "EL" | "PT" => all_digits(number, &[9]),
+        "DK" | "FI" | "HU" | "LU" | "MT" | "SI" => all_digits(number, &[8]),
+        "ES" => {
+            chars.len() == 9
+                && chars.iter().all(|c| c.is_ascii_alphanumeric())
+                && all_digits(&number[1..8], &[7])
+        }
+        "FR" => {
+            chars.len() == 11
+                && chars[..2].iter().all(|c| c.is_ascii_alphanumeric())
+                && all_digits(&number[2..], &[9])
+        }
+        "HR" | "IT" | "LV" => all_digits(number, &[11]),
+        "IE" => {
+            (chars.len() == 8 || chars.len() == 9)
+                && chars[0].is_ascii_digit()
+                && chars
+                    .iter()
+                    .all(|c| c.is_ascii_alphanumeric() || *c == '+' || *c == '*')
+        }
+        "LT" => all_digits(number, &[9, 12]),
+        "NL" => {
+            chars.len() == 12
+                && all_digits(&number[..9], &[9])
+                && chars[9] == 'B'
+                && all_digits(&number[10..], &[2])
+        }
+        "PL" | "SK" => all_digits(number, &[10]),
+        "RO" => all_digits(number, &[2, 3, 4, 5, 6, 7, 8, 9, 10]),
+        "SE" => all_digits(number, &[12]) && number.ends_with("01"),
+        "ZA" => all_digits(number, &[10]) && chars[0] == '4',
+        _ => return None,
+    };
+    Some(valid)
+}
+
+fn weighted_sum(digits: &[u32], weights: &[u32]) -> u32 {
+    digits.iter().zip(weights).map(|(d, w)| d * w).sum()
+}
+
+/// ISO 7064 MOD 11,10, used by Germany and Croatia.
+fn iso7064_mod_11_10_is_valid(digits: &[u32]) -> bool {
+    let (check_digit, body) = match digits.split_last() {
+        Some(split) => split,
+        None => return false,
+    };
+    let mut product = 10;
+    for digit in body {
+        let mut sum = (digit + product) % 10;
+        if sum == 0 {
+            sum = 10;
+        }
+        product = (2 * sum) % 11;
+    }
+    (11 - product) % 10 == *check_digit
+}
+
+// `is_multiple_of` isn't available on the oldest Rust version that
+// StructForm supports.
+#[allow(clippy::manual_is_multiple_of)]
+fn is_valid_checksum(country: &str, number: &str) -> bool {
+    let d = digits(number);
+    match country {
+        "BE" => {
+            let body: u64 = number[..8].parse().unwrap_or(0);
+            let check: u64 = number[8..].parse().unwrap_or(0);
+            97 - body % 97 == check
+        }
+        "DE" | "HR" => iso7064_mod_11_10_is_valid(&d),
+        "DK" => weighted_sum(&d, &[2, 7, 6, 5, 4, 3, 2, 1]) % 11 == 0,
+        "FI" => {
+            let remainder = weighted_sum(&d, &[7, 9, 10, 5, 8, 4, 2]) % 11;
+            match remainder {
+                0 => d[7] == 0,
+                1 => false,
+                remainder => d[7] == 11 - remainder,
+            }
+        }
+        "FR" => match number[..2].parse::<u64>() {
+            Ok(key) => {
+                let siren: u64 = number[2..].parse().unwrap_or(0);
+                (12 + 3 * (siren % 97)) % 97 == key
+            }
+            // Newer French VAT numbers use letters in the key, which
+            // can't be checked offline.
+            Err(_) => true,
+        },
+        "IT" => luhn_is_valid(&d),
+        "LU" => {
+            let body: u64 = number[..6].parse().unwrap_or(0);
+            let check: u64 = number[6..].parse().unwrap_or(0);
+            body % 89 == check
+        }
+        "PL" => weighted_sum(&d, &[6, 5, 7, 2, 3, 4, 5, 6, 7]) % 11 == d[9],
+        "PT" => {
+            let check = 11 - weighted_sum(&d, &[9, 8, 7, 6, 5, 4, 3, 2]) % 11;
+            let check = if check > 9 { 0 } else { check };
+            check == d[8]
+        }
+        "SE" => luhn_is_valid(&d[..10]),
+        "SK" => number.parse::<u64>().map(|n| n % 11 == 0).unwrap_or(false),
+        _ => true,
+    }
+}
@@ -0,0 +1,88 @@
+use crate::{field_id, KeyPress};
+use wasm_bindgen::JsCast;
+use web_sys::{
+    Event, HtmlElement, HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement, KeyboardEvent,
+};
+
+/// The value of the `<input>`, `<textarea>` or `<select>` element that
+/// an event came from. This is `None` if the event's target is any
+/// other kind of element.
+pub fn event_value(event: &Event) -> Option<String> {
+    let target = event.target()?;
+    if let Some(input) = target.dyn_ref::<HtmlInputElement>() {
+        Some(input.value())
+    } else if let Some(textarea) = target.dyn_ref::<HtmlTextAreaElement>() {
+        Some(textarea.value())
+    } else {
+        target
+            .dyn_ref::<HtmlSelectElement>()
+            .map(HtmlSelectElement::value)
+    }
+}
+
+/// Whether the checkbox or radio button that an event came from is
+/// checked. This is `None` if the event's target isn't an `<input>`.
+pub fn event_checked(event: &Event) -> Option<bool> {
+    event
+        .target()?
+        .dyn_ref::<HtmlInputElement>()
+        .map(HtmlInputElement::checked)
+}
+
+/// The value of the selected option of the `<select>` that an event
+/// came from. This is `None` if the event's target isn't a `<select>`,
+/// or if no option is selected.
+pub fn event_selected_option(event: &Event) -> Option<String> {
+    let select = event.target()?.dyn_into::<HtmlSelectElement>().ok()?;
+    if select.selected_index() < 0 {
+        None
+    } else {
+        Some(select.value())
+    }
+}
+
+/// Pairs a field with the value of the `<input>`, `<textarea>` or
+/// `<select>` that an event came from, ready to pass to
+/// `StructForm::set_input`. See `event_value`.
+pub fn input_event<Field>(field: Field, event: &Event) -> Option<(Field, String)> {
+    event_value(event).map(|value| (field, value))
+}
+
+/// Pairs a field with whether the checkbox that an event came from is
+/// checked, as `"true"` or `"false"`. These parse with inputs using
+/// `impl_bool_input`, and with `bool`'s `FromStr`, so they also work
+/// with `StdTextInput<bool>`.
+pub fn checked_event<Field>(field: Field, event: &Event) -> Option<(Field, String)> {
+    event_checked(event).map(|checked| (field, checked.to_string()))
+}
+
+/// Pairs a field with the selected option of the `<select>` that an
+/// event came from. See `event_selected_option`.
+pub fn select_event<Field>(field: Field, event: &Event) -> Option<(Field, String)> {
+    event_selected_option(event).map(|value| (field, value))
+}
+
+/// The key press of a `keydown` event, for looking up in a
+/// `ShortcutMap`.
+pub fn key_press(event: &KeyboardEvent) -> KeyPress {
+    KeyPress {
+        key: event.key(),
+        ctrl: event.ctrl_key(),
+        shift: event.shift_key(),
+        alt: event.alt_key(),
+        meta: event.meta_key(),
+    }
+}
+
+/// Moves the focus to the input at a field path, like when an entry in
+/// an `ErrorSummary` is clicked. The input must be rendered with the
+/// `id` from `field_id`. Returns false if there is no such element in
+/// the document.
+pub fn focus_field(path: &str) -> bool {
+    web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.get_element_by_id(&field_id(path)))
+        .and_then(|element| element.dyn_into::<HtmlElement>().ok())
+        .map(|element| element.focus().is_ok())
+        .unwrap_or(false)
+}
@@ -1,18 +1,155 @@
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
+use syn::ext::IdentExt;
 use syn::*;
 
 #[proc_macro_derive(StructForm, attributes(structform))]
 pub fn derive_structform(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
-    let form_ident = input.ident.clone();
-    let field_enum_ident = field_enum_ident_transform(&form_ident);
+    expand_structform(&input).into()
+}
 
-    let input_struct_data = match input.data {
+/// Everything that `#[derive(StructForm)]` generates. Each part of the
+/// generated code comes from its own `impl_*` function below, so that
+/// they can be read, and tested, one at a time.
+fn expand_structform(input: &DeriveInput) -> proc_macro2::TokenStream {
+    let container_attrs = container_attrs(input);
+    let input_struct_data = match &input.data {
         Data::Struct(data) => data,
-        _ => panic!("StructForm can only be derived for structs"),
+        Data::Enum(data) => return impl_enum_form(input, data, &container_attrs),
+        _ => panic!("StructForm can only be derived for structs and enums"),
+    };
+    if container_attrs.default {
+        panic!("#[structform(default)] is only for enum forms. Derive Default on a struct form instead");
+    }
+    let form = FormStruct::new(input, input_struct_data, &container_attrs);
+    let form_ident = form.ident;
+    let model = &container_attrs.model;
+    let field_enum_ident = &form.field_enum_ident;
+    let vis = form.vis;
+
+    // `parse` peeks at the rate limit, rather than recording an attempt.
+    let submit_guards = impl_guards(&form.fields, format_ident!("check"));
+    let parse_guards = impl_guards(&form.fields, format_ident!("peek"));
+    let model_validations = impl_model_validations(
+        &form.fields,
+        container_attrs.at_least_one_of.as_ref(),
+        field_enum_ident,
+        &container_attrs.rename_variants,
+        &container_attrs.variant_prefixes,
+    );
+
+    let field_enum = impl_field_enum(&form);
+    let impl_field_enum_paths = impl_field_enum_paths(&form);
+
+    let impl_new = impl_new(&form);
+    let impl_submit = impl_submit(&form, &submit_guards);
+    let impl_parse = impl_parse(&form, &parse_guards);
+    let (impl_submit_update, impl_parse_update, assembled_model_errors) =
+        impl_update_fns(&form, &submit_guards, &parse_guards, &model_validations);
+    let impl_set_clock = impl_set_clock(&form);
+    let impl_validate = impl_validate(&form, assembled_model_errors.as_ref());
+    let impl_validate_model = impl_validate_model(&form, &model_validations);
+    let impl_fields_fn = impl_fields_fn(
+        &form.fields,
+        field_enum_ident,
+        &container_attrs.rename_variants,
+        &container_attrs.variant_prefixes,
+    );
+    let impl_set_input = impl_set_input(&form);
+    let impl_set_input_str = impl_set_input_str(&form);
+    let impl_apply_events = impl_apply_events(&form);
+    let impl_set_input_at_path = impl_set_input_at_path(&form);
+    let impl_set_error_at_path = impl_set_error_at_path(&form);
+    let impl_inputs_by_path = impl_inputs_by_path(&form);
+    let impl_values_by_path = impl_values_by_path(&form);
+    let impl_labels_by_path = impl_labels_by_path(&form.fields);
+    let impl_summary = impl_summary(&form.fields);
+    let impl_is_sensitive_path = impl_is_sensitive_path(&form.fields);
+    let impl_submit_attempted = impl_submit_attempted(&form);
+    let impl_reset = impl_reset(&form);
+    let impl_dirty_fields = impl_dirty_fields(&form.fields, field_enum_ident);
+    let impl_has_pending_validations = impl_has_pending_validations(&form);
+    let impl_is_empty = impl_is_empty(&form);
+
+    let impl_form = quote! {
+        #[automatically_derived]
+        impl structform::StructForm<#model> for #form_ident {
+            type Field = #field_enum_ident;
+
+            #impl_new
+            #impl_submit
+            #impl_submit_update
+            #impl_parse
+            #impl_parse_update
+            #impl_set_clock
+            #impl_validate
+            #impl_validate_model
+            #impl_fields_fn
+            fn field_path(field: &#field_enum_ident) -> String {
+                field.to_path()
+            }
+            #impl_set_input
+            #impl_set_input_str
+            #impl_apply_events
+            #impl_set_input_at_path
+            #impl_set_error_at_path
+            #impl_inputs_by_path
+            #impl_values_by_path
+            #impl_labels_by_path
+            #impl_summary
+            #impl_is_sensitive_path
+            #impl_submit_attempted
+            #impl_reset
+            #impl_dirty_fields
+            #impl_has_pending_validations
+            #impl_is_empty
+        }
     };
-    let container_attrs: FormContainerAttribute = input
+
+    let impl_fields = impl_form_fields(&form);
+    let impl_layout = impl_layout(&form);
+
+    let draft_model = container_attrs.draft_model.as_ref().map(|draft_model| {
+        impl_draft_model(
+            vis,
+            form_ident,
+            model,
+            field_enum_ident,
+            draft_model,
+            &form.fields,
+        )
+    });
+    let partial_model = container_attrs.partial_model.as_ref().map(|partial_model| {
+        impl_partial_model(
+            vis,
+            form_ident,
+            partial_model,
+            &form.fields,
+            &submit_guards,
+            assembled_model_errors.as_ref(),
+        )
+    });
+
+    quote! {
+        #field_enum
+
+        #impl_field_enum_paths
+
+        #impl_form
+
+        #impl_fields
+
+        #impl_layout
+
+        #partial_model
+
+        #draft_model
+    }
+}
+
+fn container_attrs(input: &DeriveInput) -> FormContainerAttribute {
+    input
         .attrs
         .iter()
         .find(|attr| attr.path.is_ident("structform"))
@@ -20,273 +157,3367 @@ pub fn derive_structform(input: TokenStream) -> TokenStream {
             attr.parse_args()
                 .expect("Failed to parse the #[structform] attr on the container")
         })
-        .expect("Require a #[structform] attribute on the container");
-    let model = container_attrs.model;
+        .expect("Require a #[structform] attribute on the container")
+}
 
-    let enriched_fields = enrich_fields(&input_struct_data);
+/// The fields of one kind on a form struct, like its inputs or its
+/// lists of subforms, with the names that generated code uses for
+/// them.
+struct FieldGroup {
+    snake_case: Vec<Ident>,
+    /// The fields' variants in the field enum.
+    pascal_case: Vec<Ident>,
+    /// The input types, or the subform types. This is empty for kinds
+    /// of fields that aren't inputs or subforms.
+    types: Vec<Type>,
+    /// The model fields that the fields fill in.
+    models: Vec<Ident>,
+    /// The fields' paths, which are their names.
+    paths: Vec<String>,
+}
 
-    let (input_names, input_fields_type): (Vec<(Ident, Ident)>, Vec<Type>) = enriched_fields
-        .iter()
-        .filter_map(|field| match &field.ty {
-            FieldType::Input { input_type } => Some((field.names(), input_type.clone())),
-            _ => None,
-        })
-        .unzip();
-    let (input_fields_snake_case, input_fields_pascal_case): (Vec<Ident>, Vec<Ident>) =
-        input_names.into_iter().unzip();
+impl FieldGroup {
+    fn new(enriched_fields: &[RichField], is_kind: fn(&FieldType) -> bool) -> FieldGroup {
+        let fields: Vec<&RichField> = enriched_fields
+            .iter()
+            .filter(|field| is_kind(&field.ty))
+            .collect();
+        FieldGroup {
+            snake_case: fields
+                .iter()
+                .map(|field| field.snake_case_ident.clone())
+                .collect(),
+            pascal_case: fields
+                .iter()
+                .map(|field| field.pascal_case_ident.clone())
+                .collect(),
+            types: fields
+                .iter()
+                .filter_map(|field| match &field.ty {
+                    FieldType::Input { input_type } => Some(input_type.clone()),
+                    FieldType::Subform { subform_type }
+                    | FieldType::OptionalSubform { subform_type }
+                    | FieldType::ListSubform { subform_type, .. }
+                    | FieldType::MapSubform { subform_type, .. } => Some(subform_type.clone()),
+                    _ => None,
+                })
+                .collect(),
+            models: fields.iter().map(|field| field.model_ident()).collect(),
+            paths: fields
+                .iter()
+                .map(|field| field.snake_case_ident.to_string())
+                .collect(),
+        }
+    }
 
-    let (option_form_names, option_form_fields_type): (Vec<(Ident, Ident)>, Vec<Type>) =
-        enriched_fields
+    /// The field enums of the subforms, like `AddressFormField`.
+    fn field_enums(&self) -> Vec<Ident> {
+        self.types.iter().map(type_to_field_enum_ident).collect()
+    }
+}
+
+/// A form struct that StructForm is being derived for, with its fields
+/// grouped by kind.
+struct FormStruct<'a> {
+    ident: &'a Ident,
+    vis: &'a Visibility,
+    attrs: &'a FormContainerAttribute,
+    field_enum_name: Ident,
+    field_enum_ident: proc_macro2::TokenStream,
+    fields: Vec<RichField>,
+    inputs: FieldGroup,
+    option_forms: FieldGroup,
+    /// The variants that toggle the optional subforms.
+    option_form_toggles: Vec<Ident>,
+    list_forms: FieldGroup,
+    list_forms_lazy: Vec<bool>,
+    list_form_adds: Vec<Ident>,
+    list_form_removes: Vec<Ident>,
+    list_form_moves: Vec<Ident>,
+    map_forms: FieldGroup,
+    map_form_key_types: Vec<Type>,
+    map_form_adds: Vec<Ident>,
+    map_form_removes: Vec<Ident>,
+    subforms: FieldGroup,
+    submit_attempted: FieldGroup,
+    honeypots: FieldGroup,
+    rate_limits: FieldGroup,
+    captchas: FieldGroup,
+    change_logs: FieldGroup,
+    skips: FieldGroup,
+    skip_defaults: Vec<proc_macro2::TokenStream>,
+}
+
+impl<'a> FormStruct<'a> {
+    fn new(
+        input: &'a DeriveInput,
+        struct_data: &DataStruct,
+        container_attrs: &'a FormContainerAttribute,
+    ) -> FormStruct<'a> {
+        let (field_enum_name, field_enum_ident) =
+            field_enum_names(&input.ident, container_attrs.module.as_ref());
+        let rename_variants = &container_attrs.rename_variants;
+        let variant_prefixes = &container_attrs.variant_prefixes;
+        let fields = enrich_fields(struct_data, rename_variants);
+
+        let group = |is_kind: fn(&FieldType) -> bool| FieldGroup::new(&fields, is_kind);
+        let inputs = group(|ty| matches!(ty, FieldType::Input { .. }));
+        let option_forms = group(|ty| matches!(ty, FieldType::OptionalSubform { .. }));
+        let list_forms = group(|ty| matches!(ty, FieldType::ListSubform { .. }));
+        let map_forms = group(|ty| matches!(ty, FieldType::MapSubform { .. }));
+        let subforms = group(|ty| matches!(ty, FieldType::Subform { .. }));
+        let submit_attempted = group(|ty| matches!(ty, FieldType::SubmitAttempted));
+        let honeypots = group(|ty| matches!(ty, FieldType::Honeypot));
+        let rate_limits = group(|ty| matches!(ty, FieldType::RateLimit { .. }));
+        let captchas = group(|ty| matches!(ty, FieldType::Captcha));
+        let change_logs = group(|ty| matches!(ty, FieldType::ChangeLog));
+        let skips = group(|ty| matches!(ty, FieldType::Skip { .. }));
+
+        let list_forms_lazy = fields
             .iter()
             .filter_map(|field| match &field.ty {
-                FieldType::OptionalSubform { subform_type } => {
-                    Some((field.names(), subform_type.clone()))
-                }
+                FieldType::ListSubform { lazy, .. } => Some(*lazy),
                 _ => None,
             })
-            .unzip();
-    let (option_form_fields_snake_case, option_form_fields_pascal_case): (Vec<Ident>, Vec<Ident>) =
-        option_form_names.into_iter().unzip();
-    let option_form_fields_type_field_enum: Vec<Ident> = option_form_fields_type
-        .iter()
-        .map(type_to_field_enum_ident)
-        .collect();
-
-    let option_form_fields_toggles_pascal_case: Vec<Ident> = option_form_fields_pascal_case
-        .iter()
-        .map(|field_ident| Ident::new(&format!("Toggle{}", field_ident), field_ident.span()))
-        .collect();
-
-    let (list_form_names, list_form_fields_type): (Vec<(Ident, Ident)>, Vec<Type>) =
-        enriched_fields
+            .collect();
+        let map_form_key_types = fields
             .iter()
             .filter_map(|field| match &field.ty {
-                FieldType::ListSubform { subform_type } => {
-                    Some((field.names(), subform_type.clone()))
-                }
+                FieldType::MapSubform { key_type, .. } => Some(key_type.clone()),
                 _ => None,
             })
-            .unzip();
-    let (list_form_fields_snake_case, list_form_fields_pascal_case): (Vec<Ident>, Vec<Ident>) =
-        list_form_names.into_iter().unzip();
-    let list_form_fields_type_field_enum: Vec<Ident> = list_form_fields_type
-        .iter()
-        .map(type_to_field_enum_ident)
-        .collect();
+            .collect();
+        let skip_defaults = fields
+            .iter()
+            .filter_map(|field| match &field.ty {
+                FieldType::Skip { default } => Some(
+                    default
+                        .as_ref()
+                        .map(|default| quote! { #default })
+                        .unwrap_or_else(|| quote! { Default::default() }),
+                ),
+                _ => None,
+            })
+            .collect();
 
-    let list_form_fields_add_pascal_case: Vec<Ident> = list_form_fields_pascal_case
-        .iter()
-        .map(|field_ident| Ident::new(&format!("Add{}", field_ident), field_ident.span()))
-        .collect();
-    let list_form_fields_remove_pascal_case: Vec<Ident> = list_form_fields_pascal_case
-        .iter()
-        .map(|field_ident| Ident::new(&format!("Remove{}", field_ident), field_ident.span()))
-        .collect();
+        if container_attrs.flatten && !captchas.snake_case.is_empty() {
+            panic!("#[structform(captcha)] fields can't be used on flattened forms, since there is no model field to carry the token");
+        }
+        if !container_attrs.skip.is_empty()
+            && (container_attrs.flatten || container_attrs.submit_with.is_some())
+        {
+            panic!("#[structform(skip = \"...\")] can't be used with flatten or submit_with, since the model isn't built by the derived submit");
+        }
 
-    let (subform_names, subform_fields_type): (Vec<(Ident, Ident)>, Vec<Type>) = enriched_fields
-        .iter()
-        .filter_map(|field| match &field.ty {
-            FieldType::Subform { subform_type } => Some((field.names(), subform_type.clone())),
-            _ => None,
-        })
-        .unzip();
-    let (subform_fields_snake_case, subform_fields_pascal_case): (Vec<Ident>, Vec<Ident>) =
-        subform_names.into_iter().unzip();
-    let subform_fields_type_field_enum: Vec<Ident> = subform_fields_type
-        .iter()
-        .map(type_to_field_enum_ident)
-        .collect();
+        let prefixed = |prefix: &str, group: &FieldGroup| -> Vec<Ident> {
+            group
+                .snake_case
+                .iter()
+                .map(|field_ident| rename_variants.ident(prefix, field_ident))
+                .collect()
+        };
+        FormStruct {
+            ident: &input.ident,
+            vis: &input.vis,
+            attrs: container_attrs,
+            field_enum_name,
+            field_enum_ident,
+            option_form_toggles: prefixed(&variant_prefixes.toggle, &option_forms),
+            list_form_adds: prefixed(&variant_prefixes.add, &list_forms),
+            list_form_removes: prefixed(&variant_prefixes.remove, &list_forms),
+            list_form_moves: prefixed(&variant_prefixes.move_, &list_forms),
+            map_form_adds: prefixed(&variant_prefixes.add, &map_forms),
+            map_form_removes: prefixed(&variant_prefixes.remove, &map_forms),
+            fields,
+            inputs,
+            option_forms,
+            list_forms,
+            list_forms_lazy,
+            map_forms,
+            map_form_key_types,
+            subforms,
+            submit_attempted,
+            honeypots,
+            rate_limits,
+            captchas,
+            change_logs,
+            skips,
+            skip_defaults,
+        }
+    }
+}
 
-    let submit_attempted_fields_snake_case: Vec<Ident> = enriched_fields
-        .iter()
-        .filter_map(|field| match &field.ty {
-            FieldType::SubmitAttempted => Some(field.snake_case_ident.clone()),
-            _ => None,
-        })
-        .collect();
+/// The field enum, with a variant for every input, and for every
+/// action on a subform, like adding an entry to a list.
+fn impl_field_enum(form: &FormStruct) -> proc_macro2::TokenStream {
+    let field_enum_name = &form.field_enum_name;
+    let input_fields_pascal_case = &form.inputs.pascal_case;
+    let option_form_fields_toggles_pascal_case = &form.option_form_toggles;
+    let option_form_fields_pascal_case = &form.option_forms.pascal_case;
+    let option_form_fields_type_field_enum = form.option_forms.field_enums();
+    let list_form_fields_add_pascal_case = &form.list_form_adds;
+    let list_form_fields_pascal_case = &form.list_forms.pascal_case;
+    let list_form_fields_type_field_enum = form.list_forms.field_enums();
+    let list_form_fields_remove_pascal_case = &form.list_form_removes;
+    let list_form_fields_move_pascal_case = &form.list_form_moves;
+    let map_form_fields_add_pascal_case = &form.map_form_adds;
+    let map_form_fields_pascal_case = &form.map_forms.pascal_case;
+    let map_form_fields_key_type = &form.map_form_key_types;
+    let map_form_fields_type_field_enum = form.map_forms.field_enums();
+    let map_form_fields_remove_pascal_case = &form.map_form_removes;
+    let subform_fields_pascal_case = &form.subforms.pascal_case;
+    let subform_fields_type_field_enum = form.subforms.field_enums();
+    let honeypot_fields_pascal_case = &form.honeypots.pascal_case;
+    let captcha_fields_pascal_case = &form.captchas.pascal_case;
 
+    check_unique_variants(
+        form.ident,
+        input_fields_pascal_case
+            .iter()
+            .chain(option_form_fields_toggles_pascal_case)
+            .chain(option_form_fields_pascal_case)
+            .chain(list_form_fields_add_pascal_case)
+            .chain(list_form_fields_pascal_case)
+            .chain(list_form_fields_remove_pascal_case)
+            .chain(list_form_fields_move_pascal_case)
+            .chain(map_form_fields_add_pascal_case)
+            .chain(map_form_fields_pascal_case)
+            .chain(map_form_fields_remove_pascal_case)
+            .chain(subform_fields_pascal_case)
+            .chain(honeypot_fields_pascal_case)
+            .chain(captcha_fields_pascal_case),
+    );
     let field_enum = quote! {
-        #[derive(Debug)]
-        pub enum #field_enum_ident {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        // Generated items can't be documented, and some variants may never
+        // be constructed, so lints that downstream crates deny for their own
+        // code are allowed on them.
+        #[allow(missing_docs, dead_code, non_camel_case_types, clippy::enum_variant_names)]
+        pub enum #field_enum_name {
             #(#input_fields_pascal_case,)*
             #(#option_form_fields_toggles_pascal_case,)*
             #(#option_form_fields_pascal_case(#option_form_fields_type_field_enum),)*
             #(#list_form_fields_add_pascal_case,)*
             #(#list_form_fields_pascal_case(usize, #list_form_fields_type_field_enum),)*
             #(#list_form_fields_remove_pascal_case(usize),)*
+            #(#list_form_fields_move_pascal_case(usize, usize),)*
+            #(#map_form_fields_add_pascal_case(#map_form_fields_key_type),)*
+            #(#map_form_fields_pascal_case(#map_form_fields_key_type, #map_form_fields_type_field_enum),)*
+            #(#map_form_fields_remove_pascal_case(#map_form_fields_key_type),)*
             #(#subform_fields_pascal_case(#subform_fields_type_field_enum),)*
+            #(#honeypot_fields_pascal_case,)*
+            #(#captcha_fields_pascal_case,)*
         }
     };
+    wrap_field_enum_in_module(form.vis, form.attrs.module.as_ref(), field_enum)
+}
 
-    let impl_new = if container_attrs.flatten {
-        quote! {
+/// `new`, which fills in the form from a model.
+fn impl_new(form: &FormStruct) -> proc_macro2::TokenStream {
+    let form_ident = form.ident;
+    let model = &form.attrs.model;
+    let input_fields_snake_case = &form.inputs.snake_case;
+    let input_fields_type = &form.inputs.types;
+    let submit_attempted_fields_snake_case = &form.submit_attempted.snake_case;
+    let honeypot_fields_snake_case = &form.honeypots.snake_case;
+    let rate_limit_fields_snake_case = &form.rate_limits.snake_case;
+    let change_log_fields_snake_case = &form.change_logs.snake_case;
+    let skip_fields_snake_case = &form.skips.snake_case;
+    let skip_fields_default = &form.skip_defaults;
+
+    if form.attrs.flatten {
+        return quote! {
             fn new(model: &#model) -> #form_ident {
                 #form_ident {
                     #(#input_fields_snake_case: <#input_fields_type>::new(&model),)*
                     #(#submit_attempted_fields_snake_case: false,)*
+                    #(#honeypot_fields_snake_case: String::new(),)*
+                    #(#rate_limit_fields_snake_case: Default::default(),)*
+                    #(#change_log_fields_snake_case: Default::default(),)*
+                    #(#skip_fields_snake_case: #skip_fields_default,)*
+                }
+            }
+        };
+    }
+
+    let input_fields_from_model: Vec<proc_macro2::TokenStream> = form
+        .fields
+        .iter()
+        .filter(|field| matches!(field.ty, FieldType::Input { .. }))
+        .map(|field| match field.model_fields().as_slice() {
+            [model_field] => quote! { &model.#model_field },
+            model_fields => quote! { &(#(model.#model_fields.clone(),)*) },
+        })
+        .collect();
+    let option_form_fields_snake_case = &form.option_forms.snake_case;
+    let option_form_fields_model = &form.option_forms.models;
+    let option_form_fields_type = &form.option_forms.types;
+    let list_form_fields_snake_case = &form.list_forms.snake_case;
+    let list_form_fields_from_model: Vec<proc_macro2::TokenStream> = form
+        .list_forms
+        .models
+        .iter()
+        .zip(&form.list_forms.types)
+        .zip(&form.list_forms_lazy)
+        .map(|((model_field, field_type), lazy)| {
+            if *lazy {
+                quote! {
+                    structform::LazyList::from_models(&model.#model_field)
+                }
+            } else {
+                quote! {
+                    model.#model_field.iter().map(<#field_type>::new).collect()
                 }
             }
+        })
+        .collect();
+    let map_form_fields_snake_case = &form.map_forms.snake_case;
+    let map_form_fields_model = &form.map_forms.models;
+    let map_form_fields_type = &form.map_forms.types;
+    let subform_fields_snake_case = &form.subforms.snake_case;
+    let subform_fields_model = &form.subforms.models;
+    let subform_fields_type = &form.subforms.types;
+    let captcha_fields_snake_case = &form.captchas.snake_case;
+    quote! {
+        fn new(model: &#model) -> #form_ident {
+            #form_ident {
+                #(#input_fields_snake_case: <#input_fields_type>::new(#input_fields_from_model),)*
+                #(#option_form_fields_snake_case: model.#option_form_fields_model.as_ref().map(<#option_form_fields_type>::new),)*
+                #(#list_form_fields_snake_case: #list_form_fields_from_model,)*
+                #(#map_form_fields_snake_case: model.#map_form_fields_model
+                    .iter()
+                    .map(|(key, inner_model)| (key.clone(), <#map_form_fields_type>::new(inner_model)))
+                    .collect(),)*
+                #(#subform_fields_snake_case: <#subform_fields_type>::new(&model.#subform_fields_model),)*
+                #(#submit_attempted_fields_snake_case: false,)*
+                #(#honeypot_fields_snake_case: String::new(),)*
+                #(#rate_limit_fields_snake_case: Default::default(),)*
+                #(#change_log_fields_snake_case: Default::default(),)*
+                #(#captcha_fields_snake_case: String::new(),)*
+                #(#skip_fields_snake_case: #skip_fields_default,)*
+            }
+        }
+    }
+}
+
+/// The start of `parse` and `submit` on forms that aren't flattened,
+/// which starts the model with the defaults of the fields that the
+/// form doesn't fill in.
+fn impl_default_model(form: &FormStruct) -> proc_macro2::TokenStream {
+    let model = &form.attrs.model;
+    let (skip_model_fields, skip_model_fields_default): (
+        Vec<&Ident>,
+        Vec<proc_macro2::TokenStream>,
+    ) = form
+        .attrs
+        .skip
+        .iter()
+        .map(|skip| {
+            let default = skip
+                .default
+                .as_ref()
+                .map(|default| quote! { #default })
+                .unwrap_or_else(|| quote! { Default::default() });
+            (&skip.field, default)
+        })
+        .unzip();
+    quote! {
+        #[allow(unused_mut)]
+        let mut model = <#model>::default();
+        #(model.#skip_model_fields = #skip_model_fields_default;)*
+    }
+}
+
+/// `parse`, which checks what `submit` would return without changing
+/// the form.
+fn impl_parse(
+    form: &FormStruct,
+    parse_guards: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let model = &form.attrs.model;
+    let input_fields_snake_case = &form.inputs.snake_case;
+    let flatten_map_submit = impl_flatten_map_submit(form);
+    // A custom submit function needs a form that it can change, so forms
+    // with one are cloned to parse them.
+    if form.attrs.submit_with.is_some() {
+        quote! {
+            fn parse(&self) -> Result<#model, structform::ParseError> {
+                <Self as Clone>::clone(self).submit()
+            }
+        }
+    } else if form.attrs.flatten {
+        quote! {
+            fn parse(&self) -> Result<#model, structform::ParseError> {
+                #parse_guards
+                #(self.#input_fields_snake_case.peek_submit())* #flatten_map_submit
+            }
         }
     } else {
+        let default_model = impl_default_model(form);
         quote! {
-            fn new(model: &#model) -> #form_ident {
-                #form_ident {
-                    #(#input_fields_snake_case: <#input_fields_type>::new(&model.#input_fields_snake_case),)*
-                    #(#option_form_fields_snake_case: model.#option_form_fields_snake_case.as_ref().map(<#option_form_fields_type>::new),)*
-                    #(#list_form_fields_snake_case: model.#list_form_fields_snake_case.iter().map(<#list_form_fields_type>::new).collect(),)*
-                    #(#subform_fields_snake_case: <#subform_fields_type>::new(&model.#subform_fields_snake_case),)*
-                    #(#submit_attempted_fields_snake_case: false,)*
-                }
+            fn parse(&self) -> Result<#model, structform::ParseError> {
+                #default_model
+                self.parse_update(model)
             }
         }
-    };
+    }
+}
 
-    let impl_submit = container_attrs
-        .submit_with
-        .map(|submit_with| {
-            quote! {
-                fn submit(&mut self) -> Result<#model, structform::ParseError> {
-                    #(self.#submit_attempted_fields_snake_case = true;)*
-                    #submit_with(self)
-                }
+/// `submit`, which parses the form into its model.
+fn impl_submit(
+    form: &FormStruct,
+    submit_guards: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let model = &form.attrs.model;
+    let input_fields_snake_case = &form.inputs.snake_case;
+    let submit_attempted_fields_snake_case = &form.submit_attempted.snake_case;
+    let flatten_map_submit = impl_flatten_map_submit(form);
+    if let Some(submit_with) = &form.attrs.submit_with {
+        quote! {
+            fn submit(&mut self) -> Result<#model, structform::ParseError> {
+                #(self.#submit_attempted_fields_snake_case = true;)*
+                #submit_guards
+                #submit_with(self)
             }
-        })
-        .unwrap_or(if container_attrs.flatten {
+        }
+    } else if form.attrs.flatten {
+        quote! {
+            fn submit(&mut self) -> Result<#model, structform::ParseError> {
+                #(self.#submit_attempted_fields_snake_case = true;)*
+                #submit_guards
+                #(self.#input_fields_snake_case.submit())* #flatten_map_submit
+            }
+        }
+    } else {
+        let default_model = impl_default_model(form);
+        quote! {
+            fn submit(&mut self) -> Result<#model, structform::ParseError> {
+                #(self.#submit_attempted_fields_snake_case = true;)*
+                #default_model
+                self.submit_update(model)
+            }
+        }
+    }
+}
+
+/// The `#[structform(map_submit = "...")]` function applied to the
+/// result of a flattened form's input.
+fn impl_flatten_map_submit(form: &FormStruct) -> Option<proc_macro2::TokenStream> {
+    form.attrs
+        .map_submit
+        .as_ref()
+        .map(|map_submit| quote! { .map(#map_submit) })
+}
+
+/// `validate_model`, which checks the rules on the assembled model.
+/// Forms without any rules use the default, which accepts any model.
+fn impl_validate_model(
+    form: &FormStruct,
+    model_validations: &[proc_macro2::TokenStream],
+) -> proc_macro2::TokenStream {
+    let model = &form.attrs.model;
+    let field_enum_ident = &form.field_enum_ident;
+    if model_validations.is_empty() {
+        quote! {}
+    } else {
+        quote! {
+            fn validate_model(&self, model: &#model) -> Result<(), structform::ValidationErrors<#field_enum_ident>> {
+                let mut errors = structform::ValidationErrors::new();
+                #(#model_validations)*
+                errors.into_result()
+            }
+        }
+    }
+}
+
+/// `submit_update` and `parse_update`, along with an expression for
+/// the errors of `validate_model` on the model that the form's inputs
+/// would assemble, for `validate` and partial models. The expression
+/// is `None` if there's no model to check, because the form is
+/// flattened or has no rules on its model.
+fn impl_update_fns(
+    form: &FormStruct,
+    submit_guards: &proc_macro2::TokenStream,
+    parse_guards: &proc_macro2::TokenStream,
+    model_validations: &[proc_macro2::TokenStream],
+) -> (
+    proc_macro2::TokenStream,
+    proc_macro2::TokenStream,
+    Option<proc_macro2::TokenStream>,
+) {
+    let model = &form.attrs.model;
+    let input_fields_snake_case = &form.inputs.snake_case;
+    let submit_attempted_fields_snake_case = &form.submit_attempted.snake_case;
+    let captcha_fields_snake_case = &form.captchas.snake_case;
+
+    if form.attrs.flatten {
+        let flatten_map_submit = impl_flatten_map_submit(form);
+        return (
             quote! {
-                fn submit(&mut self) -> Result<#model, structform::ParseError> {
+                fn submit_update(&mut self, mut model: #model) -> Result<#model, structform::ParseError> {
                     #(self.#submit_attempted_fields_snake_case = true;)*
-                    #(self.#input_fields_snake_case.submit())*
+                    #submit_guards
+                    #(self.#input_fields_snake_case.submit())* #flatten_map_submit
                 }
-            }
-        } else {
+            },
             quote! {
-                fn submit(&mut self) -> Result<#model, structform::ParseError> {
-                    #(self.#submit_attempted_fields_snake_case = true;)*
-                    self.submit_update(<#model>::default())
+                fn parse_update(&self, _model: #model) -> Result<#model, structform::ParseError> {
+                    self.parse()
                 }
-            }
-        });
+            },
+            None,
+        );
+    }
 
-    let impl_submit_update = if container_attrs.flatten {
+    let check_model = if model_validations.is_empty() {
+        quote! {}
+    } else {
         quote! {
-            fn submit_update(&mut self, mut model: #model) -> Result<#model, structform::ParseError> {
-                #(self.#submit_attempted_fields_snake_case = true;)*
-                #(self.#input_fields_snake_case.submit())*
+            if let Err(errors) = self.validate_model(&model) {
+                return Err(errors.into_iter().next().expect("Errors are never empty").1);
+            }
+        }
+    };
+    let captcha_guards = quote! {
+        #(if self.#captcha_fields_snake_case.trim().is_empty() {
+            return Err(structform::ParseError::CaptchaRequired);
+        })*
+    };
+    // `submit_update` and `parse_update` are the same, except that
+    // parsing can't change the form. `validate` assembles the model the
+    // same way as parsing, but without the guards and rules.
+    let submit_update_body = impl_update_body(
+        form,
+        false,
+        &quote! { #submit_guards #captcha_guards },
+        &check_model,
+    );
+    let parse_update_body = impl_update_body(
+        form,
+        true,
+        &quote! { #parse_guards #captcha_guards },
+        &check_model,
+    );
+    let assemble_body = impl_update_body(form, true, &quote! {}, &quote! {});
+    let submit_update_body = if captcha_fields_snake_case.is_empty() {
+        submit_update_body
+    } else {
+        // Captcha tokens can only be verified once, so a new one is
+        // needed after any failed submit.
+        quote! {
+            let result = (|| { #submit_update_body })();
+            if result.is_err() {
+                #(self.#captcha_fields_snake_case.clear();)*
             }
+            result
         }
+    };
+    // Rules on the model can only be checked once every input parses,
+    // since the model can't be assembled before then.
+    let assembled_model_errors = if model_validations.is_empty() {
+        None
     } else {
+        let default_model = impl_default_model(form);
+        Some(quote! {{
+            #default_model
+            let assembled: Result<#model, structform::ParseError> = (|| { #assemble_body })();
+            assembled.ok().and_then(|model| self.validate_model(&model).err())
+        }})
+    };
+    (
         quote! {
             fn submit_update(&mut self, mut model: #model) -> Result<#model, structform::ParseError> {
                 #(self.#submit_attempted_fields_snake_case = true;)*
+                #submit_update_body
+            }
+        },
+        quote! {
+            fn parse_update(&self, mut model: #model) -> Result<#model, structform::ParseError> {
+                #parse_update_body
+            }
+        },
+        assembled_model_errors,
+    )
+}
 
-                #(let #input_fields_snake_case = self.#input_fields_snake_case.submit();)*
-                #(let #option_form_fields_snake_case = self.#option_form_fields_snake_case.as_mut().map(|inner_form| {
-                    model.#option_form_fields_snake_case
-                        .clone()
-                        .map(|inner_model| inner_form.submit_update(inner_model))
-                        .unwrap_or_else(|| inner_form.submit())
-                }).transpose();)*
-                #(let #list_form_fields_snake_case = self.#list_form_fields_snake_case.iter_mut().enumerate().map(|(i, inner_form)| {
-                    model.#list_form_fields_snake_case
-                        .get(i)
-                        .map(|inner_model| inner_form.submit_update(inner_model.clone()))
-                        .unwrap_or_else(|| inner_form.submit())
-                }).collect::<Result<Vec<_>,_>>();)*
-                #(let #subform_fields_snake_case = self.#subform_fields_snake_case.submit_update(model.#subform_fields_snake_case.clone());)*
-
-                #(model.#input_fields_snake_case = #input_fields_snake_case?;)*
-                #(model.#option_form_fields_snake_case = #option_form_fields_snake_case?;)*
-                #(model.#list_form_fields_snake_case = #list_form_fields_snake_case?;)*
-                #(model.#subform_fields_snake_case = #subform_fields_snake_case?;)*
-                Ok(model)
+/// The body of `submit_update`, or of `parse_update` if `parse` is
+/// true, which fills in `model` from the form's inputs and subforms.
+/// `guards` run first, and `check_model` runs on the assembled model.
+fn impl_update_body(
+    form: &FormStruct,
+    parse: bool,
+    guards: &proc_macro2::TokenStream,
+    check_model: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let (update, submit, iter, as_ref) = update_methods(parse);
+    let input_fields_snake_case = &form.inputs.snake_case;
+    let input_fields_update: Vec<proc_macro2::TokenStream> = form
+        .fields
+        .iter()
+        .filter(|field| matches!(field.ty, FieldType::Input { .. }))
+        .map(|field| {
+            if parse {
+                impl_input_field_parse(field)
+            } else {
+                impl_input_field_submit(field)
+            }
+        })
+        .collect();
+    let input_fields_into_model: Vec<proc_macro2::TokenStream> = form
+        .fields
+        .iter()
+        .filter(|field| matches!(field.ty, FieldType::Input { .. }))
+        .map(|field| {
+            let input_field = &field.snake_case_ident;
+            match field.model_fields().as_slice() {
+                [model_field] => quote! { model.#model_field = #input_field?; },
+                model_fields => quote! {
+                    let (#(#model_fields,)*) = #input_field?;
+                    #(model.#model_fields = #model_fields;)*
+                },
+            }
+        })
+        .collect();
+    let option_form_fields_snake_case = &form.option_forms.snake_case;
+    let option_form_fields_model = &form.option_forms.models;
+    let list_form_fields_snake_case = &form.list_forms.snake_case;
+    let list_form_fields_model = &form.list_forms.models;
+    let list_form_fields_update: Vec<proc_macro2::TokenStream> = list_form_fields_snake_case
+        .iter()
+        .zip(list_form_fields_model)
+        .zip(&form.list_forms_lazy)
+        .map(|((field_ident, model_field), lazy)| {
+            if *lazy {
+                quote! {
+                    self.#field_ident.#update(&model.#model_field)
+                }
+            } else {
+                quote! {{
+                    let mut inner_models = std::mem::take(&mut model.#model_field).into_iter();
+                    self.#field_ident.#iter().map(|inner_form| {
+                        match inner_models.next() {
+                            Some(inner_model) => inner_form.#update(inner_model),
+                            None => inner_form.#submit(),
+                        }
+                    }).collect::<Result<Vec<_>,_>>()
+                }}
             }
+        })
+        .collect();
+    let map_form_fields_snake_case = &form.map_forms.snake_case;
+    let map_form_fields_model = &form.map_forms.models;
+    let subform_fields_snake_case = &form.subforms.snake_case;
+    let subform_fields_model = &form.subforms.models;
+    let captcha_fields_snake_case = &form.captchas.snake_case;
+    let map_submit = form.attrs.map_submit.as_ref().map(|map_submit| {
+        quote! {
+            let model = #map_submit(model);
         }
-    };
+    });
+    quote! {
+        #guards
+
+        #(let #input_fields_snake_case = #input_fields_update;)*
+        #(let #option_form_fields_snake_case = self.#option_form_fields_snake_case.#as_ref().map(|inner_form| {
+            match model.#option_form_fields_model.take() {
+                Some(inner_model) => inner_form.#update(inner_model),
+                None => inner_form.#submit(),
+            }
+        }).transpose();)*
+        #(let #list_form_fields_snake_case = #list_form_fields_update;)*
+        #(let #map_form_fields_snake_case = {
+            let mut inner_models = std::mem::take(&mut model.#map_form_fields_model);
+            self.#map_form_fields_snake_case.#iter().map(|(key, inner_form)| {
+                match inner_models.remove(key) {
+                    Some(inner_model) => inner_form.#update(inner_model),
+                    None => inner_form.#submit(),
+                }.map(|inner_model| (key.clone(), inner_model))
+            }).collect::<Result<_, structform::ParseError>>()
+        };)*
+        // Subform models are moved out of the model, so they don't need
+        // to be Clone. Every field that is moved out is assigned again
+        // below.
+        #(let #subform_fields_snake_case = self.#subform_fields_snake_case.#update(model.#subform_fields_model);)*
+
+        #(#input_fields_into_model)*
+        #(model.#option_form_fields_model = #option_form_fields_snake_case?;)*
+        #(model.#list_form_fields_model = #list_form_fields_snake_case?;)*
+        #(model.#map_form_fields_model = #map_form_fields_snake_case?;)*
+        #(model.#subform_fields_model = #subform_fields_snake_case?;)*
+        #(model.#captcha_fields_snake_case = structform::CaptchaToken::new(self.#captcha_fields_snake_case.clone());)*
+        #map_submit
+        #check_model
+        Ok(model)
+    }
+}
+
+/// The arms of `set_input` and `apply_events` for everything but the
+/// inputs themselves, which both set the same way.
+fn impl_set_input_other_arms(form: &FormStruct) -> proc_macro2::TokenStream {
+    let field_enum_ident = &form.field_enum_ident;
+    let option_form_fields_toggles_pascal_case = &form.option_form_toggles;
+    let option_form_fields_snake_case = &form.option_forms.snake_case;
+    let option_form_fields_pascal_case = &form.option_forms.pascal_case;
+    let option_form_fields_type = &form.option_forms.types;
+    let list_form_fields_add_pascal_case = &form.list_form_adds;
+    let list_form_fields_snake_case = &form.list_forms.snake_case;
+    let list_form_fields_pascal_case = &form.list_forms.pascal_case;
+    let list_form_fields_type = &form.list_forms.types;
+    let list_form_fields_remove_pascal_case = &form.list_form_removes;
+    let list_form_fields_move_pascal_case = &form.list_form_moves;
+    let map_form_fields_add_pascal_case = &form.map_form_adds;
+    let map_form_fields_snake_case = &form.map_forms.snake_case;
+    let map_form_fields_pascal_case = &form.map_forms.pascal_case;
+    let map_form_fields_type = &form.map_forms.types;
+    let map_form_fields_remove_pascal_case = &form.map_form_removes;
+    let subform_fields_snake_case = &form.subforms.snake_case;
+    let subform_fields_pascal_case = &form.subforms.pascal_case;
+    let honeypot_fields_snake_case = &form.honeypots.snake_case;
+    let honeypot_fields_pascal_case = &form.honeypots.pascal_case;
+    let captcha_fields_snake_case = &form.captchas.snake_case;
+    let captcha_fields_pascal_case = &form.captchas.pascal_case;
+    quote! {
+        #(#field_enum_ident::#option_form_fields_toggles_pascal_case => {
+            if self.#option_form_fields_snake_case.is_some() {
+                self.#option_form_fields_snake_case = None;
+            } else {
+                self.#option_form_fields_snake_case = Some(#option_form_fields_type::default());
+            }
+        },)*
+        #(#field_enum_ident::#option_form_fields_pascal_case(subfield) => {
+            self.#option_form_fields_snake_case
+                .as_mut()
+                .map(|inner_form| inner_form.set_input(subfield, value));
+        },)*
+        #(#field_enum_ident::#list_form_fields_add_pascal_case => {
+            self.#list_form_fields_snake_case
+                .push(#list_form_fields_type::default());
+        },)*
+        #(#field_enum_ident::#list_form_fields_pascal_case(i, subfield) => {
+            self.#list_form_fields_snake_case
+                .get_mut(i)
+                .map(|inner_form| inner_form.set_input(subfield, value));
+        },)*
+        #(#field_enum_ident::#list_form_fields_remove_pascal_case(i) => {
+            if i < self.#list_form_fields_snake_case.len() {
+                self.#list_form_fields_snake_case.remove(i);
+            }
+        },)*
+        #(#field_enum_ident::#list_form_fields_move_pascal_case(from, to) => {
+            if from < self.#list_form_fields_snake_case.len()
+                && to < self.#list_form_fields_snake_case.len()
+            {
+                let inner_form = self.#list_form_fields_snake_case.remove(from);
+                self.#list_form_fields_snake_case.insert(to, inner_form);
+            }
+        },)*
+        #(#field_enum_ident::#map_form_fields_add_pascal_case(key) => {
+            self.#map_form_fields_snake_case
+                .entry(key)
+                .or_insert_with(#map_form_fields_type::default);
+        },)*
+        #(#field_enum_ident::#map_form_fields_pascal_case(key, subfield) => {
+            self.#map_form_fields_snake_case
+                .get_mut(&key)
+                .map(|inner_form| inner_form.set_input(subfield, value));
+        },)*
+        #(#field_enum_ident::#map_form_fields_remove_pascal_case(key) => {
+            self.#map_form_fields_snake_case.remove(&key);
+        },)*
+
+        #(#field_enum_ident::#subform_fields_pascal_case(subfield) => {
+            self.#subform_fields_snake_case.set_input(subfield, value);
+        },)*
+        #(#field_enum_ident::#honeypot_fields_pascal_case => {
+            self.#honeypot_fields_snake_case = value;
+        },)*
+        #(#field_enum_ident::#captcha_fields_pascal_case => {
+            self.#captcha_fields_snake_case = value;
+        },)*
+    }
+}
 
-    let impl_set_input = quote! {
+/// `set_input`, which sets one field and revalidates the fields that
+/// depend on it.
+fn impl_set_input(form: &FormStruct) -> proc_macro2::TokenStream {
+    let field_enum_ident = &form.field_enum_ident;
+    let input_fields_pascal_case = &form.inputs.pascal_case;
+    let input_fields_set_input: Vec<proc_macro2::TokenStream> = form
+        .inputs
+        .snake_case
+        .iter()
+        .map(|input_field| impl_input_field_set_input(&form.fields, input_field, true))
+        .collect();
+    let set_input_other_arms = impl_set_input_other_arms(form);
+    let (record_changes_before, record_changes_after) =
+        impl_record_changes(&form.change_logs.snake_case);
+    quote! {
         fn set_input(&mut self, field: #field_enum_ident, value: String) {
+            #record_changes_before
             match field {
-                #(#field_enum_ident::#input_fields_pascal_case => self.#input_fields_snake_case.set_input(value),)*
-                #(#field_enum_ident::#option_form_fields_toggles_pascal_case => {
-                    if self.#option_form_fields_snake_case.is_some() {
-                        self.#option_form_fields_snake_case = None;
-                    } else {
-                        self.#option_form_fields_snake_case = Some(#option_form_fields_type::default());
-                    }
-                },)*
-                #(#field_enum_ident::#option_form_fields_pascal_case(subfield) => {
-                    self.#option_form_fields_snake_case
-                        .as_mut()
-                        .map(|inner_form| inner_form.set_input(subfield, value));
-                },)*
-                #(#field_enum_ident::#list_form_fields_add_pascal_case => {
-                    self.#list_form_fields_snake_case
-                        .push(#list_form_fields_type::default());
-                },)*
-                #(#field_enum_ident::#list_form_fields_pascal_case(i, subfield) => {
-                    self.#list_form_fields_snake_case
-                        .get_mut(i)
-                        .map(|inner_form| inner_form.set_input(subfield, value));
-                },)*
-                #(#field_enum_ident::#list_form_fields_remove_pascal_case(i) => {
-                    if i < self.#list_form_fields_snake_case.len() {
-                        self.#list_form_fields_snake_case.remove(i);
+                #(#field_enum_ident::#input_fields_pascal_case => #input_fields_set_input,)*
+                #set_input_other_arms
+            }
+            #record_changes_after
+        }
+    }
+}
+
+/// `apply_events`, which sets many fields, and only revalidates the
+/// fields that depend on others once they're all set.
+fn impl_apply_events(form: &FormStruct) -> proc_macro2::TokenStream {
+    let field_enum_ident = &form.field_enum_ident;
+    let input_fields_pascal_case = &form.inputs.pascal_case;
+    let input_fields_apply_event: Vec<proc_macro2::TokenStream> = form
+        .inputs
+        .snake_case
+        .iter()
+        .map(|input_field| impl_input_field_set_input(&form.fields, input_field, false))
+        .collect();
+    let set_input_other_arms = impl_set_input_other_arms(form);
+    let revalidate_dependent_fields: Vec<proc_macro2::TokenStream> = form
+        .fields
+        .iter()
+        .filter(|field| field.attrs.depends_on.is_some())
+        .map(impl_revalidate_dependent_field)
+        .collect();
+    let (record_changes_before, record_changes_after) =
+        impl_record_changes(&form.change_logs.snake_case);
+    quote! {
+        fn apply_events<I>(&mut self, events: I)
+        where
+            I: IntoIterator<Item = (#field_enum_ident, String)>,
+        {
+            #record_changes_before
+            for (field, value) in events {
+                match field {
+                    #(#field_enum_ident::#input_fields_pascal_case => #input_fields_apply_event,)*
+                    #set_input_other_arms
+                }
+            }
+            #(#revalidate_dependent_fields)*
+            #record_changes_after
+        }
+    }
+}
+
+/// `set_input_str`, which sets inputs from a `&str` where it can.
+fn impl_set_input_str(form: &FormStruct) -> proc_macro2::TokenStream {
+    let field_enum_ident = &form.field_enum_ident;
+    if !form.change_logs.snake_case.is_empty() {
+        return quote! {
+            fn set_input_str(&mut self, field: #field_enum_ident, value: &str) {
+                self.set_input(field, value.to_string());
+            }
+        };
+    }
+
+    // Inputs that other fields follow or depend on need the owned value
+    // in `set_input`, so only the rest can be set from a `&str`.
+    let (simple_input_fields_snake_case, simple_input_fields_pascal_case): (
+        Vec<Ident>,
+        Vec<Ident>,
+    ) = form
+        .fields
+        .iter()
+        .filter(|field| matches!(field.ty, FieldType::Input { .. }))
+        .filter(|field| {
+            field.attrs.depends_on.is_none()
+                && field.attrs.empty.is_none()
+                && !form.fields.iter().any(|other| {
+                    other.attrs.slug_of.as_ref() == Some(&field.snake_case_ident)
+                        || other.attrs.depends_on.as_ref() == Some(&field.snake_case_ident)
+                })
+        })
+        .map(RichField::names)
+        .unzip();
+    let option_form_fields_snake_case = &form.option_forms.snake_case;
+    let option_form_fields_pascal_case = &form.option_forms.pascal_case;
+    let list_form_fields_snake_case = &form.list_forms.snake_case;
+    let list_form_fields_pascal_case = &form.list_forms.pascal_case;
+    let map_form_fields_snake_case = &form.map_forms.snake_case;
+    let map_form_fields_pascal_case = &form.map_forms.pascal_case;
+    let subform_fields_snake_case = &form.subforms.snake_case;
+    let subform_fields_pascal_case = &form.subforms.pascal_case;
+    quote! {
+        fn set_input_str(&mut self, field: #field_enum_ident, value: &str) {
+            #[allow(unreachable_patterns)]
+            match field {
+                #(#field_enum_ident::#simple_input_fields_pascal_case => {
+                    self.#simple_input_fields_snake_case.set_input_str(value);
+                },)*
+                #(#field_enum_ident::#option_form_fields_pascal_case(subfield) => {
+                    if let Some(inner_form) = self.#option_form_fields_snake_case.as_mut() {
+                        inner_form.set_input_str(subfield, value);
+                    }
+                },)*
+                #(#field_enum_ident::#list_form_fields_pascal_case(i, subfield) => {
+                    if let Some(inner_form) = self.#list_form_fields_snake_case.get_mut(i) {
+                        inner_form.set_input_str(subfield, value);
+                    }
+                },)*
+                #(#field_enum_ident::#map_form_fields_pascal_case(key, subfield) => {
+                    if let Some(inner_form) = self.#map_form_fields_snake_case.get_mut(&key) {
+                        inner_form.set_input_str(subfield, value);
+                    }
+                },)*
+                #(#field_enum_ident::#subform_fields_pascal_case(subfield) => {
+                    self.#subform_fields_snake_case.set_input_str(subfield, value);
+                },)*
+                field => self.set_input(field, value.to_string()),
+            }
+        }
+    }
+}
+
+/// `set_input_at_path`, which sets an input from its field path,
+/// creating the subforms and list entries on the way to it. Anything
+/// created for a path that turns out not to lead to an input is
+/// removed again, so a bad path leaves the form as it was.
+fn impl_set_input_at_path(form: &FormStruct) -> proc_macro2::TokenStream {
+    let field_enum_ident = &form.field_enum_ident;
+    let input_fields_path = &form.inputs.paths;
+    let input_fields_pascal_case = &form.inputs.pascal_case;
+    let option_form_fields_path = &form.option_forms.paths;
+    let option_form_fields_snake_case = &form.option_forms.snake_case;
+    let option_form_fields_type = &form.option_forms.types;
+    let list_form_fields_path = &form.list_forms.paths;
+    let list_form_fields_snake_case = &form.list_forms.snake_case;
+    let list_form_fields_type = &form.list_forms.types;
+    let map_form_fields_path = &form.map_forms.paths;
+    let map_form_fields_snake_case = &form.map_forms.snake_case;
+    let map_form_fields_type = &form.map_forms.types;
+    let map_form_fields_key_type = &form.map_form_key_types;
+    let subform_fields_path = &form.subforms.paths;
+    let subform_fields_snake_case = &form.subforms.snake_case;
+    let honeypot_fields_path = &form.honeypots.paths;
+    let honeypot_fields_pascal_case = &form.honeypots.pascal_case;
+    let captcha_fields_path = &form.captchas.paths;
+    let captcha_fields_pascal_case = &form.captchas.pascal_case;
+    let (record_changes_before, record_changes_after) =
+        impl_record_changes(&form.change_logs.snake_case);
+    quote! {
+        fn set_input_at_path(&mut self, path: &str, value: String) -> bool {
+            match structform::split_field_path(path) {
+                #(Some((#input_fields_path, None, "")) => {
+                    self.set_input(#field_enum_ident::#input_fields_pascal_case, value);
+                    true
+                },)*
+                #(Some((#option_form_fields_path, None, rest)) if !rest.is_empty() => {
+                    #record_changes_before
+                    let is_new = self.#option_form_fields_snake_case.is_none();
+                    let is_set = self.#option_form_fields_snake_case
+                        .get_or_insert_with(#option_form_fields_type::default)
+                        .set_input_at_path(rest, value);
+                    if is_new && !is_set {
+                        self.#option_form_fields_snake_case = None;
+                    }
+                    #record_changes_after
+                    is_set
+                },)*
+                #(Some((#list_form_fields_path, Some(i), rest)) if !rest.is_empty() => {
+                    #record_changes_before
+                    let is_new = i == self.#list_form_fields_snake_case.len();
+                    if is_new {
+                        self.#list_form_fields_snake_case
+                            .push(#list_form_fields_type::default());
+                    }
+                    let is_set = self.#list_form_fields_snake_case
+                        .get_mut(i)
+                        .map(|inner_form| inner_form.set_input_at_path(rest, value))
+                        .unwrap_or(false);
+                    if is_new && !is_set {
+                        self.#list_form_fields_snake_case.remove(i);
+                    }
+                    #record_changes_after
+                    is_set
+                },)*
+                #(Some((#map_form_fields_path, None, rest)) if !rest.is_empty() => {
+                    match structform::split_map_key::<#map_form_fields_key_type>(rest) {
+                        Some((key, subpath)) => {
+                            #record_changes_before
+                            let is_new = !self.#map_form_fields_snake_case.contains_key(&key);
+                            let is_set = self.#map_form_fields_snake_case
+                                .entry(key)
+                                .or_insert_with(#map_form_fields_type::default)
+                                .set_input_at_path(subpath, value);
+                            if is_new && !is_set {
+                                // The key was moved into the map, so it's
+                                // parsed again to remove it.
+                                if let Some((key, _)) = structform::split_map_key::<#map_form_fields_key_type>(rest) {
+                                    self.#map_form_fields_snake_case.remove(&key);
+                                }
+                            }
+                            #record_changes_after
+                            is_set
+                        }
+                        None => false,
+                    }
+                },)*
+                #(Some((#subform_fields_path, None, rest)) if !rest.is_empty() => {
+                    #record_changes_before
+                    let is_set = self.#subform_fields_snake_case.set_input_at_path(rest, value);
+                    #record_changes_after
+                    is_set
+                },)*
+                #(Some((#honeypot_fields_path, None, "")) => {
+                    self.set_input(#field_enum_ident::#honeypot_fields_pascal_case, value);
+                    true
+                },)*
+                #(Some((#captcha_fields_path, None, "")) => {
+                    self.set_input(#field_enum_ident::#captcha_fields_pascal_case, value);
+                    true
+                },)*
+                _ => false,
+            }
+        }
+    }
+}
+
+/// `set_error_at_path`, which sets an error on the input at a field
+/// path, like one returned by a server.
+fn impl_set_error_at_path(form: &FormStruct) -> proc_macro2::TokenStream {
+    let input_fields_path = &form.inputs.paths;
+    let input_fields_snake_case = &form.inputs.snake_case;
+    let option_form_fields_path = &form.option_forms.paths;
+    let option_form_fields_snake_case = &form.option_forms.snake_case;
+    let list_form_fields_path = &form.list_forms.paths;
+    let list_form_fields_snake_case = &form.list_forms.snake_case;
+    let map_form_fields_path = &form.map_forms.paths;
+    let map_form_fields_snake_case = &form.map_forms.snake_case;
+    let map_form_fields_key_type = &form.map_form_key_types;
+    let subform_fields_path = &form.subforms.paths;
+    let subform_fields_snake_case = &form.subforms.snake_case;
+    quote! {
+        fn set_error_at_path(&mut self, path: &str, error: structform::ParseError) -> bool {
+            match structform::split_field_path(path) {
+                #(Some((#input_fields_path, None, "")) => {
+                    self.#input_fields_snake_case.set_error(error);
+                    true
+                },)*
+                #(Some((#option_form_fields_path, None, rest)) if !rest.is_empty() => {
+                    self.#option_form_fields_snake_case
+                        .as_mut()
+                        .map(|inner_form| inner_form.set_error_at_path(rest, error))
+                        .unwrap_or(false)
+                },)*
+                #(Some((#list_form_fields_path, Some(i), rest)) if !rest.is_empty() => {
+                    self.#list_form_fields_snake_case
+                        .get_mut(i)
+                        .map(|inner_form| inner_form.set_error_at_path(rest, error))
+                        .unwrap_or(false)
+                },)*
+                #(Some((#map_form_fields_path, None, rest)) if !rest.is_empty() => {
+                    structform::split_map_key::<#map_form_fields_key_type>(rest)
+                        .and_then(|(key, rest)| {
+                            self.#map_form_fields_snake_case
+                                .get_mut(&key)
+                                .map(|inner_form| inner_form.set_error_at_path(rest, error))
+                        })
+                        .unwrap_or(false)
+                },)*
+                #(Some((#subform_fields_path, None, rest)) if !rest.is_empty() => {
+                    self.#subform_fields_snake_case.set_error_at_path(rest, error)
+                },)*
+                _ => false,
+            }
+        }
+    }
+}
+
+/// `inputs_by_path`, which pairs the path of every input with what
+/// was typed into it.
+fn impl_inputs_by_path(form: &FormStruct) -> proc_macro2::TokenStream {
+    let input_fields_path = &form.inputs.paths;
+    let input_fields_snake_case = &form.inputs.snake_case;
+    let option_form_fields_path = &form.option_forms.paths;
+    let option_form_fields_snake_case = &form.option_forms.snake_case;
+    let list_form_fields_path = &form.list_forms.paths;
+    let list_form_fields_snake_case = &form.list_forms.snake_case;
+    let map_form_fields_path = &form.map_forms.paths;
+    let map_form_fields_snake_case = &form.map_forms.snake_case;
+    let subform_fields_path = &form.subforms.paths;
+    let subform_fields_snake_case = &form.subforms.snake_case;
+    quote! {
+        fn inputs_by_path(&self) -> Vec<(String, String)> {
+            let mut inputs = Vec::new();
+            #(inputs.push((#input_fields_path.to_string(), self.#input_fields_snake_case.input.clone()));)*
+            #(if let Some(inner_form) = &self.#option_form_fields_snake_case {
+                for (path, input) in inner_form.inputs_by_path() {
+                    inputs.push((format!("{}.{}", #option_form_fields_path, path), input));
+                }
+            })*
+            #(for (i, inner_form) in self.#list_form_fields_snake_case.iter().enumerate() {
+                for (path, input) in inner_form.inputs_by_path() {
+                    inputs.push((format!("{}[{}].{}", #list_form_fields_path, i, path), input));
+                }
+            })*
+            #(for (key, inner_form) in &self.#map_form_fields_snake_case {
+                for (path, input) in inner_form.inputs_by_path() {
+                    inputs.push((format!("{}.{}.{}", #map_form_fields_path, structform::escape_map_key(key), path), input));
+                }
+            })*
+            #(for (path, input) in self.#subform_fields_snake_case.inputs_by_path() {
+                inputs.push((format!("{}.{}", #subform_fields_path, path), input));
+            })*
+            inputs
+        }
+    }
+}
+
+/// `values_by_path`, which pairs the path of every input with its
+/// formatted value, or what was typed into it if it doesn't parse.
+fn impl_values_by_path(form: &FormStruct) -> proc_macro2::TokenStream {
+    let input_fields_path = &form.inputs.paths;
+    let input_fields_snake_case = &form.inputs.snake_case;
+    let input_fields_type = &form.inputs.types;
+    let option_form_fields_path = &form.option_forms.paths;
+    let option_form_fields_snake_case = &form.option_forms.snake_case;
+    let list_form_fields_path = &form.list_forms.paths;
+    let list_form_fields_snake_case = &form.list_forms.snake_case;
+    let map_form_fields_path = &form.map_forms.paths;
+    let map_form_fields_snake_case = &form.map_forms.snake_case;
+    let subform_fields_path = &form.subforms.paths;
+    let subform_fields_snake_case = &form.subforms.snake_case;
+    quote! {
+        fn values_by_path(&self) -> Vec<(String, String)> {
+            let mut values = Vec::new();
+            #(values.push((
+                #input_fields_path.to_string(),
+                self.#input_fields_snake_case
+                    .value
+                    .as_ref()
+                    .map(|value| <#input_fields_type as structform::ParseAndFormat<_>>::format(value))
+                    .unwrap_or_else(|_| self.#input_fields_snake_case.input.clone()),
+            ));)*
+            #(if let Some(inner_form) = &self.#option_form_fields_snake_case {
+                for (path, value) in inner_form.values_by_path() {
+                    values.push((format!("{}.{}", #option_form_fields_path, path), value));
+                }
+            })*
+            #(for (i, inner_form) in self.#list_form_fields_snake_case.iter().enumerate() {
+                for (path, value) in inner_form.values_by_path() {
+                    values.push((format!("{}[{}].{}", #list_form_fields_path, i, path), value));
+                }
+            })*
+            #(for (key, inner_form) in &self.#map_form_fields_snake_case {
+                for (path, value) in inner_form.values_by_path() {
+                    values.push((format!("{}.{}.{}", #map_form_fields_path, structform::escape_map_key(key), path), value));
+                }
+            })*
+            #(for (path, value) in self.#subform_fields_snake_case.values_by_path() {
+                values.push((format!("{}.{}", #subform_fields_path, path), value));
+            })*
+            values
+        }
+    }
+}
+
+/// `summary`, which pairs the label of every field that isn't
+/// sensitive with its formatted value.
+fn impl_summary(enriched_fields: &[RichField]) -> proc_macro2::TokenStream {
+    let summary_entries: Vec<proc_macro2::TokenStream> = enriched_fields
+        .iter()
+        .filter(|field| !field.attrs.sensitive)
+        .filter_map(impl_summary_entry)
+        .collect();
+    quote! {
+        fn summary(&self) -> Vec<(String, String)> {
+            let mut summary = Vec::new();
+            #(#summary_entries)*
+            summary
+        }
+    }
+}
+
+/// `validate`, which lists the errors of every input, and then the
+/// rules on the model if every input parses.
+fn impl_validate(
+    form: &FormStruct,
+    assembled_model_errors: Option<&proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+    let field_enum_ident = &form.field_enum_ident;
+    let validate_entries: Vec<proc_macro2::TokenStream> = form
+        .fields
+        .iter()
+        .filter_map(|field| impl_validate_entry(field, field_enum_ident))
+        .collect();
+    let validate_assembled_model = assembled_model_errors.map(|model_errors| {
+        quote! {
+            if errors.is_empty() {
+                if let Some(model_errors) = #model_errors {
+                    errors = model_errors;
+                }
+            }
+        }
+    });
+    quote! {
+        fn validate(&self) -> Result<(), structform::ValidationErrors<#field_enum_ident>> {
+            let mut errors = structform::ValidationErrors::new();
+            #(#validate_entries)*
+            #validate_assembled_model
+            errors.into_result()
+        }
+    }
+}
+
+/// `to_path` and `from_path` on the field enum.
+fn impl_field_enum_paths(form: &FormStruct) -> proc_macro2::TokenStream {
+    let field_enum_ident = &form.field_enum_ident;
+    let input_fields_path = &form.inputs.paths;
+    let input_fields_pascal_case = &form.inputs.pascal_case;
+    let option_form_fields_path = &form.option_forms.paths;
+    let option_form_fields_toggles_pascal_case = &form.option_form_toggles;
+    let option_form_fields_pascal_case = &form.option_forms.pascal_case;
+    let option_form_fields_type_field_enum = form.option_forms.field_enums();
+    let list_form_fields_path = &form.list_forms.paths;
+    let list_form_fields_add_pascal_case = &form.list_form_adds;
+    let list_form_fields_pascal_case = &form.list_forms.pascal_case;
+    let list_form_fields_remove_pascal_case = &form.list_form_removes;
+    let list_form_fields_move_pascal_case = &form.list_form_moves;
+    let list_form_fields_type_field_enum = form.list_forms.field_enums();
+    let map_form_fields_path = &form.map_forms.paths;
+    let map_form_fields_add_pascal_case = &form.map_form_adds;
+    let map_form_fields_pascal_case = &form.map_forms.pascal_case;
+    let map_form_fields_remove_pascal_case = &form.map_form_removes;
+    let map_form_fields_key_type = &form.map_form_key_types;
+    let map_form_fields_type_field_enum = form.map_forms.field_enums();
+    let subform_fields_path = &form.subforms.paths;
+    let subform_fields_pascal_case = &form.subforms.pascal_case;
+    let subform_fields_type_field_enum = form.subforms.field_enums();
+    let honeypot_fields_path = &form.honeypots.paths;
+    let honeypot_fields_pascal_case = &form.honeypots.pascal_case;
+    let captcha_fields_path = &form.captchas.paths;
+    let captcha_fields_pascal_case = &form.captchas.pascal_case;
+
+    // Fields that aren't inputs, like the buttons that add and remove
+    // list entries, use the path of the subform or entry they act on.
+    let to_path_arms = quote! {
+        #(#field_enum_ident::#input_fields_pascal_case => #input_fields_path.to_string(),)*
+        #(#field_enum_ident::#option_form_fields_toggles_pascal_case => #option_form_fields_path.to_string(),)*
+        #(#field_enum_ident::#option_form_fields_pascal_case(subfield) => {
+            format!("{}.{}", #option_form_fields_path, subfield.to_path())
+        },)*
+        #(#field_enum_ident::#list_form_fields_add_pascal_case => #list_form_fields_path.to_string(),)*
+        #(#field_enum_ident::#list_form_fields_pascal_case(i, subfield) => {
+            format!("{}[{}].{}", #list_form_fields_path, i, subfield.to_path())
+        },)*
+        #(#field_enum_ident::#list_form_fields_remove_pascal_case(i) => format!("{}[{}]", #list_form_fields_path, i),)*
+        #(#field_enum_ident::#list_form_fields_move_pascal_case(i, _) => format!("{}[{}]", #list_form_fields_path, i),)*
+        #(#field_enum_ident::#map_form_fields_add_pascal_case(key) => format!("{}.{}", #map_form_fields_path, structform::escape_map_key(key)),)*
+        #(#field_enum_ident::#map_form_fields_pascal_case(key, subfield) => {
+            format!("{}.{}.{}", #map_form_fields_path, structform::escape_map_key(key), subfield.to_path())
+        },)*
+        #(#field_enum_ident::#map_form_fields_remove_pascal_case(key) => format!("{}.{}", #map_form_fields_path, structform::escape_map_key(key)),)*
+        #(#field_enum_ident::#subform_fields_pascal_case(subfield) => {
+            format!("{}.{}", #subform_fields_path, subfield.to_path())
+        },)*
+        #(#field_enum_ident::#honeypot_fields_pascal_case => #honeypot_fields_path.to_string(),)*
+        #(#field_enum_ident::#captcha_fields_pascal_case => #captcha_fields_path.to_string(),)*
+    };
+    let to_path_body = if to_path_arms.is_empty() {
+        quote! { match *self {} }
+    } else {
+        quote! {
+            match self {
+                #to_path_arms
+            }
+        }
+    };
+    quote! {
+        #[automatically_derived]
+        impl #field_enum_ident {
+            /// The field path of the field, like `addresses[0].city`.
+            /// Fields that aren't inputs, like `AddAddresses` or
+            /// `RemoveAddresses(0)`, have the path of the subform or
+            /// list entry that they act on.
+            #[allow(dead_code)]
+            pub fn to_path(&self) -> String {
+                #to_path_body
+            }
+
+            /// The input field at a field path, like the keys of a
+            /// submitted HTML form. Only paths of inputs are parsed,
+            /// so that a stray key can't add, remove or toggle
+            /// subforms.
+            #[allow(dead_code)]
+            pub fn from_path(path: &str) -> Option<#field_enum_ident> {
+                match structform::split_field_path(path) {
+                    #(Some((#input_fields_path, None, "")) => Some(#field_enum_ident::#input_fields_pascal_case),)*
+                    #(Some((#option_form_fields_path, None, rest)) if !rest.is_empty() => {
+                        #option_form_fields_type_field_enum::from_path(rest).map(#field_enum_ident::#option_form_fields_pascal_case)
+                    },)*
+                    #(Some((#list_form_fields_path, Some(i), rest)) if !rest.is_empty() => {
+                        #list_form_fields_type_field_enum::from_path(rest)
+                            .map(|subfield| #field_enum_ident::#list_form_fields_pascal_case(i, subfield))
+                    },)*
+                    #(Some((#map_form_fields_path, None, rest)) if !rest.is_empty() => {
+                        structform::split_map_key::<#map_form_fields_key_type>(rest).and_then(|(key, rest)| {
+                            #map_form_fields_type_field_enum::from_path(rest)
+                                .map(|subfield| #field_enum_ident::#map_form_fields_pascal_case(key, subfield))
+                        })
+                    },)*
+                    #(Some((#subform_fields_path, None, rest)) if !rest.is_empty() => {
+                        #subform_fields_type_field_enum::from_path(rest).map(#field_enum_ident::#subform_fields_pascal_case)
+                    },)*
+                    #(Some((#honeypot_fields_path, None, "")) => Some(#field_enum_ident::#honeypot_fields_pascal_case),)*
+                    #(Some((#captcha_fields_path, None, "")) => Some(#field_enum_ident::#captcha_fields_pascal_case),)*
+                    _ => None,
+                }
+            }
+        }
+    }
+}
+
+fn impl_submit_attempted(form: &FormStruct) -> proc_macro2::TokenStream {
+    let submit_attempted_fields_snake_case = &form.submit_attempted.snake_case;
+    quote! {
+        fn submit_attempted(&self) -> bool {
+            false #(|| self.#submit_attempted_fields_snake_case)*
+        }
+    }
+}
+
+/// `reset`, which clears the form. Rate limits and change logs are
+/// left as they are, so that resetting the form can't be used to get
+/// around them.
+fn impl_reset(form: &FormStruct) -> proc_macro2::TokenStream {
+    let input_fields_snake_case = &form.inputs.snake_case;
+    let option_form_fields_snake_case = &form.option_forms.snake_case;
+    let list_form_fields_snake_case = &form.list_forms.snake_case;
+    let map_form_fields_snake_case = &form.map_forms.snake_case;
+    let subform_fields_snake_case = &form.subforms.snake_case;
+    let submit_attempted_fields_snake_case = &form.submit_attempted.snake_case;
+    let honeypot_fields_snake_case = &form.honeypots.snake_case;
+    let captcha_fields_snake_case = &form.captchas.snake_case;
+    quote! {
+        fn reset(&mut self) {
+            #(self.#input_fields_snake_case.clear();)*
+            #(self.#option_form_fields_snake_case = None;)*
+            #(self.#list_form_fields_snake_case = Default::default();)*
+            #(self.#map_form_fields_snake_case = Default::default();)*
+            #(self.#subform_fields_snake_case.reset();)*
+            #(self.#submit_attempted_fields_snake_case = false;)*
+            #(self.#honeypot_fields_snake_case.clear();)*
+            #(self.#captcha_fields_snake_case.clear();)*
+        }
+    }
+}
+
+/// `set_clock`, which gives the clock to everything on the form that
+/// reads the time, including subforms.
+fn impl_set_clock(form: &FormStruct) -> proc_macro2::TokenStream {
+    let input_fields_snake_case = &form.inputs.snake_case;
+    let rate_limit_fields_snake_case = &form.rate_limits.snake_case;
+    let change_log_fields_snake_case = &form.change_logs.snake_case;
+    let option_form_fields_snake_case = &form.option_forms.snake_case;
+    let list_form_fields_set_clock: Vec<proc_macro2::TokenStream> = form
+        .list_forms
+        .snake_case
+        .iter()
+        .zip(&form.list_forms_lazy)
+        .map(|(field_ident, lazy)| {
+            if *lazy {
+                quote! { self.#field_ident.set_clock(clock); }
+            } else {
+                quote! {
+                    for inner_form in self.#field_ident.iter_mut() {
+                        inner_form.set_clock(clock);
+                    }
+                }
+            }
+        })
+        .collect();
+    let map_form_fields_snake_case = &form.map_forms.snake_case;
+    let subform_fields_snake_case = &form.subforms.snake_case;
+    quote! {
+        fn set_clock(&mut self, clock: &structform::FormClock) {
+            #(self.#input_fields_snake_case.edit_timestamp.set_clock(clock);)*
+            #(self.#rate_limit_fields_snake_case.clock = clock.clone();)*
+            #(self.#change_log_fields_snake_case.set_clock(clock.clone());)*
+            #(if let Some(inner_form) = &mut self.#option_form_fields_snake_case {
+                inner_form.set_clock(clock);
+            })*
+            #(#list_form_fields_set_clock)*
+            #(for inner_form in self.#map_form_fields_snake_case.values_mut() {
+                inner_form.set_clock(clock);
+            })*
+            #(self.#subform_fields_snake_case.set_clock(clock);)*
+        }
+    }
+}
+
+fn impl_has_pending_validations(form: &FormStruct) -> proc_macro2::TokenStream {
+    let input_fields_snake_case = &form.inputs.snake_case;
+    let option_form_fields_snake_case = &form.option_forms.snake_case;
+    let list_form_fields_snake_case = &form.list_forms.snake_case;
+    let map_form_fields_snake_case = &form.map_forms.snake_case;
+    let subform_fields_snake_case = &form.subforms.snake_case;
+    quote! {
+        fn has_pending_validations(&self) -> bool {
+            false
+            #(|| self.#input_fields_snake_case.is_pending())*
+            #(|| self.#option_form_fields_snake_case.as_ref().map(|inner_form| inner_form.has_pending_validations()).unwrap_or(false))*
+            #(|| self.#list_form_fields_snake_case.iter().any(|inner_form| inner_form.has_pending_validations()))*
+            #(|| self.#map_form_fields_snake_case.values().any(|inner_form| inner_form.has_pending_validations()))*
+            #(|| self.#subform_fields_snake_case.has_pending_validations())*
+        }
+    }
+}
+
+fn impl_is_empty(form: &FormStruct) -> proc_macro2::TokenStream {
+    let input_fields_snake_case = &form.inputs.snake_case;
+    let option_form_fields_snake_case = &form.option_forms.snake_case;
+    let list_form_fields_snake_case = &form.list_forms.snake_case;
+    let map_form_fields_snake_case = &form.map_forms.snake_case;
+    let subform_fields_snake_case = &form.subforms.snake_case;
+    quote! {
+        fn is_empty(&self) -> bool {
+            true
+            #(&& self.#input_fields_snake_case.is_empty())*
+            #(&& self.#option_form_fields_snake_case.as_ref().map(|inner_form| inner_form.is_empty()).unwrap_or(true))*
+            #(&& self.#list_form_fields_snake_case.iter().all(|inner_form| inner_form.is_empty()))*
+            #(&& self.#map_form_fields_snake_case.values().all(|inner_form| inner_form.is_empty()))*
+            #(&& self.#subform_fields_snake_case.is_empty())*
+        }
+    }
+}
+
+/// The `FormFields` impl, and the typed setters from
+/// `#[structform(setters)]`.
+fn impl_form_fields(form: &FormStruct) -> proc_macro2::TokenStream {
+    let form_ident = form.ident;
+    let field_meta_entries = impl_field_meta_entries(&form.fields);
+    let typed_setters = if form.attrs.setters {
+        impl_typed_setters(
+            form.vis,
+            &form.attrs.model,
+            &form.field_enum_ident,
+            &form.fields,
+        )
+    } else {
+        Vec::new()
+    };
+    quote! {
+        #[automatically_derived]
+        impl structform::FormFields for #form_ident {
+            const FIELDS: &'static [structform::FieldMeta] = &[#(#field_meta_entries),*];
+        }
+
+        #[automatically_derived]
+        impl #form_ident {
+            #(#typed_setters)*
+        }
+    }
+}
+
+/// The `FormLayout` impl, from `#[structform(layout = "...")]` or with
+/// one field per row, and the form's manifest.
+fn impl_layout(form: &FormStruct) -> proc_macro2::TokenStream {
+    let form_ident = form.ident;
+    let vis = form.vis;
+    let layout = match &form.attrs.layout {
+        Some(layout) => quote! { #layout() },
+        None => {
+            quote! { structform::Layout::one_field_per_row(<Self as structform::FormFields>::FIELDS) }
+        }
+    };
+    quote! {
+        #[automatically_derived]
+        impl structform::FormLayout for #form_ident {
+            fn layout() -> structform::Layout {
+                #layout
+            }
+        }
+
+        structform::__impl_form_manifest!(#vis, #form_ident);
+    }
+}
+
+/// The name that the field enum is declared with, and the path that
+/// generated code uses to refer to it. The field enum is usually named
+/// after the form, like `LoginFormField`. With
+/// `#[structform(module = "...")]`, it is just `Field` inside of that
+/// module instead.
+fn field_enum_names(
+    form_ident: &Ident,
+    module: Option<&Ident>,
+) -> (Ident, proc_macro2::TokenStream) {
+    match module {
+        Some(module) => {
+            let field_enum_name = Ident::new("Field", form_ident.span());
+            let field_enum_ident = quote! { #module::#field_enum_name };
+            (field_enum_name, field_enum_ident)
+        }
+        None => {
+            let field_enum_name = field_enum_ident_transform(form_ident);
+            let field_enum_ident = quote! { #field_enum_name };
+            (field_enum_name, field_enum_ident)
+        }
+    }
+}
+
+fn wrap_field_enum_in_module(
+    vis: &Visibility,
+    module: Option<&Ident>,
+    field_enum: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    match module {
+        Some(module) => quote! {
+            #[allow(missing_docs)]
+            #vis mod #module {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #field_enum
+            }
+        },
+        None => field_enum,
+    }
+}
+
+/// Derives `StructForm` for a form over an enum model, like
+///
+/// ```ignore
+/// #[derive(Clone, StructForm)]
+/// #[structform(model = "PaymentMethod")]
+/// enum PaymentMethodForm {
+///     Card(CardDetailsForm),
+///     Eft(EftDetailsForm),
+///     Cash,
+/// }
+/// ```
+///
+/// Each variant of the form matches the variant of the model with the
+/// same name, and either holds the subform for that variant's data or
+/// has no fields. The field enum has a `Variant` field, which switches
+/// variants when it's set to a variant's name, and a field for each
+/// variant's subform. Switching to another variant starts it with an
+/// empty subform, and setting the inputs of a variant that isn't
+/// selected does nothing.
+///
+/// `Default`, starting on the first variant, is only implemented with
+/// `#[structform(default)]`, so that it doesn't clash with the form's
+/// own. The variants are listed and selected through
+/// `structform::FormVariants`, for the same reason.
+fn impl_enum_form(
+    input: &DeriveInput,
+    data: &DataEnum,
+    container_attrs: &FormContainerAttribute,
+) -> proc_macro2::TokenStream {
+    if container_attrs.submit_with.is_some()
+        || container_attrs.flatten
+        || container_attrs.setters
+        || container_attrs.at_least_one_of.is_some()
+        || container_attrs.map_submit.is_some()
+        || container_attrs.partial_model.is_some()
+        || container_attrs.draft_model.is_some()
+        || container_attrs.rename_variants != VariantCase::Pascal
+        || container_attrs.variant_prefixes != VariantPrefixes::default()
+    {
+        panic!(
+            "StructForm on an enum only supports the model, module and layout annotations on the container"
+        );
+    }
+    let form_ident = &input.ident;
+    let model = &container_attrs.model;
+    let vis = &input.vis;
+    let (field_enum_name, field_enum_ident) =
+        field_enum_names(form_ident, container_attrs.module.as_ref());
+
+    let mut variant_values = Vec::new();
+    let mut variant_labels = Vec::new();
+    let mut variant_patterns = Vec::new();
+    let mut variant_defaults = Vec::new();
+    let mut unit_variants = Vec::new();
+    let mut data_variants = Vec::new();
+    let mut data_variants_type = Vec::new();
+    let mut data_variants_label = Vec::new();
+    for variant in &data.variants {
+        let variant_ident = &variant.ident;
+        let label = variant
+            .attrs
+            .iter()
+            .find(|attr| attr.path.is_ident("structform"))
+            .and_then(|attr| {
+                let meta_list = attr
+                    .parse_args_with(
+                        punctuated::Punctuated::<NestedMeta, token::Comma>::parse_terminated,
+                    )
+                    .expect("Failed to parse the #[structform] attr on a variant");
+                find_str_arg(&meta_list, "label")
+            })
+            .unwrap_or_else(|| pascal_to_sentence_case(&variant_ident.to_string()));
+        match &variant.fields {
+            Fields::Unit => {
+                variant_patterns.push(quote! { #form_ident::#variant_ident });
+                variant_defaults.push(quote! { #form_ident::#variant_ident });
+                unit_variants.push(variant_ident.clone());
+            }
+            Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                variant_patterns.push(quote! { #form_ident::#variant_ident(_) });
+                variant_defaults.push(quote! { #form_ident::#variant_ident(Default::default()) });
+                data_variants.push(variant_ident.clone());
+                data_variants_type.push(fields.unnamed[0].ty.clone());
+                data_variants_label.push(label.clone());
+            }
+            _ => panic!(
+                "StructForm on an enum needs variants with one subform or no fields, but {} has other fields",
+                variant_ident
+            ),
+        }
+        variant_values.push(variant_ident.to_string());
+        variant_labels.push(label);
+    }
+    let first_variant_default = variant_defaults
+        .first()
+        .expect("StructForm can't be derived for an enum without variants");
+    let data_variants_path: Vec<String> = data_variants
+        .iter()
+        .map(|variant_ident| pascal_to_snake_case(&variant_ident.to_string()))
+        .collect();
+    let data_variants_field_enum: Vec<Ident> = data_variants_type
+        .iter()
+        .map(type_to_field_enum_ident)
+        .collect();
+
+    let field_enum = quote! {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #[allow(missing_docs, dead_code, clippy::enum_variant_names)]
+        pub enum #field_enum_name {
+            Variant,
+            #(#data_variants(#data_variants_field_enum),)*
+        }
+    };
+    let field_enum = wrap_field_enum_in_module(vis, container_attrs.module.as_ref(), field_enum);
+
+    // By default, the variant selector goes above the variants' subforms,
+    // which is the order of `FIELDS`.
+    let layout = match &container_attrs.layout {
+        Some(layout) => quote! { #layout() },
+        None => quote! {
+            structform::Layout::one_field_per_row(<Self as structform::FormFields>::FIELDS)
+        },
+    };
+    let default_impl = if container_attrs.default {
+        quote! {
+            #[automatically_derived]
+            impl Default for #form_ident {
+                fn default() -> #form_ident {
+                    #first_variant_default
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+    let first_variant_value = &variant_values[0];
+
+    quote! {
+        #field_enum
+
+        #[automatically_derived]
+        impl #field_enum_ident {
+            /// The field path of the field, like `eft.bank`.
+            #[allow(dead_code)]
+            pub fn to_path(&self) -> String {
+                match self {
+                    #field_enum_ident::Variant => "variant".to_string(),
+                    #(#field_enum_ident::#data_variants(subfield) => {
+                        format!("{}.{}", #data_variants_path, subfield.to_path())
+                    },)*
+                }
+            }
+
+            /// The input field at a field path, like the keys of a
+            /// submitted HTML form.
+            #[allow(dead_code)]
+            pub fn from_path(path: &str) -> Option<#field_enum_ident> {
+                match structform::split_field_path(path) {
+                    Some(("variant", None, "")) => Some(#field_enum_ident::Variant),
+                    #(Some((#data_variants_path, None, rest)) if !rest.is_empty() => {
+                        #data_variants_field_enum::from_path(rest).map(#field_enum_ident::#data_variants)
+                    },)*
+                    _ => None,
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl structform::FormLayout for #form_ident {
+            fn layout() -> structform::Layout {
+                #layout
+            }
+        }
+
+        structform::__impl_form_manifest!(#vis, #form_ident);
+
+        #default_impl
+
+        // The variant selector comes first. Only the selected variant's
+        // subform exists, so the subforms are listed as optional
+        // subforms.
+        #[automatically_derived]
+        impl structform::FormFields for #form_ident {
+            const FIELDS: &'static [structform::FieldMeta] = &[structform::FieldMeta {
+                name: "variant",
+                label: "Variant",
+                sensitive: false,
+                kind: structform::FieldKind::Input,
+                value_type: "String",
+            }, #(structform::FieldMeta {
+                name: #data_variants_path,
+                label: #data_variants_label,
+                sensitive: false,
+                kind: structform::FieldKind::OptionalSubform(<#data_variants_type as structform::FormFields>::FIELDS),
+                value_type: "",
+            }),*];
+        }
+
+        #[automatically_derived]
+        impl structform::FormVariants for #form_ident {
+            const VARIANTS: &'static [(&'static str, &'static str)] = &[#((#variant_values, #variant_labels)),*];
+
+            fn variant(&self) -> &'static str {
+                match self {
+                    #(#variant_patterns => #variant_values,)*
+                }
+            }
+
+            fn select_variant(&mut self, value: &str) -> bool {
+                let value = value.trim();
+                if structform::FormVariants::variant(self) == value {
+                    return true;
+                }
+                match value {
+                    #(#variant_values => {
+                        *self = #variant_defaults;
+                        true
+                    },)*
+                    _ => false,
+                }
+            }
+        }
+
+        #[automatically_derived]
+        #[allow(irrefutable_let_patterns, unreachable_patterns)]
+        impl structform::StructForm<#model> for #form_ident {
+            type Field = #field_enum_ident;
+
+            fn new(model: &#model) -> #form_ident {
+                match model {
+                    #(#model::#data_variants(inner_model) => #form_ident::#data_variants(<#data_variants_type>::new(inner_model)),)*
+                    #(#model::#unit_variants => #form_ident::#unit_variants,)*
+                }
+            }
+
+            fn submit(&mut self) -> Result<#model, structform::ParseError> {
+                match self {
+                    #(#form_ident::#data_variants(inner_form) => inner_form.submit().map(#model::#data_variants),)*
+                    #(#form_ident::#unit_variants => Ok(#model::#unit_variants),)*
+                }
+            }
+
+            fn submit_update(&mut self, model: #model) -> Result<#model, structform::ParseError> {
+                match (&mut *self, model) {
+                    #((#form_ident::#data_variants(inner_form), #model::#data_variants(inner_model)) => {
+                        inner_form.submit_update(inner_model).map(#model::#data_variants)
+                    },)*
+                    _ => self.submit(),
+                }
+            }
+
+            fn parse(&self) -> Result<#model, structform::ParseError> {
+                match self {
+                    #(#form_ident::#data_variants(inner_form) => inner_form.parse().map(#model::#data_variants),)*
+                    #(#form_ident::#unit_variants => Ok(#model::#unit_variants),)*
+                }
+            }
+
+            fn parse_update(&self, model: #model) -> Result<#model, structform::ParseError> {
+                match (self, model) {
+                    #((#form_ident::#data_variants(inner_form), #model::#data_variants(inner_model)) => {
+                        inner_form.parse_update(inner_model).map(#model::#data_variants)
+                    },)*
+                    _ => self.parse(),
+                }
+            }
+
+            fn validate(&self) -> Result<(), structform::ValidationErrors<#field_enum_ident>> {
+                let mut errors = structform::ValidationErrors::new();
+                match self {
+                    #(#form_ident::#data_variants(inner_form) => {
+                        if let Err(inner_errors) = inner_form.validate() {
+                            errors.extend_nested(inner_errors, #field_enum_ident::#data_variants);
+                        }
+                    },)*
+                    _ => {}
+                }
+                errors.into_result()
+            }
+
+            fn field_path(field: &#field_enum_ident) -> String {
+                field.to_path()
+            }
+
+            fn set_input(&mut self, field: #field_enum_ident, value: String) {
+                match field {
+                    #field_enum_ident::Variant => {
+                        structform::FormVariants::select_variant(self, &value);
+                    },
+                    #(#field_enum_ident::#data_variants(subfield) => {
+                        if let #form_ident::#data_variants(inner_form) = self {
+                            inner_form.set_input(subfield, value);
+                        }
+                    },)*
+                }
+            }
+
+            fn set_input_str(&mut self, field: #field_enum_ident, value: &str) {
+                match field {
+                    #field_enum_ident::Variant => {
+                        structform::FormVariants::select_variant(self, value);
+                    },
+                    #(#field_enum_ident::#data_variants(subfield) => {
+                        if let #form_ident::#data_variants(inner_form) = self {
+                            inner_form.set_input_str(subfield, value);
+                        }
+                    },)*
+                }
+            }
+
+            fn apply_events<I>(&mut self, events: I)
+            where
+                I: IntoIterator<Item = (#field_enum_ident, String)>,
+            {
+                for (field, value) in events {
+                    self.set_input(field, value);
+                }
+            }
+
+            fn set_input_at_path(&mut self, path: &str, value: String) -> bool {
+                match structform::split_field_path(path) {
+                    Some(("variant", None, "")) => structform::FormVariants::select_variant(self, &value),
+                    #(Some((#data_variants_path, None, rest)) if !rest.is_empty() => {
+                        if let #form_ident::#data_variants(inner_form) = self {
+                            inner_form.set_input_at_path(rest, value)
+                        } else {
+                            // The variant is only switched once the path is
+                            // known to lead to an input.
+                            let mut inner_form = <#data_variants_type>::default();
+                            let is_set = inner_form.set_input_at_path(rest, value);
+                            if is_set {
+                                *self = #form_ident::#data_variants(inner_form);
+                            }
+                            is_set
+                        }
+                    },)*
+                    _ => false,
+                }
+            }
+
+            fn set_error_at_path(&mut self, path: &str, error: structform::ParseError) -> bool {
+                match (structform::split_field_path(path), self) {
+                    #((Some((#data_variants_path, None, rest)), #form_ident::#data_variants(inner_form)) if !rest.is_empty() => {
+                        inner_form.set_error_at_path(rest, error)
+                    },)*
+                    _ => false,
+                }
+            }
+
+            fn inputs_by_path(&self) -> Vec<(String, String)> {
+                let mut inputs = vec![("variant".to_string(), structform::FormVariants::variant(self).to_string())];
+                match self {
+                    #(#form_ident::#data_variants(inner_form) => {
+                        for (path, input) in inner_form.inputs_by_path() {
+                            inputs.push((format!("{}.{}", #data_variants_path, path), input));
+                        }
+                    },)*
+                    _ => {}
+                }
+                inputs
+            }
+
+            fn values_by_path(&self) -> Vec<(String, String)> {
+                let mut values = vec![("variant".to_string(), structform::FormVariants::variant(self).to_string())];
+                match self {
+                    #(#form_ident::#data_variants(inner_form) => {
+                        for (path, value) in inner_form.values_by_path() {
+                            values.push((format!("{}.{}", #data_variants_path, path), value));
+                        }
+                    },)*
+                    _ => {}
+                }
+                values
+            }
+
+            // The variant selector comes first, followed by the fields of
+            // every variant's subform.
+            fn fields() -> std::vec::IntoIter<structform::FormField<#field_enum_ident>> {
+                let mut fields = vec![structform::FormField {
+                    path: "variant".to_string(),
+                    field: #field_enum_ident::Variant,
+                    meta: &structform::FieldMeta {
+                        name: "variant",
+                        label: "Variant",
+                        sensitive: false,
+                        kind: structform::FieldKind::Input,
+                        value_type: "String",
+                    },
+                }];
+                #(for inner in <#data_variants_type as structform::StructForm<_>>::fields() {
+                    fields.push(structform::FormField {
+                        path: format!("{}.{}", #data_variants_path, inner.path),
+                        field: #field_enum_ident::#data_variants(inner.field),
+                        meta: inner.meta,
+                    });
+                })*
+                fields.into_iter()
+            }
+
+            fn labels_by_path() -> Vec<(String, String)> {
+                let mut labels = vec![("variant".to_string(), "Variant".to_string())];
+                #(for (path, label) in <#data_variants_type>::labels_by_path() {
+                    labels.push((format!("{}.{}", #data_variants_path, path), format!("{} - {}", #data_variants_label, label)));
+                })*
+                labels
+            }
+
+            fn summary(&self) -> Vec<(String, String)> {
+                let variant = structform::FormVariants::variant(self);
+                let variant_label = <Self as structform::FormVariants>::VARIANTS
+                    .iter()
+                    .find(|(value, _)| *value == variant)
+                    .map(|(_, label)| label.to_string())
+                    .unwrap_or_default();
+                let mut summary = vec![("Variant".to_string(), variant_label)];
+                match self {
+                    #(#form_ident::#data_variants(inner_form) => {
+                        for (label, value) in inner_form.summary() {
+                            summary.push((format!("{} - {}", #data_variants_label, label), value));
+                        }
+                    },)*
+                    _ => {}
+                }
+                summary
+            }
+
+            fn is_sensitive_path(&self, path: &str) -> bool {
+                match (structform::split_field_path(path), self) {
+                    #((Some((#data_variants_path, None, rest)), #form_ident::#data_variants(inner_form)) => {
+                        inner_form.is_sensitive_path(rest)
+                    },)*
+                    _ => false,
+                }
+            }
+
+            fn submit_attempted(&self) -> bool {
+                match self {
+                    #(#form_ident::#data_variants(inner_form) => inner_form.submit_attempted(),)*
+                    _ => false,
+                }
+            }
+
+            fn reset(&mut self) {
+                *self = #first_variant_default;
+            }
+
+            fn set_clock(&mut self, clock: &structform::FormClock) {
+                match self {
+                    #(#form_ident::#data_variants(inner_form) => inner_form.set_clock(clock),)*
+                    _ => {}
+                }
+            }
+
+            // An enum form can't remember which variant it was created
+            // with, so switching variants is compared with the first
+            // variant, which is the one that the form starts on and
+            // resets to.
+            fn dirty_fields(&self) -> Vec<#field_enum_ident> {
+                let mut fields = Vec::new();
+                if structform::FormVariants::variant(self) != #first_variant_value {
+                    fields.push(#field_enum_ident::Variant);
+                }
+                match self {
+                    #(#form_ident::#data_variants(inner_form) => fields.extend(
+                        inner_form
+                            .dirty_fields()
+                            .into_iter()
+                            .map(#field_enum_ident::#data_variants),
+                    ),)*
+                    _ => {}
+                }
+                fields
+            }
+
+            fn has_pending_validations(&self) -> bool {
+                match self {
+                    #(#form_ident::#data_variants(inner_form) => inner_form.has_pending_validations(),)*
+                    _ => false,
+                }
+            }
+
+            fn is_empty(&self) -> bool {
+                match self {
+                    #(#form_ident::#data_variants(inner_form) => inner_form.is_empty(),)*
+                    _ => true,
+                }
+            }
+        }
+    }
+}
+
+/// Derives the plumbing for a newtype around a number or a string, like
+/// `struct Port(u16)`, so that it can be used in a form input:
+///
+/// - `Display`, which formats the wrapped value.
+/// - `TryFrom<via>`, which checks that the value is between `min` and
+///   `max`. These default to the `MIN` and `MAX` of `via`. Text
+///   newtypes are only checked if `min` or `max` is given.
+/// - `FromStr`, which parses the wrapped value and then uses `TryFrom`.
+/// - `ParseAndFormat` on `input`, using
+///   `impl_numeric_input_with_stringops` for the `numeric` kind or
+///   `impl_text_input_with_stringops` for the `text` kind.
+///
+/// ```ignore
+/// #[derive(ParseAndFormatNewtype)]
+/// #[parseformat(kind = "numeric", via = "u16", min = "1", input = "FormNumberInput", type_name = "a port")]
+/// struct Port(u16);
+/// ```
+#[proc_macro_derive(ParseAndFormatNewtype, attributes(parseformat))]
+pub fn derive_parse_and_format_newtype(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let newtype_ident = input.ident.clone();
+    match input.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Unnamed(fields),
+            ..
+        }) if fields.unnamed.len() == 1 => {}
+        _ => panic!("ParseAndFormatNewtype can only be derived for structs with one unnamed field, like `struct Port(u16)`"),
+    };
+    let attrs: NewtypeAttribute = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("parseformat"))
+        .map(|attr| {
+            attr.parse_args()
+                .expect("Failed to parse the #[parseformat] attr on the container")
+        })
+        .expect("Require a #[parseformat] attribute on the container");
+    let NewtypeAttribute {
+        kind,
+        via,
+        input: input_ident,
+        type_name,
+        min,
+        max,
+    } = attrs;
+    // Text newtypes only check a range if one is given, since types
+    // like `String` don't have a `MIN` and `MAX`.
+    let has_range = kind == "numeric" || min.is_some() || max.is_some();
+    let min = min.unwrap_or_else(|| parse_quote! { <#via>::MIN });
+    let max = max.unwrap_or_else(|| parse_quote! { <#via>::MAX });
+    let impl_input = match kind.as_str() {
+        "numeric" => quote! {
+            structform::impl_numeric_input_with_stringops!(
+                #input_ident, #type_name, #newtype_ident, #via, #min, #max
+            );
+        },
+        "text" => quote! {
+            structform::impl_text_input_with_stringops!(
+                #input_ident, #type_name, #newtype_ident
+            );
+        },
+        _ => panic!("#[parseformat(kind = \"...\")] must be \"numeric\" or \"text\""),
+    };
+    let try_from_body = if has_range {
+        quote! {
+            if (#min..=#max).contains(&value) {
+                Ok(#newtype_ident(value))
+            } else {
+                Err(format!("Expected {} between {} and {}", #type_name, #min, #max))
+            }
+        }
+    } else {
+        quote! {
+            Ok(#newtype_ident(value))
+        }
+    };
+
+    (quote! {
+        #[automatically_derived]
+        impl std::fmt::Display for #newtype_ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                std::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        #[automatically_derived]
+        impl std::convert::TryFrom<#via> for #newtype_ident {
+            type Error = String;
+            fn try_from(value: #via) -> Result<Self, Self::Error> {
+                #try_from_body
+            }
+        }
+
+        #[automatically_derived]
+        impl std::str::FromStr for #newtype_ident {
+            type Err = String;
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                use std::convert::TryFrom;
+                value
+                    .parse::<#via>()
+                    .map_err(|e| e.to_string())
+                    .and_then(#newtype_ident::try_from)
+            }
+        }
+
+        #impl_input
+    })
+    .into()
+}
+
+/// Derives what a plain enum needs to be picked from a dropdown:
+///
+/// - `Display` and `FromStr`, which use the variant's name, like
+///   `DarkBlue`, as the option's value.
+/// - `OPTIONS`, which lists the value and label of every variant.
+///   Labels default to the variant's name in sentence case, like
+///   "Dark blue", and can be set with `#[formselect(label = "...")]`.
+/// - `ParseAndFormat` on `input`, using
+///   `impl_text_input_with_stringops`.
+///
+/// ```ignore
+/// #[derive(FormSelect)]
+/// #[formselect(input = "FormSelectInput")]
+/// enum Colour {
+///     Red,
+///     DarkBlue,
+/// }
+/// ```
+#[proc_macro_derive(FormSelect, attributes(formselect))]
+pub fn derive_form_select(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let enum_ident = input.ident.clone();
+    let variants = match input.data {
+        Data::Enum(data) => data.variants,
+        _ => panic!("FormSelect can only be derived for enums"),
+    };
+    let input_ident = input
+        .attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("formselect"))
+        .map(|attr| {
+            let meta_list = attr
+                .parse_args_with(
+                    punctuated::Punctuated::<NestedMeta, token::Comma>::parse_terminated,
+                )
+                .expect("Failed to parse the #[formselect] attr on the container");
+            find_str_arg(&meta_list, "input")
+                .map(|input| Ident::new(&input, attr.path.segments[0].ident.span()))
+                .expect("Expected to find an attribute indicating the input type: #[formselect(input = \"???\")]")
+        })
+        .expect("Require a #[formselect] attribute on the container");
+
+    let (variant_idents, (variant_values, variant_labels)): (
+        Vec<Ident>,
+        (Vec<String>, Vec<String>),
+    ) = variants
+        .iter()
+        .map(|variant| {
+            if !matches!(variant.fields, Fields::Unit) {
+                panic!(
+                    "FormSelect can only be derived for enums without fields, but {} has fields",
+                    variant.ident
+                );
+            }
+            let label = variant
+                .attrs
+                .iter()
+                .find(|attr| attr.path.is_ident("formselect"))
+                .and_then(|attr| {
+                    let meta_list = attr
+                        .parse_args_with(
+                            punctuated::Punctuated::<NestedMeta, token::Comma>::parse_terminated,
+                        )
+                        .expect("Failed to parse the #[formselect] attr on a variant");
+                    find_str_arg(&meta_list, "label")
+                })
+                .unwrap_or_else(|| pascal_to_sentence_case(&variant.ident.to_string()));
+            (variant.ident.clone(), (variant.ident.to_string(), label))
+        })
+        .unzip();
+    let type_name = format!("one of {}", variant_labels.join(", "));
+
+    (quote! {
+        #[automatically_derived]
+        impl #enum_ident {
+            /// The value and label of every option, in the order that
+            /// they are declared.
+            #[allow(dead_code)]
+            pub const OPTIONS: &'static [(&'static str, &'static str)] = &[#((#variant_values, #variant_labels)),*];
+        }
+
+        #[automatically_derived]
+        impl std::fmt::Display for #enum_ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    #(#enum_ident::#variant_idents => f.write_str(#variant_values),)*
+                }
+            }
+        }
+
+        #[automatically_derived]
+        impl std::str::FromStr for #enum_ident {
+            type Err = String;
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                match value {
+                    #(#variant_values => Ok(#enum_ident::#variant_idents),)*
+                    _ => Err(format!("Expected {}", #type_name)),
+                }
+            }
+        }
+
+        structform::impl_text_input_with_stringops!(#input_ident, #type_name, #enum_ident);
+    })
+    .into()
+}
+
+/// Generates a companion struct with a `StringOr` for each input, and
+/// implements `FormDraft` with a `submit_draft` function that never
+/// fails. Inputs that don't parse are kept as their raw input, so a
+/// draft can be saved at any time and restored later with
+/// `restore_draft`.
+///
+/// Subforms, optional subforms and lists of subforms are included as
+/// their own drafts, so they need a draft model too. Map subforms and
+/// lazy lists aren't supported, and deriving a draft model for a form
+/// with one is a compile error.
+fn impl_draft_model(
+    vis: &Visibility,
+    form_ident: &Ident,
+    model: &Ident,
+    field_enum_ident: &proc_macro2::TokenStream,
+    draft_model: &Ident,
+    enriched_fields: &[RichField],
+) -> proc_macro2::TokenStream {
+    let mut draft_fields_decl = Vec::new();
+    let mut draft_fields_submit = Vec::new();
+    let mut draft_input_events = Vec::new();
+    let mut draft_subforms_restore = Vec::new();
+    for field in enriched_fields {
+        let field_ident = &field.snake_case_ident;
+        let restore_subform = |subform_type: &Type| {
+            quote! {
+                |inner_draft| {
+                    let mut inner_form = <#subform_type>::default();
+                    structform::FormDraft::restore_draft(&mut inner_form, inner_draft);
+                    inner_form
+                }
+            }
+        };
+        match &field.ty {
+            FieldType::Input { input_type } => {
+                let field_pascal = &field.pascal_case_ident;
+                let value_type = input_value_type(input_type);
+                draft_fields_decl.push(quote! {
+                    pub #field_ident: structform::StringOr<#value_type>
+                });
+                draft_fields_submit.push(quote! {
+                    #field_ident: match &self.#field_ident.value {
+                        Ok(value) => structform::StringOr::Value(value.clone()),
+                        Err(_) => structform::StringOr::Raw(self.#field_ident.input.clone()),
+                    }
+                });
+                draft_input_events.push(quote! {(
+                    #field_enum_ident::#field_pascal,
+                    match &draft.#field_ident {
+                        structform::StringOr::Value(value) => {
+                            <#input_type as structform::ParseAndFormat<_>>::format(value)
+                        }
+                        structform::StringOr::Raw(raw) => raw.clone(),
+                    },
+                )});
+            }
+            FieldType::Subform { subform_type } => {
+                draft_fields_decl.push(quote! {
+                    pub #field_ident: <#subform_type as structform::FormDraft>::Draft
+                });
+                draft_fields_submit.push(quote! {
+                    #field_ident: structform::FormDraft::submit_draft(&self.#field_ident)
+                });
+                draft_subforms_restore.push(quote! {
+                    structform::FormDraft::restore_draft(&mut self.#field_ident, &draft.#field_ident);
+                });
+            }
+            FieldType::OptionalSubform { subform_type } => {
+                let restore_subform = restore_subform(subform_type);
+                draft_fields_decl.push(quote! {
+                    pub #field_ident: Option<<#subform_type as structform::FormDraft>::Draft>
+                });
+                draft_fields_submit.push(quote! {
+                    #field_ident: self.#field_ident.as_ref().map(structform::FormDraft::submit_draft)
+                });
+                draft_subforms_restore.push(quote! {
+                    self.#field_ident = draft.#field_ident.as_ref().map(#restore_subform);
+                });
+            }
+            FieldType::ListSubform {
+                subform_type,
+                lazy: false,
+            } => {
+                let restore_subform = restore_subform(subform_type);
+                draft_fields_decl.push(quote! {
+                    pub #field_ident: Vec<<#subform_type as structform::FormDraft>::Draft>
+                });
+                draft_fields_submit.push(quote! {
+                    #field_ident: self.#field_ident.iter().map(structform::FormDraft::submit_draft).collect()
+                });
+                draft_subforms_restore.push(quote! {
+                    self.#field_ident = draft.#field_ident.iter().map(#restore_subform).collect();
+                });
+            }
+            FieldType::ListSubform { lazy: true, .. } | FieldType::MapSubform { .. } => panic!(
+                "#[structform(draft_model = \"...\")] can't be used on forms with map subforms or lazy lists, but {} is one",
+                field_ident
+            ),
+            _ => continue,
+        }
+    }
+
+    quote! {
+        #[derive(Debug, Clone, Default, PartialEq)]
+        #[allow(missing_docs, dead_code)]
+        #vis struct #draft_model {
+            #(#draft_fields_decl,)*
+        }
+
+        #[automatically_derived]
+        impl structform::FormDraft for #form_ident {
+            type Draft = #draft_model;
+
+            fn submit_draft(&self) -> #draft_model {
+                #draft_model {
+                    #(#draft_fields_submit,)*
+                }
+            }
+
+            fn restore_draft(&mut self, draft: &#draft_model) {
+                <Self as structform::StructForm<#model>>::apply_events(
+                    self,
+                    vec![#(#draft_input_events),*],
+                );
+                #(#draft_subforms_restore)*
+            }
+        }
+    }
+}
+
+/// Generates a companion struct where every model field is optional,
+/// and a `submit_partial` function that only fills in the inputs which
+/// have been changed from their initial value. This is useful for
+/// backends with PATCH semantics.
+///
+/// Changed inputs are submitted like `submit` would, including their
+/// `empty` and `required_if` annotations, and the honeypot, rate limit
+/// and captcha checks apply to the whole form. Rules on the model are
+/// checked against the whole form, whenever all of its inputs parse.
+///
+/// Subforms don't know their model type, so only forms made entirely
+/// of inputs are supported. Deriving a partial model for a form with
+/// subforms is a compile error.
+fn impl_partial_model(
+    vis: &Visibility,
+    form_ident: &Ident,
+    partial_model: &Ident,
+    enriched_fields: &[RichField],
+    submit_guards: &proc_macro2::TokenStream,
+    assembled_model_errors: Option<&proc_macro2::TokenStream>,
+) -> proc_macro2::TokenStream {
+    let mut partial_fields = Vec::new();
+    let mut partial_fields_submit = Vec::new();
+    let captcha_fields_snake_case: Vec<&Ident> = enriched_fields
+        .iter()
+        .filter(|field| matches!(field.ty, FieldType::Captcha))
+        .map(|field| &field.snake_case_ident)
+        .collect();
+    for field in enriched_fields {
+        let field_ident = &field.snake_case_ident;
+        let input_type = match &field.ty {
+            FieldType::Input { input_type } => input_type,
+            FieldType::Subform { .. }
+            | FieldType::OptionalSubform { .. }
+            | FieldType::ListSubform { .. }
+            | FieldType::MapSubform { .. } => panic!(
+                "#[structform(partial_model = \"...\")] can only be used on forms without subforms, but {} is a subform",
+                field_ident
+            ),
+            _ => continue,
+        };
+        let value_type = input_value_type(input_type);
+        let changed = quote! {
+            self.#field_ident.input != self.#field_ident.initial_input
+        };
+        let submit = impl_input_field_submit(field);
+        match &field.attrs.model_fields {
+            Some(model_fields) => {
+                let model_fields_type = match &value_type {
+                    Type::Tuple(tuple) if tuple.elems.len() == model_fields.len() => {
+                        tuple.elems.iter().cloned().collect::<Vec<_>>()
+                    }
+                    _ => panic!(
+                        "#[structform(model_fields = \"...\")] inputs need a tuple value type with one element per model field to be used in a partial model"
+                    ),
+                };
+                partial_fields.push(quote! {
+                    #(pub #model_fields: Option<#model_fields_type>,)*
+                });
+                partial_fields_submit.push(quote! {
+                    if #changed {
+                        let (#(#model_fields,)*) = #submit?;
+                        #(partial.#model_fields = Some(#model_fields);)*
+                    }
+                });
+            }
+            None => {
+                let model_field = field.model_ident();
+                partial_fields.push(quote! {
+                    pub #model_field: Option<#value_type>,
+                });
+                partial_fields_submit.push(quote! {
+                    if #changed {
+                        partial.#model_field = Some(#submit?);
+                    }
+                });
+            }
+        }
+    }
+
+    let check_model = assembled_model_errors.map(|model_errors| {
+        quote! {
+            if let Some(errors) = #model_errors {
+                return Err(errors.into_iter().next().expect("Errors are never empty").1);
+            }
+        }
+    });
+    let submit_partial_body = quote! {
+        #submit_guards
+        #(if self.#captcha_fields_snake_case.trim().is_empty() {
+            return Err(structform::ParseError::CaptchaRequired);
+        })*
+        let mut partial = #partial_model::default();
+        #(#partial_fields_submit)*
+        #check_model
+        #(partial.#captcha_fields_snake_case = Some(structform::CaptchaToken::new(self.#captcha_fields_snake_case.clone()));)*
+        Ok(partial)
+    };
+    // Like `submit`, a new captcha is needed after any failed submit.
+    let submit_partial_body = if captcha_fields_snake_case.is_empty() {
+        submit_partial_body
+    } else {
+        quote! {
+            let result = (|| { #submit_partial_body })();
+            if result.is_err() {
+                #(self.#captcha_fields_snake_case.clear();)*
+            }
+            result
+        }
+    };
+
+    quote! {
+        #[derive(Debug, Clone, Default, PartialEq)]
+        #[allow(missing_docs, dead_code)]
+        #vis struct #partial_model {
+            #(#partial_fields)*
+            #(pub #captcha_fields_snake_case: Option<structform::CaptchaToken>,)*
+        }
+
+        #[automatically_derived]
+        impl #form_ident {
+            /// Submits only the inputs which have been changed from
+            /// their initial value. Unchanged inputs are `None`.
+            pub fn submit_partial(&mut self) -> Result<#partial_model, structform::ParseError> {
+                #submit_partial_body
+            }
+        }
+    }
+}
+
+/// The first generic argument of a type. For inputs, this is the type
+/// of value they hold, like `String` in `FormTextInput<String>`, and
+/// for lazy lists it is the subform type.
+fn input_value_type(input_type: &Type) -> Type {
+    match input_type {
+        Type::Path(TypePath { path, .. }) => match &path.segments.last().unwrap().arguments {
+            PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => {
+                match args.first() {
+                    Some(GenericArgument::Type(value_type)) => value_type.clone(),
+                    _ => panic!("Input's type argument was not a generic type"),
+                }
+            }
+            _ => panic!("Input type did not have an angle bracketed generic argument"),
+        },
+        _ => panic!("Input type was not a TypePath"),
+    }
+}
+
+/// Sets an input field, and updates any slug fields that are derived
+/// from it. Slug fields only follow their source field while they are
+/// empty or still match it, so once the user edits a slug manually it
+/// is left alone.
+///
+/// Fields with a `depends_on` annotation are validated again whenever
+/// they or the field they depend on change, unless `revalidate` is
+/// false. In that case, the caller must revalidate them afterwards.
+fn impl_input_field_set_input(
+    enriched_fields: &[RichField],
+    input_field: &Ident,
+    revalidate: bool,
+) -> proc_macro2::TokenStream {
+    let slug_fields: Vec<Ident> = enriched_fields
+        .iter()
+        .filter(|field| field.attrs.slug_of.as_ref() == Some(input_field))
+        .map(|field| field.snake_case_ident.clone())
+        .collect();
+    let revalidate_fields: Vec<proc_macro2::TokenStream> = enriched_fields
+        .iter()
+        .filter(|field| {
+            revalidate
+                && (field.attrs.depends_on.as_ref() == Some(input_field)
+                    || (&field.snake_case_ident == input_field && field.attrs.depends_on.is_some()))
+        })
+        .map(impl_revalidate_dependent_field)
+        .collect();
+    let empty_policies: Vec<proc_macro2::TokenStream> = enriched_fields
+        .iter()
+        .filter(|field| {
+            field.attrs.empty.is_some()
+                && (&field.snake_case_ident == input_field
+                    || field.attrs.slug_of.as_ref() == Some(input_field))
+        })
+        .map(impl_empty_policy)
+        .collect();
+    if slug_fields.is_empty() && revalidate_fields.is_empty() && empty_policies.is_empty() {
+        return quote! {
+            self.#input_field.set_input(value)
+        };
+    }
+
+    let slug_fields_follow: Vec<Ident> = slug_fields
+        .iter()
+        .map(|slug_field| format_ident!("{}_follows", slug_field))
+        .collect();
+    let update_slug_fields = if slug_fields.is_empty() {
+        quote! {
+            self.#input_field.set_input(value);
+        }
+    } else {
+        quote! {
+            #(let #slug_fields_follow = self.#slug_fields.is_empty()
+                || self.#slug_fields.input == structform::slugify(&self.#input_field.input);)*
+            let slug = structform::slugify(&value);
+            self.#input_field.set_input(value);
+            #(if #slug_fields_follow {
+                let is_edited = self.#slug_fields.is_edited;
+                self.#slug_fields.set_input(slug.clone());
+                self.#slug_fields.is_edited = is_edited;
+            })*
+        }
+    };
+    quote! {{
+        #update_slug_fields
+        #(#revalidate_fields)*
+        #(#empty_policies)*
+    }}
+}
+
+/// Parses a field with a `depends_on` annotation from its current
+/// input, and then checks it against the raw input of the field it
+/// depends on using its `validate_with` function.
+fn impl_revalidate_dependent_field(field: &RichField) -> proc_macro2::TokenStream {
+    let field_ident = &field.snake_case_ident;
+    let input_type = match &field.ty {
+        FieldType::Input { input_type } => input_type,
+        _ => panic!("#[structform(depends_on = \"...\")] can only be used on input fields"),
+    };
+    let depends_on = field.attrs.depends_on.as_ref();
+    let validate_with = field.attrs.validate_with.as_ref().expect(
+        "#[structform(depends_on = \"...\")] also needs #[structform(validate_with = \"...\")]",
+    );
+    let empty_policy = impl_empty_policy(field);
+    quote! {
+        self.#field_ident.value =
+            <#input_type as structform::ParseAndFormat<_>>::parse(&self.#field_ident.input)
+                .and_then(|value| #validate_with(&self.#depends_on.input, value))
+                .and_then(|value| self.#field_ident.check_rules(value));
+        #empty_policy
+    }
+}
+
+/// Replaces the value of an empty input according to its `empty`
+/// annotation, whatever its input type's `parse` does with empty
+/// input. This is empty for fields without the annotation.
+fn impl_empty_policy(field: &RichField) -> proc_macro2::TokenStream {
+    let field_ident = &field.snake_case_ident;
+    let empty_value = match field.attrs.empty {
+        Some(EmptyPolicy::Default) => quote! { Ok(Default::default()) },
+        Some(EmptyPolicy::None) => quote! { Ok(None) },
+        Some(EmptyPolicy::Required) => quote! { Err(structform::ParseError::Required) },
+        None => return quote! {},
+    };
+    quote! {
+        if self.#field_ident.input.trim().is_empty() {
+            self.#field_ident.value = #empty_value;
+        }
+    }
+}
+
+/// What submitting an input field would return, without changing it,
+/// for `parse`. See `impl_input_field_submit`.
+fn impl_input_field_parse(field: &RichField) -> proc_macro2::TokenStream {
+    let field_ident = &field.snake_case_ident;
+    let empty_value = match (&field.attrs.required_if, &field.attrs.empty) {
+        (Some(required_if), _) => quote! {
+            if #required_if(self) {
+                Err(structform::ParseError::Required)
+            } else {
+                Ok(Default::default())
+            }
+        },
+        (None, Some(EmptyPolicy::Default)) => quote! { Ok(Default::default()) },
+        (None, Some(EmptyPolicy::None)) => quote! { Ok(None) },
+        (None, Some(EmptyPolicy::Required)) => quote! { Err(structform::ParseError::Required) },
+        (None, None) => return quote! { self.#field_ident.peek_submit() },
+    };
+    quote! {
+        if self.#field_ident.input.trim().is_empty() {
+            let value: Result<_, structform::ParseError> = #empty_value;
+            value.and_then(|value| self.#field_ident.check_rules(value))
+        } else {
+            self.#field_ident.peek_submit()
+        }
+    }
+}
+
+/// The methods that `submit_update` calls on subforms and lists of
+/// subforms, or the ones that `parse_update` calls instead, since it
+/// can't change the form: the update, the submit for entries without
+/// a model, the iterator, and the `Option` accessor.
+fn update_methods(parse: bool) -> (Ident, Ident, Ident, Ident) {
+    if parse {
+        (
+            format_ident!("parse_update"),
+            format_ident!("parse"),
+            format_ident!("iter"),
+            format_ident!("as_ref"),
+        )
+    } else {
+        (
+            format_ident!("submit_update"),
+            format_ident!("submit"),
+            format_ident!("iter_mut"),
+            format_ident!("as_mut"),
+        )
+    }
+}
+
+/// Submits an input field. Fields with a `required_if` annotation are
+/// only required while their predicate holds. Otherwise, leaving them
+/// empty submits the default value.
+fn impl_input_field_submit(field: &RichField) -> proc_macro2::TokenStream {
+    let field_ident = &field.snake_case_ident;
+    match &field.attrs.required_if {
+        Some(required_if) => quote! {{
+            if self.#field_ident.input.trim().is_empty() {
+                self.#field_ident.value = if #required_if(&*self) {
+                    Err(structform::ParseError::Required)
+                } else {
+                    Ok(Default::default())
+                };
+            }
+            self.#field_ident.submit()
+        }},
+        None => {
+            let empty_policy = impl_empty_policy(field);
+            quote! {{
+                #empty_policy
+                self.#field_ident.submit()
+            }}
+        }
+    }
+}
+
+/// Adds a field's errors to the `ValidationErrors` in `validate`. Like
+/// `submit`, empty inputs with a `required_if` annotation are only
+/// required while their predicate holds.
+fn impl_validate_entry(
+    field: &RichField,
+    field_enum_ident: &proc_macro2::TokenStream,
+) -> Option<proc_macro2::TokenStream> {
+    let field_ident = &field.snake_case_ident;
+    let field_pascal = &field.pascal_case_ident;
+    match &field.ty {
+        FieldType::Input { .. } => {
+            let input_error = quote! {
+                if let Err(error) = &self.#field_ident.value {
+                    errors.push(#field_enum_ident::#field_pascal, error.clone());
+                }
+            };
+            Some(match &field.attrs.required_if {
+                Some(required_if) => quote! {
+                    if self.#field_ident.input.trim().is_empty() {
+                        if #required_if(self) {
+                            errors.push(#field_enum_ident::#field_pascal, structform::ParseError::Required);
+                        }
+                    } else #input_error
+                },
+                None => input_error,
+            })
+        }
+        FieldType::OptionalSubform { .. } => Some(quote! {
+            if let Some(Err(inner_errors)) = self.#field_ident.as_ref().map(|inner_form| inner_form.validate()) {
+                errors.extend_nested(inner_errors, #field_enum_ident::#field_pascal);
+            }
+        }),
+        FieldType::ListSubform { lazy, .. } => {
+            let entries = if *lazy {
+                quote! { self.#field_ident.materialized() }
+            } else {
+                quote! { self.#field_ident.iter().enumerate() }
+            };
+            Some(quote! {
+                for (i, inner_form) in #entries {
+                    if let Err(inner_errors) = inner_form.validate() {
+                        errors.extend_nested(inner_errors, |subfield| #field_enum_ident::#field_pascal(i, subfield));
+                    }
+                }
+            })
+        }
+        FieldType::MapSubform { .. } => Some(quote! {
+            for (key, inner_form) in &self.#field_ident {
+                if let Err(inner_errors) = inner_form.validate() {
+                    errors.extend_nested(inner_errors, |subfield| #field_enum_ident::#field_pascal(key.clone(), subfield));
+                }
+            }
+        }),
+        FieldType::Subform { .. } => Some(quote! {
+            if let Err(inner_errors) = self.#field_ident.validate() {
+                errors.extend_nested(inner_errors, #field_enum_ident::#field_pascal);
+            }
+        }),
+        _ => None,
+    }
+}
+
+/// Adds a field's entries to the `summary`, in the order that the
+/// fields are declared.
+fn impl_summary_entry(field: &RichField) -> Option<proc_macro2::TokenStream> {
+    let field_ident = &field.snake_case_ident;
+    let label = field.label();
+    match &field.ty {
+        FieldType::Input { input_type } => Some(quote! {
+            summary.push((
+                #label.to_string(),
+                self.#field_ident
+                    .value
+                    .as_ref()
+                    .map(|value| <#input_type as structform::ParseAndFormat<_>>::format(value))
+                    .unwrap_or_else(|_| self.#field_ident.input.clone()),
+            ));
+        }),
+        FieldType::OptionalSubform { .. } => Some(quote! {
+            if let Some(inner_form) = &self.#field_ident {
+                for (label, value) in inner_form.summary() {
+                    summary.push((format!("{} - {}", #label, label), value));
+                }
+            }
+        }),
+        FieldType::ListSubform { .. } => Some(quote! {
+            for (i, inner_form) in self.#field_ident.iter().enumerate() {
+                for (label, value) in inner_form.summary() {
+                    summary.push((format!("{} {} - {}", #label, i + 1, label), value));
+                }
+            }
+        }),
+        FieldType::MapSubform { .. } => Some(quote! {
+            for (key, inner_form) in &self.#field_ident {
+                for (label, value) in inner_form.summary() {
+                    summary.push((format!("{} {} - {}", #label, key, label), value));
+                }
+            }
+        }),
+        FieldType::Subform { .. } => Some(quote! {
+            for (label, value) in self.#field_ident.summary() {
+                summary.push((format!("{} - {}", #label, label), value));
+            }
+        }),
+        _ => None,
+    }
+}
+
+/// The rules that a field checks on the assembled model, for rules
+/// that involve more than one input. Rules on a whole list, like
+/// `sum_of`, are keyed by `list_variant`, the variant that adds to the
+/// list, since it has the list's field path.
+fn impl_model_validation(
+    field: &RichField,
+    enriched_fields: &[RichField],
+    field_enum_ident: &proc_macro2::TokenStream,
+    list_variant: Ident,
+) -> Option<proc_macro2::TokenStream> {
+    let field_ident = &field.model_ident();
+    let field_name = field.snake_case_ident.to_string();
+    let field_pascal = &field.pascal_case_ident;
+    let mut validations = Vec::new();
+
+    match (&field.attrs.sum_of, &field.attrs.sum_to) {
+        (Some(sum_of), Some(sum_to)) => {
+            if !matches!(field.ty, FieldType::ListSubform { .. }) {
+                panic!("#[structform(sum_of = \"...\")] can only be used on lists of subforms");
+            }
+            validations.push(quote! {
+                let values = model.#field_ident.iter().map(|inner_model| &inner_model.#sum_of);
+                if let Err(error) = structform::check_total(#field_name, values, #sum_to) {
+                    errors.push(#field_enum_ident::#list_variant, error);
+                }
+            });
+        }
+        (None, None) => {}
+        _ => panic!(
+            "#[structform(sum_of = \"...\")] and #[structform(sum_to = \"...\")] must be used together"
+        ),
+    }
+
+    if let Some(unique_by) = &field.attrs.unique_by {
+        if !matches!(field.ty, FieldType::ListSubform { .. }) {
+            panic!("#[structform(unique_by = \"...\")] can only be used on lists of subforms");
+        }
+        let unique_by_name = unique_by.to_string();
+        // Every entry that clashes gets an error on its own input, which
+        // names the first other entry that it clashes with.
+        validations.push(quote! {
+            for (index, inner_model) in model.#field_ident.iter().enumerate() {
+                let other_index = model.#field_ident
+                    .iter()
+                    .enumerate()
+                    .position(|(other_index, other)| {
+                        other_index != index && other.#unique_by == inner_model.#unique_by
+                    });
+                if let Some(other_index) = other_index {
+                    let entry_field = #field_enum_ident::from_path(&format!("{}[{}].{}", #field_name, index, #unique_by_name))
+                        .unwrap_or(#field_enum_ident::#list_variant);
+                    errors.push(entry_field, structform::ParseError::DuplicateEntry {
+                        field: #field_name.to_string(),
+                        subfield: #unique_by_name.to_string(),
+                        first_index: index.min(other_index),
+                        index: index.max(other_index),
+                    });
+                }
+            }
+        });
+    }
+
+    if let Some(at_least) = &field.attrs.at_least {
+        let at_least_name = at_least.to_string();
+        let at_least = enriched_fields
+            .iter()
+            .find(|other| &other.snake_case_ident == at_least)
+            .map(RichField::model_ident)
+            .unwrap_or_else(|| at_least.clone());
+        validations.push(quote! {
+            if model.#field_ident < model.#at_least {
+                errors.push(#field_enum_ident::#field_pascal, structform::ParseError::OutOfOrder {
+                    field: #field_name.to_string(),
+                    at_least: #at_least_name.to_string(),
+                });
+            }
+        });
+    }
+
+    if validations.is_empty() {
+        None
+    } else {
+        Some(quote! {{ #(#validations)* }})
+    }
+}
+
+/// The checks for `#[structform(honeypot)]` and
+/// `#[structform(rate_limit = ...)]` fields that run before a form is
+/// submitted. `rate_limit_check` is the method called on each rate
+/// limit, so that `parse` can peek at it rather than recording an
+/// attempt.
+fn impl_guards(enriched_fields: &[RichField], rate_limit_check: Ident) -> proc_macro2::TokenStream {
+    let honeypot_fields_snake_case: Vec<&Ident> = enriched_fields
+        .iter()
+        .filter(|field| matches!(field.ty, FieldType::Honeypot))
+        .map(|field| &field.snake_case_ident)
+        .collect();
+    let (rate_limit_fields_snake_case, rate_limit_fields_interval): (Vec<&Ident>, Vec<u64>) =
+        enriched_fields
+            .iter()
+            .filter_map(|field| match field.ty {
+                FieldType::RateLimit { interval_ms } => {
+                    Some((&field.snake_case_ident, interval_ms))
+                }
+                _ => None,
+            })
+            .unzip();
+    quote! {
+        #(if !self.#honeypot_fields_snake_case.is_empty() {
+            return Err(structform::ParseError::Spam);
+        })*
+        #(self.#rate_limit_fields_snake_case.#rate_limit_check(std::time::Duration::from_millis(#rate_limit_fields_interval))?;)*
+    }
+}
+
+/// The rules that are checked on the assembled model by
+/// `validate_model`, from `#[structform(sum_of = ...)]`,
+/// `#[structform(unique_by = ...)]` and friends on the fields, and
+/// `#[structform(at_least_one_of = "...")]` on the container.
+fn impl_model_validations(
+    enriched_fields: &[RichField],
+    at_least_one_of: Option<&Vec<Ident>>,
+    field_enum_ident: &proc_macro2::TokenStream,
+    rename_variants: &VariantCase,
+    variant_prefixes: &VariantPrefixes,
+) -> Vec<proc_macro2::TokenStream> {
+    let mut model_validations: Vec<proc_macro2::TokenStream> = enriched_fields
+        .iter()
+        .filter_map(|field| {
+            impl_model_validation(
+                field,
+                enriched_fields,
+                field_enum_ident,
+                rename_variants.ident(&variant_prefixes.add, &field.snake_case_ident),
+            )
+        })
+        .collect();
+    if let Some(group) = at_least_one_of {
+        for group_field in group {
+            let is_input = enriched_fields.iter().any(|field| {
+                &field.snake_case_ident == group_field
+                    && matches!(field.ty, FieldType::Input { .. })
+            });
+            if !is_input {
+                panic!(
+                    "#[structform(at_least_one_of = \"...\")] must only refer to input fields on the same form, but {} is not one",
+                    group_field
+                );
+            }
+        }
+        let group_names: Vec<String> = group.iter().map(|field| field.to_string()).collect();
+        let (group_pascal_case, group_model_fields): (Vec<&Ident>, Vec<Ident>) = group
+            .iter()
+            .filter_map(|group_field| {
+                enriched_fields
+                    .iter()
+                    .find(|field| &field.snake_case_ident == group_field)
+                    .map(|field| (&field.pascal_case_ident, field.model_ident()))
+            })
+            .unzip();
+        // Every field in the group gets the error, since filling in any
+        // of them would fix it.
+        model_validations.push(quote! {
+            if true #(&& !structform::FilledIn::is_filled_in(&model.#group_model_fields))* {
+                let error = structform::ParseError::AtLeastOneRequired {
+                    fields: vec![#(#group_names.to_string()),*],
+                };
+                #(errors.push(#field_enum_ident::#group_pascal_case, error.clone());)*
+            }
+        });
+    }
+    model_validations
+}
+
+/// The code around every change to the inputs that records it in the
+/// form's `#[structform(change_log)]` fields, if it has any. The
+/// first part runs before the change, and the second after it.
+fn impl_record_changes(
+    change_log_fields_snake_case: &[Ident],
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    if change_log_fields_snake_case.is_empty() {
+        (quote! {}, quote! {})
+    } else {
+        (
+            quote! {
+                let before = self.inputs_by_path();
+            },
+            quote! {
+                let after = self.inputs_by_path();
+                #({
+                    let mut change_log = std::mem::take(&mut self.#change_log_fields_snake_case);
+                    change_log.record(&before, &after, |path| self.is_sensitive_path(path));
+                    self.#change_log_fields_snake_case = change_log;
+                })*
+            },
+        )
+    }
+}
+
+/// `labels_by_path`, which pairs the path of every input with its
+/// label. Inputs in subforms are labelled with the subform's label
+/// in front of their own.
+fn impl_labels_by_path(enriched_fields: &[RichField]) -> proc_macro2::TokenStream {
+    let label_entries: Vec<proc_macro2::TokenStream> = enriched_fields
+        .iter()
+        .filter_map(|field| {
+            let path = field.snake_case_ident.to_string();
+            let label = field.label();
+            match &field.ty {
+                FieldType::Input { .. } => Some(quote! {
+                    labels.push((#path.to_string(), #label.to_string()));
+                }),
+                FieldType::Subform { subform_type } => Some(quote! {
+                    for (path, label) in <#subform_type>::labels_by_path() {
+                        labels.push((format!("{}.{}", #path, path), format!("{} - {}", #label, label)));
+                    }
+                }),
+                _ => None,
+            }
+        })
+        .collect();
+    quote! {
+        fn labels_by_path() -> Vec<(String, String)> {
+            let mut labels = Vec::new();
+            #(#label_entries)*
+            labels
+        }
+    }
+}
+
+/// `is_sensitive_path`, which is true for `#[structform(sensitive)]`
+/// fields, and asks subforms about paths inside of them.
+fn impl_is_sensitive_path(enriched_fields: &[RichField]) -> proc_macro2::TokenStream {
+    let sensitive_fields_path: Vec<String> = enriched_fields
+        .iter()
+        .filter(|field| field.attrs.sensitive)
+        .map(|field| field.snake_case_ident.to_string())
+        .collect();
+    let nested_fields_lookup: Vec<proc_macro2::TokenStream> = enriched_fields
+        .iter()
+        .filter(|field| !field.attrs.sensitive)
+        .filter_map(|field| {
+            let field_ident = &field.snake_case_ident;
+            let path = field_ident.to_string();
+            let lookup = match &field.ty {
+                FieldType::OptionalSubform { .. } => quote! {
+                    Some((#path, None, rest)) => self.#field_ident
+                        .as_ref()
+                        .map(|inner_form| inner_form.is_sensitive_path(rest))
+                        .unwrap_or(false),
+                },
+                FieldType::ListSubform { .. } => quote! {
+                    Some((#path, Some(i), rest)) => self.#field_ident
+                        .get(i)
+                        .map(|inner_form| inner_form.is_sensitive_path(rest))
+                        .unwrap_or(false),
+                },
+                FieldType::MapSubform { key_type, .. } => quote! {
+                    Some((#path, None, rest)) => structform::split_map_key::<#key_type>(rest)
+                        .and_then(|(key, rest)| {
+                            self.#field_ident
+                                .get(&key)
+                                .map(|inner_form| inner_form.is_sensitive_path(rest))
+                        })
+                        .unwrap_or(false),
+                },
+                FieldType::Subform { .. } => quote! {
+                    Some((#path, None, rest)) => self.#field_ident.is_sensitive_path(rest),
+                },
+                _ => return None,
+            };
+            Some(lookup)
+        })
+        .collect();
+    quote! {
+        fn is_sensitive_path(&self, path: &str) -> bool {
+            match structform::split_field_path(path) {
+                #(Some((#sensitive_fields_path, _, _)) => true,)*
+                #(#nested_fields_lookup)*
+                _ => false,
+            }
+        }
+    }
+}
+
+/// `dirty_fields`, which lists the inputs whose input has changed
+/// since the form was created, including those in subforms.
+fn impl_dirty_fields(
+    enriched_fields: &[RichField],
+    field_enum_ident: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let dirty_field_entries: Vec<proc_macro2::TokenStream> = enriched_fields
+        .iter()
+        .filter_map(|field| {
+            let field_ident = &field.snake_case_ident;
+            let field_pascal = &field.pascal_case_ident;
+            match &field.ty {
+                FieldType::Input { .. } => Some(quote! {
+                    if self.#field_ident.input != self.#field_ident.initial_input {
+                        fields.push(#field_enum_ident::#field_pascal);
+                    }
+                }),
+                FieldType::OptionalSubform { .. } => Some(quote! {
+                    if let Some(inner_form) = &self.#field_ident {
+                        fields.extend(inner_form.dirty_fields().into_iter().map(#field_enum_ident::#field_pascal));
+                    }
+                }),
+                FieldType::ListSubform { .. } => Some(quote! {
+                    for (i, inner_form) in self.#field_ident.iter().enumerate() {
+                        fields.extend(
+                            inner_form
+                                .dirty_fields()
+                                .into_iter()
+                                .map(|subfield| #field_enum_ident::#field_pascal(i, subfield)),
+                        );
+                    }
+                }),
+                FieldType::MapSubform { .. } => Some(quote! {
+                    for (key, inner_form) in &self.#field_ident {
+                        fields.extend(
+                            inner_form
+                                .dirty_fields()
+                                .into_iter()
+                                .map(|subfield| #field_enum_ident::#field_pascal(key.clone(), subfield)),
+                        );
+                    }
+                }),
+                FieldType::Subform { .. } => Some(quote! {
+                    fields.extend(self.#field_ident.dirty_fields().into_iter().map(#field_enum_ident::#field_pascal));
+                }),
+                _ => None,
+            }
+        })
+        .collect();
+    quote! {
+        fn dirty_fields(&self) -> Vec<#field_enum_ident> {
+            let mut fields = Vec::new();
+            #(#dirty_field_entries)*
+            fields
+        }
+    }
+}
+
+/// The entries of `FormFields::FIELDS`, one for every input and
+/// subform, in the order that they're declared.
+fn impl_field_meta_entries(enriched_fields: &[RichField]) -> Vec<proc_macro2::TokenStream> {
+    enriched_fields
+        .iter()
+        .filter_map(|field| {
+            let value_type = match &field.ty {
+                FieldType::Input { input_type } => {
+                    let value_type = input_value_type(input_type);
+                    quote!(#value_type).to_string().replace(' ', "")
+                }
+                _ => String::new(),
+            };
+            let kind = match &field.ty {
+                FieldType::Input { .. } => quote! { structform::FieldKind::Input },
+                FieldType::Subform { subform_type } => quote! {
+                    structform::FieldKind::Subform(<#subform_type as structform::FormFields>::FIELDS)
+                },
+                FieldType::OptionalSubform { subform_type } => quote! {
+                    structform::FieldKind::OptionalSubform(<#subform_type as structform::FormFields>::FIELDS)
+                },
+                FieldType::ListSubform { subform_type, .. } => quote! {
+                    structform::FieldKind::ListSubform(<#subform_type as structform::FormFields>::FIELDS)
+                },
+                FieldType::MapSubform { subform_type, .. } => quote! {
+                    structform::FieldKind::MapSubform(<#subform_type as structform::FormFields>::FIELDS)
+                },
+                _ => return None,
+            };
+            let name = field.snake_case_ident.to_string();
+            let label = field.label();
+            let sensitive = field.attrs.sensitive;
+            Some(quote! {
+                structform::FieldMeta {
+                    name: #name,
+                    label: #label,
+                    sensitive: #sensitive,
+                    kind: #kind,
+                    value_type: #value_type,
+                }
+            })
+        })
+        .collect()
+}
+
+/// `fields`, which lists every input with its path and field enum
+/// variant. Optional subforms and lists also get the variant that
+/// toggles or adds to them.
+fn impl_fields_fn(
+    enriched_fields: &[RichField],
+    field_enum_ident: &proc_macro2::TokenStream,
+    rename_variants: &VariantCase,
+    variant_prefixes: &VariantPrefixes,
+) -> proc_macro2::TokenStream {
+    let form_field_entries: Vec<proc_macro2::TokenStream> = enriched_fields
+        .iter()
+        .filter(|field| {
+            matches!(
+                field.ty,
+                FieldType::Input { .. }
+                    | FieldType::Subform { .. }
+                    | FieldType::OptionalSubform { .. }
+                    | FieldType::ListSubform { .. }
+                    | FieldType::MapSubform { .. }
+            )
+        })
+        .enumerate()
+        .map(|(i, field)| {
+            let path = field.snake_case_ident.to_string();
+            let pascal = &field.pascal_case_ident;
+            let nested = |subform_type: &Type| {
+                quote! {
+                    for inner in <#subform_type as structform::StructForm<_>>::fields() {
+                        fields.push(structform::FormField {
+                            path: format!("{}.{}", #path, inner.path),
+                            field: #field_enum_ident::#pascal(inner.field),
+                            meta: inner.meta,
+                        });
+                    }
+                }
+            };
+            match &field.ty {
+                FieldType::Input { .. } => quote! {
+                    fields.push(structform::FormField {
+                        path: #path.to_string(),
+                        field: #field_enum_ident::#pascal,
+                        meta: &<Self as structform::FormFields>::FIELDS[#i],
+                    });
+                },
+                FieldType::Subform { subform_type } => nested(subform_type),
+                FieldType::OptionalSubform { subform_type } => {
+                    let toggle =
+                        rename_variants.ident(&variant_prefixes.toggle, &field.snake_case_ident);
+                    let nested = nested(subform_type);
+                    quote! {
+                        fields.push(structform::FormField {
+                            path: #path.to_string(),
+                            field: #field_enum_ident::#toggle,
+                            meta: &<Self as structform::FormFields>::FIELDS[#i],
+                        });
+                        #nested
                     }
-                },)*
-
-                #(#field_enum_ident::#subform_fields_pascal_case(subfield) => {
-                    self.#subform_fields_snake_case.set_input(subfield, value);
-                },)*
+                }
+                FieldType::ListSubform { .. } => {
+                    let add = rename_variants.ident(&variant_prefixes.add, &field.snake_case_ident);
+                    quote! {
+                        fields.push(structform::FormField {
+                            path: #path.to_string(),
+                            field: #field_enum_ident::#add,
+                            meta: &<Self as structform::FormFields>::FIELDS[#i],
+                        });
+                    }
+                }
+                // Map entries can only be added with a key, so there is
+                // no field that stands for the map as a whole.
+                FieldType::MapSubform { .. } => quote! {},
+                _ => unreachable!(),
             }
+        })
+        .collect();
+    quote! {
+        fn fields() -> std::vec::IntoIter<structform::FormField<#field_enum_ident>> {
+            let mut fields = Vec::new();
+            #(#form_field_entries)*
+            fields.into_iter()
         }
-    };
+    }
+}
 
-    let impl_submit_attempted = quote! {
-        fn submit_attempted(&self) -> bool {
-            false #(|| self.#submit_attempted_fields_snake_case)*
-        }
-    };
+/// The typed `set_<field>` methods from `#[structform(setters)]`.
+/// Setters are inherent methods, so they are only generated when asked
+/// for, since they could clash with the form's own methods. Setters
+/// that would have the same name as a method of StructForm, like
+/// `set_input` for a field named `input`, would hide it, so those
+/// fields don't get one.
+fn impl_typed_setters(
+    vis: &Visibility,
+    model: &Ident,
+    field_enum_ident: &proc_macro2::TokenStream,
+    enriched_fields: &[RichField],
+) -> Vec<proc_macro2::TokenStream> {
+    enriched_fields
+        .iter()
+        .filter_map(|field| {
+            let input_type = match &field.ty {
+                FieldType::Input { input_type } => input_type,
+                _ => return None,
+            };
+            let field_ident = &field.snake_case_ident;
+            let field_pascal = &field.pascal_case_ident;
+            let setter = format_ident!("set_{}", field_ident);
+            if ["input", "input_str", "input_at_path", "error_at_path", "checked"]
+                .contains(&field_ident.to_string().as_str())
+            {
+                return None;
+            }
+            let value_type = input_value_type(input_type);
+            let doc = format!(
+                "Sets `{}` from a typed value, for filling in the form from code. The input shows the formatted value, but the value itself is kept as it is, rather than parsed back from the input.",
+                field_ident
+            );
+            // A field that depends on another keeps the error from its
+            // `validate_with` function.
+            let set_value = if field.attrs.depends_on.is_some() {
+                quote! {
+                    if self.#field_ident.value.is_ok() {
+                        self.#field_ident.value = self.#field_ident.check_rules(value);
+                    }
+                }
+            } else {
+                quote! {
+                    self.#field_ident.value = self.#field_ident.check_rules(value);
+                }
+            };
+            Some(quote! {
+                #[doc = #doc]
+                #[allow(dead_code)]
+                #vis fn #setter(&mut self, value: #value_type) {
+                    let input = <#input_type as structform::ParseAndFormat<#value_type>>::format(&value);
+                    <Self as structform::StructForm<#model>>::set_input(self, #field_enum_ident::#field_pascal, input);
+                    #set_value
+                }
+            })
+        })
+        .collect()
+}
 
-    let impl_is_empty = quote! {
-        fn is_empty(&self) -> bool {
-            true
-            #(&& self.#input_fields_snake_case.is_empty())*
-            #(&& self.#option_form_fields_snake_case.as_ref().map(|inner_form| inner_form.is_empty()).unwrap_or(true))*
-            #(&& self.#list_form_fields_snake_case.iter().all(|inner_form| inner_form.is_empty()))*
-            #(&& self.#subform_fields_snake_case.is_empty())*
+/// Splits a name like `DarkBlue` into words, like "Dark blue".
+fn pascal_to_sentence_case(pascal: &str) -> String {
+    let mut sentence = String::new();
+    for (i, c) in pascal.chars().enumerate() {
+        if i == 0 {
+            sentence.push(c);
+        } else if c.is_uppercase() {
+            sentence.push(' ');
+            sentence.extend(c.to_lowercase());
+        } else {
+            sentence.push(c);
         }
-    };
-
-    let impl_form = quote! {
-        impl structform::StructForm<#model> for #form_ident {
-            type Field = #field_enum_ident;
+    }
+    sentence
+}
 
-            #impl_new
-            #impl_submit
-            #impl_submit_update
-            #impl_set_input
-            #impl_submit_attempted
-            #impl_is_empty
+fn pascal_to_snake_case(pascal: &str) -> String {
+    let mut snake = String::new();
+    for (i, c) in pascal.chars().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            snake.push('_');
         }
-    };
-
-    (quote! {
-        #field_enum
-
-        #impl_form
-    })
-    .into()
+        snake.extend(c.to_lowercase());
+    }
+    snake
 }
 
-fn snake_to_pascal_case(snake: &str) -> String {
-    snake
-        .split('_')
-        .map(|s| {
-            let (head, tail) = s.split_at(1);
-            format!("{}{}", head.to_uppercase(), tail)
-        })
-        .collect::<Vec<_>>()
-        .join("")
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
 }
 
 fn is_option(field: &Field) -> bool {
@@ -307,6 +3538,17 @@ fn is_vec(field: &Field) -> bool {
     }
 }
 
+/// Maps are recognised by their last path segment, so that they can be
+/// written as `HashMap` or `std::collections::HashMap`.
+fn is_map(field: &Field) -> bool {
+    if let Type::Path(TypePath { path, .. }) = &field.ty {
+        let path_ident = &path.segments.last().unwrap().ident;
+        path_ident == "HashMap" || path_ident == "BTreeMap"
+    } else {
+        false
+    }
+}
+
 fn parse_option_type_generic_type(option_type: &Type) -> Type {
     match option_type {
         Type::Path(TypePath { path, .. }) => match &path.segments.first().unwrap().arguments {
@@ -337,6 +3579,25 @@ fn parse_vec_type_generic_type(vec_type: &Type) -> Type {
     }
 }
 
+fn parse_map_type_generic_types(map_type: &Type) -> (Type, Type) {
+    match map_type {
+        Type::Path(TypePath { path, .. }) => match &path.segments.last().unwrap().arguments {
+            PathArguments::AngleBracketed(AngleBracketedGenericArguments { args, .. }) => {
+                let mut generic_types = args.iter().map(|arg| match arg {
+                    GenericArgument::Type(generic_type) => generic_type.clone(),
+                    _ => panic!("Map's type arguments were not generic types"),
+                });
+                match (generic_types.next(), generic_types.next()) {
+                    (Some(key_type), Some(subform_type)) => (key_type, subform_type),
+                    _ => panic!("Map type did not have a key and a value type argument"),
+                }
+            }
+            _ => panic!("Map type did not have angle bracketed generic arguments"),
+        },
+        _ => panic!("Map type did not have generic arguments"),
+    }
+}
+
 fn type_to_field_enum_ident(ty: &Type) -> Ident {
     match ty {
         Type::Path(TypePath { path, .. }) => {
@@ -350,57 +3611,310 @@ fn field_enum_ident_transform(ident: &Ident) -> Ident {
     Ident::new(&format!("{}Field", ident), ident.span())
 }
 
+fn find_str_arg(
+    meta_list: &punctuated::Punctuated<NestedMeta, token::Comma>,
+    name: &str,
+) -> Option<String> {
+    meta_list
+        .iter()
+        .filter_map(|arg| match arg {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(lit),
+                ..
+            })) if path.is_ident(name) => Some(lit.value()),
+            _ => None,
+        })
+        .next()
+}
+
 struct FormContainerAttribute {
     model: Ident,
     submit_with: Option<Ident>,
     flatten: bool,
+    /// Whether to generate a typed `set_<field>` method for every
+    /// input, from `#[structform(setters)]`.
+    setters: bool,
+    /// Whether to implement `Default` for an enum form, starting on its
+    /// first variant, from `#[structform(default)]`.
+    default: bool,
+    at_least_one_of: Option<Vec<Ident>>,
+    map_submit: Option<Path>,
+    partial_model: Option<Ident>,
+    draft_model: Option<Ident>,
+    module: Option<Ident>,
+    layout: Option<Path>,
+    skip: Vec<SkipModelField>,
+    rename_variants: VariantCase,
+    variant_prefixes: VariantPrefixes,
+}
+
+/// The prefixes of the field enum's variants that act on subforms,
+/// from `#[structform(add_prefix = "...")]` and friends on the
+/// container. They're given in snake case, and default to the names
+/// that the variants have always had, like `AddAddresses`.
+#[derive(PartialEq, Eq)]
+struct VariantPrefixes {
+    add: String,
+    remove: String,
+    move_: String,
+    toggle: String,
+}
+
+impl Default for VariantPrefixes {
+    fn default() -> Self {
+        VariantPrefixes {
+            add: "add".to_string(),
+            remove: "remove".to_string(),
+            move_: "move".to_string(),
+            toggle: "toggle".to_string(),
+        }
+    }
+}
+
+impl VariantPrefixes {
+    fn from_meta_list(
+        meta_list: &punctuated::Punctuated<NestedMeta, token::Comma>,
+    ) -> VariantPrefixes {
+        let prefix = |name: &str, default: String| match find_str_arg(meta_list, name) {
+            Some(prefix) => {
+                let is_snake_case = prefix.split('_').all(|word| {
+                    !word.is_empty()
+                        && word
+                            .chars()
+                            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+                }) && prefix.starts_with(|c: char| c.is_ascii_lowercase());
+                if !is_snake_case {
+                    panic!(
+                        "#[structform({} = \"{}\")] must be a non-empty prefix in snake_case",
+                        name, prefix
+                    );
+                }
+                prefix
+            }
+            None => default,
+        };
+        let defaults = VariantPrefixes::default();
+        VariantPrefixes {
+            add: prefix("add_prefix", defaults.add),
+            remove: prefix("remove_prefix", defaults.remove),
+            move_: prefix("move_prefix", defaults.move_),
+            toggle: prefix("toggle_prefix", defaults.toggle),
+        }
+    }
+}
+
+/// Panics if two fields would get the same variant, like a list named
+/// `addresses` next to an input named `add_addresses`, with a message
+/// that points to the prefix annotations rather than leaving a
+/// duplicate variant error on the generated enum.
+fn check_unique_variants<'a>(form_ident: &Ident, variants: impl Iterator<Item = &'a Ident>) {
+    let mut seen = std::collections::HashSet::new();
+    for variant in variants {
+        if !seen.insert(variant.to_string()) {
+            panic!(
+                "{} has more than one field with the variant {}. Rename one of the fields, or change the prefix of the generated variants with add_prefix, remove_prefix, move_prefix or toggle_prefix.",
+                form_ident, variant
+            );
+        }
+    }
+}
+
+/// The naming convention of the field enum's variants, from
+/// `#[structform(rename_variants = "...")]` on the container. The
+/// names are the same as serde's `rename_all`.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum VariantCase {
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+}
+
+impl VariantCase {
+    fn parse(case: &str) -> VariantCase {
+        match case {
+            "PascalCase" => VariantCase::Pascal,
+            "camelCase" => VariantCase::Camel,
+            "snake_case" => VariantCase::Snake,
+            "SCREAMING_SNAKE_CASE" => VariantCase::ScreamingSnake,
+            _ => panic!(
+                "#[structform(rename_variants = \"{}\")] must be one of PascalCase, camelCase, snake_case or SCREAMING_SNAKE_CASE",
+                case
+            ),
+        }
+    }
+
+    /// The variant for a field, with a prefix like `add` for the
+    /// variants that act on a subform, or an empty prefix for the
+    /// field itself. Both are given in snake case. Raw fields, like
+    /// `r#type`, are named without the `r#`, unless that leaves a
+    /// keyword.
+    fn ident(&self, prefix: &str, field_ident: &Ident) -> Ident {
+        let field = field_ident.unraw().to_string();
+        let words = prefix
+            .split('_')
+            .chain(field.split('_'))
+            .filter(|word| !word.is_empty());
+        let name = match self {
+            VariantCase::Pascal => words.map(capitalize).collect::<String>(),
+            VariantCase::Camel => words
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 {
+                        word.to_string()
+                    } else {
+                        capitalize(word)
+                    }
+                })
+                .collect(),
+            VariantCase::Snake => words.collect::<Vec<_>>().join("_"),
+            VariantCase::ScreamingSnake => words.collect::<Vec<_>>().join("_").to_uppercase(),
+        };
+        match parse_str::<Ident>(&name) {
+            Ok(_) => Ident::new(&name, field_ident.span()),
+            Err(_) => Ident::new_raw(&name, field_ident.span()),
+        }
+    }
+}
+
+/// A model field from `#[structform(skip = "...")]` on the container,
+/// which has no input and is filled in by `submit`.
+struct SkipModelField {
+    field: Ident,
+    default: Option<Expr>,
+}
+
+impl parse::Parse for SkipModelField {
+    fn parse(parse_buffer: &syn::parse::ParseBuffer<'_>) -> parse::Result<Self> {
+        let field = parse_buffer.parse()?;
+        let default = if parse_buffer.peek(Token![=]) {
+            parse_buffer.parse::<Token![=]>()?;
+            Some(parse_buffer.parse()?)
+        } else {
+            None
+        };
+        Ok(SkipModelField { field, default })
+    }
 }
 
 impl parse::Parse for FormContainerAttribute {
     fn parse(parse_buffer: &syn::parse::ParseBuffer<'_>) -> parse::Result<Self> {
         let meta_list = parse_buffer.parse_terminated::<_, syn::token::Comma>(NestedMeta::parse)?;
-        let model: String = meta_list
-            .iter()
-            .filter_map(|arg| match arg {
-                NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. }))
-                    if path.is_ident("model") =>
-                {
-                    match lit {
-                        Lit::Str(lit) => Some(lit.value()),
-                        _ => None,
-                    }
-                }
-                _ => None,
-            })
-            .next()
-            .expect(
-                "Expected to find an attribute indicating the model type: #[structform(model = \"???\")]",
-            );
+        let model: String = find_str_arg(&meta_list, "model").expect(
+            "Expected to find an attribute indicating the model type: #[structform(model = \"???\")]",
+        );
         let model = Ident::new(&model, parse_buffer.span());
-        let submit_with: Option<String> = meta_list
-            .iter()
-            .filter_map(|arg| match arg {
-                NestedMeta::Meta(Meta::NameValue(MetaNameValue { path, lit, .. }))
-                    if path.is_ident("submit_with") =>
-                {
-                    match lit {
-                        Lit::Str(lit) => Some(lit.value()),
-                        _ => None,
-                    }
-                }
-                _ => None,
-            })
-            .next();
+        let submit_with = find_str_arg(&meta_list, "submit_with");
         let submit_with =
             submit_with.map(|submit_with| Ident::new(&submit_with, parse_buffer.span()));
         let flatten = meta_list.iter().any(
             |arg| matches!(arg, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("flatten")),
         );
+        let setters = meta_list.iter().any(
+            |arg| matches!(arg, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("setters")),
+        );
+        let default = meta_list.iter().any(
+            |arg| matches!(arg, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("default")),
+        );
+        let at_least_one_of = find_str_arg(&meta_list, "at_least_one_of").map(|fields| {
+            fields
+                .split(',')
+                .map(|field| Ident::new(field.trim(), parse_buffer.span()))
+                .collect()
+        });
+
+        let map_submit = find_str_arg(&meta_list, "map_submit")
+            .map(|map_submit| parse_str::<Path>(&map_submit))
+            .transpose()?;
+
+        let partial_model = find_str_arg(&meta_list, "partial_model")
+            .map(|partial_model| Ident::new(&partial_model, parse_buffer.span()));
+
+        let draft_model = find_str_arg(&meta_list, "draft_model")
+            .map(|draft_model| Ident::new(&draft_model, parse_buffer.span()));
+
+        let module = find_str_arg(&meta_list, "module")
+            .map(|module| Ident::new(&module, parse_buffer.span()));
+
+        let layout = find_str_arg(&meta_list, "layout")
+            .map(|layout| parse_str::<Path>(&layout))
+            .transpose()?;
+
+        let rename_variants = find_str_arg(&meta_list, "rename_variants")
+            .map(|case| VariantCase::parse(&case))
+            .unwrap_or(VariantCase::Pascal);
+        let variant_prefixes = VariantPrefixes::from_meta_list(&meta_list);
+
+        let skip = find_str_arg(&meta_list, "skip")
+            .map(|skip| {
+                parse::Parser::parse_str(
+                    punctuated::Punctuated::<SkipModelField, Token![,]>::parse_terminated,
+                    &skip,
+                )
+                .map(|skip| skip.into_iter().collect())
+            })
+            .transpose()?
+            .unwrap_or_default();
 
         Ok(FormContainerAttribute {
             model,
             submit_with,
             flatten,
+            setters,
+            default,
+            at_least_one_of,
+            map_submit,
+            partial_model,
+            draft_model,
+            module,
+            layout,
+            skip,
+            rename_variants,
+            variant_prefixes,
+        })
+    }
+}
+
+struct NewtypeAttribute {
+    kind: String,
+    via: Type,
+    input: Ident,
+    type_name: String,
+    min: Option<Expr>,
+    max: Option<Expr>,
+}
+
+impl parse::Parse for NewtypeAttribute {
+    fn parse(parse_buffer: &syn::parse::ParseBuffer<'_>) -> parse::Result<Self> {
+        let meta_list = parse_buffer.parse_terminated::<_, syn::token::Comma>(NestedMeta::parse)?;
+        let kind = find_str_arg(&meta_list, "kind").expect(
+            "Expected to find an attribute indicating the kind of input: #[parseformat(kind = \"numeric\")]",
+        );
+        let via = find_str_arg(&meta_list, "via")
+            .map(|via| parse_str::<Type>(&via))
+            .transpose()?
+            .expect("Expected to find an attribute indicating the wrapped type: #[parseformat(via = \"???\")]");
+        let input = find_str_arg(&meta_list, "input")
+            .map(|input| Ident::new(&input, parse_buffer.span()))
+            .expect("Expected to find an attribute indicating the input type: #[parseformat(input = \"???\")]");
+        let type_name = find_str_arg(&meta_list, "type_name").expect(
+            "Expected to find an attribute describing the type for error messages: #[parseformat(type_name = \"???\")]",
+        );
+        let min = find_str_arg(&meta_list, "min")
+            .map(|min| parse_str::<Expr>(&min))
+            .transpose()?;
+        let max = find_str_arg(&meta_list, "max")
+            .map(|max| parse_str::<Expr>(&max))
+            .transpose()?;
+
+        Ok(NewtypeAttribute {
+            kind,
+            via,
+            input,
+            type_name,
+            min,
+            max,
         })
     }
 }
@@ -408,7 +3922,35 @@ impl parse::Parse for FormContainerAttribute {
 #[derive(Default)]
 struct FormFieldAttribute {
     submit_attempted: bool,
+    honeypot: bool,
+    captcha: bool,
+    change_log: bool,
+    rate_limit_ms: Option<u64>,
     subform: bool,
+    lazy: bool,
+    label: Option<String>,
+    sensitive: bool,
+    slug_of: Option<Ident>,
+    model_fields: Option<Vec<Ident>>,
+    depends_on: Option<Ident>,
+    validate_with: Option<Path>,
+    sum_of: Option<Ident>,
+    sum_to: Option<Expr>,
+    unique_by: Option<Ident>,
+    required_if: Option<Path>,
+    at_least: Option<Ident>,
+    empty: Option<EmptyPolicy>,
+    skip: bool,
+    default: Option<Expr>,
+    rename: Option<Ident>,
+}
+
+/// How an empty input is submitted, from `#[structform(empty = "...")]`.
+#[derive(Clone, Copy)]
+enum EmptyPolicy {
+    Default,
+    None,
+    Required,
 }
 
 impl parse::Parse for FormFieldAttribute {
@@ -418,31 +3960,170 @@ impl parse::Parse for FormFieldAttribute {
         let subform = meta_list.iter().any(
             |arg| matches!(arg, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("subform")),
         );
+        let honeypot = meta_list.iter().any(
+            |arg| matches!(arg, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("honeypot")),
+        );
+        let captcha = meta_list.iter().any(
+            |arg| matches!(arg, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("captcha")),
+        );
+        let change_log = meta_list.iter().any(
+            |arg| matches!(arg, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("change_log")),
+        );
+        let rate_limit_ms = find_str_arg(&meta_list, "rate_limit_ms")
+            .map(|rate_limit_ms| {
+                rate_limit_ms.parse::<u64>().map_err(|_| {
+                    parse_buffer.error("rate_limit_ms must be a whole number of milliseconds")
+                })
+            })
+            .transpose()?;
+
+        let lazy = meta_list
+            .iter()
+            .any(|arg| matches!(arg, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("lazy")));
+        let label = find_str_arg(&meta_list, "label");
+        let sensitive = meta_list.iter().any(
+            |arg| matches!(arg, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("sensitive")),
+        );
+
+        let slug_of = find_str_arg(&meta_list, "slug_of")
+            .map(|slug_of| Ident::new(&slug_of, parse_buffer.span()));
+
+        let model_fields = find_str_arg(&meta_list, "model_fields").map(|model_fields| {
+            model_fields
+                .split(',')
+                .map(|model_field| Ident::new(model_field.trim(), parse_buffer.span()))
+                .collect()
+        });
+
+        let depends_on = find_str_arg(&meta_list, "depends_on")
+            .map(|depends_on| Ident::new(&depends_on, parse_buffer.span()));
+        let validate_with = find_str_arg(&meta_list, "validate_with")
+            .map(|validate_with| parse_str::<Path>(&validate_with))
+            .transpose()?;
+
+        let sum_of = find_str_arg(&meta_list, "sum_of")
+            .map(|sum_of| Ident::new(&sum_of, parse_buffer.span()));
+        let sum_to = find_str_arg(&meta_list, "sum_to")
+            .map(|sum_to| parse_str::<Expr>(&sum_to))
+            .transpose()?;
+        let unique_by = find_str_arg(&meta_list, "unique_by")
+            .map(|unique_by| Ident::new(&unique_by, parse_buffer.span()));
+        let required_if = find_str_arg(&meta_list, "required_if")
+            .map(|required_if| parse_str::<Path>(&required_if))
+            .transpose()?;
+        let at_least = find_str_arg(&meta_list, "at_least")
+            .map(|at_least| Ident::new(&at_least, parse_buffer.span()));
+        let empty = find_str_arg(&meta_list, "empty")
+            .map(|empty| match empty.as_str() {
+                "default" => Ok(EmptyPolicy::Default),
+                "none" => Ok(EmptyPolicy::None),
+                "required" => Ok(EmptyPolicy::Required),
+                _ => Err(parse_buffer.error("empty must be \"default\", \"none\" or \"required\"")),
+            })
+            .transpose()?;
+        let skip = meta_list
+            .iter()
+            .any(|arg| matches!(arg, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip")));
+        let default = find_str_arg(&meta_list, "default")
+            .map(|default| parse_str::<Expr>(&default))
+            .transpose()?;
+        let rename = find_str_arg(&meta_list, "rename")
+            .map(|rename| Ident::new(&rename, parse_buffer.span()));
+        if rename.is_some() && (skip || model_fields.is_some()) {
+            return Err(parse_buffer.error(
+                "rename can't be used with skip or model_fields, since it names the one model field that the field fills in",
+            ));
+        }
+        if default.is_some() && !skip {
+            return Err(parse_buffer.error(
+                "default can only be given on #[structform(skip)] fields, since inputs are filled in from the model",
+            ));
+        }
+        if empty.is_some() && required_if.is_some() {
+            return Err(parse_buffer.error(
+                "empty and required_if can't be used together, since required_if already decides how empty inputs are submitted",
+            ));
+        }
 
         Ok(FormFieldAttribute {
             submit_attempted,
+            honeypot,
+            captcha,
+            change_log,
+            rate_limit_ms,
             subform,
+            lazy,
+            label,
+            sensitive,
+            slug_of,
+            model_fields,
+            depends_on,
+            validate_with,
+            sum_of,
+            sum_to,
+            unique_by,
+            required_if,
+            at_least,
+            empty,
+            skip,
+            default,
+            rename,
         })
     }
 }
 
 struct RichField {
     snake_case_ident: Ident,
+    /// The field's variant in the field enum. This is in PascalCase,
+    /// unless it is changed with `#[structform(rename_variants = "...")]`.
     pascal_case_ident: Ident,
     ty: FieldType,
+    attrs: FormFieldAttribute,
 }
 
 impl RichField {
+    /// The model field that this field fills in, which has the same
+    /// name unless it is given with `#[structform(rename = "...")]`.
+    fn model_ident(&self) -> Ident {
+        self.attrs
+            .rename
+            .clone()
+            .unwrap_or_else(|| self.snake_case_ident.clone())
+    }
+
+    /// The model fields that an input fills in. This is the model
+    /// field with the same name, unless the input fills in more than one
+    /// with `#[structform(model_fields = "...")]`.
+    fn model_fields(&self) -> Vec<Ident> {
+        self.attrs
+            .model_fields
+            .clone()
+            .unwrap_or_else(|| vec![self.model_ident()])
+    }
+
     fn names(&self) -> (Ident, Ident) {
         (
             self.snake_case_ident.clone(),
             self.pascal_case_ident.clone(),
         )
     }
+
+    /// The label from `#[structform(label = "...")]`, or the field name
+    /// in sentence case, like "First name" for `first_name`.
+    fn label(&self) -> String {
+        self.attrs.label.clone().unwrap_or_else(|| {
+            let name = self.snake_case_ident.to_string().replace('_', " ");
+            let mut chars = name.chars();
+            chars
+                .next()
+                .map(|first| first.to_uppercase().chain(chars).collect())
+                .unwrap_or_default()
+        })
+    }
 }
 
-fn enrich_fields(struct_data: &DataStruct) -> Vec<RichField> {
-    struct_data
+fn enrich_fields(struct_data: &DataStruct, rename_variants: &VariantCase) -> Vec<RichField> {
+    let enriched_fields = struct_data
         .fields
         .iter()
         .map(|field| {
@@ -450,10 +4131,7 @@ fn enrich_fields(struct_data: &DataStruct) -> Vec<RichField> {
                 .ident
                 .clone()
                 .expect("Only normal structs are supported.");
-            let pascal_case_ident = Ident::new(
-                &snake_to_pascal_case(&snake_case_ident.to_string()),
-                snake_case_ident.span(),
-            );
+            let pascal_case_ident = rename_variants.ident("", &snake_case_ident);
             let attrs = field
                 .attrs
                 .iter()
@@ -467,6 +4145,23 @@ fn enrich_fields(struct_data: &DataStruct) -> Vec<RichField> {
 
             let ty = if attrs.submit_attempted {
                 FieldType::SubmitAttempted
+            } else if attrs.honeypot {
+                FieldType::Honeypot
+            } else if attrs.captcha {
+                FieldType::Captcha
+            } else if attrs.change_log {
+                FieldType::ChangeLog
+            } else if let Some(interval_ms) = attrs.rate_limit_ms {
+                FieldType::RateLimit { interval_ms }
+            } else if attrs.skip {
+                FieldType::Skip {
+                    default: attrs.default.clone(),
+                }
+            } else if attrs.lazy {
+                FieldType::ListSubform {
+                    subform_type: input_value_type(&field.ty),
+                    lazy: true,
+                }
             } else if attrs.subform {
                 FieldType::Subform {
                     subform_type: field.ty.clone(),
@@ -478,6 +4173,13 @@ fn enrich_fields(struct_data: &DataStruct) -> Vec<RichField> {
             } else if is_vec(field) {
                 FieldType::ListSubform {
                     subform_type: parse_vec_type_generic_type(&field.ty),
+                    lazy: false,
+                }
+            } else if is_map(field) {
+                let (key_type, subform_type) = parse_map_type_generic_types(&field.ty);
+                FieldType::MapSubform {
+                    key_type,
+                    subform_type,
                 }
             } else {
                 FieldType::Input {
@@ -489,15 +4191,272 @@ fn enrich_fields(struct_data: &DataStruct) -> Vec<RichField> {
                 snake_case_ident,
                 pascal_case_ident,
                 ty,
+                attrs,
             }
         })
-        .collect()
+        .collect::<Vec<_>>();
+
+    let references = enriched_fields.iter().flat_map(|field| {
+        vec![
+            field
+                .attrs
+                .slug_of
+                .as_ref()
+                .map(|slug_of| ("slug_of", slug_of)),
+            field
+                .attrs
+                .depends_on
+                .as_ref()
+                .map(|depends_on| ("depends_on", depends_on)),
+        ]
+        .into_iter()
+        .flatten()
+    });
+    for (attr, referenced_field) in references {
+        let is_input = enriched_fields.iter().any(|field| {
+            &field.snake_case_ident == referenced_field
+                && matches!(field.ty, FieldType::Input { .. })
+        });
+        if !is_input {
+            panic!(
+                "#[structform({} = \"{}\")] must refer to an input field on the same form",
+                attr, referenced_field
+            );
+        }
+    }
+
+    enriched_fields
 }
 
 enum FieldType {
     Input { input_type: Type },
     Subform { subform_type: Type },
     OptionalSubform { subform_type: Type },
-    ListSubform { subform_type: Type },
+    ListSubform { subform_type: Type, lazy: bool },
+    MapSubform { key_type: Type, subform_type: Type },
     SubmitAttempted,
+    Honeypot,
+    Captcha,
+    ChangeLog,
+    RateLimit { interval_ms: u64 },
+    Skip { default: Option<Expr> },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Runs a generator on a form struct, like the derive would.
+    fn generate(
+        input: DeriveInput,
+        generator: fn(&FormStruct) -> proc_macro2::TokenStream,
+    ) -> String {
+        let container_attrs = container_attrs(&input);
+        let struct_data = match &input.data {
+            Data::Struct(data) => data,
+            _ => panic!("Only form structs can be generated from"),
+        };
+        generator(&FormStruct::new(&input, struct_data, &container_attrs)).to_string()
+    }
+
+    #[test]
+    fn the_field_enum_has_a_variant_for_every_field_and_action() {
+        let input = parse_quote! {
+            #[structform(model = "Order")]
+            struct OrderForm {
+                customer: FormTextInput<String>,
+                gift_wrap: Option<GiftWrapForm>,
+                lines: Vec<LineForm>,
+            }
+        };
+        assert_eq!(
+            generate(input, impl_field_enum),
+            quote! {
+                #[derive(Debug, Clone, PartialEq, Eq)]
+                #[allow(missing_docs, dead_code, non_camel_case_types, clippy::enum_variant_names)]
+                pub enum OrderFormField {
+                    Customer,
+                    ToggleGiftWrap,
+                    GiftWrap(GiftWrapFormField),
+                    AddLines,
+                    Lines(usize, LineFormField),
+                    RemoveLines(usize),
+                    MoveLines(usize, usize),
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn the_field_enum_can_be_put_in_a_module() {
+        let input = parse_quote! {
+            #[structform(model = "Login", module = "login_form")]
+            pub struct LoginForm {
+                username: FormTextInput<String>,
+            }
+        };
+        assert_eq!(
+            generate(input, impl_field_enum),
+            quote! {
+                #[allow(missing_docs)]
+                pub mod login_form {
+                    #[allow(unused_imports)]
+                    use super::*;
+
+                    #[derive(Debug, Clone, PartialEq, Eq)]
+                    #[allow(missing_docs, dead_code, non_camel_case_types, clippy::enum_variant_names)]
+                    pub enum Field {
+                        Username,
+                    }
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "OrderForm has more than one field with the variant AddNotes")]
+    fn fields_with_the_same_variant_are_rejected() {
+        let input = parse_quote! {
+            #[structform(model = "Order")]
+            struct OrderForm {
+                add_notes: FormTextInput<String>,
+                notes: Vec<NoteForm>,
+            }
+        };
+        generate(input, impl_field_enum);
+    }
+
+    #[test]
+    fn reset_leaves_rate_limits_and_change_logs_alone() {
+        let input = parse_quote! {
+            #[structform(model = "Comment")]
+            struct CommentForm {
+                text: FormTextInput<String>,
+                replies: Vec<CommentForm>,
+                #[structform(honeypot)]
+                website: String,
+                #[structform(rate_limit_ms = "1000")]
+                rate_limit: structform::RateLimit,
+                #[structform(change_log)]
+                changes: structform::ChangeLog,
+            }
+        };
+        assert_eq!(
+            generate(input, impl_reset),
+            quote! {
+                fn reset(&mut self) {
+                    self.text.clear();
+                    self.replies = Default::default();
+                    self.website.clear();
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn flattened_forms_submit_their_input_as_the_model() {
+        let input = parse_quote! {
+            #[structform(model = "Email", flatten, map_submit = "Email::new")]
+            struct EmailForm {
+                email: FormTextInput<String>,
+            }
+        };
+        assert_eq!(
+            generate(input, |form| impl_submit(form, &quote! {})),
+            quote! {
+                fn submit(&mut self) -> Result<Email, structform::ParseError> {
+                    self.email.submit().map(Email::new)
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn inputs_for_more_than_one_model_field_start_from_a_tuple() {
+        let input = parse_quote! {
+            #[structform(model = "Booking")]
+            struct BookingForm {
+                #[structform(model_fields = "start, end")]
+                dates: FormDateRangeInput<(Date, Date)>,
+                #[structform(rename = "guest_count")]
+                guests: FormNumericInput<u32>,
+            }
+        };
+        assert_eq!(
+            generate(input, impl_new),
+            quote! {
+                fn new(model: &Booking) -> BookingForm {
+                    BookingForm {
+                        dates: <FormDateRangeInput<(Date, Date)> >::new(&(model.start.clone(), model.end.clone(),)),
+                        guests: <FormNumericInput<u32> >::new(&model.guest_count),
+                    }
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn generated_impls_are_for_the_form_and_its_model() {
+        let input: DeriveInput = parse_quote! {
+            #[structform(model = "Login")]
+            struct LoginForm {
+                username: FormTextInput<String>,
+            }
+        };
+        let generated = expand_structform(&input).to_string();
+        for expected in &[
+            quote! { pub enum LoginFormField },
+            quote! { impl LoginFormField },
+            quote! { impl structform::StructForm<Login> for LoginForm },
+            quote! { impl structform::FormFields for LoginForm },
+            quote! { impl structform::FormLayout for LoginForm },
+        ] {
+            assert!(
+                generated.contains(&expected.to_string()),
+                "{} isn't generated",
+                expected
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "can't be used on flattened forms")]
+    fn captchas_are_rejected_on_flattened_forms() {
+        let input: DeriveInput = parse_quote! {
+            #[structform(model = "Email", flatten)]
+            struct EmailForm {
+                email: FormTextInput<String>,
+                #[structform(captcha)]
+                captcha: String,
+            }
+        };
+        expand_structform(&input);
+    }
+
+    #[test]
+    fn variants_are_named_without_the_raw_prefix() {
+        let field = Ident::new_raw("type", proc_macro2::Span::call_site());
+        assert_eq!(VariantCase::Pascal.ident("", &field), "Type");
+        assert_eq!(VariantCase::Pascal.ident("add", &field), "AddType");
+        assert_eq!(VariantCase::Snake.ident("add", &field), "add_type");
+        assert_eq!(VariantCase::Snake.ident("", &field).to_string(), "r#type");
+    }
+
+    #[test]
+    fn capitalize_handles_non_ascii_letters() {
+        assert_eq!(capitalize("élan"), "Élan");
+        assert_eq!(capitalize("ß"), "SS");
+        assert_eq!(capitalize(""), "");
+    }
+
+    #[test]
+    fn names_are_split_into_words() {
+        assert_eq!(pascal_to_sentence_case("DarkBlue"), "Dark blue");
+        assert_eq!(pascal_to_snake_case("DarkBlue"), "dark_blue");
+    }
 }
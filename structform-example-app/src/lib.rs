@@ -0,0 +1,315 @@
+//! A small registration app, written in the Elm architecture that
+//! StructForm was designed for. It has a `Model`, `Msg`s which update
+//! it, and a `view` of the current model.
+//!
+//! To keep this buildable everywhere without a browser, the view
+//! renders HTML to a string instead of using a frontend framework. The
+//! way the form is wired up is the same as it would be in an app using
+//! Seed or Yew: every HTML input sends a `Msg::Input` with its field,
+//! and the buttons send the other messages.
+//!
+//! The app is a wizard with three pages:
+//!
+//! 1. Personal details, in a required subform, including a dropdown.
+//! 2. A list of addresses, which can be added and removed.
+//! 3. A review page, which summarises the form before saving it.
+//!
+//! Each page shows a summary of its validation errors at the top, and
+//! won't move on to the next page until its inputs are valid. The CSS
+//! classes in the view come from a `Theme`.
+
+use std::fmt::Write;
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    DefaultTheme, FormSelect, ParseAndFormat, ParseError, StructForm, Theme,
+};
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "an age", u32, u32, min = 18, max = 150);
+
+derive_form_input! {FormSelectInput}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FormSelect)]
+#[formselect(input = "FormSelectInput")]
+pub enum ContactMethod {
+    Email,
+    Phone,
+    #[formselect(label = "Post (this can take a few weeks)")]
+    Post,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Registration {
+    pub details: Details,
+    pub addresses: Vec<Address>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Details {
+    pub name: String,
+    pub age: u32,
+    pub contact_method: ContactMethod,
+}
+
+impl Default for Details {
+    fn default() -> Details {
+        Details {
+            name: String::new(),
+            age: 18,
+            contact_method: ContactMethod::Email,
+        }
+    }
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct Address {
+    pub street_address: String,
+    pub city: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Registration")]
+pub struct RegistrationForm {
+    #[structform(subform)]
+    pub details: DetailsForm,
+    pub addresses: Vec<AddressForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Details")]
+pub struct DetailsForm {
+    pub name: FormTextInput<String>,
+    pub age: FormNumberInput<u32>,
+    #[structform(label = "How should we contact you?")]
+    pub contact_method: FormSelectInput<ContactMethod>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+pub struct AddressForm {
+    pub street_address: FormTextInput<String>,
+    pub city: FormTextInput<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Page {
+    Details,
+    Addresses,
+    Review,
+    Done,
+}
+
+pub struct Model {
+    pub page: Page,
+    pub form: RegistrationForm,
+    pub saved: Option<Registration>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Msg {
+    Input(RegistrationFormField, String),
+    Next,
+    Back,
+    Save,
+}
+
+pub fn init() -> Model {
+    Model {
+        page: Page::Details,
+        form: RegistrationForm::default(),
+        saved: None,
+    }
+}
+
+pub fn update(msg: Msg, model: &mut Model) {
+    match msg {
+        Msg::Input(field, value) => model.form.set_input(field, value),
+        Msg::Next => {
+            let page_is_valid = match model.page {
+                Page::Details => model.form.details.submit().is_ok(),
+                Page::Addresses => model
+                    .form
+                    .addresses
+                    .iter_mut()
+                    .all(|address| address.submit().is_ok()),
+                Page::Review | Page::Done => false,
+            };
+            if page_is_valid {
+                model.page = match model.page {
+                    Page::Details => Page::Addresses,
+                    _ => Page::Review,
+                };
+            }
+        }
+        Msg::Back => {
+            model.page = match model.page {
+                Page::Addresses => Page::Details,
+                Page::Review => Page::Addresses,
+                page => page,
+            };
+        }
+        Msg::Save => {
+            if let Ok(registration) = model.form.submit() {
+                model.saved = Some(registration);
+                model.page = Page::Done;
+            }
+        }
+    }
+}
+
+/// The validation errors on the current page, with the label of the
+/// input that each error belongs to.
+pub fn error_summary(model: &Model) -> Vec<(String, ParseError)> {
+    let mut errors = Vec::new();
+    match model.page {
+        Page::Details => {
+            let details = &model.form.details;
+            let inputs = [
+                ("name", details.name.validation_error()),
+                ("age", details.age.validation_error()),
+                ("contact_method", details.contact_method.validation_error()),
+            ];
+            for (path, error) in inputs.iter() {
+                if let Some(error) = error {
+                    errors.push((label::<DetailsForm, _>(path), (*error).clone()));
+                }
+            }
+        }
+        Page::Addresses => {
+            for (i, address) in model.form.addresses.iter().enumerate() {
+                let inputs = [
+                    ("street_address", address.street_address.validation_error()),
+                    ("city", address.city.validation_error()),
+                ];
+                for (path, error) in inputs.iter() {
+                    if let Some(error) = error {
+                        errors.push((
+                            format!("Address {}: {}", i + 1, label::<AddressForm, _>(path)),
+                            (*error).clone(),
+                        ));
+                    }
+                }
+            }
+        }
+        Page::Review | Page::Done => {}
+    }
+    errors
+}
+
+fn label<Form: StructForm<Model>, Model>(path: &str) -> String {
+    Form::labels_by_path()
+        .into_iter()
+        .find(|(label_path, _)| label_path == path)
+        .map(|(_, label)| label)
+        .unwrap_or_else(|| path.to_string())
+}
+
+pub fn view(model: &Model) -> String {
+    view_with_theme(model, &DefaultTheme)
+}
+
+pub fn view_with_theme(model: &Model, theme: &dyn Theme) -> String {
+    let mut html = String::new();
+    let errors = error_summary(model);
+    if !errors.is_empty() {
+        writeln!(html, "<ul class=\"{}\">", theme.error_class()).unwrap();
+        for (label, error) in errors {
+            writeln!(html, "<li>{}: {}</li>", label, error).unwrap();
+        }
+        html.push_str("</ul>\n");
+    }
+
+    match model.page {
+        Page::Details => {
+            let details = &model.form.details;
+            view_text_input(
+                &mut html,
+                theme,
+                "Name",
+                &details.name.input,
+                details.name.validation_error(),
+            );
+            view_text_input(
+                &mut html,
+                theme,
+                "Age",
+                &details.age.input,
+                details.age.validation_error(),
+            );
+            writeln!(
+                html,
+                "<select class=\"{}\" name=\"contact_method\">",
+                theme.input_classes(details.contact_method.validation_error().is_some())
+            )
+            .unwrap();
+            for (value, option_label) in ContactMethod::OPTIONS {
+                let selected = if details.contact_method.input == *value {
+                    " selected"
+                } else {
+                    ""
+                };
+                writeln!(
+                    html,
+                    "<option value=\"{}\"{}>{}</option>",
+                    value, selected, option_label
+                )
+                .unwrap();
+            }
+            html.push_str("</select>\n");
+            html.push_str("<button>Next</button>\n");
+        }
+        Page::Addresses => {
+            for (i, address) in model.form.addresses.iter().enumerate() {
+                writeln!(html, "<fieldset><legend>Address {}</legend>", i + 1).unwrap();
+                view_text_input(
+                    &mut html,
+                    theme,
+                    "Street address",
+                    &address.street_address.input,
+                    address.street_address.validation_error(),
+                );
+                view_text_input(
+                    &mut html,
+                    theme,
+                    "City",
+                    &address.city.input,
+                    address.city.validation_error(),
+                );
+                html.push_str("<button>Remove</button></fieldset>\n");
+            }
+            html.push_str("<button>Add address</button>\n");
+            html.push_str("<button>Back</button><button>Next</button>\n");
+        }
+        Page::Review => {
+            html.push_str("<dl>\n");
+            for (label, value) in model.form.summary() {
+                writeln!(html, "<dt>{}</dt><dd>{}</dd>", label, value).unwrap();
+            }
+            html.push_str("</dl>\n");
+            html.push_str("<button>Back</button><button>Save</button>\n");
+        }
+        Page::Done => html.push_str("<p>Thanks for registering!</p>\n"),
+    }
+    html
+}
+
+fn view_text_input(
+    html: &mut String,
+    theme: &dyn Theme,
+    label: &str,
+    input: &str,
+    error: Option<&ParseError>,
+) {
+    writeln!(
+        html,
+        "<label class=\"{}\">{}<input class=\"{}\" value=\"{}\"></label>",
+        theme.label_class(),
+        label,
+        theme.input_classes(error.is_some()),
+        input
+    )
+    .unwrap();
+}
@@ -0,0 +1,147 @@
+use structform_example_app::*;
+
+fn input(model: &mut Model, field: RegistrationFormField, value: &str) {
+    update(Msg::Input(field, value.to_string()), model);
+}
+
+#[test]
+fn registering_goes_through_every_page() {
+    let mut model = init();
+    assert_eq!(model.page, Page::Details);
+
+    input(
+        &mut model,
+        RegistrationFormField::Details(DetailsFormField::Name),
+        "Justin",
+    );
+    input(
+        &mut model,
+        RegistrationFormField::Details(DetailsFormField::Age),
+        "30",
+    );
+    input(
+        &mut model,
+        RegistrationFormField::Details(DetailsFormField::ContactMethod),
+        "Post",
+    );
+    update(Msg::Next, &mut model);
+    assert_eq!(model.page, Page::Addresses);
+
+    input(&mut model, RegistrationFormField::AddAddresses, "");
+    input(
+        &mut model,
+        RegistrationFormField::Addresses(0, AddressFormField::StreetAddress),
+        "123 StructForm Drive",
+    );
+    input(
+        &mut model,
+        RegistrationFormField::Addresses(0, AddressFormField::City),
+        "Johannesburg",
+    );
+    update(Msg::Next, &mut model);
+    assert_eq!(model.page, Page::Review);
+
+    let review = view(&model);
+    assert!(review.contains("<dt>Details - How should we contact you?</dt><dd>Post</dd>"));
+    assert!(review.contains("<dd>Johannesburg</dd>"));
+
+    update(Msg::Save, &mut model);
+    assert_eq!(model.page, Page::Done);
+    assert_eq!(
+        model.saved,
+        Some(Registration {
+            details: Details {
+                name: "Justin".to_string(),
+                age: 30,
+                contact_method: ContactMethod::Post,
+            },
+            addresses: vec![Address {
+                street_address: "123 StructForm Drive".to_string(),
+                city: "Johannesburg".to_string(),
+            }],
+        })
+    );
+}
+
+#[test]
+fn each_page_must_be_valid_to_move_on() {
+    let mut model = init();
+    input(
+        &mut model,
+        RegistrationFormField::Details(DetailsFormField::Age),
+        "12",
+    );
+    update(Msg::Next, &mut model);
+    assert_eq!(model.page, Page::Details);
+
+    let html = view(&model);
+    assert!(html.contains("<li>Name: This field is required.</li>"));
+    assert!(html.contains("<li>Age: Expected an age between 18 and 150.</li>"));
+    assert!(html.contains("<li>How should we contact you?: This field is required.</li>"));
+    assert!(html.contains("<option value=\"Email\">Email</option>"));
+
+    input(
+        &mut model,
+        RegistrationFormField::Details(DetailsFormField::Name),
+        "Justin",
+    );
+    input(
+        &mut model,
+        RegistrationFormField::Details(DetailsFormField::Age),
+        "30",
+    );
+    input(
+        &mut model,
+        RegistrationFormField::Details(DetailsFormField::ContactMethod),
+        "Email",
+    );
+    update(Msg::Next, &mut model);
+    assert_eq!(model.page, Page::Addresses);
+
+    input(&mut model, RegistrationFormField::AddAddresses, "");
+    input(&mut model, RegistrationFormField::AddAddresses, "");
+    input(&mut model, RegistrationFormField::RemoveAddresses(0), "");
+    update(Msg::Next, &mut model);
+    assert_eq!(model.page, Page::Addresses);
+    let html = view(&model);
+    assert!(html.contains("<li>Address 1: Street address: This field is required.</li>"));
+    assert!(!html.contains("Address 2"));
+
+    update(Msg::Back, &mut model);
+    assert_eq!(model.page, Page::Details);
+    assert!(view(&model).contains("<option value=\"Email\" selected>Email</option>"));
+}
+
+struct BootstrapTheme;
+
+impl structform::Theme for BootstrapTheme {
+    fn input_class(&self) -> &str {
+        "form-control"
+    }
+
+    fn error_class(&self) -> &str {
+        "alert alert-danger"
+    }
+
+    fn invalid_class(&self) -> &str {
+        "is-invalid"
+    }
+}
+
+#[test]
+fn the_view_uses_the_theme_classes() {
+    let mut model = init();
+    input(
+        &mut model,
+        RegistrationFormField::Details(DetailsFormField::Name),
+        "Justin",
+    );
+    update(Msg::Next, &mut model);
+
+    let html = view_with_theme(&model, &BootstrapTheme);
+    assert!(html.contains("<ul class=\"alert alert-danger\">"));
+    assert!(html.contains(
+        "<label class=\"structform-label\">Name<input class=\"form-control\" value=\"Justin\"></label>"
+    ));
+    assert!(html.contains("<select class=\"form-control is-invalid\" name=\"contact_method\">"));
+}
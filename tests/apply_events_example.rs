@@ -0,0 +1,95 @@
+#![cfg(all(feature = "postal_code", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_postal_code_input, impl_text_input_with_stringops, ChangeLog,
+    ParseAndFormat, StructForm,
+};
+
+// This example shows how to set many inputs at once, like when
+// restoring a saved draft, without doing the form's bookkeeping after
+// every single input.
+
+// This example builds on the [postal code example](./postal_code_example.rs)
+// and the [change log example](./change_log_example.rs). This example
+// is written assuming that you're already familiar with those
+// examples, so if not please refer to them first.
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Address {
+    country: String,
+    postal_code: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    country: FormTextInput<String>,
+    #[structform(
+        depends_on = "country",
+        validate_with = "structform::validate_postal_code"
+    )]
+    postal_code: FormPostalCodeInput<String>,
+    #[structform(change_log)]
+    changes: ChangeLog,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormPostalCodeInput}
+impl_postal_code_input!(FormPostalCodeInput);
+
+// `apply_events` takes the same fields and values as `set_input`, but
+// inputs that depend on other inputs are only validated once all of
+// the events have been applied. This means that the order of the
+// events doesn't matter, and a draft with hundreds of inputs isn't
+// validated hundreds of times.
+
+#[test]
+fn dependent_inputs_are_validated_after_the_whole_batch() {
+    let mut form = AddressForm::default();
+
+    form.apply_events(vec![
+        (AddressFormField::PostalCode, "sw1a1aa".to_string()),
+        (AddressFormField::Country, "GB".to_string()),
+    ]);
+    assert_eq!(
+        form.submit(),
+        Ok(Address {
+            country: "GB".to_string(),
+            postal_code: "SW1A 1AA".to_string(),
+        })
+    );
+}
+
+// The change log records each input's value from before the batch and
+// after it, so an input that changes more than once in the same batch
+// is only logged once.
+
+#[test]
+fn change_log_records_one_change_per_input_for_the_whole_batch() {
+    let mut form = AddressForm::default();
+
+    form.apply_events(vec![
+        (AddressFormField::Country, "US".to_string()),
+        (AddressFormField::PostalCode, "90210".to_string()),
+        (AddressFormField::Country, "ZA".to_string()),
+        (AddressFormField::PostalCode, "8001".to_string()),
+    ]);
+    let changes: Vec<(&str, &str, &str)> = form
+        .changes
+        .changes()
+        .iter()
+        .map(|change| {
+            (
+                change.path.as_str(),
+                change.old_value.as_str(),
+                change.new_value.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        changes,
+        vec![("country", "", "ZA"), ("postal_code", "", "8001")]
+    );
+}
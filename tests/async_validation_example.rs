@@ -0,0 +1,154 @@
+#![cfg(feature = "text")]
+
+use std::future::Future;
+use std::pin::{pin, Pin};
+use std::task::{Context, Poll, Waker};
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, AsyncValidate, ParseAndFormat, ParseError,
+    PendingValidation, StructForm, ValidationState,
+};
+
+// This example shows how to check an input with something that can't
+// answer straight away, like asking a server whether a username is
+// already taken.
+
+// This example builds on the [validation service
+// example](./validation_service_example.rs). This example is written
+// assuming that you're already familiar with that example, so if not
+// please refer to that first.
+
+// When the input changes, call `start_async_validation` on it. This
+// marks the input as pending, and returns a `PendingValidation` with
+// the value to check. Run the check however your app runs futures, and
+// when it's done, pass the `PendingValidation` and the result back to
+// `finish_async_validation`. If the input changed in the meantime, the
+// result is stale and is ignored.
+//
+// While any check is pending, `has_pending_validations` is true and
+// `submit` fails with `ParseError::ValidationPending`.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Signup {
+    username: String,
+    display_name: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Signup")]
+struct SignupForm {
+    username: FormTextInput<String>,
+    display_name: FormTextInput<String>,
+}
+
+// A pretend API client. Any `Fn(&T)` that returns a future can also
+// be used as an `AsyncValidate` check.
+struct UsernameService;
+
+impl AsyncValidate<String> for UsernameService {
+    type Future = Pin<Box<dyn Future<Output = Result<(), ParseError>>>>;
+
+    fn validate(&self, username: &String) -> Self::Future {
+        let username = username.clone();
+        Box::pin(async move {
+            if username == "admin" {
+                Err(ParseError::External(
+                    "That username is already taken.".to_string(),
+                ))
+            } else {
+                Ok(())
+            }
+        })
+    }
+}
+
+// Apps would usually run the future with their framework's executor,
+// like `wasm_bindgen_futures::spawn_local`. The pretend API call
+// finishes immediately, so polling it once is enough here.
+fn run<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    match future
+        .as_mut()
+        .poll(&mut Context::from_waker(Waker::noop()))
+    {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("The example check should finish straight away"),
+    }
+}
+
+fn check_username(form: &mut SignupForm) -> Option<PendingValidation<String>> {
+    form.set_input(SignupFormField::DisplayName, "Justin".to_string());
+    form.username.start_async_validation()
+}
+
+#[test]
+fn submit_waits_for_pending_checks() {
+    let mut form = SignupForm::default();
+    form.set_input(SignupFormField::Username, "justin".to_string());
+    let pending = check_username(&mut form).unwrap();
+
+    assert!(form.has_pending_validations());
+    assert_eq!(form.username.validation_state(), ValidationState::Pending);
+    assert_eq!(form.submit(), Err(ParseError::ValidationPending));
+
+    let (pending, result) = run(pending.check(&UsernameService));
+    form.username.finish_async_validation(pending, result);
+
+    assert!(!form.has_pending_validations());
+    assert_eq!(
+        form.username.validation_state(),
+        ValidationState::Valid(&"justin".to_string())
+    );
+    assert_eq!(
+        form.submit(),
+        Ok(Signup {
+            username: "justin".to_string(),
+            display_name: "Justin".to_string(),
+        })
+    );
+}
+
+#[test]
+fn failed_checks_are_shown_on_the_input() {
+    let mut form = SignupForm::default();
+    form.set_input(SignupFormField::Username, "admin".to_string());
+    let pending = check_username(&mut form).unwrap();
+
+    let (pending, result) = run(pending.check(&UsernameService));
+    form.username.finish_async_validation(pending, result);
+
+    let taken = ParseError::External("That username is already taken.".to_string());
+    assert_eq!(
+        form.username.validation_state(),
+        ValidationState::Invalid(taken.clone())
+    );
+    assert_eq!(form.username.validation_error(), Some(&taken));
+    assert_eq!(form.submit(), Err(taken));
+}
+
+#[test]
+fn stale_results_are_ignored() {
+    let mut form = SignupForm::default();
+    form.set_input(SignupFormField::Username, "admin".to_string());
+    let stale = check_username(&mut form).unwrap();
+
+    // The user keeps typing before the check comes back.
+    form.set_input(SignupFormField::Username, "admin2".to_string());
+    assert!(!form.has_pending_validations());
+    let (stale, result) = run(stale.check(&UsernameService));
+    form.username.finish_async_validation(stale, result);
+
+    assert_eq!(
+        form.username.validation_state(),
+        ValidationState::Valid(&"admin2".to_string())
+    );
+}
+
+#[test]
+fn invalid_values_are_not_checked() {
+    let mut form = SignupForm::default();
+    assert_eq!(form.username.start_async_validation(), None);
+    assert!(!form.has_pending_validations());
+}
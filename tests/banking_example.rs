@@ -0,0 +1,108 @@
+#![cfg(all(feature = "banking", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_bank_account_input, impl_text_input_with_stringops, Iban, IbanError,
+    ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows the banking inputs, which are available with the
+// `banking` feature.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Debug, PartialEq, Eq)]
+struct PayoutDetails {
+    iban: Iban,
+    local_account: Option<String>,
+}
+
+impl Default for PayoutDetails {
+    fn default() -> PayoutDetails {
+        PayoutDetails {
+            iban: "GB82 WEST 1234 5698 7654 32".parse().unwrap(),
+            local_account: None,
+        }
+    }
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "PayoutDetails")]
+struct PayoutDetailsForm {
+    iban: FormTextInput<Iban>,
+    local_account: FormZaBankAccountInput<Option<String>>,
+}
+
+// `Iban` implements `FromStr` and `Display`, so it works with the
+// usual text input macros.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, Iban);
+
+// Domestic account numbers don't have a standard format, but many
+// countries have rules for how long they can be.
+// `impl_bank_account_input` implements `ParseAndFormat` using a
+// specific country's rules.
+
+derive_form_input! {FormZaBankAccountInput}
+impl_bank_account_input!(FormZaBankAccountInput, "ZA");
+
+#[test]
+fn ibans_are_validated_and_grouped_in_fours() {
+    let mut form = PayoutDetailsForm::default();
+
+    form.set_input(
+        PayoutDetailsFormField::Iban,
+        "de89370400440532013000".to_string(),
+    );
+    let iban = form.iban.value.clone().unwrap();
+    assert_eq!(iban.country(), "DE");
+    assert_eq!(iban.electronic(), "DE89370400440532013000");
+    assert_eq!(iban.to_string(), "DE89 3704 0044 0532 0130 00");
+
+    let form = PayoutDetailsForm::new(&PayoutDetails::default());
+    assert_eq!(form.iban.input, "GB82 WEST 1234 5698 7654 32".to_string());
+}
+
+#[test]
+fn invalid_ibans_are_rejected() {
+    assert_eq!(
+        "GB82 WEST 1234 5698 7654 33".parse::<Iban>(),
+        Err(IbanError::InvalidChecksum)
+    );
+    assert_eq!(
+        "GB82 WEST 1234 5698 7654".parse::<Iban>(),
+        Err(IbanError::InvalidLength {
+            country: "GB".to_string(),
+            length: 22
+        })
+    );
+    assert_eq!(
+        "US12 3456 7890".parse::<Iban>(),
+        Err(IbanError::UnsupportedCountry("US".to_string()))
+    );
+    assert_eq!("not an iban".parse::<Iban>(), Err(IbanError::InvalidFormat));
+}
+
+#[test]
+fn domestic_account_numbers_are_checked_by_country() {
+    let mut form = PayoutDetailsForm::default();
+
+    form.set_input(
+        PayoutDetailsFormField::LocalAccount,
+        "62 1234 5678".to_string(),
+    );
+    assert_eq!(form.local_account.value, Ok(Some("6212345678".to_string())));
+
+    form.set_input(PayoutDetailsFormField::LocalAccount, "12345".to_string());
+    assert_eq!(
+        form.local_account.value,
+        Err(ParseError::InvalidFormat {
+            required_type: "an account number of 7 to 11 digits".to_string()
+        })
+    );
+
+    form.set_input(PayoutDetailsFormField::LocalAccount, "".to_string());
+    assert_eq!(form.local_account.value, Ok(None));
+}
@@ -0,0 +1,138 @@
+#![cfg(all(feature = "barcode", feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops, Barcode,
+    BarcodeError, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows the barcode input, which is available with the
+// `barcode` feature, for forms that are filled in with a barcode or
+// QR code scanner.
+
+// This example builds on the [login example](./login_example.rs) and
+// the [validation errors example](./validation_errors_example.rs).
+// This example is written assuming that you're already familiar with
+// those examples, so if not please refer to them first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct StockCount {
+    item: Option<Barcode>,
+    sku: String,
+    quantity: u32,
+}
+
+// Most scanners act like a keyboard, and type what they read into the
+// focused input. `Barcode` implements `FromStr` and `Display`, so it
+// works with the usual text input macros.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "StockCount")]
+struct StockCountForm {
+    item: FormTextInput<Option<Barcode>>,
+    sku: FormTextInput<String>,
+    quantity: FormNumberInput<u32>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+impl_text_input_with_stringops!(FormTextInput, Barcode);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(
+    FormNumberInput,
+    "a quantity",
+    u32,
+    u32,
+    min = 0,
+    max = 10000
+);
+
+#[test]
+fn ean_13_barcodes_have_their_check_digit_validated() {
+    assert_eq!(
+        "4006381333931".parse::<Barcode>(),
+        Ok(Barcode::Ean13("4006381333931".to_string()))
+    );
+    assert_eq!(
+        "4006381333932".parse::<Barcode>(),
+        Err(BarcodeError::InvalidChecksum)
+    );
+
+    let mut form = StockCountForm::default();
+    form.set_input(StockCountFormField::Item, "4006381333932".to_string());
+    assert_eq!(
+        form.item.value,
+        Err(ParseError::FromStrError(
+            "This is not a valid EAN-13 barcode".to_string()
+        ))
+    );
+}
+
+#[test]
+fn other_barcodes_are_code_128() {
+    // Code 128 barcodes can hold any ASCII text. Their check digit is
+    // checked by the scanner, and isn't typed into the input.
+    assert_eq!(
+        "PLT-0042".parse::<Barcode>(),
+        Ok(Barcode::Code128("PLT-0042".to_string()))
+    );
+    assert_eq!(
+        "Pallet ü".parse::<Barcode>(),
+        Err(BarcodeError::InvalidCharacter('ü'))
+    );
+}
+
+#[test]
+fn qr_codes_fill_in_several_fields() {
+    // QR codes with URL-encoded contents are parsed into key-value
+    // pairs.
+    let mut form = StockCountForm::default();
+    form.set_input(
+        StockCountFormField::Item,
+        "sku=KTL-1&qty=3&batch=2024%2F07".to_string(),
+    );
+    let scanned = form.item.value.clone().unwrap().unwrap();
+    assert_eq!(scanned.get("batch"), Some("2024/07"));
+
+    // `fill_form` sets the fields that the QR code's keys map to. The
+    // mapping is a function, so keys can be matched however your
+    // labels are printed, and keys that aren't on the form are left
+    // out.
+    assert_eq!(
+        scanned.fill_form(&mut form, |key| match key {
+            "sku" => Some(StockCountFormField::Sku),
+            "qty" => Some(StockCountFormField::Quantity),
+            _ => None,
+        }),
+        Ok(())
+    );
+    assert_eq!(
+        form.submit(),
+        Ok(StockCount {
+            item: Some(scanned),
+            sku: "KTL-1".to_string(),
+            quantity: 3,
+        })
+    );
+}
+
+#[test]
+fn fields_that_a_qr_code_fills_in_are_validated() {
+    let mut form = StockCountForm::default();
+    let scanned: Barcode = "sku=KTL-1&qty=lots".parse().unwrap();
+
+    let errors = scanned
+        .fill_form(&mut form, |key| match key {
+            "sku" => Some(StockCountFormField::Sku),
+            "qty" => Some(StockCountFormField::Quantity),
+            _ => None,
+        })
+        .unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert!(errors.get(&StockCountFormField::Quantity).is_some());
+
+    // Barcodes that aren't QR codes don't fill in anything.
+    let scanned: Barcode = "4006381333931".parse().unwrap();
+    assert_eq!(scanned.fill_form(&mut form, |_| None), Ok(()));
+    assert_eq!(form.sku.input, "KTL-1");
+}
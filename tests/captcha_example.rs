@@ -0,0 +1,110 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, CaptchaToken, ParseAndFormat, ParseError,
+    StructForm,
+};
+
+// This example shows how to carry a captcha token from a form through
+// to the server, where it can be verified.
+
+// This example builds on the [honeypot example](./honeypot_example.rs).
+// This example is written assuming that you're already familiar with
+// the honeypot example, so if not please refer to that first.
+
+// The model has a `structform::CaptchaToken` field, so that the token
+// is sent to the server along with the rest of the model.
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct SignupData {
+    email: String,
+    captcha: CaptchaToken,
+}
+
+// The form has a `String` field of the same name with the `captcha`
+// annotation. The captcha widget's JavaScript callback should set it,
+// for example with `set_input_at_path("captcha", token)`. It is
+// required, and it is cleared whenever a submit fails because captcha
+// tokens can only be verified once, so the widget needs to be reset to
+// get a new one.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "SignupData", partial_model = "PartialSignupData")]
+struct SignupForm {
+    email: FormTextInput<String>,
+    #[structform(captcha)]
+    captcha: String,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[test]
+fn the_token_is_carried_through_to_the_model() {
+    let mut form = SignupForm::default();
+    form.set_input(SignupFormField::Email, "joe@example.com".to_string());
+    assert!(form.set_input_at_path("captcha", "token-from-widget".to_string()));
+
+    let model = form.submit().unwrap();
+    assert_eq!(model.captcha.as_str(), "token-from-widget");
+
+    // On the server, the token is checked with the captcha provider
+    // before the rest of the model is used. Anything implementing
+    // `structform::VerifyCaptcha`, including closures, can verify it.
+    assert_eq!(
+        model
+            .captcha
+            .verify(&|token: &str| token == "token-from-widget"),
+        Ok(())
+    );
+    let rejected = model.captcha.verify(&|_: &str| false);
+    assert_eq!(rejected, Err(ParseError::CaptchaFailed));
+    assert_eq!(
+        rejected.unwrap_err().to_string(),
+        "The captcha could not be verified. Please complete it again."
+    );
+}
+
+#[test]
+fn the_captcha_is_required() {
+    let mut form = SignupForm::default();
+    form.set_input(SignupFormField::Email, "joe@example.com".to_string());
+
+    let result = form.submit();
+    assert_eq!(result, Err(ParseError::CaptchaRequired));
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Please complete the captcha."
+    );
+}
+
+#[test]
+fn the_token_is_cleared_when_submitting_fails() {
+    let mut form = SignupForm::default();
+    form.set_input(SignupFormField::Captcha, "token-from-widget".to_string());
+
+    assert_eq!(form.submit(), Err(ParseError::Required));
+    assert_eq!(form.captcha, "");
+}
+
+// The captcha is checked the same way by `submit_partial`, from the
+// [partial model example](./partial_model_example.rs), and the token
+// is always included in the partial model.
+
+#[test]
+fn partial_submits_need_a_captcha_too() {
+    let mut form = SignupForm::new(&SignupData {
+        email: "joe@example.com".to_string(),
+        captcha: CaptchaToken::default(),
+    });
+    form.set_input(SignupFormField::Email, "joe@example.org".to_string());
+    assert_eq!(form.submit_partial(), Err(ParseError::CaptchaRequired));
+
+    form.set_input(SignupFormField::Captcha, "token-from-widget".to_string());
+    let partial = form.submit_partial().unwrap();
+    assert_eq!(partial.email, Some("joe@example.org".to_string()));
+    assert_eq!(
+        partial.captcha.map(|captcha| captcha.as_str().to_string()),
+        Some("token-from-widget".to_string())
+    );
+}
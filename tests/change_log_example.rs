@@ -0,0 +1,103 @@
+#![cfg(feature = "text")]
+
+use std::time::Duration;
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, Change, ChangeLog, FormClock, MockClock,
+    ParseAndFormat, StructForm, REDACTED,
+};
+
+// This example shows how to keep an audit trail of every change made
+// to a form, for admin tools where changes need to be logged.
+
+// This example builds on the [subforms example](./subforms_example.rs)
+// and the [summary example](./summary_example.rs). This example is
+// written assuming that you're already familiar with those examples,
+// so if not please refer to them first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Account {
+    email: String,
+    password: String,
+    addresses: Vec<Address>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+// The `change_log` annotation marks a `structform::ChangeLog` field,
+// which isn't part of the model. Every time an input on the form or
+// any of its subforms changes, it records the input's field path, its
+// old and new values, and the time. Inputs marked as `sensitive` are
+// still logged, but their values are redacted.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Account")]
+struct AccountForm {
+    email: FormTextInput<String>,
+    #[structform(sensitive)]
+    password: FormTextInput<String>,
+    addresses: Vec<AddressForm>,
+    #[structform(change_log)]
+    changes: ChangeLog,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[test]
+fn every_change_is_recorded_with_secrets_redacted() {
+    let mut form = AccountForm::new(&Account {
+        email: "joe@example.com".to_string(),
+        password: "hunter2".to_string(),
+        addresses: vec![],
+    });
+    // The time comes from the system clock, unless the form is given
+    // another one, like this `MockClock`.
+    let clock = MockClock::new(Duration::from_secs(100));
+    form.set_clock(&FormClock::new(clock.clone()));
+    form.set_input(AccountFormField::Email, "joe@example.org".to_string());
+    clock.advance(Duration::from_secs(5));
+    form.set_input(AccountFormField::Password, "correct horse".to_string());
+    form.set_input_at_path("addresses[0].city", "Johannesburg".to_string());
+
+    // Setting an input to the value it already has isn't a change.
+    form.set_input(AccountFormField::Email, "joe@example.org".to_string());
+
+    assert_eq!(
+        form.changes.changes(),
+        &[
+            Change {
+                path: "email".to_string(),
+                old_value: "joe@example.com".to_string(),
+                new_value: "joe@example.org".to_string(),
+                at: Some(Duration::from_secs(100)),
+            },
+            Change {
+                path: "password".to_string(),
+                old_value: REDACTED.to_string(),
+                new_value: REDACTED.to_string(),
+                at: Some(Duration::from_secs(105)),
+            },
+            Change {
+                path: "addresses[0].city".to_string(),
+                old_value: "".to_string(),
+                new_value: "Johannesburg".to_string(),
+                at: Some(Duration::from_secs(105)),
+            },
+        ][..]
+    );
+
+    // At submit time, take the changes to send them to the audit log
+    // along with the model.
+    assert!(form.submit().is_ok());
+    assert_eq!(form.changes.take().len(), 3);
+    assert!(form.changes.changes().is_empty());
+}
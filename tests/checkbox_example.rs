@@ -0,0 +1,98 @@
+#![cfg(all(feature = "bool", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_bool_input, impl_text_input_with_stringops, ParseAndFormat, ParseError,
+    StructForm,
+};
+
+// This example shows how to use checkboxes for `bool` fields.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+// `impl_bool_input` implements `ParseAndFormat` for `bool` and
+// `Option<bool>` on an input. An unchecked checkbox sends nothing, so
+// an empty input is `false`, or `None` for optional fields, which
+// suits questions that may not have been answered yet. Values that
+// browsers and frameworks commonly send for checkboxes, like "on",
+// are understood too.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormCheckboxInput}
+impl_bool_input!(FormCheckboxInput);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Signup {
+    email: String,
+    newsletter: bool,
+    has_attended_before: Option<bool>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Signup")]
+struct SignupForm {
+    email: FormTextInput<String>,
+    newsletter: FormCheckboxInput<bool>,
+    has_attended_before: FormCheckboxInput<Option<bool>>,
+}
+
+#[test]
+fn checkboxes_start_unchecked() {
+    let mut form = SignupForm::default();
+    form.set_input(SignupFormField::Email, "justin@example.com".to_string());
+    assert!(!form.newsletter.is_checked());
+    assert_eq!(
+        form.submit(),
+        Ok(Signup {
+            email: "justin@example.com".to_string(),
+            newsletter: false,
+            has_attended_before: None,
+        })
+    );
+}
+
+#[test]
+fn checkboxes_can_be_set_without_strings() {
+    let mut form = SignupForm::default();
+    form.set_input(SignupFormField::Email, "justin@example.com".to_string());
+
+    // Through the form, so that anything else watching the form's
+    // inputs, like a change log, sees the change too.
+    form.set_checked(SignupFormField::Newsletter, true);
+
+    // Or directly on the input.
+    form.has_attended_before.set_checked(false);
+
+    assert!(form.newsletter.is_checked());
+    assert!(!form.has_attended_before.is_checked());
+    assert_eq!(
+        form.submit(),
+        Ok(Signup {
+            email: "justin@example.com".to_string(),
+            newsletter: true,
+            has_attended_before: Some(false),
+        })
+    );
+}
+
+#[test]
+fn common_checkbox_values_are_understood() {
+    for checked in &["true", "on", "Yes", "1"] {
+        assert_eq!(FormCheckboxInput::<bool>::parse(checked), Ok(true));
+    }
+    for unchecked in &["false", "off", "No", "0", ""] {
+        assert_eq!(FormCheckboxInput::<bool>::parse(unchecked), Ok(false));
+    }
+    assert_eq!(FormCheckboxInput::<Option<bool>>::parse(" "), Ok(None));
+    assert_eq!(
+        FormCheckboxInput::<bool>::parse("maybe"),
+        Err(ParseError::InvalidFormat {
+            required_type: "yes or no".to_string(),
+        })
+    );
+    assert_eq!(FormCheckboxInput::<bool>::format(&true), "true");
+    assert_eq!(FormCheckboxInput::<Option<bool>>::format(&None), "");
+}
@@ -0,0 +1,117 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    cli_flag, derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    CliArgsError, ParseAndFormat, StructForm,
+};
+
+// This example shows how to fill in a form from command line flags,
+// so that ops tooling can script the same forms that people fill in
+// interactively.
+
+// This example builds on the [query string
+// example](./query_string_example.rs). This example is written
+// assuming that you're already familiar with that example, so if not
+// please refer to that first.
+
+// Each input's flag is its field path, with list indexes written as
+// another segment, like `--addresses.0.city`. Values can follow the
+// flag as the next argument, or after an `=`. Flags that don't match
+// an input are an error, and if anything is wrong none of the flags
+// are applied.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "a port number", u16, u16);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct MailConfig {
+    smtp_host: String,
+    smtp_port: u16,
+    relays: Vec<Relay>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Relay {
+    host: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "MailConfig")]
+struct MailConfigForm {
+    smtp_host: FormTextInput<String>,
+    smtp_port: FormNumberInput<u16>,
+    relays: Vec<RelayForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Relay")]
+struct RelayForm {
+    host: FormTextInput<String>,
+}
+
+#[test]
+fn flags_fill_in_the_form() {
+    let mut form = MailConfigForm::default();
+    let args = vec![
+        "--smtp_host",
+        "mail.example.com",
+        "--smtp_port=587",
+        "--relays.0.host",
+        "relay.example.com",
+    ];
+    assert_eq!(form.apply_cli_args(args), Ok(()));
+    assert_eq!(
+        form.submit(),
+        Ok(MailConfig {
+            smtp_host: "mail.example.com".to_string(),
+            smtp_port: 587,
+            relays: vec![Relay {
+                host: "relay.example.com".to_string(),
+            }],
+        })
+    );
+}
+
+#[test]
+fn bad_arguments_leave_the_form_unchanged() {
+    let mut form = MailConfigForm::default();
+    assert_eq!(
+        form.apply_cli_args(vec![
+            "--smtp_host",
+            "mail.example.com",
+            "--smtp_prot",
+            "587"
+        ]),
+        Err(CliArgsError::UnknownFlag("--smtp_prot".to_string()))
+    );
+    assert!(form.is_empty());
+
+    assert_eq!(
+        form.apply_cli_args(vec!["--smtp_host"]),
+        Err(CliArgsError::MissingValue("--smtp_host".to_string()))
+    );
+    assert_eq!(
+        form.apply_cli_args(vec!["mail.example.com"]),
+        Err(CliArgsError::UnexpectedArgument(
+            "mail.example.com".to_string()
+        ))
+    );
+}
+
+// The flags for a form can be listed for help output, using the paths
+// of its inputs.
+
+#[test]
+fn flags_can_be_listed_for_help() {
+    let mut form = MailConfigForm::default();
+    form.set_input(MailConfigFormField::AddRelays, String::new());
+    let flags: Vec<String> = form
+        .inputs_by_path()
+        .iter()
+        .map(|(path, _)| cli_flag(path))
+        .collect();
+    assert_eq!(flags, vec!["--smtp_host", "--smtp_port", "--relays.0.host"]);
+}
@@ -0,0 +1,89 @@
+#![cfg(all(feature = "name", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_full_name_input, impl_text_input_with_stringops, ParseAndFormat,
+    ParseError, StructForm,
+};
+
+// This example shows how a single input can fill in several fields on
+// your model.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+// Our model stores a first name and a last name separately, but we'd
+// like our form to only have a single "full name" input.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Contact {
+    first_name: String,
+    last_name: String,
+    email: String,
+}
+
+// The `model_fields` annotation maps a form field onto several model
+// fields. The input's value is a tuple, with one element per model
+// field in the same order.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Contact")]
+struct ContactForm {
+    #[structform(model_fields = "first_name, last_name")]
+    full_name: FormFullNameInput<(String, String)>,
+    email: FormTextInput<String>,
+}
+
+// `impl_full_name_input` implements `ParseAndFormat` for a full name
+// split into a first name and last name. Any input with a tuple value
+// can be used the same way.
+
+derive_form_input! {FormFullNameInput}
+impl_full_name_input!(FormFullNameInput);
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[test]
+fn a_full_name_is_submitted_into_two_model_fields() {
+    let mut form = ContactForm::default();
+
+    form.set_input(
+        ContactFormField::FullName,
+        "  Pieter   van der Merwe ".to_string(),
+    );
+    form.set_input(ContactFormField::Email, "pieter@example.com".to_string());
+
+    assert_eq!(
+        form.submit(),
+        Ok(Contact {
+            first_name: "Pieter".to_string(),
+            last_name: "van der Merwe".to_string(),
+            email: "pieter@example.com".to_string(),
+        })
+    );
+}
+
+#[test]
+fn a_full_name_is_initialized_from_two_model_fields() {
+    let form = ContactForm::new(&Contact {
+        first_name: "Justin".to_string(),
+        last_name: "Wernick".to_string(),
+        email: "justin@example.com".to_string(),
+    });
+
+    assert_eq!(form.full_name.input, "Justin Wernick".to_string());
+}
+
+#[test]
+fn a_full_name_needs_both_names() {
+    let mut form = ContactForm::default();
+
+    form.set_input(ContactFormField::FullName, "Cher".to_string());
+    assert_eq!(
+        form.full_name.value,
+        Err(ParseError::InvalidFormat {
+            required_type: "a first name and a last name".to_string()
+        })
+    );
+}
@@ -0,0 +1,122 @@
+#![cfg(all(feature = "toml", feature = "yaml", feature = "text"))]
+
+use structform::{derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm};
+
+// This example shows how to load a config file into a form, so that it
+// can be edited in a UI, and write the edited inputs back out.
+
+// This example builds on the [JSON values
+// example](./json_values_example.rs). This example is written assuming
+// that you're already familiar with the JSON values example, so if not
+// please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct ServerConfig {
+    host: String,
+    port: String,
+    tls: Tls,
+    upstreams: Vec<Upstream>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct Tls {
+    certificate: String,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct Upstream {
+    url: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "ServerConfig")]
+struct ServerConfigForm {
+    host: FormTextInput<String>,
+    port: FormTextInput<String>,
+    #[structform(subform)]
+    tls: TlsForm,
+    upstreams: Vec<UpstreamForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Tls")]
+struct TlsForm {
+    certificate: FormTextInput<String>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Upstream")]
+struct UpstreamForm {
+    url: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// Tables in a TOML file are read as subforms, and arrays of tables as
+// lists of subforms. Numbers and booleans become the raw input that
+// the user would have typed.
+
+const CONFIG: &str = r#"
+# The address to listen on.
+host = "0.0.0.0"
+port = 8080
+
+[tls]
+certificate = "/etc/ssl/server.pem"
+
+[[upstreams]]
+url = "http://10.0.0.1"
+
+[[upstreams]]
+url = "http://10.0.0.2"
+"#;
+
+#[test]
+fn a_toml_file_can_be_edited_in_a_form() {
+    let mut form = ServerConfigForm::from_toml_str(CONFIG).unwrap();
+    assert_eq!(form.port.input, "8080");
+    assert_eq!(form.upstreams.len(), 2);
+
+    form.set_input(ServerConfigFormField::Port, "8443".to_string());
+    form.set_input(ServerConfigFormField::RemoveUpstreams(0), "".to_string());
+
+    // The file is written from scratch, so the comment is gone, and
+    // every value is written as the string that was typed in.
+    assert_eq!(
+        form.to_toml_string().unwrap(),
+        r#"host = "0.0.0.0"
+port = "8443"
+
+[tls]
+certificate = "/etc/ssl/server.pem"
+
+[[upstreams]]
+url = "http://10.0.0.2"
+"#
+    );
+}
+
+#[test]
+fn a_yaml_file_can_be_edited_in_a_form() {
+    let mut form = ServerConfigForm::from_yaml_str(
+        "host: 0.0.0.0\nport: 8080\ntls:\n  certificate: /etc/ssl/server.pem\n",
+    )
+    .unwrap();
+    form.set_input(ServerConfigFormField::AddUpstreams, "".to_string());
+    form.set_input(
+        ServerConfigFormField::Upstreams(0, UpstreamFormField::Url),
+        "http://10.0.0.3".to_string(),
+    );
+
+    let written = form.to_yaml_string().unwrap();
+    let reloaded = ServerConfigForm::from_yaml_str(&written).unwrap();
+    assert_eq!(reloaded.inputs_by_path(), form.inputs_by_path());
+    assert_eq!(reloaded.upstreams[0].url.input, "http://10.0.0.3");
+}
+
+#[test]
+fn files_that_are_not_valid_are_errors() {
+    assert!(ServerConfigForm::from_toml_str("host = ").is_err());
+    assert!(ServerConfigForm::from_yaml_str("host: [").is_err());
+}
@@ -0,0 +1,115 @@
+#![cfg(all(feature = "cron", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, CronExpression, ParseAndFormat, ParseError,
+    StructForm,
+};
+
+// This example shows the cron expression input, which is available
+// with the `cron` feature.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Debug, PartialEq, Eq)]
+struct BackupJob {
+    schedule: CronExpression,
+}
+
+impl Default for BackupJob {
+    fn default() -> BackupJob {
+        BackupJob {
+            schedule: "@daily".parse().unwrap(),
+        }
+    }
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "BackupJob")]
+struct BackupJobForm {
+    schedule: FormTextInput<CronExpression>,
+}
+
+// `CronExpression` implements `FromStr` and `Display`, so it works
+// with the usual text input macros.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, CronExpression);
+
+#[test]
+fn valid_cron_expressions_can_be_described() {
+    let mut form = BackupJobForm::default();
+
+    form.set_input(BackupJobFormField::Schedule, "0 9 * * MON-FRI".to_string());
+
+    // It's useful to show users what their cron expression means
+    // next to the input, so they can see if they got it right.
+    assert_eq!(
+        form.schedule.value.as_ref().map(|cron| cron.describe()),
+        Ok("At 09:00, on Monday through Friday".to_string())
+    );
+
+    let cases = [
+        ("* * * * *", "Every minute"),
+        ("*/15 * * * *", "Every 15 minutes"),
+        ("5 * * * *", "At minute 5 past every hour"),
+        ("30 2 1 * *", "At 02:30, on day-of-month 1"),
+        (
+            "0 0 1,15 JAN,jul *",
+            "At 00:00, on day-of-month 1 and 15, in January and July",
+        ),
+        (
+            "0 8-17/3 * * 0",
+            "At minute 0 past hour every 3 hours from 8 through 17, on Sunday",
+        ),
+        ("@weekly", "At 00:00, on Sunday"),
+    ];
+    for (expression, description) in &cases {
+        form.set_input(BackupJobFormField::Schedule, expression.to_string());
+        assert_eq!(
+            form.schedule.value.as_ref().map(|cron| cron.describe()),
+            Ok(description.to_string())
+        );
+    }
+}
+
+#[test]
+fn invalid_cron_expressions_are_rejected() {
+    let mut form = BackupJobForm::default();
+
+    form.set_input(BackupJobFormField::Schedule, "0 9 * *".to_string());
+    assert_eq!(
+        form.schedule.value,
+        Err(ParseError::FromStrError(
+            "Expected a cron expression with 5 fields".to_string()
+        ))
+    );
+
+    form.set_input(BackupJobFormField::Schedule, "60 * * * *".to_string());
+    assert_eq!(
+        form.schedule.value,
+        Err(ParseError::FromStrError(
+            "Expected the minute to be between 0 and 59".to_string()
+        ))
+    );
+
+    form.set_input(BackupJobFormField::Schedule, "0 0 * * FRI-MON".to_string());
+    assert_eq!(
+        form.schedule.value,
+        Err(ParseError::FromStrError(
+            "Expected the day-of-week range to go from low to high".to_string()
+        ))
+    );
+}
+
+#[test]
+fn cron_expressions_are_formatted_numerically() {
+    let form = BackupJobForm::new(&BackupJob::default());
+    assert_eq!(form.schedule.input, "0 0 * * *".to_string());
+
+    let form = BackupJobForm::new(&BackupJob {
+        schedule: "*/5 9-17 * jan-mar mon,wed".parse().unwrap(),
+    });
+    assert_eq!(form.schedule.input, "*/5 9-17 * 1-3 1,3".to_string());
+}
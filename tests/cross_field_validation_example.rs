@@ -0,0 +1,256 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows validation rules that depend on more than one
+// field of the form, without needing a custom submit function.
+
+// This example builds on the [validation example](./validation_example.rs).
+// This example is written assuming that you're already familiar with
+// the validation example, so if not please refer to that first.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// The `required_if` annotation takes a function which receives the
+// form, and returns whether the field is currently required. When it
+// isn't required, an empty field submits its default value.
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Account {
+    account_type: String,
+    company_name: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Account")]
+struct AccountForm {
+    account_type: FormTextInput<String>,
+    #[structform(required_if = "is_business")]
+    company_name: FormTextInput<String>,
+}
+
+fn is_business(form: &AccountForm) -> bool {
+    form.account_type.input == "Business"
+}
+
+#[test]
+fn company_name_is_only_required_for_business_accounts() {
+    let mut form = AccountForm::default();
+
+    form.set_input(AccountFormField::AccountType, "Personal".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(Account {
+            account_type: "Personal".to_string(),
+            company_name: String::new(),
+        })
+    );
+    assert_eq!(form.company_name.validation_error(), None);
+
+    form.set_input(AccountFormField::AccountType, "Business".to_string());
+    assert_eq!(form.submit(), Err(ParseError::Required));
+    assert_eq!(
+        form.company_name.validation_error(),
+        Some(&ParseError::Required)
+    );
+
+    form.set_input(AccountFormField::CompanyName, "Panoptix".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(Account {
+            account_type: "Business".to_string(),
+            company_name: "Panoptix".to_string(),
+        })
+    );
+}
+
+// The `at_least_one_of` annotation on the form says that at least one
+// of a group of fields must be filled in. If they're all empty,
+// submitting returns a `ParseError::AtLeastOneRequired` listing every
+// field in the group, and `validate` returns the error on each of
+// them, so the message can be shown next to every field in the group.
+//
+// The check is on the model, so the fields' types need to implement
+// `FilledIn`, which `Option`s, `String`s and `Vec`s do.
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ContactDetails {
+    phone: Option<String>,
+    email: Option<String>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "ContactDetails", at_least_one_of = "phone, email")]
+struct ContactDetailsForm {
+    phone: FormTextInput<Option<String>>,
+    email: FormTextInput<Option<String>>,
+}
+
+#[test]
+fn either_a_phone_number_or_email_address_is_required() {
+    let mut form = ContactDetailsForm::default();
+
+    let result = form.submit();
+    assert_eq!(
+        result,
+        Err(ParseError::AtLeastOneRequired {
+            fields: vec!["phone".to_string(), "email".to_string()]
+        })
+    );
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Expected at least one of phone, email to be filled in."
+    );
+
+    // `validate` has the error on every field in the group.
+    let errors = form.validate().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(
+        errors.get(&ContactDetailsFormField::Phone),
+        errors.get(&ContactDetailsFormField::Email)
+    );
+    assert!(matches!(
+        errors.get(&ContactDetailsFormField::Phone),
+        Some(ParseError::AtLeastOneRequired { .. })
+    ));
+
+    form.set_input(ContactDetailsFormField::Email, "me@example.com".to_string());
+    assert_eq!(form.validate(), Ok(()));
+    assert_eq!(
+        form.submit(),
+        Ok(ContactDetails {
+            phone: None,
+            email: Some("me@example.com".to_string()),
+        })
+    );
+}
+
+// Since the rule is checked on the model, `validate_model` gives the
+// same answer for a model that didn't come from the form's inputs.
+
+#[test]
+fn at_least_one_of_checks_the_model() {
+    let form = ContactDetailsForm::default();
+    assert!(form.validate_model(&ContactDetails::default()).is_err());
+    assert_eq!(
+        form.validate_model(&ContactDetails {
+            phone: Some("0821234567".to_string()),
+            email: None,
+        }),
+        Ok(())
+    );
+}
+
+// The `at_least` annotation checks that a field's submitted value is
+// greater than or equal to another field's, like a maximum which must
+// not be below the minimum, or an end date which must not be before
+// the start date. The fields' types need to implement `PartialOrd`. If
+// the check fails, submitting returns a `ParseError::OutOfOrder`
+// naming the annotated field.
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct PriceFilter {
+    min_price: u32,
+    max_price: u32,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "PriceFilter")]
+struct PriceFilterForm {
+    min_price: FormNumberInput<u32>,
+    #[structform(at_least = "min_price")]
+    max_price: FormNumberInput<u32>,
+}
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "a price", u32, u32);
+
+#[test]
+fn the_maximum_price_cannot_be_below_the_minimum() {
+    let mut form = PriceFilterForm::default();
+    form.set_input(PriceFilterFormField::MinPrice, "100".to_string());
+    form.set_input(PriceFilterFormField::MaxPrice, "50".to_string());
+
+    let result = form.submit();
+    assert_eq!(
+        result,
+        Err(ParseError::OutOfOrder {
+            field: "max_price".to_string(),
+            at_least: "min_price".to_string(),
+        })
+    );
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Expected max_price to be at least min_price."
+    );
+
+    form.set_input(PriceFilterFormField::MaxPrice, "100".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(PriceFilter {
+            min_price: 100,
+            max_price: 100,
+        })
+    );
+}
+
+// `validate` checks these rules as well, once every input parses, so
+// that their errors can be shown with the errors of the inputs. Each
+// error is keyed by the field that it should be shown on.
+
+#[test]
+fn validate_checks_the_rules_once_every_input_parses() {
+    let mut form = PriceFilterForm::default();
+    form.set_input(PriceFilterFormField::MinPrice, "100".to_string());
+
+    let errors = form.validate().unwrap_err();
+    assert_eq!(
+        errors.get(&PriceFilterFormField::MaxPrice),
+        Some(&ParseError::Required)
+    );
+
+    form.set_input(PriceFilterFormField::MaxPrice, "50".to_string());
+    let errors = form.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors.get(&PriceFilterFormField::MaxPrice),
+        Some(&ParseError::OutOfOrder {
+            field: "max_price".to_string(),
+            at_least: "min_price".to_string(),
+        })
+    );
+
+    form.set_input(PriceFilterFormField::MaxPrice, "150".to_string());
+    assert_eq!(form.validate(), Ok(()));
+}
+
+// The `at_least` error belongs to the second field of the pair, the
+// one with the annotation, since that is the one the user would
+// usually change.
+
+#[test]
+fn the_out_of_order_error_is_on_the_second_field() {
+    let mut form = PriceFilterForm::default();
+    form.set_input(PriceFilterFormField::MinPrice, "100".to_string());
+    form.set_input(PriceFilterFormField::MaxPrice, "50".to_string());
+
+    assert_eq!(
+        form.errors_by_path(),
+        vec![(
+            "max_price".to_string(),
+            ParseError::OutOfOrder {
+                field: "max_price".to_string(),
+                at_least: "min_price".to_string(),
+            }
+        )]
+    );
+
+    // Lowering the minimum fixes it just as well as raising the
+    // maximum.
+    form.set_input(PriceFilterFormField::MinPrice, "10".to_string());
+    assert_eq!(form.errors_by_path(), vec![]);
+}
@@ -0,0 +1,123 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    CsvImportError, CsvList, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows how to download a list of subforms as a CSV
+// file, so that people can keep a copy of what they entered.
+
+// This example builds on the [CSV import
+// example](./csv_import_example.rs) and the [summary
+// example](./summary_example.rs). This example is written assuming
+// that you're already familiar with those examples, so if not please
+// refer to them first.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "an age", u32, u32);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Team {
+    members: Vec<Member>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Member {
+    name: String,
+    age: u32,
+    id_number: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Team")]
+struct TeamForm {
+    members: Vec<MemberForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Member")]
+struct MemberForm {
+    #[structform(label = "Full name")]
+    name: FormTextInput<String>,
+    age: FormNumberInput<u32>,
+    #[structform(sensitive)]
+    id_number: FormTextInput<String>,
+}
+
+// `export_csv` uses the same labels and formatted values as `summary`,
+// with a column for each input and a row for each subform. Sensitive
+// inputs are left out, the same as they are in the summary.
+
+#[test]
+fn lists_are_exported_with_a_header_row() {
+    let form = TeamForm::new(&Team {
+        members: vec![
+            Member {
+                name: "Wernick, Justin".to_string(),
+                age: 30,
+                id_number: "8001015009087".to_string(),
+            },
+            Member {
+                name: "Jane".to_string(),
+                age: 28,
+                id_number: "9202204720082".to_string(),
+            },
+        ],
+    });
+    assert_eq!(
+        form.members.export_csv(),
+        "Full name,Age\r\n\"Wernick, Justin\",30\r\nJane,28\r\n"
+    );
+}
+
+// Inputs that don't parse are exported as they were typed, so nothing
+// that was entered is lost.
+
+#[test]
+fn invalid_inputs_are_exported_as_typed() {
+    let mut form = TeamForm::default();
+    form.set_input(TeamFormField::AddMembers, String::new());
+    form.set_input(
+        TeamFormField::Members(0, MemberFormField::Age),
+        "twenty".to_string(),
+    );
+    assert_eq!(form.members.export_csv(), "Full name,Age\r\n,twenty\r\n");
+}
+
+// An exported file can be imported again, using its labels as the
+// column names. Sensitive inputs weren't exported, so they still need
+// to be filled in.
+
+#[test]
+fn exported_files_can_be_imported() {
+    let mut form = TeamForm::default();
+    form.set_input(TeamFormField::AddMembers, String::new());
+    form.set_input(
+        TeamFormField::Members(0, MemberFormField::Name),
+        "Jane".to_string(),
+    );
+    form.set_input(
+        TeamFormField::Members(0, MemberFormField::Age),
+        "28".to_string(),
+    );
+    let csv = form.members.export_csv();
+
+    let mut imported = Vec::<MemberForm>::new();
+    let mapping = [
+        ("Full name", MemberFormField::Name),
+        ("Age", MemberFormField::Age),
+    ];
+    match imported.import_csv(csv.as_bytes(), &mapping) {
+        Err(CsvImportError::InvalidRows(errors)) => assert_eq!(
+            errors.get(&(0, MemberFormField::IdNumber)),
+            Some(&ParseError::Required)
+        ),
+        other => panic!("Expected the ID number to be missing, got {:?}", other),
+    }
+    assert_eq!(imported[0].name.input, "Jane");
+    assert_eq!(imported[0].age.input, "28");
+}
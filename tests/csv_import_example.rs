@@ -0,0 +1,137 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    CsvImportError, CsvList, ParseAndFormat, ParseError, StructForm, ValidationErrors,
+};
+
+// This example shows how to fill in a list of subforms from a CSV
+// file, for bulk entry forms like "upload your team".
+
+// This example builds on the [list of subforms
+// example](./list_of_subforms_example.rs) and the [validation errors
+// example](./validation_errors_example.rs). This example is written
+// assuming that you're already familiar with those examples, so if not
+// please refer to them first.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "an age", u32, u32);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Team {
+    name: String,
+    members: Vec<Member>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Member {
+    name: String,
+    age: u32,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Team")]
+struct TeamForm {
+    name: FormTextInput<String>,
+    members: Vec<MemberForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Member")]
+struct MemberForm {
+    name: FormTextInput<String>,
+    age: FormNumberInput<u32>,
+}
+
+// `import_csv` is on lists of subforms, from the `CsvList` trait. The
+// mapping pairs the names of columns in the file's header row with the
+// subform's fields. Each row of the file is added to the end of the
+// list.
+
+const MAPPING: &[(&str, MemberFormField)] = &[
+    ("Name", MemberFormField::Name),
+    ("Age", MemberFormField::Age),
+];
+
+#[test]
+fn each_row_becomes_a_subform() {
+    let mut form = TeamForm::default();
+    form.set_input(TeamFormField::Name, "Panoptix".to_string());
+    form.set_input(TeamFormField::AddMembers, String::new());
+    form.set_input(
+        TeamFormField::Members(0, MemberFormField::Name),
+        "Justin".to_string(),
+    );
+    form.set_input(
+        TeamFormField::Members(0, MemberFormField::Age),
+        "30".to_string(),
+    );
+
+    let csv = "Name,Age,Email\n\"Wernick, Jane\",28,jane@example.com\nSam,41,\n";
+    form.members.import_csv(csv.as_bytes(), MAPPING).unwrap();
+
+    assert_eq!(
+        form.submit(),
+        Ok(Team {
+            name: "Panoptix".to_string(),
+            members: vec![
+                Member {
+                    name: "Justin".to_string(),
+                    age: 30,
+                },
+                Member {
+                    name: "Wernick, Jane".to_string(),
+                    age: 28,
+                },
+                Member {
+                    name: "Sam".to_string(),
+                    age: 41,
+                },
+            ],
+        })
+    );
+}
+
+// Rows with inputs that don't parse are still added, so that they can
+// be fixed on the form. Their errors are returned keyed by the row's
+// index in the list, and can be nested under the parent form's fields
+// to show them with the rest of the form's errors.
+
+#[test]
+fn invalid_rows_are_added_with_their_errors() {
+    let mut form = TeamForm::default();
+    let csv = "name,age\nJane,twenty\n,41\n";
+    let errors = match form.members.import_csv(csv.as_bytes(), MAPPING) {
+        Err(CsvImportError::InvalidRows(errors)) => errors,
+        other => panic!("Expected invalid rows, got {:?}", other),
+    };
+    assert_eq!(form.members.len(), 2);
+
+    let mut form_errors = ValidationErrors::new();
+    form_errors.extend_nested(errors, |(index, field)| {
+        TeamFormField::Members(index, field)
+    });
+    assert_eq!(
+        form_errors.get(&TeamFormField::Members(0, MemberFormField::Age)),
+        Some(&ParseError::NumberOutOfRange {
+            required_type: "an age".to_string(),
+            min: "0".to_string(),
+            max: "4294967295".to_string(),
+        })
+    );
+    assert_eq!(
+        form_errors.get(&TeamFormField::Members(1, MemberFormField::Name)),
+        Some(&ParseError::Required)
+    );
+}
+
+#[test]
+fn files_without_a_mapped_column_add_nothing() {
+    let mut form = TeamForm::default();
+    let result = form.members.import_csv("Name\nJane\n".as_bytes(), MAPPING);
+    assert!(matches!(result, Err(CsvImportError::MissingColumn(column)) if column == "Age"));
+    assert!(form.members.is_empty());
+}
@@ -1,3 +1,5 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
 use std::net::{IpAddr, Ipv4Addr};
 use structform::{
     derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
@@ -0,0 +1,251 @@
+#![cfg(all(feature = "time", feature = "text"))]
+
+use std::time::Duration;
+use structform::{
+    derive_form_input, format_date, impl_date_input, impl_datetime_input,
+    impl_text_input_with_stringops, impl_time_input, parse_relative_date, resolve_relative_date,
+    FormClock, MockClock, ParseAndFormat, ParseError, StructForm,
+};
+use time::{Date, Month, PrimitiveDateTime, Time};
+
+// This example shows how to use date and time inputs, with types from
+// version 0.3 of the `time` crate, which apps depend on themselves.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+// `impl_date_input`, `impl_time_input` and `impl_datetime_input`
+// parse ISO 8601 dates and times, which are the same as the values of
+// HTML `date`, `time` and `datetime-local` inputs. Optional fields are
+// `None` when the input is empty. Times are formatted without seconds
+// when they're zero, the way that browsers show them.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormDateInput}
+impl_date_input!(FormDateInput);
+
+derive_form_input! {FormTimeInput}
+impl_time_input!(FormTimeInput);
+
+derive_form_input! {FormDateTimeInput}
+impl_datetime_input!(FormDateTimeInput);
+
+#[derive(Debug, PartialEq, Eq)]
+struct Booking {
+    name: String,
+    date: Date,
+    arrival: Option<Time>,
+    reminder: Option<PrimitiveDateTime>,
+}
+
+impl Default for Booking {
+    fn default() -> Booking {
+        Booking {
+            name: String::new(),
+            date: Date::from_calendar_date(2000, Month::January, 1).unwrap(),
+            arrival: None,
+            reminder: None,
+        }
+    }
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Booking")]
+struct BookingForm {
+    name: FormTextInput<String>,
+    date: FormDateInput<Date>,
+    arrival: FormTimeInput<Option<Time>>,
+    reminder: FormDateTimeInput<Option<PrimitiveDateTime>>,
+}
+
+#[test]
+fn dates_and_times_are_parsed_from_html_input_values() {
+    let mut form = BookingForm::default();
+    form.set_input(BookingFormField::Name, "Justin".to_string());
+    form.set_input(BookingFormField::Date, "2021-12-31".to_string());
+    form.set_input(BookingFormField::Arrival, "13:45".to_string());
+    form.set_input(BookingFormField::Reminder, "2021-12-30T09:00".to_string());
+    let date = Date::from_calendar_date(2021, Month::December, 31).unwrap();
+    assert_eq!(
+        form.submit(),
+        Ok(Booking {
+            name: "Justin".to_string(),
+            date,
+            arrival: Some(Time::from_hms(13, 45, 0).unwrap()),
+            reminder: Some(PrimitiveDateTime::new(
+                Date::from_calendar_date(2021, Month::December, 30).unwrap(),
+                Time::from_hms(9, 0, 0).unwrap(),
+            )),
+        })
+    );
+}
+
+#[test]
+fn optional_dates_and_times_can_be_empty() {
+    let mut form = BookingForm::default();
+    form.set_input(BookingFormField::Name, "Justin".to_string());
+    form.set_input(BookingFormField::Date, "2021-12-31".to_string());
+    assert_eq!(form.submit().map(|booking| booking.arrival), Ok(None));
+    assert_eq!(form.date.validation_error(), None);
+}
+
+#[test]
+fn invalid_dates_are_errors() {
+    let mut form = BookingForm::default();
+    form.set_input(BookingFormField::Date, "2021-02-30".to_string());
+    form.set_input(BookingFormField::Arrival, "1pm".to_string());
+    assert_eq!(
+        form.date.validation_error(),
+        Some(&ParseError::InvalidFormat {
+            required_type: "a date, like 2021-12-31".to_string()
+        })
+    );
+    assert_eq!(
+        form.arrival.validation_error(),
+        Some(&ParseError::InvalidFormat {
+            required_type: "a time, like 13:45".to_string()
+        })
+    );
+}
+
+#[test]
+fn forms_show_dates_and_times_in_html_input_formats() {
+    let form = BookingForm::new(&Booking {
+        name: "Justin".to_string(),
+        date: Date::from_calendar_date(2021, Month::December, 31).unwrap(),
+        arrival: Some(Time::from_hms(13, 45, 30).unwrap()),
+        reminder: Some(PrimitiveDateTime::new(
+            Date::from_calendar_date(2021, Month::December, 30).unwrap(),
+            Time::from_hms(9, 0, 0).unwrap(),
+        )),
+    });
+    assert_eq!(form.date.input, "2021-12-31");
+    assert_eq!(form.arrival.input, "13:45:30");
+    assert_eq!(form.reminder.input, "2021-12-30T09:00");
+}
+
+// Apps that don't have HTML date inputs, like egui or terminal apps,
+// can give `impl_date_input` a format instead, to show dates the way
+// their users expect. `%Y`, `%m` and `%d` are the year, month and day.
+
+derive_form_input! {FormLocalDateInput}
+impl_date_input!(FormLocalDateInput, "%d/%m/%Y");
+
+#[derive(Debug, PartialEq, Eq)]
+struct Reminder {
+    date: Date,
+    follow_up: Option<Date>,
+}
+
+impl Default for Reminder {
+    fn default() -> Reminder {
+        Reminder {
+            date: Date::from_calendar_date(2000, Month::January, 1).unwrap(),
+            follow_up: None,
+        }
+    }
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Reminder")]
+struct ReminderForm {
+    date: FormLocalDateInput<Date>,
+    follow_up: FormLocalDateInput<Option<Date>>,
+}
+
+#[test]
+fn dates_can_be_parsed_and_shown_in_a_local_format() {
+    let mut form = ReminderForm::new(&Reminder {
+        date: Date::from_calendar_date(2021, Month::December, 31).unwrap(),
+        follow_up: None,
+    });
+    assert_eq!(form.date.input, "31/12/2021");
+    assert_eq!(form.follow_up.input, "");
+
+    form.set_input(ReminderFormField::FollowUp, "7/1/2022".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(Reminder {
+            date: Date::from_calendar_date(2021, Month::December, 31).unwrap(),
+            follow_up: Some(Date::from_calendar_date(2022, Month::January, 7).unwrap()),
+        })
+    );
+}
+
+#[test]
+fn dates_in_the_wrong_format_show_the_local_format() {
+    let mut form = ReminderForm::default();
+    form.set_input(ReminderFormField::Date, "2021-12-31".to_string());
+    assert_eq!(
+        form.date.validation_error(),
+        Some(&ParseError::InvalidFormat {
+            required_type: "a date, like 31/12/2021".to_string()
+        })
+    );
+}
+
+// Scheduling forms can also accept dates relative to today, like
+// `tomorrow`, `+3d` or `next monday`. `resolve_relative_date` turns
+// them into the date that they mean before they're set on the input,
+// so the input always holds an absolute date. Today is read from the
+// form's clock, which is a `MockClock` here.
+
+fn clock_on_new_years_eve() -> (MockClock, FormClock) {
+    let mock_clock = MockClock::new(Duration::from_secs(1_640_908_800)); // 2021-12-31, a Friday
+    (mock_clock.clone(), FormClock::new(mock_clock))
+}
+
+#[test]
+fn relative_dates_are_resolved_against_the_forms_clock() {
+    let (mock_clock, clock) = clock_on_new_years_eve();
+    let mut form = BookingForm::default();
+    form.set_clock(&clock);
+    form.set_input(BookingFormField::Name, "Justin".to_string());
+
+    form.set_input(BookingFormField::Date, resolve_relative_date("+3d", &clock));
+    assert_eq!(form.date.input, "2022-01-03");
+
+    mock_clock.advance(Duration::from_secs(24 * 60 * 60));
+    form.set_input(
+        BookingFormField::Date,
+        resolve_relative_date("Tomorrow", &clock),
+    );
+    assert_eq!(
+        form.submit().map(|booking| booking.date),
+        Ok(Date::from_calendar_date(2022, Month::January, 2).unwrap())
+    );
+}
+
+#[test]
+fn relative_dates_count_days_weeks_and_weekdays_from_today() {
+    let (_, clock) = clock_on_new_years_eve();
+    let date = |value| parse_relative_date(value, &clock).map(|date| format_date(&date));
+    assert_eq!(date("today"), Ok("2021-12-31".to_string()));
+    assert_eq!(date("yesterday"), Ok("2021-12-30".to_string()));
+    assert_eq!(date("-1d"), Ok("2021-12-30".to_string()));
+    assert_eq!(date("+2w"), Ok("2022-01-14".to_string()));
+    assert_eq!(date("next monday"), Ok("2022-01-03".to_string()));
+    assert_eq!(date("next friday"), Ok("2022-01-07".to_string()));
+    assert_eq!(date("2022-02-01"), Ok("2022-02-01".to_string()));
+    assert_eq!(
+        date("+3 days"),
+        Err(ParseError::InvalidFormat {
+            required_type: "a date, like 2021-12-31 or +3d".to_string()
+        })
+    );
+}
+
+#[test]
+fn invalid_relative_dates_are_left_for_the_input_to_show() {
+    let (_, clock) = clock_on_new_years_eve();
+    let mut form = BookingForm::default();
+    form.set_input(
+        BookingFormField::Date,
+        resolve_relative_date("next month", &clock),
+    );
+    assert_eq!(form.date.input, "next month");
+    assert!(form.date.validation_error().is_some());
+}
@@ -0,0 +1,105 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    FieldDiff, ParseAndFormat, StructForm, REDACTED,
+};
+
+// This example shows how to list what has changed on a form compared
+// to the saved model, for confirmations like "you changed these 3
+// settings" before saving.
+
+// This example builds on the [subforms example](./subforms_example.rs)
+// and the [summary example](./summary_example.rs). This example is
+// written assuming that you're already familiar with those examples,
+// so if not please refer to them first.
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Settings {
+    display_name: String,
+    api_key: String,
+    page_size: u32,
+    notifications: Vec<Notification>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct Notification {
+    email: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Settings")]
+struct SettingsForm {
+    display_name: FormTextInput<String>,
+    #[structform(sensitive)]
+    api_key: FormTextInput<String>,
+    page_size: FormNumberInput<u32>,
+    notifications: Vec<NotificationForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Notification")]
+struct NotificationForm {
+    email: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "a number", u32, u32);
+
+fn saved_settings() -> Settings {
+    Settings {
+        display_name: "Joe".to_string(),
+        api_key: "abc123".to_string(),
+        page_size: 20,
+        notifications: vec![Notification {
+            email: "joe@example.com".to_string(),
+        }],
+    }
+}
+
+// `diff` compares the form to a pristine model, and returns the field
+// path, old value and new value of each input that changed. Values are
+// formatted before they are compared, so typing " 20 " into a number
+// input that was 20 isn't a change. Sensitive values are redacted.
+
+#[test]
+fn the_diff_lists_each_changed_input() {
+    let saved = saved_settings();
+    let mut form = SettingsForm::new(&saved);
+    form.set_input(SettingsFormField::DisplayName, "Joseph".to_string());
+    form.set_input(SettingsFormField::ApiKey, "def456".to_string());
+    form.set_input(SettingsFormField::PageSize, " 20 ".to_string());
+    form.set_input_at_path("notifications[1].email", "jo@example.com".to_string());
+
+    assert_eq!(
+        form.diff(&saved),
+        vec![
+            FieldDiff {
+                path: "display_name".to_string(),
+                old_value: "Joe".to_string(),
+                new_value: "Joseph".to_string(),
+            },
+            FieldDiff {
+                path: "api_key".to_string(),
+                old_value: REDACTED.to_string(),
+                new_value: REDACTED.to_string(),
+            },
+            FieldDiff {
+                path: "notifications[1].email".to_string(),
+                old_value: "".to_string(),
+                new_value: "jo@example.com".to_string(),
+            },
+        ]
+    );
+}
+
+#[test]
+fn an_unchanged_form_has_no_diff() {
+    let saved = saved_settings();
+    let form = SettingsForm::new(&saved);
+
+    assert!(form.diff(&saved).is_empty());
+}
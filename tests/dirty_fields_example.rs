@@ -0,0 +1,120 @@
+#![cfg(feature = "text")]
+
+use structform::{derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm};
+
+// This example shows how to find which inputs have been changed, for
+// highlighting them in the view.
+
+// This example builds on the [unsaved changes
+// example](./unsaved_changes_example.rs). This example is written
+// assuming that you're already familiar with that example, so if not
+// please refer to that first.
+
+// `dirty_fields` lists every input whose raw input differs from what
+// the form started with, including inputs on subforms and list
+// entries, in the order that they're declared. It only compares raw
+// inputs, so it's cheap enough to call while rendering. Changing an
+// input back to what it was makes it clean again.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Profile {
+    display_name: String,
+    bio: String,
+    address: Address,
+    previous_addresses: Vec<Address>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Profile")]
+struct ProfileForm {
+    display_name: FormTextInput<String>,
+    bio: FormTextInput<String>,
+    #[structform(subform)]
+    address: AddressForm,
+    previous_addresses: Vec<AddressForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+fn profile() -> Profile {
+    Profile {
+        display_name: "Joe".to_string(),
+        bio: "Hi".to_string(),
+        address: Address {
+            city: "Johannesburg".to_string(),
+        },
+        previous_addresses: vec![
+            Address {
+                city: "Durban".to_string(),
+            },
+            Address {
+                city: "Pretoria".to_string(),
+            },
+        ],
+    }
+}
+
+#[test]
+fn changed_inputs_are_dirty() {
+    let mut form = ProfileForm::new(&profile());
+    assert_eq!(form.dirty_fields(), vec![]);
+
+    form.set_input(ProfileFormField::Bio, "Hello".to_string());
+    form.set_input(
+        ProfileFormField::Address(AddressFormField::City),
+        "Cape Town".to_string(),
+    );
+    form.set_input(
+        ProfileFormField::PreviousAddresses(1, AddressFormField::City),
+        "Polokwane".to_string(),
+    );
+    assert_eq!(
+        form.dirty_fields(),
+        vec![
+            ProfileFormField::Bio,
+            ProfileFormField::Address(AddressFormField::City),
+            ProfileFormField::PreviousAddresses(1, AddressFormField::City),
+        ]
+    );
+}
+
+#[test]
+fn changing_an_input_back_makes_it_clean() {
+    let mut form = ProfileForm::new(&profile());
+    form.set_input(ProfileFormField::DisplayName, "Joseph".to_string());
+    assert_eq!(form.dirty_fields(), vec![ProfileFormField::DisplayName]);
+
+    form.set_input(ProfileFormField::DisplayName, "Joe".to_string());
+    assert_eq!(form.dirty_fields(), vec![]);
+}
+
+#[test]
+fn new_list_entries_are_dirty_once_typed_in() {
+    let mut form = ProfileForm::new(&profile());
+    form.set_input(ProfileFormField::AddPreviousAddresses, String::new());
+    assert_eq!(form.dirty_fields(), vec![]);
+
+    form.set_input(
+        ProfileFormField::PreviousAddresses(2, AddressFormField::City),
+        "Kimberley".to_string(),
+    );
+    assert_eq!(
+        form.dirty_fields(),
+        vec![ProfileFormField::PreviousAddresses(
+            2,
+            AddressFormField::City
+        )]
+    );
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, Disclosure, ParseAndFormat, StructForm,
+};
+
+// This example shows how to show an optional subform as a section
+// that can be expanded and collapsed, like in an accordion.
+
+// This example builds on the [subforms example](./subforms_example.rs).
+// This example is written assuming that you're already familiar with
+// the subforms example, so if not please refer to that first.
+
+// A `Disclosure` keeps track of whether an optional subform's section
+// is expanded, separately from whether the subform is toggled on.
+// Collapsing a section keeps what was typed into it, and removing it
+// toggles the subform off. When the subform needs to be toggled, the
+// `Disclosure` returns the event to send to the form, so it would
+// usually be turned into a message in your app.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct User {
+    name: String,
+    postal_address: Option<Address>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "User")]
+struct UserForm {
+    name: FormTextInput<String>,
+    postal_address: Option<AddressForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+struct Model {
+    form: UserForm,
+    postal_address: Disclosure<UserFormField>,
+}
+
+fn click_header(model: &mut Model) {
+    if let Some((field, value)) = model.postal_address.click(&model.form.postal_address) {
+        model.form.set_input(field, value);
+    }
+}
+
+#[test]
+fn expanding_a_section_toggles_its_subform_on() {
+    let mut model = Model {
+        form: UserForm::default(),
+        postal_address: Disclosure::new(UserFormField::TogglePostalAddress),
+    };
+    assert!(!model.postal_address.is_expanded(&model.form.postal_address));
+
+    click_header(&mut model);
+    assert!(model.form.postal_address.is_some());
+    assert!(model.postal_address.is_expanded(&model.form.postal_address));
+    assert_eq!(
+        model
+            .postal_address
+            .aria_expanded(&model.form.postal_address),
+        "true"
+    );
+}
+
+#[test]
+fn collapsing_a_section_keeps_its_inputs() {
+    let mut model = Model {
+        form: UserForm::default(),
+        postal_address: Disclosure::new(UserFormField::TogglePostalAddress),
+    };
+    click_header(&mut model);
+    model.form.set_input(
+        UserFormField::PostalAddress(AddressFormField::City),
+        "Johannesburg".to_string(),
+    );
+
+    click_header(&mut model);
+    assert!(!model.postal_address.is_expanded(&model.form.postal_address));
+    assert_eq!(
+        model.form.postal_address.as_ref().unwrap().city.input,
+        "Johannesburg"
+    );
+
+    click_header(&mut model);
+    assert!(model.postal_address.is_expanded(&model.form.postal_address));
+    assert_eq!(
+        model.form.postal_address.as_ref().unwrap().city.input,
+        "Johannesburg"
+    );
+}
+
+#[test]
+fn removing_a_section_toggles_its_subform_off() {
+    let mut model = Model {
+        form: UserForm::new(&User {
+            name: "Justin".to_string(),
+            postal_address: Some(Address {
+                city: "Johannesburg".to_string(),
+            }),
+        }),
+        postal_address: Disclosure::new(UserFormField::TogglePostalAddress),
+    };
+
+    // Sections start collapsed, even if their subform is on.
+    assert!(!model.postal_address.is_expanded(&model.form.postal_address));
+
+    let event = model.postal_address.remove(&model.form.postal_address);
+    assert_eq!(
+        event,
+        Some((UserFormField::TogglePostalAddress, String::new()))
+    );
+    let (field, value) = event.unwrap();
+    model.form.set_input(field, value);
+    assert!(model.form.postal_address.is_none());
+    assert_eq!(
+        model.form.submit(),
+        Ok(User {
+            name: "Justin".to_string(),
+            postal_address: None,
+        })
+    );
+}
@@ -0,0 +1,130 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    FormDraft, ParseAndFormat, ParseError, StringOr, StructForm,
+};
+
+// This example shows how to add a "Save draft" button to a form,
+// which works even when some of the inputs are empty or invalid.
+
+// This example builds on the [draft values example](./draft_values_example.rs).
+// This example is written assuming that you're already familiar with
+// the draft values example, so if not please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Product {
+    name: String,
+    quantity: u32,
+}
+
+// The `draft_model` annotation generates a second struct, with a
+// `StringOr` for each input on the form. The form gets a
+// `submit_draft` function, which never fails: inputs that parse are
+// saved as their value, and the rest are saved as they were typed.
+// `restore_draft` puts a saved draft back into the form. `submit`
+// still requires every input to be valid. These functions are on the
+// `FormDraft` trait, so it needs to be imported to call them.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Product", draft_model = "ProductDraft")]
+struct ProductForm {
+    name: FormTextInput<String>,
+    quantity: FormNumberInput<u32>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "a quantity", u32, u32);
+
+#[test]
+fn drafts_can_be_saved_and_restored_with_invalid_inputs() {
+    let mut form = ProductForm::default();
+    form.set_input(ProductFormField::Quantity, "a dozen".to_string());
+
+    let draft = form.submit_draft();
+    assert_eq!(
+        draft,
+        ProductDraft {
+            name: StringOr::Raw("".to_string()),
+            quantity: StringOr::Raw("a dozen".to_string()),
+        }
+    );
+    assert_eq!(form.name.validation_error(), None);
+
+    let mut restored = ProductForm::default();
+    restored.restore_draft(&draft);
+    assert_eq!(restored.quantity.input, "a dozen".to_string());
+    assert_eq!(restored.submit(), Err(ParseError::Required));
+
+    restored.set_input(ProductFormField::Name, "Widget".to_string());
+    restored.set_input(ProductFormField::Quantity, "12".to_string());
+    assert_eq!(
+        restored.submit_draft(),
+        ProductDraft {
+            name: StringOr::Value("Widget".to_string()),
+            quantity: StringOr::Value(12),
+        }
+    );
+    assert_eq!(
+        restored.submit(),
+        Ok(Product {
+            name: "Widget".to_string(),
+            quantity: 12,
+        })
+    );
+}
+
+// Subforms, optional subforms and lists of subforms are saved as their
+// own drafts, so they need a `draft_model` annotation too. Restoring a
+// draft recreates the list entries and optional subforms that were in
+// it. Map subforms and lazy lists can't be used in a form with a draft
+// model.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Order {
+    reference: String,
+    lines: Vec<Product>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Order", draft_model = "OrderDraft")]
+struct OrderForm {
+    reference: FormTextInput<String>,
+    lines: Vec<ProductForm>,
+}
+
+#[test]
+fn drafts_include_subforms() {
+    let mut form = OrderForm::default();
+    form.set_input(OrderFormField::Reference, "PO-1".to_string());
+    form.set_input(OrderFormField::AddLines, "".to_string());
+    form.set_input(OrderFormField::AddLines, "".to_string());
+    form.set_input(
+        OrderFormField::Lines(0, ProductFormField::Name),
+        "Widget".to_string(),
+    );
+    form.set_input(
+        OrderFormField::Lines(1, ProductFormField::Quantity),
+        "a dozen".to_string(),
+    );
+
+    let draft = form.submit_draft();
+    assert_eq!(
+        draft.lines[1],
+        ProductDraft {
+            name: StringOr::Raw("".to_string()),
+            quantity: StringOr::Raw("a dozen".to_string()),
+        }
+    );
+
+    let mut restored = OrderForm::default();
+    restored.restore_draft(&draft);
+    assert_eq!(restored.reference.input, "PO-1".to_string());
+    assert_eq!(restored.lines.len(), 2);
+    assert_eq!(restored.lines[0].name.input, "Widget".to_string());
+    assert_eq!(restored.lines[1].quantity.input, "a dozen".to_string());
+    assert_eq!(restored.submit_draft(), draft);
+}
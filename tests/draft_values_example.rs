@@ -0,0 +1,81 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_string_or_input,
+    impl_text_input_with_stringops, ParseAndFormat, ParseError, StringOr, StructForm,
+};
+
+// This example shows how to let a form be submitted while some of its
+// values are still invalid, for import and review workflows where
+// drafts need to be saved as they are.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+// A `StringOr<T>` in the model holds either a valid `T`, or the raw
+// input that didn't parse as one.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct ImportedProduct {
+    name: String,
+    quantity: StringOr<u32>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "ImportedProduct")]
+struct ImportedProductForm {
+    name: FormTextInput<String>,
+    quantity: FormNumberInput<StringOr<u32>>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// `impl_string_or_input` lets an input hold a `StringOr` of any type
+// that it already parses. Invalid input is kept as `StringOr::Raw`
+// instead of failing, and `draft_error` says why it isn't valid yet.
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "a quantity", u32, u32);
+impl_string_or_input!(FormNumberInput);
+
+#[test]
+fn invalid_values_are_submitted_as_raw_strings() {
+    let mut form = ImportedProductForm::default();
+    form.set_input(ImportedProductFormField::Name, "Widget".to_string());
+    form.set_input(ImportedProductFormField::Quantity, "a dozen".to_string());
+
+    assert_eq!(
+        form.submit(),
+        Ok(ImportedProduct {
+            name: "Widget".to_string(),
+            quantity: StringOr::Raw("a dozen".to_string()),
+        })
+    );
+    assert_eq!(
+        form.quantity.draft_error(),
+        Some(ParseError::NumberOutOfRange {
+            required_type: "a quantity".to_string(),
+            min: "0".to_string(),
+            max: "4294967295".to_string(),
+        })
+    );
+
+    // Once the value is fixed, it is submitted as a `StringOr::Value`.
+    form.set_input(ImportedProductFormField::Quantity, "12".to_string());
+    assert_eq!(form.quantity.draft_error(), None);
+    assert_eq!(
+        form.submit().map(|product| product.quantity),
+        Ok(StringOr::Value(12))
+    );
+}
+
+#[test]
+fn raw_strings_are_shown_as_they_were_saved() {
+    let form = ImportedProductForm::new(&ImportedProduct {
+        name: "Widget".to_string(),
+        quantity: StringOr::Raw("a dozen".to_string()),
+    });
+    assert_eq!(form.quantity.input, "a dozen".to_string());
+}
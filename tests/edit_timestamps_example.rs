@@ -0,0 +1,94 @@
+#![cfg(all(feature = "text", feature = "edit-timestamps"))]
+
+use std::time::Duration;
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, Clock, FormClock, MockClock, ParseAndFormat,
+    StructForm, SystemClock,
+};
+
+// This example shows how inputs can record when they were last
+// edited, with the `edit-timestamps` feature. Without it, inputs don't
+// carry a timestamp or a clock.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct LoginData {
+    username: String,
+    password: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "LoginData")]
+struct LoginForm {
+    username: FormTextInput<String>,
+    password: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// By default, the system time is used, or JavaScript's `Date.now()`
+// in the browser with the `web` feature. You can give a form another
+// `Clock` to use with `set_clock`. In tests, `MockClock` is a clock
+// that you control. Each form has its own clock, so tests that run
+// at the same time don't affect each other.
+
+#[test]
+fn inputs_record_when_they_were_last_edited() {
+    let clock = MockClock::new(Duration::from_secs(1000));
+    let mut form = LoginForm::default();
+    form.set_clock(&FormClock::new(clock.clone()));
+    assert_eq!(form.username.edit_timestamp.last_edited_at(), None);
+    assert_eq!(form.username.edit_timestamp.time_since_edit(), None);
+
+    form.set_input(LoginFormField::Username, "joe".to_string());
+    assert_eq!(
+        form.username.edit_timestamp.last_edited_at(),
+        Some(Duration::from_secs(1000))
+    );
+
+    clock.advance(Duration::from_secs(120));
+    assert_eq!(
+        form.username.edit_timestamp.time_since_edit(),
+        Some(Duration::from_secs(120))
+    );
+    assert_eq!(form.password.edit_timestamp.time_since_edit(), None);
+
+    form.username.clear();
+    assert_eq!(form.username.edit_timestamp.last_edited_at(), None);
+}
+
+#[test]
+fn the_system_clock_is_used_by_default() {
+    let mut form = LoginForm::default();
+    form.set_input(LoginFormField::Username, "joe".to_string());
+    let last_edited_at = form.username.edit_timestamp.last_edited_at().unwrap();
+    assert!(last_edited_at <= SystemClock.now());
+    assert!(last_edited_at > Duration::from_secs(1_600_000_000));
+}
+
+#[test]
+fn each_form_has_its_own_clock() {
+    let mut form = LoginForm::default();
+    form.set_clock(&FormClock::new(MockClock::new(Duration::from_secs(1000))));
+
+    // Other forms still use the system clock.
+    let mut other_form = LoginForm::default();
+    other_form.set_input(LoginFormField::Username, "jane".to_string());
+    assert!(
+        other_form.username.edit_timestamp.last_edited_at().unwrap()
+            > Duration::from_secs(1_600_000_000)
+    );
+
+    // The clock goes wherever the form goes, including other threads.
+    let last_edited_at = std::thread::spawn(move || {
+        form.set_input(LoginFormField::Username, "joe".to_string());
+        form.username.edit_timestamp.last_edited_at()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(last_edited_at, Some(Duration::from_secs(1000)));
+}
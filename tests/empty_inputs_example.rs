@@ -0,0 +1,89 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows how to choose what an empty input means on each
+// field of a form, without deriving a different input for each
+// choice.
+
+// This example builds on the [validation example](./validation_example.rs).
+// This example is written assuming that you're already familiar with
+// the validation example, so if not please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Order {
+    product: String,
+    quantity: u32,
+    gift_message: Option<String>,
+    reference: String,
+}
+
+// The `empty` annotation decides how an empty input is submitted,
+// whatever its input type's parsing does with empty input:
+//
+// - "default" submits the type's default value, like 0 for numbers.
+// - "none" submits `None`, for optional values.
+// - "required" fails with `ParseError::Required`, even for types that
+//   would otherwise accept an empty string.
+//
+// Inputs without the annotation keep their input type's behaviour.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Order")]
+struct OrderForm {
+    product: FormTextInput<String>,
+    #[structform(empty = "default")]
+    quantity: FormNumberInput<u32>,
+    #[structform(empty = "none")]
+    gift_message: FormTextInput<Option<String>>,
+    #[structform(empty = "required")]
+    reference: FormPlainInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "a quantity", u32, u32);
+
+// This input accepts anything, including an empty string.
+
+derive_form_input! {FormPlainInput}
+
+impl ParseAndFormat<String> for FormPlainInput<String> {
+    fn parse(value: &str) -> Result<String, ParseError> {
+        Ok(value.to_string())
+    }
+
+    fn format(value: &String) -> String {
+        value.clone()
+    }
+}
+
+#[test]
+fn empty_inputs_follow_their_field_annotation() {
+    let mut form = OrderForm::default();
+    form.set_input(OrderFormField::Product, "Widget".to_string());
+    form.set_input(OrderFormField::Quantity, "".to_string());
+    assert_eq!(form.quantity.validation_error(), None);
+
+    assert_eq!(form.submit(), Err(ParseError::Required));
+    assert_eq!(
+        form.reference.validation_error(),
+        Some(&ParseError::Required)
+    );
+
+    form.set_input_str(OrderFormField::Reference, "PO-123");
+    assert_eq!(
+        form.submit(),
+        Ok(Order {
+            product: "Widget".to_string(),
+            quantity: 0,
+            gift_message: None,
+            reference: "PO-123".to_string(),
+        })
+    );
+}
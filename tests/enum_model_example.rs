@@ -0,0 +1,293 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, FormFields, FormVariants, ParseAndFormat,
+    ParseError, StructForm,
+};
+
+// This example shows how to create a form for a model that is an enum,
+// where the user picks a variant and then fills in its details.
+
+// This example builds on the [subforms example](./subforms_example.rs).
+// This example is written assuming that you're already familiar with
+// the subforms example, so if not please refer to that first.
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PaymentMethod {
+    Card(CardDetails),
+    Eft(EftDetails),
+    Cash,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct CardDetails {
+    cardholder: String,
+    number: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct EftDetails {
+    bank: String,
+    account_number: String,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Order {
+    reference: String,
+    payment_method: PaymentMethod,
+}
+
+impl Default for Order {
+    fn default() -> Order {
+        Order {
+            reference: String::new(),
+            payment_method: PaymentMethod::Cash,
+        }
+    }
+}
+
+// The form for an enum model is also an enum, with a variant for each
+// of the model's variants. Variants with data hold a subform for that
+// data, and variants without data have no fields either. With
+// `#[structform(default)]`, `Default` is generated too, starting on the
+// first variant.
+//
+// Variants are labelled in sentence case, or with
+// `#[structform(label = "...")]`. They're listed and selected with the
+// `FormVariants` trait.
+
+#[derive(Clone, StructForm)]
+#[structform(model = "PaymentMethod", default)]
+enum PaymentMethodForm {
+    #[structform(label = "Credit card")]
+    Card(CardDetailsForm),
+    Eft(EftDetailsForm),
+    Cash,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "CardDetails")]
+struct CardDetailsForm {
+    cardholder: FormTextInput<String>,
+    number: FormTextInput<String>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "EftDetails")]
+struct EftDetailsForm {
+    bank: FormTextInput<String>,
+    account_number: FormTextInput<String>,
+}
+
+// An enum form can be used as a subform like any other form.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Order")]
+struct OrderForm {
+    reference: FormTextInput<String>,
+    #[structform(subform)]
+    payment_method: PaymentMethodForm,
+}
+
+// These derivations generate the following field definitions. The
+// `Variant` field picks the variant, using the variant's name as the
+// value, and the other fields set inputs on the selected variant.
+// ```
+// pub enum PaymentMethodFormField {
+//     Variant,
+//     Card(CardDetailsFormField),
+//     Eft(EftDetailsFormField),
+// }
+// ```
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[test]
+fn variants_can_be_listed_for_a_dropdown() {
+    assert_eq!(
+        PaymentMethodForm::VARIANTS,
+        &[("Card", "Credit card"), ("Eft", "Eft"), ("Cash", "Cash")][..]
+    );
+    assert_eq!(PaymentMethodForm::default().variant(), "Card");
+}
+
+#[test]
+fn picking_a_variant_and_filling_it_in() {
+    let mut form = OrderForm::default();
+    form.set_input(OrderFormField::Reference, "INV-1".to_string());
+    form.set_input(
+        OrderFormField::PaymentMethod(PaymentMethodFormField::Variant),
+        "Eft".to_string(),
+    );
+    form.set_input(
+        OrderFormField::PaymentMethod(PaymentMethodFormField::Eft(EftDetailsFormField::Bank)),
+        "StructBank".to_string(),
+    );
+    form.set_input(
+        OrderFormField::PaymentMethod(PaymentMethodFormField::Eft(
+            EftDetailsFormField::AccountNumber,
+        )),
+        "12345".to_string(),
+    );
+
+    assert_eq!(
+        form.submit(),
+        Ok(Order {
+            reference: "INV-1".to_string(),
+            payment_method: PaymentMethod::Eft(EftDetails {
+                bank: "StructBank".to_string(),
+                account_number: "12345".to_string(),
+            }),
+        })
+    );
+}
+
+#[test]
+fn only_the_selected_variant_is_validated() {
+    let mut form = PaymentMethodForm::default();
+    assert_eq!(form.submit(), Err(ParseError::Required));
+
+    form.set_input(PaymentMethodFormField::Variant, "Cash".to_string());
+    assert_eq!(form.submit(), Ok(PaymentMethod::Cash));
+}
+
+#[test]
+fn inputs_for_other_variants_are_ignored() {
+    let mut form = PaymentMethodForm::default();
+    form.set_input(
+        PaymentMethodFormField::Eft(EftDetailsFormField::Bank),
+        "StructBank".to_string(),
+    );
+    assert_eq!(form.variant(), "Card");
+    assert!(form.is_empty());
+
+    // Unknown variants are ignored too.
+    form.set_input(PaymentMethodFormField::Variant, "Cheque".to_string());
+    assert_eq!(form.variant(), "Card");
+}
+
+#[test]
+fn switching_variants_starts_with_an_empty_subform() {
+    let mut form = PaymentMethodForm::new(&PaymentMethod::Card(CardDetails {
+        cardholder: "J Wernick".to_string(),
+        number: "4111".to_string(),
+    }));
+
+    // Selecting the variant that's already selected keeps its inputs.
+    form.set_input(PaymentMethodFormField::Variant, "Card".to_string());
+    assert!(!form.is_empty());
+
+    form.set_input(PaymentMethodFormField::Variant, "Eft".to_string());
+    form.set_input(PaymentMethodFormField::Variant, "Card".to_string());
+    assert!(form.is_empty());
+}
+
+#[test]
+fn field_paths_include_the_variant() {
+    let mut form = PaymentMethodForm::default();
+    assert!(!form.set_input_at_path("cash", "".to_string()));
+    assert!(form.set_input_at_path("eft.bank", "StructBank".to_string()));
+    assert_eq!(form.variant(), "Eft");
+    assert_eq!(
+        form.inputs_by_path(),
+        vec![
+            ("variant".to_string(), "Eft".to_string()),
+            ("eft.bank".to_string(), "StructBank".to_string()),
+            ("eft.account_number".to_string(), "".to_string()),
+        ]
+    );
+
+    let mut copy = PaymentMethodForm::default();
+    copy.apply_query_string(&form.to_urlencoded());
+    assert_eq!(copy.inputs_by_path(), form.inputs_by_path());
+
+    assert_eq!(
+        form.summary(),
+        vec![
+            ("Variant".to_string(), "Eft".to_string()),
+            ("Eft - Bank".to_string(), "StructBank".to_string()),
+            ("Eft - Account number".to_string(), "".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn a_path_that_isnt_an_input_keeps_the_variant() {
+    let mut form = PaymentMethodForm::default();
+    assert!(form.set_input_at_path("card.number", "4111".to_string()));
+
+    // The variant is only switched once the rest of the path is found
+    // in the other variant's subform.
+    assert!(!form.set_input_at_path("eft.swift_code", "STRBZAJJ".to_string()));
+    assert_eq!(form.variant(), "Card");
+    assert_eq!(
+        form.inputs_by_path()[2],
+        ("card.number".to_string(), "4111".to_string())
+    );
+}
+
+#[test]
+fn fields_list_every_variant() {
+    let paths: Vec<String> = PaymentMethodForm::fields()
+        .map(|field| field.path)
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "variant",
+            "card.cardholder",
+            "card.number",
+            "eft.bank",
+            "eft.account_number"
+        ]
+    );
+    assert_eq!(
+        PaymentMethodForm::field_path(&PaymentMethodFormField::Eft(
+            EftDetailsFormField::AccountNumber
+        )),
+        "eft.account_number"
+    );
+}
+
+#[test]
+fn fields_and_the_field_table_both_start_with_the_variant() {
+    let names: Vec<&str> = PaymentMethodForm::FIELDS
+        .iter()
+        .map(|field| field.name)
+        .collect();
+    assert_eq!(names, vec!["variant", "card", "eft"]);
+    assert_eq!(
+        PaymentMethodForm::fields().next().unwrap().meta.name,
+        "variant"
+    );
+}
+
+// Switching variants makes the `Variant` field dirty. An enum form
+// can't remember which variant it was created with, so it's compared
+// with the first variant, which is the one that the form starts on and
+// resets to. To compare with the variant of the model that a form was
+// created from, use `diff` with that model.
+
+#[test]
+fn switching_variants_is_dirty() {
+    let mut form = PaymentMethodForm::default();
+    assert_eq!(form.dirty_fields(), vec![]);
+
+    form.set_input(PaymentMethodFormField::Variant, "Eft".to_string());
+    form.set_input(
+        PaymentMethodFormField::Eft(EftDetailsFormField::Bank),
+        "StructBank".to_string(),
+    );
+    assert_eq!(
+        form.dirty_fields(),
+        vec![
+            PaymentMethodFormField::Variant,
+            PaymentMethodFormField::Eft(EftDetailsFormField::Bank),
+        ]
+    );
+
+    form.reset();
+    assert_eq!(form.variant(), "Card");
+    assert_eq!(form.dirty_fields(), vec![]);
+}
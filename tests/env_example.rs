@@ -0,0 +1,98 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    ParseAndFormat, StructForm,
+};
+
+// This example shows how to fill in a form from environment
+// variables, so that a form for editing configuration can start from
+// the values of the environment that is deployed.
+
+// This example builds on the [command line flags
+// example](./cli_args_example.rs). This example is written assuming
+// that you're already familiar with that example, so if not please
+// refer to that first.
+
+// Each input's variable is its field path in upper case, with a
+// prefix, and with `_` between segments and around list indexes. With
+// the prefix `MYAPP`, `smtp_port` is `MYAPP_SMTP_PORT` and
+// `relays[0].host` is `MYAPP_RELAYS_0_HOST`. Variables are matched
+// against the form's `FIELDS` table, so field names with underscores
+// in them aren't ambiguous. Variables that don't match an input are
+// ignored, since the environment has plenty of those.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "a port number", u16, u16);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct MailConfig {
+    smtp_host: String,
+    smtp_port: u16,
+    relays: Vec<Relay>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Relay {
+    host: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "MailConfig")]
+struct MailConfigForm {
+    smtp_host: FormTextInput<String>,
+    smtp_port: FormNumberInput<u16>,
+    relays: Vec<RelayForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Relay")]
+struct RelayForm {
+    host: FormTextInput<String>,
+}
+
+fn vars(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+    vars.iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn variables_with_the_prefix_fill_in_the_form() {
+    let mut form = MailConfigForm::default();
+    form.apply_env_vars(
+        "MYAPP",
+        vars(&[
+            ("MYAPP_SMTP_HOST", "mail.example.com"),
+            ("MYAPP_SMTP_PORT", "587"),
+            ("MYAPP_RELAYS_0_HOST", "relay.example.com"),
+            ("MYAPP_SMTP_PROT", "25"),
+            ("OTHERAPP_SMTP_HOST", "other.example.com"),
+            ("PATH", "/usr/bin"),
+        ]),
+    );
+    assert_eq!(
+        form.submit(),
+        Ok(MailConfig {
+            smtp_host: "mail.example.com".to_string(),
+            smtp_port: 587,
+            relays: vec![Relay {
+                host: "relay.example.com".to_string(),
+            }],
+        })
+    );
+}
+
+// `apply_env` reads the variables from the process's environment.
+
+#[test]
+fn variables_are_read_from_the_environment() {
+    std::env::set_var("STRUCTFORM_ENV_EXAMPLE_SMTP_PORT", "2525");
+    let mut form = MailConfigForm::default();
+    form.apply_env("STRUCTFORM_ENV_EXAMPLE");
+    assert_eq!(form.smtp_port.input, "2525");
+    assert_eq!(form.smtp_host.input, "");
+}
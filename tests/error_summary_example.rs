@@ -0,0 +1,136 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, field_id, impl_text_input_with_stringops, DefaultTheme, ErrorSummary,
+    FormFields, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows how to show a summary of every error on a form,
+// with links to the inputs that need fixing.
+
+// This example builds on the [showing every error at once
+// example](./validation_errors_example.rs). This example is written
+// assuming that you're already familiar with that example, so if not
+// please refer to that first.
+
+// `errors_by_path` gives the errors from `validate` keyed by field
+// path. An `ErrorSummary` pairs each of them with its input's label,
+// and the `id` that the input should be rendered with, which comes
+// from `field_id`. Its links then jump to the inputs. In a browser,
+// the `web` feature's `focus_field` can also move the focus to the
+// input when a link is clicked.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct User {
+    name: String,
+    billing_address: Address,
+    addresses: Vec<Address>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Address {
+    street_address: String,
+    city: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "User")]
+struct UserForm {
+    #[structform(label = "Full name")]
+    name: FormTextInput<String>,
+    #[structform(subform)]
+    billing_address: AddressForm,
+    addresses: Vec<AddressForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    street_address: FormTextInput<String>,
+    city: FormTextInput<String>,
+}
+
+#[test]
+fn fields_have_paths() {
+    assert_eq!(UserForm::field_path(&UserFormField::Name), "name");
+    assert_eq!(
+        UserForm::field_path(&UserFormField::BillingAddress(AddressFormField::City)),
+        "billing_address.city"
+    );
+    assert_eq!(
+        UserForm::field_path(&UserFormField::Addresses(1, AddressFormField::City)),
+        "addresses[1].city"
+    );
+    assert_eq!(
+        UserForm::field_path(&UserFormField::RemoveAddresses(1)),
+        "addresses[1]"
+    );
+}
+
+#[test]
+fn summary_links_every_error_to_its_input() {
+    let mut form = UserForm::default();
+    form.set_input(
+        UserFormField::BillingAddress(AddressFormField::StreetAddress),
+        "1 Main Road".to_string(),
+    );
+    form.set_input(UserFormField::AddAddresses, String::new());
+    form.set_input(
+        UserFormField::Addresses(0, AddressFormField::StreetAddress),
+        "2 Side Street".to_string(),
+    );
+
+    let summary = ErrorSummary::new(UserForm::FIELDS, form.errors_by_path());
+    let labels: Vec<&str> = summary
+        .entries
+        .iter()
+        .map(|entry| entry.label.as_str())
+        .collect();
+    assert_eq!(
+        labels,
+        vec!["Full name", "Billing address - City", "Addresses 1 - City"]
+    );
+    assert_eq!(summary.entries[2].id, field_id("addresses[0].city"));
+    assert_eq!(summary.entries[2].id, "structform-addresses-0-city");
+
+    assert_eq!(
+        summary.to_html(&DefaultTheme),
+        "<ul class=\"structform-error\" role=\"alert\">\n\
+         <li><a href=\"#structform-name\">Full name: This field is required.</a></li>\n\
+         <li><a href=\"#structform-billing_address-city\">Billing address - City: This field is required.</a></li>\n\
+         <li><a href=\"#structform-addresses-0-city\">Addresses 1 - City: This field is required.</a></li>\n\
+         </ul>\n"
+    );
+}
+
+#[test]
+fn summaries_can_show_errors_from_a_server() {
+    let summary = ErrorSummary::new(
+        UserForm::FIELDS,
+        vec![(
+            "name".to_string(),
+            ParseError::External("<b>Already</b> registered".to_string()),
+        )],
+    );
+    assert!(summary
+        .to_html(&DefaultTheme)
+        .contains("Full name: &lt;b&gt;Already&lt;/b&gt; registered"));
+}
+
+#[test]
+fn valid_forms_have_no_summary() {
+    let form = UserForm::new(&User {
+        name: "Justin".to_string(),
+        billing_address: Address {
+            street_address: "1 Main Road".to_string(),
+            city: "Johannesburg".to_string(),
+        },
+        addresses: vec![],
+    });
+    let summary = ErrorSummary::new(UserForm::FIELDS, form.errors_by_path());
+    assert!(summary.is_empty());
+    assert_eq!(summary.to_html(&DefaultTheme), "");
+}
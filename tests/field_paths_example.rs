@@ -0,0 +1,109 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, parse_query_string, ParseAndFormat,
+    StructForm,
+};
+
+// This example shows how to convert between fields and field paths,
+// for server side frameworks that receive a submitted HTML form as
+// string keys.
+
+// This example builds on the [query string
+// example](./query_string_example.rs). This example is written
+// assuming that you're already familiar with that example, so if not
+// please refer to that first.
+
+// Every field enum has `to_path` and `from_path`, which convert
+// fields to and from paths like `addresses[2].city`. Use the paths as
+// the `name` of each HTML input, and then map the submitted keys back
+// onto fields to pass to `set_input`. `from_path` only finds inputs,
+// so a key like `addresses` can't add a list entry.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct UserDetails {
+    username: String,
+    primary_address: Address,
+    previous_addresses: Vec<Address>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "UserDetails")]
+struct UserDetailsForm {
+    username: FormTextInput<String>,
+    #[structform(subform)]
+    primary_address: AddressForm,
+    previous_addresses: Vec<AddressForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+#[test]
+fn fields_convert_to_and_from_paths() {
+    let fields = vec![
+        UserDetailsFormField::Username,
+        UserDetailsFormField::PrimaryAddress(AddressFormField::City),
+        UserDetailsFormField::PreviousAddresses(2, AddressFormField::City),
+    ];
+    for field in fields {
+        assert_eq!(
+            UserDetailsFormField::from_path(&field.to_path()),
+            Some(field)
+        );
+    }
+    assert_eq!(
+        UserDetailsFormField::PreviousAddresses(2, AddressFormField::City).to_path(),
+        "previous_addresses[2].city"
+    );
+}
+
+#[test]
+fn only_inputs_are_found() {
+    assert_eq!(
+        UserDetailsFormField::AddPreviousAddresses.to_path(),
+        "previous_addresses"
+    );
+    assert_eq!(UserDetailsFormField::from_path("previous_addresses"), None);
+    assert_eq!(
+        UserDetailsFormField::from_path("previous_addresses[0]"),
+        None
+    );
+    assert_eq!(UserDetailsFormField::from_path("primary_address"), None);
+    assert_eq!(UserDetailsFormField::from_path("nickname"), None);
+}
+
+#[test]
+fn submitted_keys_map_onto_set_input() {
+    let body = "username=justin&primary_address.city=Johannesburg&admin=true";
+    let mut form = UserDetailsForm::default();
+    let mut unknown_keys = Vec::new();
+    for (key, value) in parse_query_string(body) {
+        match UserDetailsFormField::from_path(&key) {
+            Some(field) => form.set_input(field, value),
+            None => unknown_keys.push(key),
+        }
+    }
+    assert_eq!(unknown_keys, vec!["admin"]);
+    assert_eq!(
+        form.submit(),
+        Ok(UserDetails {
+            username: "justin".to_string(),
+            primary_address: Address {
+                city: "Johannesburg".to_string(),
+            },
+            previous_addresses: vec![],
+        })
+    );
+}
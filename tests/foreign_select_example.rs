@@ -0,0 +1,117 @@
+#![cfg(feature = "select")]
+
+use structform::{
+    derive_form_input, impl_select_input_for_enum, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows how to let users pick a value of an enum from
+// another crate, like with a dropdown.
+
+// This example builds on the [select example](./select_example.rs).
+// This example is written assuming that you're already familiar with
+// the select example, so if not please refer to that first.
+
+// `#[derive(FormSelect)]` can only be used on enums in your own crate.
+// For enums from other crates, `impl_select_input_for_enum` implements
+// `ParseAndFormat` on a select input from a list of the enum's
+// variants and their labels. It also adds a `variants` function to the
+// input, for rendering the dropdown's options.
+
+mod other_crate {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Weekday {
+        Monday,
+        Tuesday,
+        Wednesday,
+    }
+}
+
+use other_crate::Weekday;
+
+derive_form_input! {FormSelectInput}
+impl_select_input_for_enum!(FormSelectInput, Weekday, [
+    Monday => "Monday",
+    Tuesday => "Tuesday",
+    Wednesday => "Wednesday (half day)",
+]);
+
+#[derive(Debug, PartialEq, Eq)]
+struct Meeting {
+    day: Weekday,
+    follow_up_day: Option<Weekday>,
+}
+
+impl Default for Meeting {
+    fn default() -> Meeting {
+        Meeting {
+            day: Weekday::Monday,
+            follow_up_day: None,
+        }
+    }
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Meeting")]
+struct MeetingForm {
+    day: FormSelectInput<Weekday>,
+    follow_up_day: FormSelectInput<Option<Weekday>>,
+}
+
+#[test]
+fn variants_list_every_option_with_a_label() {
+    assert_eq!(
+        FormSelectInput::<Weekday>::variants(),
+        &[
+            ("Monday", "Monday"),
+            ("Tuesday", "Tuesday"),
+            ("Wednesday", "Wednesday (half day)"),
+        ][..]
+    );
+    assert_eq!(
+        FormSelectInput::<Option<Weekday>>::variants(),
+        FormSelectInput::<Weekday>::variants()
+    );
+}
+
+#[test]
+fn selecting_an_option_sets_the_enum() {
+    let mut form = MeetingForm::default();
+    form.set_input(MeetingFormField::Day, "Sunday".to_string());
+    assert_eq!(
+        form.day.validation_error(),
+        Some(&ParseError::InvalidFormat {
+            required_type: "one of Monday, Tuesday, Wednesday (half day)".to_string(),
+        })
+    );
+
+    form.set_input(MeetingFormField::Day, "Wednesday".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(Meeting {
+            day: Weekday::Wednesday,
+            follow_up_day: None,
+        })
+    );
+
+    form.set_input(MeetingFormField::FollowUpDay, "Tuesday".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(Meeting {
+            day: Weekday::Wednesday,
+            follow_up_day: Some(Weekday::Tuesday),
+        })
+    );
+}
+
+#[test]
+fn enums_format_as_their_variant_name() {
+    assert_eq!(
+        FormSelectInput::<Weekday>::format(&Weekday::Tuesday),
+        "Tuesday".to_string()
+    );
+    assert_eq!(FormSelectInput::<Option<Weekday>>::format(&None), "");
+    assert_eq!(
+        FormSelectInput::<Weekday>::parse(""),
+        Err(ParseError::Required)
+    );
+}
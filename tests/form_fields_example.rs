@@ -0,0 +1,151 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, FieldKind, FormFields, ParseAndFormat,
+    StructForm,
+};
+
+// This example shows how to walk the structure of a form at runtime,
+// for renderers that work with any form instead of hand coding every
+// field.
+
+// This example builds on the [summary example](./summary_example.rs).
+// This example is written assuming that you're already familiar with
+// the summary example, so if not please refer to that first.
+
+// Every form has a `fields` function, which lists its inputs along
+// with their field path, the field to use with `set_input`, and their
+// entry in the `FIELDS` table. The inputs of subforms are included,
+// with fields that reach into the subform. Optional subforms are
+// listed with the field that toggles them on and off, and lists of
+// subforms with the field that adds an entry.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct User {
+    name: String,
+    address: Address,
+    postal_address: Option<Address>,
+    children: Vec<Child>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Child {
+    name: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "User")]
+struct UserForm {
+    name: FormTextInput<String>,
+    #[structform(subform)]
+    address: AddressForm,
+    postal_address: Option<AddressForm>,
+    children: Vec<ChildForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Child")]
+struct ChildForm {
+    #[structform(label = "Child's name")]
+    name: FormTextInput<String>,
+}
+
+#[test]
+fn fields_include_nested_subforms() {
+    let paths: Vec<String> = UserForm::fields().map(|field| field.path).collect();
+    assert_eq!(
+        paths,
+        vec![
+            "name",
+            "address.city",
+            "postal_address",
+            "postal_address.city",
+            "children"
+        ]
+    );
+
+    let fields: Vec<UserFormField> = UserForm::fields().map(|field| field.field).collect();
+    assert_eq!(
+        fields,
+        vec![
+            UserFormField::Name,
+            UserFormField::Address(AddressFormField::City),
+            UserFormField::TogglePostalAddress,
+            UserFormField::PostalAddress(AddressFormField::City),
+            UserFormField::AddChildren,
+        ]
+    );
+
+    let children = UserForm::fields().last().unwrap();
+    assert_eq!(
+        children.meta.kind,
+        FieldKind::ListSubform(ChildForm::FIELDS)
+    );
+}
+
+// A renderer that works with any form can use the fields to render an
+// input for each of them. The inputs of a list's entries are found
+// with the `fields` function of the list's subform.
+
+fn render_inputs(form: &UserForm) -> Vec<String> {
+    let inputs = form.inputs_by_path();
+    let mut rendered = Vec::new();
+    for field in UserForm::fields() {
+        if field.meta.kind == FieldKind::Input {
+            let input = inputs
+                .iter()
+                .find(|(path, _)| *path == field.path)
+                .map(|(_, input)| input.as_str());
+            if let Some(input) = input {
+                rendered.push(format!("{}: {}", field.meta.label, input));
+            }
+        }
+        if let FieldKind::ListSubform(_) = field.meta.kind {
+            for (i, _) in form.children.iter().enumerate() {
+                for child_field in ChildForm::fields() {
+                    let path = format!("{}[{}].{}", field.path, i, child_field.path);
+                    let input = inputs.iter().find(|(input_path, _)| *input_path == path);
+                    if let Some((_, input)) = input {
+                        rendered.push(format!("{}: {}", child_field.meta.label, input));
+                    }
+                }
+            }
+        }
+    }
+    rendered
+}
+
+#[test]
+fn renderers_can_walk_any_form() {
+    let mut form = UserForm::default();
+    for field in UserForm::fields() {
+        if field.meta.kind == FieldKind::Input {
+            form.set_input(field.field, format!("{}!", field.path));
+        }
+    }
+    form.set_input(UserFormField::AddChildren, String::new());
+    form.set_input(
+        UserFormField::Children(0, ChildFormField::Name),
+        "Sam".to_string(),
+    );
+
+    // The postal address is toggled off, so its input doesn't exist.
+    assert_eq!(
+        render_inputs(&form),
+        vec!["Name: name!", "City: address.city!", "Child's name: Sam"]
+    );
+}
@@ -0,0 +1,79 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, FormRegistry, ParseAndFormat, StructForm,
+};
+
+// This example shows how to manage forms that are opened and closed
+// while an app runs, like editing rows of a table inline.
+
+// This example builds on the [form set example](./form_set_example.rs).
+// This example is written assuming that you're already familiar with
+// the form set example, so if not please refer to that first.
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Product {
+    id: u32,
+    name: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Product")]
+struct ProductForm {
+    name: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// A `structform::FormRegistry` stores forms by key. `open` creates a
+// form from its model when a row starts being edited, and `close`
+// removes it again. `dirty_keys` lists the forms with changes that
+// haven't been saved, for a "save all" button or a warning before
+// leaving the page.
+
+#[test]
+fn forms_are_opened_edited_and_saved_by_key() {
+    let products = vec![
+        Product {
+            id: 1,
+            name: "Kettle".to_string(),
+        },
+        Product {
+            id: 2,
+            name: "Toaster".to_string(),
+        },
+    ];
+
+    let mut registry: FormRegistry<u32> = FormRegistry::new();
+    for product in &products {
+        assert!(registry.open::<ProductForm, _>(product.id, product));
+    }
+    assert_eq!(registry.len(), 2);
+    assert_eq!(registry.dirty_keys().count(), 0);
+
+    registry
+        .get_mut(&2)
+        .unwrap()
+        .set_input_at_path("name", "Sandwich toaster".to_string());
+    assert_eq!(registry.dirty_keys().collect::<Vec<_>>(), vec![&2]);
+
+    // Opening a form that is already open keeps its changes.
+    assert!(!registry.open::<ProductForm, _>(2, &products[1]));
+    assert_eq!(
+        registry.form::<ProductForm>(&2).unwrap().name.input,
+        "Sandwich toaster".to_string()
+    );
+
+    let saved = registry
+        .form_mut::<ProductForm>(&2)
+        .unwrap()
+        .submit()
+        .unwrap();
+    assert_eq!(saved.name, "Sandwich toaster".to_string());
+    registry.mark_saved(&2);
+    assert!(!registry.is_dirty(&2));
+
+    assert!(registry.close(&1));
+    assert_eq!(registry.keys().collect::<Vec<_>>(), vec![&2]);
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, FormSet, ParseAndFormat, ParseError,
+    StructForm,
+};
+
+// This example shows how to save several independent forms on the same
+// page with one button.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Profile {
+    display_name: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct PasswordChange {
+    new_password: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Profile")]
+struct ProfileForm {
+    display_name: FormTextInput<String>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "PasswordChange")]
+struct PasswordChangeForm {
+    new_password: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// A `structform::FormSet` holds forms for different models, each with
+// a name. Field paths on the set start with the form's name. The forms
+// themselves can be borrowed back with their type, for rendering.
+
+fn settings_page() -> FormSet {
+    let mut forms = FormSet::new();
+    forms.add(
+        "profile",
+        ProfileForm::new(&Profile {
+            display_name: "Joe".to_string(),
+        }),
+    );
+    forms.add("password", PasswordChangeForm::default());
+    forms
+}
+
+#[test]
+fn all_forms_are_submitted_together() {
+    let mut forms = settings_page();
+    assert!(!forms.is_valid());
+
+    assert!(forms.set_input_at_path("password.new_password", "hunter22".to_string()));
+    assert!(forms.is_valid());
+
+    let mut submitted = forms.submit_all().unwrap();
+    assert_eq!(
+        submitted.take::<Profile>("profile"),
+        Some(Profile {
+            display_name: "Joe".to_string()
+        })
+    );
+    assert_eq!(
+        submitted.take::<PasswordChange>("password"),
+        Some(PasswordChange {
+            new_password: "hunter22".to_string()
+        })
+    );
+}
+
+#[test]
+fn errors_are_collected_from_every_form() {
+    let mut forms = settings_page();
+    forms.set_input_at_path("profile.display_name", "".to_string());
+
+    assert_eq!(
+        forms.errors(),
+        vec![
+            ("profile".to_string(), ParseError::Required),
+            ("password".to_string(), ParseError::Required),
+        ]
+    );
+    assert!(forms.submit_all().is_err());
+
+    // Submitting shows the validation messages on every form.
+    let password_form = forms.form::<PasswordChangeForm>("password").unwrap();
+    assert!(password_form.new_password.show_validation_msg());
+}
@@ -0,0 +1,119 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops, Grid,
+    GridColumn, ParseAndFormat, StructForm,
+};
+
+// This example shows how to edit a list of subforms as a table, like
+// the line items on an invoice.
+
+// This example builds on the [list of subforms
+// example](./list_of_subforms_example.rs). This example is written
+// assuming that you're already familiar with the list of subforms
+// example, so if not please refer to that first.
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Invoice {
+    line_items: Vec<LineItem>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct LineItem {
+    description: String,
+    quantity: u32,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Invoice")]
+struct InvoiceForm {
+    line_items: Vec<LineItemForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "LineItem")]
+struct LineItemForm {
+    description: FormTextInput<String>,
+    #[structform(label = "Qty")]
+    quantity: FormNumberInput<u32>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "a number", u32, u32);
+
+// `structform::Grid` lays out a list of subforms as a table. Its
+// columns come from the subform's inputs, using the same labels as the
+// form summary, and each cell has a field path for editing it with
+// `set_input_at_path`.
+//
+// As well as the `Add` and `Remove` variants, each list gets a `Move`
+// variant on the form's field enum for reordering rows.
+
+fn invoice_form() -> InvoiceForm {
+    InvoiceForm::new(&Invoice {
+        line_items: vec![
+            LineItem {
+                description: "Kettle".to_string(),
+                quantity: 1,
+            },
+            LineItem {
+                description: "Mug".to_string(),
+                quantity: 4,
+            },
+        ],
+    })
+}
+
+#[test]
+fn the_grid_has_a_column_per_input_and_a_row_per_entry() {
+    let form = invoice_form();
+    let grid = Grid::new("line_items", &form.line_items);
+
+    assert_eq!(
+        grid.columns(),
+        &[
+            GridColumn {
+                path: "description".to_string(),
+                label: "Description".to_string(),
+            },
+            GridColumn {
+                path: "quantity".to_string(),
+                label: "Qty".to_string(),
+            },
+        ][..]
+    );
+    assert_eq!(grid.row_count(), 2);
+    assert_eq!(grid.cell_input(1, 0), Some("Mug"));
+    assert_eq!(
+        grid.cell_path(1, 1),
+        Some("line_items[1].quantity".to_string())
+    );
+}
+
+#[test]
+fn cells_and_rows_can_be_edited() {
+    let mut form = invoice_form();
+    let grid = Grid::new("line_items", &form.line_items);
+    form.set_input_at_path(&grid.cell_path(1, 1).unwrap(), "6".to_string());
+
+    form.set_input(InvoiceFormField::AddLineItems, String::new());
+    form.set_input(
+        InvoiceFormField::LineItems(2, LineItemFormField::Description),
+        "Teapot".to_string(),
+    );
+    form.set_input(
+        InvoiceFormField::LineItems(2, LineItemFormField::Quantity),
+        "1".to_string(),
+    );
+    form.set_input(InvoiceFormField::MoveLineItems(2, 0), String::new());
+    form.set_input(InvoiceFormField::RemoveLineItems(1), String::new());
+
+    let grid = Grid::new("line_items", &form.line_items);
+    assert_eq!(grid.row_count(), 2);
+    assert_eq!(grid.cell_input(0, 0), Some("Teapot"));
+    assert_eq!(grid.cell_input(1, 0), Some("Mug"));
+    assert_eq!(grid.cell_input(1, 1), Some("6"));
+}
@@ -0,0 +1,77 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows a honeypot field, which helps to stop bots from
+// submitting public forms.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ContactRequest {
+    email: String,
+    message: String,
+}
+
+// A honeypot is an extra field which is hidden from people, for
+// example with CSS, but which bots that fill in every field on a page
+// will find. The `honeypot` annotation marks a `String` field as a
+// honeypot. It isn't part of the model, but it can be set like any
+// other field. If anything is ever typed into it, submitting the form
+// fails with `ParseError::Spam`.
+//
+// Give the honeypot a name that a bot would want to fill in, and
+// render it with `autocomplete="off"` and `tabindex="-1"`, so that
+// browsers and keyboard users don't fill it in by accident.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "ContactRequest")]
+struct ContactRequestForm {
+    email: FormTextInput<String>,
+    message: FormTextInput<String>,
+    #[structform(honeypot)]
+    website: String,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[test]
+fn people_can_submit_the_form() {
+    let mut form = ContactRequestForm::default();
+    form.apply_urlencoded("email=joe%40example.com&message=Hello&website=");
+
+    assert_eq!(
+        form.submit(),
+        Ok(ContactRequest {
+            email: "joe@example.com".to_string(),
+            message: "Hello".to_string(),
+        })
+    );
+}
+
+#[test]
+fn bots_that_fill_in_the_honeypot_are_rejected() {
+    let mut form = ContactRequestForm::default();
+    form.apply_urlencoded(
+        "email=bot%40example.com&message=Buy+now&website=http%3A%2F%2Fspam.example",
+    );
+    assert_eq!(form.website, "http://spam.example".to_string());
+
+    let result = form.submit();
+    assert_eq!(result, Err(ParseError::Spam));
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "This form could not be submitted."
+    );
+
+    // The honeypot is never included when exporting the form.
+    assert_eq!(
+        form.to_urlencoded(),
+        "email=bot%40example.com&message=Buy+now"
+    );
+}
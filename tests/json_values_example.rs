@@ -0,0 +1,103 @@
+#![cfg(all(feature = "json", feature = "text"))]
+
+use serde_json::json;
+use structform::{derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm};
+
+// This example shows how to save the raw inputs of an unfinished form
+// as JSON, so that it can be looked at by support tooling or picked
+// up again on another device.
+
+// This example builds on the [query string
+// example](./query_string_example.rs). This example is written
+// assuming that you're already familiar with the query string
+// example, so if not please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Signup {
+    username: String,
+    password: String,
+    primary_address: Address,
+    previous_addresses: Vec<Address>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct Address {
+    city: String,
+    country: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Signup")]
+struct SignupForm {
+    username: FormTextInput<String>,
+    #[structform(sensitive)]
+    password: FormTextInput<String>,
+    #[structform(subform)]
+    primary_address: AddressForm,
+    previous_addresses: Vec<AddressForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+    country: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// The JSON has the same shape as the model. Subforms are objects,
+// lists of subforms are arrays, and every input is its raw string,
+// even if it doesn't parse yet.
+
+#[test]
+fn raw_inputs_are_nested_like_the_model() {
+    let mut form = SignupForm::default();
+    form.set_input_at_path("username", "joe".to_string());
+    form.set_input_at_path("password", "hunter2".to_string());
+    form.set_input_at_path("primary_address.city", "JHB".to_string());
+    form.set_input_at_path("previous_addresses[0].city", "CPT".to_string());
+
+    // Sensitive inputs, like the password, are left out.
+    assert_eq!(
+        form.to_json_values(),
+        json!({
+            "username": "joe",
+            "primary_address": {"city": "JHB", "country": ""},
+            "previous_addresses": [{"city": "CPT", "country": ""}],
+        })
+    );
+}
+
+#[test]
+fn json_values_can_be_applied_to_another_form() {
+    let mut form = SignupForm::default();
+    form.set_input_at_path("username", "joe".to_string());
+    form.set_input_at_path("primary_address.city", "JHB".to_string());
+    form.set_input_at_path("previous_addresses[0].city", "CPT".to_string());
+    form.set_input_at_path("previous_addresses[1].country", "ZA".to_string());
+    let saved = form.to_json_values().to_string();
+
+    let mut other_device = SignupForm::default();
+    other_device.apply_json_values(&serde_json::from_str(&saved).unwrap());
+
+    assert_eq!(other_device.inputs_by_path(), form.inputs_by_path());
+}
+
+// Values that aren't strings are read the way JSON writes them, and
+// values that don't match an input are ignored.
+
+#[test]
+fn hand_written_json_is_forgiving() {
+    let mut form = SignupForm::default();
+    form.apply_json_values(&json!({
+        "username": 42,
+        "unknown": "ignored",
+        "previous_addresses": [{"city": "DBN"}],
+    }));
+
+    assert_eq!(form.username.input, "42");
+    assert_eq!(form.previous_addresses.len(), 1);
+    assert_eq!(form.previous_addresses[0].city.input, "DBN");
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, Cell, FormFields, FormLayout, Layout,
+    ParseAndFormat, Section, StructForm,
+};
+
+// This example shows how to describe where a form's fields go, so
+// that the code rendering your forms doesn't need to know the layout
+// of each one.
+
+// This example builds on the [subforms example](./subforms_example.rs).
+// This example is written assuming that you're already familiar with
+// the subforms example, so if not please refer to that first.
+
+// Every derived form implements `FormLayout`. By default, each field
+// gets a row of its own, in the order that they're declared. To lay a
+// form out differently, write a function that returns its `Layout`,
+// and name it in the `layout` annotation. Layouts are a grid, split
+// into sections of rows. Each cell in a row holds a field path, and
+// can span more than one column.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Contact {
+    first_name: String,
+    last_name: String,
+    email: String,
+    address: Address,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Address {
+    street_address: String,
+    city: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Contact", layout = "contact_layout")]
+struct ContactForm {
+    first_name: FormTextInput<String>,
+    last_name: FormTextInput<String>,
+    email: FormTextInput<String>,
+    #[structform(subform)]
+    address: AddressForm,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    street_address: FormTextInput<String>,
+    city: FormTextInput<String>,
+}
+
+fn contact_layout() -> Layout {
+    Layout::new(2)
+        .section(
+            Section::new("Name")
+                .row(vec![Cell::new("first_name"), Cell::new("last_name")])
+                .row(vec![Cell::new("email").span(2)]),
+        )
+        .section(
+            Section::new("Address")
+                .row(vec![Cell::new("address.street_address").span(2)])
+                .row(vec![Cell::new("address.city")]),
+        )
+}
+
+// A renderer can then lay out any form. This one renders a plain text
+// outline, but an HTML renderer would do the same with CSS grid
+// classes.
+fn render<F: FormLayout>() -> Vec<String> {
+    let layout = F::layout();
+    let mut lines = Vec::new();
+    for section in &layout.sections {
+        if let Some(title) = &section.title {
+            lines.push(format!("# {}", title));
+        }
+        for row in &section.rows {
+            let cells: Vec<String> = row
+                .iter()
+                .map(|cell| format!("{} ({}/{})", cell.path, cell.span, layout.columns))
+                .collect();
+            lines.push(cells.join(" | "));
+        }
+    }
+    lines
+}
+
+#[test]
+fn forms_use_their_layout_annotation() {
+    assert_eq!(
+        render::<ContactForm>(),
+        vec![
+            "# Name",
+            "first_name (1/2) | last_name (1/2)",
+            "email (2/2)",
+            "# Address",
+            "address.street_address (2/2)",
+            "address.city (1/2)",
+        ]
+    );
+}
+
+#[test]
+fn forms_without_a_layout_have_one_field_per_row() {
+    assert_eq!(
+        AddressForm::layout(),
+        Layout::new(1).section(
+            Section::untitled()
+                .row(vec![Cell::new("street_address")])
+                .row(vec![Cell::new("city")])
+        )
+    );
+}
+
+#[test]
+fn layouts_can_be_checked_for_missing_fields() {
+    assert_eq!(
+        ContactForm::layout().missing_fields(ContactForm::FIELDS),
+        Vec::<&str>::new()
+    );
+
+    let incomplete = Layout::new(1).section(Section::untitled().row(vec![Cell::new("email")]));
+    assert_eq!(
+        incomplete.missing_fields(ContactForm::FIELDS),
+        vec!["first_name", "last_name", "address"]
+    );
+}
@@ -0,0 +1,101 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, LazyList, ParseAndFormat, StructForm,
+};
+
+// This example shows how to edit models with very long lists, without
+// building a form for every entry up front.
+
+// This example builds on the [list of subforms
+// example](./list_of_subforms_example.rs). This example is written
+// assuming that you're already familiar with the list of subforms
+// example, so if not please refer to that first.
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Catalogue {
+    products: Vec<Product>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Product {
+    name: String,
+}
+
+// Instead of a `Vec` of subforms, a list with the `lazy` annotation is
+// a `structform::LazyList` of the subform and its model. Entries start
+// out as their models, and are only turned into forms when they are
+// edited. Entries that are never edited are submitted as they were.
+//
+// Lazy lists have the same field enum variants and field paths as
+// other lists.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Catalogue")]
+struct CatalogueForm {
+    #[structform(lazy)]
+    products: LazyList<ProductForm, Product>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Product")]
+struct ProductForm {
+    name: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+fn catalogue(products: usize) -> Catalogue {
+    Catalogue {
+        products: (0..products)
+            .map(|i| Product {
+                name: format!("Product {}", i),
+            })
+            .collect(),
+    }
+}
+
+#[test]
+fn only_edited_entries_become_forms() {
+    let original = catalogue(5000);
+    let mut form = CatalogueForm::new(&original);
+    assert_eq!(form.products.len(), 5000);
+    assert_eq!(form.products.materialized_len(), 0);
+
+    form.set_input(
+        CatalogueFormField::Products(42, ProductFormField::Name),
+        "Renamed".to_string(),
+    );
+    form.set_input_at_path("products[5000].name", "New product".to_string());
+    assert_eq!(form.products.materialized_len(), 2);
+
+    // Entries can still be read before they are edited.
+    assert_eq!(form.products.get(7).unwrap().name.input, "Product 7");
+    assert_eq!(form.products.materialized_len(), 2);
+
+    let submitted = form.submit_update(original.clone()).unwrap();
+    assert_eq!(submitted.products.len(), 5001);
+    assert_eq!(submitted.products[41], original.products[41]);
+    assert_eq!(submitted.products[42].name, "Renamed".to_string());
+    assert_eq!(submitted.products[5000].name, "New product".to_string());
+}
+
+#[test]
+fn entries_can_be_removed_and_moved() {
+    let mut form = CatalogueForm::new(&catalogue(3));
+    form.set_input(CatalogueFormField::MoveProducts(2, 0), String::new());
+    form.set_input(CatalogueFormField::RemoveProducts(1), String::new());
+
+    assert_eq!(
+        form.submit().unwrap().products,
+        vec![
+            Product {
+                name: "Product 2".to_string()
+            },
+            Product {
+                name: "Product 1".to_string()
+            },
+        ]
+    );
+}
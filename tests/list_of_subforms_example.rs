@@ -1,3 +1,5 @@
+#![cfg(feature = "text")]
+
 use structform::{
     derive_form_input, impl_text_input_with_stringops, ParseAndFormat, ParseError, StructForm,
 };
@@ -21,7 +23,7 @@ struct UserDetails {
     addresses: Vec<Address>,
 }
 
-#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[derive(Default, Debug, PartialEq, Eq)]
 struct Address {
     street_address: String,
     city: String,
@@ -0,0 +1,241 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows validation rules that apply to a whole list of
+// subforms, rather than to a single input.
+
+// This example builds on the [list of subforms
+// example](./list_of_subforms_example.rs). This example is written
+// assuming that you're already familiar with that example, so if not
+// please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Portfolio {
+    name: String,
+    allocations: Vec<Allocation>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct Allocation {
+    fund: String,
+    percentage: u8,
+}
+
+// The `sum_of` and `sum_to` annotations check that a numeric field on
+// every subform in the list adds up to a target. If they don't,
+// submitting the form returns a `ParseError::WrongTotal` naming the
+// list field. The field can be of any type that can be summed and
+// compared, like an integer or a decimal type, and `sum_to` is an
+// expression of the same type.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Portfolio")]
+struct PortfolioForm {
+    name: FormTextInput<String>,
+    #[structform(sum_of = "percentage", sum_to = "100")]
+    allocations: Vec<AllocationForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Allocation")]
+struct AllocationForm {
+    fund: FormTextInput<String>,
+    percentage: FormNumberInput<u8>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "a percentage", u8, u8, 0, 100);
+
+fn add_allocation(form: &mut PortfolioForm, fund: &str, percentage: &str) {
+    let i = form.allocations.len();
+    form.set_input(PortfolioFormField::AddAllocations, String::new());
+    form.set_input(
+        PortfolioFormField::Allocations(i, AllocationFormField::Fund),
+        fund.to_string(),
+    );
+    form.set_input(
+        PortfolioFormField::Allocations(i, AllocationFormField::Percentage),
+        percentage.to_string(),
+    );
+}
+
+#[test]
+fn allocations_must_add_up_to_100_percent() {
+    let mut form = PortfolioForm::default();
+    form.set_input(PortfolioFormField::Name, "Retirement".to_string());
+    add_allocation(&mut form, "Equities", "60");
+    add_allocation(&mut form, "Bonds", "30");
+
+    let result = form.submit();
+    assert_eq!(
+        result,
+        Err(ParseError::WrongTotal {
+            field: "allocations".to_string(),
+            expected: "100".to_string(),
+            actual: "90".to_string(),
+        })
+    );
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Expected allocations to add up to 100, but they add up to 90."
+    );
+
+    add_allocation(&mut form, "Cash", "10");
+    assert_eq!(
+        form.submit().map(|portfolio| portfolio.allocations.len()),
+        Ok(3)
+    );
+}
+
+// `validate` returns the error on the list itself, since no single
+// entry is wrong. The list's error is keyed by the field that adds to
+// it, which has the list's field path.
+
+#[test]
+fn the_wrong_total_is_an_error_on_the_list() {
+    let mut form = PortfolioForm::default();
+    form.set_input(PortfolioFormField::Name, "Retirement".to_string());
+    add_allocation(&mut form, "Equities", "60");
+    add_allocation(&mut form, "Bonds", "30");
+
+    let errors = form.validate().unwrap_err();
+    assert_eq!(
+        errors.get(&PortfolioFormField::AddAllocations),
+        Some(&ParseError::WrongTotal {
+            field: "allocations".to_string(),
+            expected: "100".to_string(),
+            actual: "90".to_string(),
+        })
+    );
+    assert_eq!(form.errors_by_path()[0].0, "allocations");
+}
+
+#[test]
+fn errors_on_individual_inputs_are_reported_first() {
+    let mut form = PortfolioForm::default();
+    form.set_input(PortfolioFormField::Name, "Retirement".to_string());
+    add_allocation(&mut form, "Equities", "60");
+    add_allocation(&mut form, "Bonds", "");
+
+    assert_eq!(form.submit(), Err(ParseError::Required));
+}
+
+// The `unique_by` annotation checks that no two subforms in the list
+// have the same value for a field. If they do, submitting the form
+// returns a `ParseError::DuplicateEntry` with the indexes of the first
+// two entries that clash. `validate` returns an error on the field of
+// every entry that clashes, so each of them can be highlighted.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Team {
+    name: String,
+    members: Vec<TeamMember>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct TeamMember {
+    name: String,
+    email: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Team")]
+struct TeamForm {
+    name: FormTextInput<String>,
+    #[structform(unique_by = "email")]
+    members: Vec<TeamMemberForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "TeamMember")]
+struct TeamMemberForm {
+    name: FormTextInput<String>,
+    email: FormTextInput<String>,
+}
+
+fn add_member(form: &mut TeamForm, name: &str, email: &str) {
+    let i = form.members.len();
+    form.set_input(TeamFormField::AddMembers, String::new());
+    form.set_input(
+        TeamFormField::Members(i, TeamMemberFormField::Name),
+        name.to_string(),
+    );
+    form.set_input(
+        TeamFormField::Members(i, TeamMemberFormField::Email),
+        email.to_string(),
+    );
+}
+
+#[test]
+fn team_members_must_have_different_email_addresses() {
+    let mut form = TeamForm::default();
+    form.set_input(TeamFormField::Name, "Support".to_string());
+    add_member(&mut form, "Alex", "alex@example.com");
+    add_member(&mut form, "Sam", "sam@example.com");
+    add_member(&mut form, "Alex again", "alex@example.com");
+
+    let result = form.submit();
+    assert_eq!(
+        result,
+        Err(ParseError::DuplicateEntry {
+            field: "members".to_string(),
+            subfield: "email".to_string(),
+            first_index: 0,
+            index: 2,
+        })
+    );
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Expected each of the members to have a different email, but entries 1 and 3 are the same."
+    );
+
+    form.set_input(TeamFormField::RemoveMembers(2), String::new());
+    assert_eq!(form.submit().map(|team| team.members.len()), Ok(2));
+}
+
+#[test]
+fn every_entry_that_clashes_has_an_error() {
+    let mut form = TeamForm::default();
+    form.set_input(TeamFormField::Name, "Support".to_string());
+    add_member(&mut form, "Alex", "alex@example.com");
+    add_member(&mut form, "Sam", "sam@example.com");
+    add_member(&mut form, "Alex again", "alex@example.com");
+    add_member(&mut form, "Sam again", "sam@example.com");
+    add_member(&mut form, "Kim", "kim@example.com");
+
+    let duplicate = |first_index, index| ParseError::DuplicateEntry {
+        field: "members".to_string(),
+        subfield: "email".to_string(),
+        first_index,
+        index,
+    };
+    let errors = form.validate().unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        vec![
+            (
+                TeamFormField::Members(0, TeamMemberFormField::Email),
+                duplicate(0, 2)
+            ),
+            (
+                TeamFormField::Members(1, TeamMemberFormField::Email),
+                duplicate(1, 3)
+            ),
+            (
+                TeamFormField::Members(2, TeamMemberFormField::Email),
+                duplicate(0, 2)
+            ),
+            (
+                TeamFormField::Members(3, TeamMemberFormField::Email),
+                duplicate(1, 3)
+            ),
+        ]
+    );
+}
@@ -0,0 +1,80 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, invalid_entries_per_page, page_count,
+    ListWindow, ParseAndFormat, StructForm,
+};
+
+// This example shows how to page through a list of subforms with many
+// entries, so only one page needs to be rendered at a time.
+
+// This example builds on the [list of subforms
+// example](./list_of_subforms_example.rs). This example is written
+// assuming that you're already familiar with the list of subforms
+// example, so if not please refer to that first.
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Guestlist {
+    guests: Vec<Guest>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Guest {
+    name: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Guestlist")]
+struct GuestlistForm {
+    guests: Vec<GuestForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Guest")]
+struct GuestForm {
+    name: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+fn guestlist_form(guests: usize) -> GuestlistForm {
+    GuestlistForm::new(&Guestlist {
+        guests: (0..guests)
+            .map(|i| Guest {
+                name: format!("Guest {}", i),
+            })
+            .collect(),
+    })
+}
+
+// A `structform::ListWindow` picks out a range of entries, like one
+// page. Each entry comes with its index in the whole list, so it can
+// be edited with the usual field paths and field enum variants.
+
+#[test]
+fn a_window_shows_one_page_with_stable_indexes() {
+    let form = guestlist_form(250);
+    assert_eq!(page_count(form.guests.len(), 100), 3);
+
+    let last_page: Vec<(usize, String)> = ListWindow::page(2, 100)
+        .entries(&form.guests)
+        .map(|(i, guest)| (i, guest.name.input.clone()))
+        .collect();
+    assert_eq!(last_page.len(), 50);
+    assert_eq!(last_page[0], (200, "Guest 200".to_string()));
+}
+
+// Since only one page is rendered, users need to know which other
+// pages have invalid entries. Submitting still checks every entry.
+
+#[test]
+fn invalid_entries_are_counted_per_page() {
+    let mut form = guestlist_form(250);
+    form.set_input_at_path("guests[5].name", "".to_string());
+    form.set_input_at_path("guests[210].name", "".to_string());
+    form.set_input_at_path("guests[249].name", "".to_string());
+
+    assert_eq!(invalid_entries_per_page(&form.guests, 100), vec![1, 0, 2]);
+    assert!(form.submit().is_err());
+}
@@ -1,3 +1,6 @@
+#![cfg(feature = "text")]
+#![allow(clippy::bool_assert_comparison)]
+
 use structform::{
     derive_form_input, impl_text_input_with_stringops, ParseAndFormat, ParseError, StructForm,
 };
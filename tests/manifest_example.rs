@@ -0,0 +1,104 @@
+#![cfg(all(feature = "manifest", feature = "text"))]
+
+use serde_json::json;
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, Cell, FieldManifestKind, Layout,
+    ParseAndFormat, Section, StructForm,
+};
+
+// This example shows how to describe a form to a client that isn't
+// written in Rust, like a TypeScript frontend, so that it can render
+// an equivalent form.
+
+// This example builds on the [layout example](./layout_example.rs).
+// This example is written assuming that you're already familiar with
+// the layout example, so if not please refer to that first.
+
+// With the `manifest` feature, every form has a `manifest` function.
+// The manifest has the form's fields, with their labels and kinds,
+// and subforms' fields nested inside of them, along with the form's
+// layout. It can be serialized with serde, for example to JSON to
+// send to the client.
+//
+// The rules that inputs check when they're parsed are written in
+// Rust, so they aren't in the manifest. The client can send the
+// inputs back to be checked by the form, using `apply_urlencoded` and
+// `errors_by_path`.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Contact {
+    name: String,
+    password: String,
+    addresses: Vec<Address>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Contact", layout = "contact_layout")]
+struct ContactForm {
+    #[structform(label = "Full name")]
+    name: FormTextInput<String>,
+    #[structform(sensitive)]
+    password: FormTextInput<String>,
+    addresses: Vec<AddressForm>,
+}
+
+fn contact_layout() -> Layout {
+    Layout::new(2)
+        .section(Section::new("Login").row(vec![Cell::new("name"), Cell::new("password")]))
+        .section(Section::untitled().row(vec![Cell::new("addresses").span(2)]))
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+#[test]
+fn manifest_describes_the_form_tree() {
+    let manifest = ContactForm::manifest();
+    assert_eq!(manifest.fields[2].kind, FieldManifestKind::ListSubform);
+    assert_eq!(manifest.fields[2].fields[0].label, "City");
+    assert_eq!(manifest.layout, contact_layout());
+}
+
+#[test]
+fn manifest_serializes_to_json() {
+    assert_eq!(
+        serde_json::to_value(AddressForm::manifest()).unwrap(),
+        json!({
+            "fields": [
+                {
+                    "name": "city",
+                    "label": "City",
+                    "sensitive": false,
+                    "kind": "input",
+                    "value_type": "String",
+                    "fields": [],
+                },
+            ],
+            "layout": {
+                "columns": 1,
+                "sections": [
+                    {
+                        "title": null,
+                        "rows": [[{ "path": "city", "span": 1 }]],
+                    },
+                ],
+            },
+        })
+    );
+
+    let contact = serde_json::to_value(ContactForm::manifest()).unwrap();
+    assert_eq!(contact["fields"][1]["sensitive"], json!(true));
+    assert_eq!(contact["fields"][2]["kind"], json!("list_subform"));
+    assert_eq!(contact["layout"]["sections"][0]["title"], json!("Login"));
+}
@@ -0,0 +1,224 @@
+#![cfg(feature = "text")]
+
+use std::collections::BTreeMap;
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows creating forms over maps of data structures, keyed
+// by something other than their position.
+
+// This example builds on the [list of subforms
+// example](./list_of_subforms_example.rs). This example is written
+// assuming that you're already familiar with the list of subforms
+// example, so if not please refer to that first.
+
+// Sometimes the entries of a nested collection are identified by a
+// key, rather than by their position in a list. In this case, a
+// product has a translation for every language that it's sold in,
+// keyed by the language's code.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Product {
+    sku: String,
+    translations: BTreeMap<String, Translation>,
+}
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Translation {
+    title: String,
+    description: String,
+}
+
+// The derive macro identifies `HashMap` and `BTreeMap` fields as maps
+// of subforms, so like lists, no additional annotations are needed.
+// The key type is used in field paths, so it needs to implement
+// `Display` and `FromStr`, as well as `Clone`, `Debug` and `Eq` for
+// the field enum.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Product")]
+struct ProductForm {
+    sku: FormTextInput<String>,
+    translations: BTreeMap<String, TranslationForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Translation")]
+struct TranslationForm {
+    title: FormTextInput<String>,
+    description: FormTextInput<String>,
+}
+
+// These two derivations of StructForms generates the following field definitions:
+// ```
+// pub enum ProductFormField {
+//     Sku,
+//     AddTranslations(String),
+//     Translations(String, TranslationFormField),
+//     RemoveTranslations(String),
+// }
+// pub enum TranslationFormField {
+//     Title,
+//     Description,
+// }
+// ```
+
+// These inputs are the same as the login example. See that example
+// for more details.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[test]
+fn subforms_can_be_added_and_modified_by_their_key() {
+    let mut form = ProductForm::default();
+
+    // Like lists, entries are added with the add field, but it carries
+    // the key of the new entry. Adding a key that's already in the map
+    // leaves its entry as it is.
+    form.set_input(
+        ProductFormField::AddTranslations("en".to_string()),
+        "".to_string(),
+    );
+    form.set_input(
+        ProductFormField::Translations("en".to_string(), TranslationFormField::Title),
+        "Kettle".to_string(),
+    );
+    form.set_input(
+        ProductFormField::AddTranslations("en".to_string()),
+        "".to_string(),
+    );
+    assert_eq!(form.translations.len(), 1);
+    assert_eq!(form.translations["en"].title.input, "Kettle");
+
+    // Setting an input on a key that isn't in the map does nothing.
+    form.set_input(
+        ProductFormField::Translations("af".to_string(), TranslationFormField::Title),
+        "Ketel".to_string(),
+    );
+    assert_eq!(form.translations.len(), 1);
+
+    form.set_input(
+        ProductFormField::RemoveTranslations("en".to_string()),
+        "".to_string(),
+    );
+    assert!(form.translations.is_empty());
+}
+
+#[test]
+fn field_paths_include_the_key() {
+    let mut form = ProductForm::default();
+
+    // In field paths, the key is a segment of its own, like
+    // `translations.af.title`. Setting an input at a path adds the
+    // entry if it isn't in the map yet, the same way that lists add
+    // the entry just past their end.
+    assert!(form.set_input_at_path("translations.af.title", "Ketel".to_string()));
+    assert_eq!(form.translations["af"].title.input, "Ketel");
+
+    // A path that doesn't lead to an input doesn't add an entry.
+    assert!(!form.set_input_at_path("translations.de.subtitle", "Kessel".to_string()));
+    assert!(!form.translations.contains_key("de"));
+
+    assert_eq!(
+        ProductFormField::Translations("af".to_string(), TranslationFormField::Title).to_path(),
+        "translations.af.title"
+    );
+    assert_eq!(
+        ProductFormField::from_path("translations.af.title"),
+        Some(ProductFormField::Translations(
+            "af".to_string(),
+            TranslationFormField::Title
+        ))
+    );
+    assert_eq!(
+        form.summary(),
+        vec![
+            ("Sku".to_string(), "".to_string()),
+            ("Translations af - Title".to_string(), "Ketel".to_string()),
+            ("Translations af - Description".to_string(), "".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn existing_entries_are_updated_by_their_key() {
+    let mut translations = BTreeMap::new();
+    translations.insert(
+        "en".to_string(),
+        Translation {
+            title: "Kettle".to_string(),
+            description: "Boils water".to_string(),
+        },
+    );
+    let model = Product {
+        sku: "KTL-1".to_string(),
+        translations,
+    };
+    let mut form = ProductForm::new(&model);
+    assert_eq!(form.translations["en"].description.input, "Boils water");
+
+    // Entries that are added to the form are submitted as new models,
+    // and entries that are removed are left out of the submitted
+    // model.
+    form.set_input_at_path("translations.af.title", "Ketel".to_string());
+    assert_eq!(form.submit_update(model), Err(ParseError::Required));
+    form.set_input_at_path("translations.af.description", "Kook water".to_string());
+
+    let model = form.submit().unwrap();
+    assert_eq!(model.translations.len(), 2);
+    assert_eq!(model.translations["af"].title, "Ketel");
+    assert_eq!(model.translations["en"].description, "Boils water");
+
+    form.set_input(
+        ProductFormField::RemoveTranslations("en".to_string()),
+        "".to_string(),
+    );
+    let model = form.submit_update(model).unwrap();
+    assert_eq!(model.translations.keys().collect::<Vec<_>>(), vec!["af"]);
+}
+
+#[test]
+fn validation_errors_are_keyed_by_the_entry() {
+    let mut form = ProductForm::default();
+    form.set_input_at_path("translations.en.title", "Kettle".to_string());
+
+    let errors = form.validate().unwrap_err();
+    assert_eq!(
+        errors.get(&ProductFormField::Translations(
+            "en".to_string(),
+            TranslationFormField::Description
+        )),
+        Some(&ParseError::Required)
+    );
+}
+
+// Keys can contain a `.`, like the `en.us` locale. In field paths, a
+// `.`, `[`, `]` or `\` in a key is escaped with a `\`, so that the key
+// is still a single segment, like `translations.en\.us.title`. Paths
+// read back from `inputs_by_path` or `to_path` always round-trip.
+
+#[test]
+fn keys_with_dots_round_trip_through_field_paths() {
+    let mut form = ProductForm::default();
+    form.set_input(
+        ProductFormField::AddTranslations("en.us".to_string()),
+        "".to_string(),
+    );
+    form.set_input(
+        ProductFormField::Translations("en.us".to_string(), TranslationFormField::Title),
+        "Kettle".to_string(),
+    );
+
+    let field = ProductFormField::Translations("en.us".to_string(), TranslationFormField::Title);
+    assert_eq!(field.to_path(), r"translations.en\.us.title");
+    assert_eq!(ProductFormField::from_path(&field.to_path()), Some(field));
+
+    let mut copy = ProductForm::default();
+    for (path, input) in form.inputs_by_path() {
+        assert!(copy.set_input_at_path(&path, input));
+    }
+    assert_eq!(copy.translations["en.us"].title.input, "Kettle");
+    assert_eq!(copy.inputs_by_path(), form.inputs_by_path());
+}
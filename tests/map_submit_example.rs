@@ -0,0 +1,100 @@
+#![cfg(feature = "text")]
+
+use structform::{derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm};
+
+// This example shows how to clean up the model after it has been
+// submitted, while still using the derived submit function.
+
+// This example builds on the [list of subforms
+// example](./list_of_subforms_example.rs). This example is written
+// assuming that you're already familiar with that example, so if not
+// please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Profile {
+    username: String,
+    display_name: String,
+    skills: Vec<Skill>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Skill {
+    name: String,
+}
+
+// The `map_submit` annotation takes a function from the model to the
+// model. It is called after the model has been put together from the
+// inputs, and before any cross-field validation rules are checked.
+//
+// Here, it puts the skills in alphabetical order, and fills in the
+// display name, which isn't on the form.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Profile", map_submit = "normalize_profile")]
+struct ProfileForm {
+    username: FormTextInput<String>,
+    skills: Vec<SkillForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Skill")]
+struct SkillForm {
+    name: FormTextInput<String>,
+}
+
+fn normalize_profile(mut profile: Profile) -> Profile {
+    profile.skills.sort();
+    profile.skills.dedup();
+    if profile.display_name.is_empty() {
+        profile.display_name = profile.username.clone();
+    }
+    profile
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[test]
+fn the_submitted_model_is_normalized() {
+    let mut form = ProfileForm::default();
+    form.set_input(ProfileFormField::Username, "sam".to_string());
+    for (i, skill) in ["Rust", "Elm", "Rust"].iter().enumerate() {
+        form.set_input(ProfileFormField::AddSkills, String::new());
+        form.set_input(
+            ProfileFormField::Skills(i, SkillFormField::Name),
+            skill.to_string(),
+        );
+    }
+
+    assert_eq!(
+        form.submit(),
+        Ok(Profile {
+            username: "sam".to_string(),
+            display_name: "sam".to_string(),
+            skills: vec![
+                Skill {
+                    name: "Elm".to_string()
+                },
+                Skill {
+                    name: "Rust".to_string()
+                },
+            ],
+        })
+    );
+}
+
+#[test]
+fn existing_values_are_kept_when_updating_a_model() {
+    let mut form = ProfileForm::default();
+    form.set_input(ProfileFormField::Username, "sam".to_string());
+
+    let updated = form.submit_update(Profile {
+        username: "old".to_string(),
+        display_name: "Sam Smith".to_string(),
+        skills: Vec::new(),
+    });
+    assert_eq!(
+        updated.map(|profile| profile.display_name),
+        Ok("Sam Smith".to_string())
+    );
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "measurement")]
+
+use structform::{
+    derive_form_input, impl_measurement_input, ParseAndFormat, ParseError, StructForm,
+    LENGTH_UNITS, MASS_UNITS,
+};
+
+// This example shows inputs for measurements with units, like "12.5
+// kg" or "27 lb".
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+// The model always stores measurements in a canonical unit, so the
+// rest of your application doesn't need to care which unit the user
+// typed in. For `MASS_UNITS` that is kilograms, and for `LENGTH_UNITS`
+// it is metres.
+
+#[derive(Debug, Default, PartialEq)]
+struct Parcel {
+    weight_kg: f64,
+    length_m: Option<f64>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Parcel")]
+struct ParcelForm {
+    weight_kg: FormWeightInput<f64>,
+    length_m: FormLengthInput<Option<f64>>,
+}
+
+// Each measurement input is given its conversion table, and the unit
+// that values are shown in. You could also write your own conversion
+// table, as long as it has one unit with a factor of 1.
+
+derive_form_input! {FormWeightInput}
+impl_measurement_input!(FormWeightInput, MASS_UNITS, "kg");
+
+derive_form_input! {FormLengthInput}
+impl_measurement_input!(FormLengthInput, LENGTH_UNITS, "cm");
+
+#[test]
+fn measurements_are_converted_to_the_canonical_unit() {
+    let mut form = ParcelForm::default();
+
+    form.set_input(ParcelFormField::WeightKg, "2 lb".to_string());
+    form.set_input(ParcelFormField::LengthM, "30cm".to_string());
+    let parcel = form.submit().unwrap();
+    assert!((parcel.weight_kg - 0.907_184_74).abs() < 1e-9);
+    assert_eq!(parcel.length_m, Some(0.3));
+
+    assert_eq!(form.weight_kg.format_in("lb"), Some("2 lb".to_string()));
+    assert_eq!(form.weight_kg.format_in("g"), Some("907.18 g".to_string()));
+}
+
+#[test]
+fn measurements_are_formatted_in_the_preferred_unit() {
+    let form = ParcelForm::new(&Parcel {
+        weight_kg: 12.5,
+        length_m: Some(1.2),
+    });
+    assert_eq!(form.weight_kg.input, "12.5 kg".to_string());
+    assert_eq!(form.length_m.input, "120 cm".to_string());
+
+    // Numbers without a unit are in the preferred unit.
+    let mut form = ParcelForm::default();
+    form.set_input(ParcelFormField::LengthM, "45".to_string());
+    assert_eq!(form.length_m.value, Ok(Some(0.45)));
+}
+
+#[test]
+fn unknown_units_are_rejected() {
+    let mut form = ParcelForm::default();
+
+    form.set_input(ParcelFormField::WeightKg, "3 stone".to_string());
+    assert_eq!(
+        form.weight_kg.value,
+        Err(ParseError::InvalidFormat {
+            required_type: "a measurement in kg, g, mg, t, lb, oz, st".to_string()
+        })
+    );
+
+    form.set_input(ParcelFormField::WeightKg, "".to_string());
+    assert_eq!(form.weight_kg.value, Err(ParseError::Required));
+    form.set_input(ParcelFormField::LengthM, "".to_string());
+    assert_eq!(form.length_m.value, Ok(None));
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "text")]
+
+use structform::{derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm};
+
+// This example shows how to merge two drafts of a form, when the same
+// record has been edited in two places at once.
+
+// This example builds on the [query string
+// example](./query_string_example.rs), which introduces field paths.
+// This example is written assuming that you're already familiar with
+// that example, so if not please refer to that first.
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Contact {
+    name: String,
+    phone: String,
+    email: String,
+    addresses: Vec<Address>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Contact")]
+struct ContactForm {
+    name: FormTextInput<String>,
+    phone: FormTextInput<String>,
+    email: FormTextInput<String>,
+    addresses: Vec<AddressForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// `StructForm::merge` takes the draft that both edits started from,
+// and the two edited drafts. Changes that were only made on one side
+// are merged automatically. When both sides changed the same input
+// differently, the merged form keeps "mine", and the input's field
+// path is reported as a conflict so the user can be asked about it.
+
+#[test]
+fn non_conflicting_changes_are_merged() {
+    let base = ContactForm::new(&Contact {
+        name: "Joe".to_string(),
+        phone: "021 555 0100".to_string(),
+        email: "joe@example.com".to_string(),
+        addresses: Vec::new(),
+    });
+
+    let mut mine = base.clone();
+    mine.set_input(ContactFormField::Phone, "021 555 0199".to_string());
+    mine.set_input(ContactFormField::Name, "Joe Soap".to_string());
+
+    let mut theirs = base.clone();
+    theirs.set_input(ContactFormField::Email, "joe@soap.example".to_string());
+    theirs.set_input(ContactFormField::Name, "Joseph".to_string());
+    theirs.set_input(ContactFormField::AddAddresses, String::new());
+    theirs.set_input(
+        ContactFormField::Addresses(0, AddressFormField::City),
+        "Durban".to_string(),
+    );
+
+    let mut result = ContactForm::merge(&base, &mine, &theirs);
+    assert_eq!(result.conflicts, vec!["name".to_string()]);
+    assert_eq!(
+        result.merged.submit(),
+        Ok(Contact {
+            name: "Joe Soap".to_string(),
+            phone: "021 555 0199".to_string(),
+            email: "joe@soap.example".to_string(),
+            addresses: vec![Address {
+                city: "Durban".to_string()
+            }],
+        })
+    );
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "text")]
+
+use structform::{derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm};
+
+// This example shows how to keep the types generated for a form out of
+// the module that the form is declared in.
+
+// This example builds on the [subforms example](./subforms_example.rs).
+// This example is written assuming that you're already familiar with
+// the subforms example, so if not please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Login {
+    username: String,
+    address: Address,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+// Usually, deriving `StructForm` on `LoginForm` generates a
+// `LoginFormField` enum next to it. If that name is already taken, the
+// `module` annotation puts the enum in a new module instead, where
+// it's just called `Field`.
+
+#[allow(dead_code)]
+struct LoginFormField;
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Login", module = "login_form")]
+struct LoginForm {
+    username: FormTextInput<String>,
+    #[structform(subform)]
+    address: AddressForm,
+}
+
+// Forms that use a form as a subform expect its field enum to be
+// named after it, so a subform with a `module` annotation needs its
+// `Field` imported under that name, like
+// `use address_form::Field as AddressFormField;`.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[test]
+fn the_field_enum_is_in_its_own_module() {
+    let mut form = LoginForm::default();
+    form.set_input(login_form::Field::Username, "joe".to_string());
+    form.set_input(
+        login_form::Field::Address(AddressFormField::City),
+        "Johannesburg".to_string(),
+    );
+
+    assert_eq!(
+        form.submit(),
+        Ok(Login {
+            username: "joe".to_string(),
+            address: Address {
+                city: "Johannesburg".to_string()
+            },
+        })
+    );
+}
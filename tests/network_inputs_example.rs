@@ -0,0 +1,159 @@
+#![cfg(all(feature = "network", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_port_input_with_stringops, impl_text_input_with_stringops, MacAddress,
+    ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows the built in inputs for network details, which
+// are handy for network tools built on StructForm.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct ServerDetails {
+    port: u16,
+    admin_port: Option<u16>,
+    mac_address: MacAddress,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "ServerDetails")]
+struct ServerDetailsForm {
+    port: FormPortInput<u16>,
+    admin_port: FormPortInput<Option<u16>>,
+    mac_address: FormTextInput<MacAddress>,
+}
+
+// Ports can be typed in as a number, but people often know a port
+// better by the name of the service that runs on it. The
+// `impl_port_input_with_stringops` macro implements `ParseAndFormat`
+// for ports which accepts either.
+
+derive_form_input! {FormPortInput}
+impl_port_input_with_stringops!(FormPortInput);
+
+// MAC addresses are written in a few different ways. StructForm
+// provides a `MacAddress` type which implements `FromStr` and
+// `Display`, so it works with the usual text input macros.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, "a MAC address", MacAddress);
+
+#[test]
+fn ports_can_be_entered_as_numbers_or_service_names() {
+    let mut form = ServerDetailsForm::default();
+
+    form.set_input(ServerDetailsFormField::Port, "8443".to_string());
+    assert_eq!(form.port.value, Ok(8443));
+
+    // Service names are looked up in `structform::SERVICE_PORTS`,
+    // ignoring case.
+    form.set_input(ServerDetailsFormField::Port, " HTTPS ".to_string());
+    assert_eq!(form.port.value, Ok(443));
+
+    form.set_input(ServerDetailsFormField::AdminPort, "ssh".to_string());
+    form.set_input(
+        ServerDetailsFormField::MacAddress,
+        "00:1a:2b:3c:4d:5e".to_string(),
+    );
+    assert_eq!(
+        form.submit(),
+        Ok(ServerDetails {
+            port: 443,
+            admin_port: Some(22),
+            mac_address: MacAddress([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]),
+        })
+    );
+}
+
+#[test]
+fn unknown_service_names_and_out_of_range_ports_are_rejected() {
+    let mut form = ServerDetailsForm::default();
+
+    form.set_input(ServerDetailsFormField::Port, "gopherz".to_string());
+    assert_eq!(
+        form.port.value,
+        Err(ParseError::InvalidFormat {
+            required_type: "a port number or service name".to_string()
+        })
+    );
+
+    form.set_input(ServerDetailsFormField::Port, "70000".to_string());
+    assert_eq!(
+        form.port.value,
+        Err(ParseError::NumberOutOfRange {
+            required_type: "a port".to_string(),
+            min: "1".to_string(),
+            max: "65535".to_string()
+        })
+    );
+
+    // Port 0 fits in a u16, but isn't a port that can be connected to.
+    form.set_input(ServerDetailsFormField::Port, "0".to_string());
+    assert_eq!(
+        form.port.value,
+        Err(ParseError::NumberOutOfRange {
+            required_type: "a port".to_string(),
+            min: "1".to_string(),
+            max: "65535".to_string()
+        })
+    );
+
+    // Optional ports can be left empty.
+    form.set_input(ServerDetailsFormField::AdminPort, "".to_string());
+    assert_eq!(form.admin_port.value, Ok(None));
+}
+
+#[test]
+fn ports_are_always_formatted_as_numbers() {
+    let form = ServerDetailsForm::new(&ServerDetails {
+        port: 80,
+        admin_port: None,
+        mac_address: MacAddress::default(),
+    });
+    assert_eq!(form.port.input, "80".to_string());
+    assert_eq!(form.admin_port.input, "".to_string());
+}
+
+#[test]
+fn mac_addresses_accept_common_separators_and_are_formatted_canonically() {
+    let mut form = ServerDetailsForm::default();
+    let expected = MacAddress([0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]);
+
+    for input in &["00:1A:2B:3C:4D:5E", "00-1a-2b-3c-4d-5e", "001a2b3c4d5e"] {
+        form.set_input(ServerDetailsFormField::MacAddress, input.to_string());
+        assert_eq!(form.mac_address.value, Ok(expected));
+    }
+
+    // However it was typed, a MAC address from the model is always
+    // shown in lower case with colons.
+    let form = ServerDetailsForm::new(&ServerDetails {
+        port: 80,
+        admin_port: None,
+        mac_address: expected,
+    });
+    assert_eq!(form.mac_address.input, "00:1a:2b:3c:4d:5e".to_string());
+}
+
+#[test]
+fn malformed_mac_addresses_are_rejected() {
+    let mut form = ServerDetailsForm::default();
+
+    for input in &[
+        "00:1a:2b:3c:4d",
+        "00:1a-2b:3c:4d:5e",
+        "001a2b3c4d5g",
+        "0:1a:2b:3c:4d:5e",
+    ] {
+        form.set_input(ServerDetailsFormField::MacAddress, input.to_string());
+        assert_eq!(
+            form.mac_address.value,
+            Err(ParseError::InvalidFormat {
+                required_type: "a MAC address".to_string()
+            })
+        );
+    }
+}
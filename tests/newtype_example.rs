@@ -0,0 +1,112 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, ParseAndFormat, ParseAndFormatNewtype, ParseError, StructForm,
+};
+
+// This example shows how to derive everything a newtype needs to be
+// used in a form, instead of writing it by hand.
+
+// This example builds on the [validation example](./validation_example.rs).
+// This example is written assuming that you're already familiar with
+// the validation example, so if not please refer to that first.
+
+// In the validation example, the `Port` newtype needed a `Display`
+// implementation, a `TryFrom<u16>` implementation with its validation
+// rules, and a call to `impl_numeric_input_with_stringops`. The
+// `ParseAndFormatNewtype` derive generates all of these from a
+// `parseformat` annotation:
+//
+// - `kind` is either "numeric" or "text", and picks which of the
+//   input macros is used.
+// - `via` is the type being wrapped.
+// - `input` is the form input that the newtype is used in.
+// - `type_name` describes the type in error messages.
+// - `min` and `max` are the range of valid values. For numeric
+//   newtypes, they default to the range of `via`.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ParseAndFormatNewtype)]
+#[parseformat(
+    kind = "numeric",
+    via = "u16",
+    min = "1",
+    input = "FormNumberInput",
+    type_name = "a port"
+)]
+pub struct Port(u16);
+
+#[derive(Debug, Clone, PartialEq, Eq, ParseAndFormatNewtype)]
+#[parseformat(
+    kind = "text",
+    via = "String",
+    input = "FormTextInput",
+    type_name = "a hostname"
+)]
+pub struct Hostname(String);
+
+#[derive(Debug, PartialEq, Eq)]
+struct ConnectionDetails {
+    host: Hostname,
+    port: Port,
+}
+
+#[derive(Clone, StructForm)]
+#[structform(model = "ConnectionDetails", submit_with = "submit_connection_details")]
+struct ConnectionDetailsForm {
+    host: FormTextInput<Hostname>,
+    port: FormNumberInput<Port>,
+}
+
+fn submit_connection_details(
+    form: &mut ConnectionDetailsForm,
+) -> Result<ConnectionDetails, ParseError> {
+    let host = form.host.submit();
+    let port = form.port.submit();
+    Ok(ConnectionDetails {
+        host: host?,
+        port: port?,
+    })
+}
+
+derive_form_input! {FormTextInput}
+derive_form_input! {FormNumberInput}
+
+#[test]
+fn newtypes_are_validated_by_the_derived_implementations() {
+    let mut form = ConnectionDetailsForm {
+        host: Default::default(),
+        port: Default::default(),
+    };
+
+    form.set_input(ConnectionDetailsFormField::Port, "0".to_string());
+    assert_eq!(
+        form.port.submit(),
+        Err(ParseError::FromStrError(
+            "Expected a port between 1 and 65535".to_string()
+        ))
+    );
+
+    form.set_input(ConnectionDetailsFormField::Port, "eighty".to_string());
+    assert_eq!(
+        form.port.submit(),
+        Err(ParseError::NumberOutOfRange {
+            required_type: "a port".to_string(),
+            min: "1".to_string(),
+            max: "65535".to_string(),
+        })
+    );
+
+    form.set_input(
+        ConnectionDetailsFormField::Host,
+        " example.com ".to_string(),
+    );
+    form.set_input(ConnectionDetailsFormField::Port, "443".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(ConnectionDetails {
+            host: Hostname("example.com".to_string()),
+            port: Port(443),
+        })
+    );
+    assert_eq!(Port(443).to_string(), "443".to_string());
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "numeric")]
+
+use std::cell::Cell;
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example builds on the [numeric input example](./numeric_input_example.rs).
+// This example is written assuming that you're already familiar with
+// the numeric input example, so if not please refer to that first.
+
+// Sometimes the limits on a number aren't known when you write the
+// code. In this example, each tenant can configure how many seats
+// they allow on a booking.
+
+thread_local! {
+    static MAX_SEATS: Cell<u32> = const { Cell::new(10) };
+}
+
+fn max_seats() -> u32 {
+    MAX_SEATS.with(|max| max.get())
+}
+
+fn set_max_seats(max: u32) {
+    MAX_SEATS.with(|cell| cell.set(max));
+}
+
+// Passing the bounds as `min = ...` and `max = ...` makes the input
+// check the range when it's parsed. The bounds are evaluated on every
+// parse, so they can be any expression, including a function call.
+
+derive_form_input! {FormSeatsInput}
+impl_numeric_input_with_stringops!(
+    FormSeatsInput,
+    "a number of seats",
+    u32,
+    u32,
+    min = 1,
+    max = max_seats()
+);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Booking {
+    seats: u32,
+    children: Option<u32>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Booking")]
+struct BookingForm {
+    seats: FormSeatsInput<u32>,
+    children: FormSeatsInput<Option<u32>>,
+}
+
+fn out_of_range(max: u32) -> ParseError {
+    ParseError::NumberOutOfRange {
+        required_type: "a number of seats".to_string(),
+        min: "1".to_string(),
+        max: max.to_string(),
+    }
+}
+
+#[test]
+fn bounds_are_checked_when_parsing() {
+    set_max_seats(10);
+    assert_eq!(FormSeatsInput::<u32>::parse("4"), Ok(4));
+    assert_eq!(FormSeatsInput::<u32>::parse("0"), Err(out_of_range(10)));
+    assert_eq!(FormSeatsInput::<u32>::parse("11"), Err(out_of_range(10)));
+    assert_eq!(FormSeatsInput::<u32>::parse("many"), Err(out_of_range(10)));
+    assert_eq!(FormSeatsInput::<u32>::parse(""), Err(ParseError::Required));
+
+    assert_eq!(FormSeatsInput::<Option<u32>>::parse(""), Ok(None));
+    assert_eq!(FormSeatsInput::<Option<u32>>::parse("2"), Ok(Some(2)));
+    assert_eq!(
+        FormSeatsInput::<Option<u32>>::parse("11"),
+        Err(out_of_range(10))
+    );
+}
+
+#[test]
+fn bounds_follow_the_tenant_configuration() {
+    set_max_seats(10);
+    let mut form = BookingForm::default();
+    form.set_input(BookingFormField::Seats, "20".to_string());
+    assert_eq!(form.seats.validation_error(), Some(&out_of_range(10)));
+
+    // Inputs are parsed as they're typed, so the new limit applies to
+    // the next thing the user types.
+    set_max_seats(50);
+    form.set_input(BookingFormField::Seats, "30".to_string());
+    assert_eq!(form.seats.validation_error(), None);
+    assert_eq!(
+        form.submit(),
+        Ok(Booking {
+            seats: 30,
+            children: None
+        })
+    );
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "numeric")]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_default_with_stringops,
+    impl_numeric_input_with_stringops, ParseAndFormat, ParseError,
+};
+
+// This example shows how the two numeric input macros treat empty,
+// valid and invalid input, for both required and optional values.
+
+// This example builds on the [validation example](./validation_example.rs).
+// This example is written assuming that you're already familiar with
+// the validation example, so if not please refer to that first.
+
+// `impl_numeric_input_with_stringops` requires a number, while
+// `impl_numeric_input_with_default_with_stringops` submits the type's
+// default for an empty input. Both treat an empty optional input as
+// `None`, so switching between them doesn't break optional fields.
+// `impl_numeric_input_with_default_with_stringops` only implements
+// the optional case when it's asked for with `optional`.
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "a percentage", u8, u8, 0, 100);
+
+derive_form_input! {FormNumberWithDefaultInput}
+impl_numeric_input_with_default_with_stringops!(
+    FormNumberWithDefaultInput,
+    "a percentage",
+    u8,
+    u8,
+    0,
+    100,
+    optional
+);
+
+fn out_of_range() -> ParseError {
+    ParseError::NumberOutOfRange {
+        required_type: "a percentage".to_string(),
+        min: "0".to_string(),
+        max: "100".to_string(),
+    }
+}
+
+#[test]
+fn required_numbers() {
+    assert_eq!(FormNumberInput::<u8>::parse(" "), Err(ParseError::Required));
+    assert_eq!(FormNumberInput::<u8>::parse(" 42 "), Ok(42));
+    assert_eq!(FormNumberInput::<u8>::parse("lots"), Err(out_of_range()));
+    assert_eq!(FormNumberInput::<u8>::parse("300"), Err(out_of_range()));
+    assert_eq!(FormNumberInput::<u8>::format(&42), "42".to_string());
+}
+
+#[test]
+fn optional_numbers() {
+    assert_eq!(FormNumberInput::<Option<u8>>::parse(" "), Ok(None));
+    assert_eq!(FormNumberInput::<Option<u8>>::parse(" 42 "), Ok(Some(42)));
+    assert_eq!(
+        FormNumberInput::<Option<u8>>::parse("lots"),
+        Err(out_of_range())
+    );
+    assert_eq!(
+        FormNumberInput::<Option<u8>>::parse("300"),
+        Err(out_of_range())
+    );
+    assert_eq!(FormNumberInput::<Option<u8>>::format(&None), "".to_string());
+}
+
+#[test]
+fn numbers_with_default() {
+    assert_eq!(FormNumberWithDefaultInput::<u8>::parse(" "), Ok(0));
+    assert_eq!(FormNumberWithDefaultInput::<u8>::parse(" 42 "), Ok(42));
+    assert_eq!(
+        FormNumberWithDefaultInput::<u8>::parse("lots"),
+        Err(out_of_range())
+    );
+    assert_eq!(
+        FormNumberWithDefaultInput::<u8>::parse("300"),
+        Err(out_of_range())
+    );
+    assert_eq!(
+        FormNumberWithDefaultInput::<u8>::format(&42),
+        "42".to_string()
+    );
+}
+
+#[test]
+fn optional_numbers_with_default() {
+    assert_eq!(
+        FormNumberWithDefaultInput::<Option<u8>>::parse(" "),
+        Ok(None)
+    );
+    assert_eq!(
+        FormNumberWithDefaultInput::<Option<u8>>::parse(" 42 "),
+        Ok(Some(42))
+    );
+    assert_eq!(
+        FormNumberWithDefaultInput::<Option<u8>>::parse("lots"),
+        Err(out_of_range())
+    );
+    assert_eq!(
+        FormNumberWithDefaultInput::<Option<u8>>::parse("300"),
+        Err(out_of_range())
+    );
+    assert_eq!(
+        FormNumberWithDefaultInput::<Option<u8>>::format(&Some(42)),
+        "42".to_string()
+    );
+}
@@ -0,0 +1,98 @@
+#![cfg(all(feature = "openapi", feature = "numeric", feature = "text"))]
+
+use serde_json::json;
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    ParseAndFormat, StructForm,
+};
+
+// This example shows how to document the model that a form submits in
+// an API's OpenAPI documentation, so that the documentation matches
+// the form.
+
+// This example builds on the [manifest example](./manifest_example.rs).
+// This example is written assuming that you're already familiar with
+// the manifest example, so if not please refer to that first.
+
+// With the `openapi` feature, a form's manifest can be turned into an
+// OpenAPI schema. The types come from the types that the form's inputs
+// parse into, and inputs with `Option` types and optional subforms are
+// not required. Add the schema to your API documentation's
+// `components.schemas`.
+//
+// The rules that inputs check when they parse, like the bounds of a
+// numeric input, are written in Rust, so they can't be described in
+// the schema.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "a number", u32, u32);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Signup {
+    username: String,
+    password: String,
+    age: Option<u32>,
+    postal_address: Option<Address>,
+    addresses: Vec<Address>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Signup")]
+struct SignupForm {
+    username: FormTextInput<String>,
+    #[structform(sensitive)]
+    password: FormTextInput<String>,
+    #[structform(label = "Age in years")]
+    age: FormNumberInput<Option<u32>>,
+    postal_address: Option<AddressForm>,
+    addresses: Vec<AddressForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+#[test]
+fn schema_describes_the_submitted_model() {
+    let address = json!({
+        "type": "object",
+        "properties": {
+            "city": { "type": "string", "title": "City" },
+        },
+        "required": ["city"],
+    });
+    let mut postal_address = address.clone();
+    postal_address["title"] = json!("Postal address");
+    postal_address["nullable"] = json!(true);
+
+    assert_eq!(
+        SignupForm::manifest().to_openapi_schema(),
+        json!({
+            "type": "object",
+            "properties": {
+                "username": { "type": "string", "title": "Username" },
+                "password": { "type": "string", "title": "Password", "writeOnly": true },
+                "age": {
+                    "type": "integer",
+                    "format": "int64",
+                    "minimum": 0,
+                    "title": "Age in years",
+                    "nullable": true,
+                },
+                "postal_address": postal_address,
+                "addresses": { "type": "array", "items": address, "title": "Addresses" },
+            },
+            "required": ["username", "password", "addresses"],
+        })
+    );
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "otp")]
+
+use structform::{derive_form_input, impl_otp_input, ParseAndFormat, ParseError, StructForm};
+
+// This example shows an input for one-time codes, like the ones sent
+// by SMS or generated by authenticator apps.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Verification {
+    code: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Verification")]
+struct VerificationForm {
+    code: FormOtpInput<String>,
+}
+
+// `impl_otp_input` implements `ParseAndFormat` for codes of a fixed
+// number of digits.
+
+derive_form_input! {FormOtpInput}
+impl_otp_input!(FormOtpInput, 6);
+
+#[test]
+fn non_digits_are_dropped_while_typing() {
+    let mut form = VerificationForm::default();
+
+    // Unlike most inputs, the `input` string isn't always exactly what
+    // was passed to `set_input`. `ParseAndFormat::sanitize` cleans it
+    // up first, which in this case drops anything that isn't a digit
+    // and ignores anything past the sixth digit. Your UI should bind
+    // to `input`, so that the user sees the cleaned up code.
+    form.set_input(VerificationFormField::Code, "123 45a6789".to_string());
+    assert_eq!(form.code.input, "123456".to_string());
+    assert_eq!(form.code.value, Ok("123456".to_string()));
+}
+
+// Since the input is sanitized before it is compared with the current
+// input, typing a character that is dropped doesn't count as a change.
+// The input isn't parsed again, and it keeps the time it was last
+// edited at, with the `edit-timestamps` feature.
+
+#[cfg(feature = "edit-timestamps")]
+#[test]
+fn dropped_characters_do_not_change_the_input() {
+    use std::time::Duration;
+    use structform::{FormClock, MockClock};
+
+    let clock = MockClock::new(Duration::from_secs(10));
+    let mut form = VerificationForm::default();
+    form.set_clock(&FormClock::new(clock.clone()));
+
+    form.set_input_str(VerificationFormField::Code, "123");
+    clock.advance(Duration::from_secs(5));
+    form.set_input_str(VerificationFormField::Code, "123a");
+    form.set_input(VerificationFormField::Code, "123 ".to_string());
+
+    assert_eq!(form.code.input, "123".to_string());
+    assert_eq!(
+        form.code.edit_timestamp.last_edited_at(),
+        Some(Duration::from_secs(10))
+    );
+
+    form.set_input_str(VerificationFormField::Code, "1234");
+    assert_eq!(form.code.input, "1234".to_string());
+    assert_eq!(
+        form.code.edit_timestamp.last_edited_at(),
+        Some(Duration::from_secs(15))
+    );
+}
+
+#[test]
+fn codes_must_be_complete() {
+    let mut form = VerificationForm::default();
+    assert_eq!(form.submit(), Err(ParseError::Required));
+
+    form.set_input(VerificationFormField::Code, "1234".to_string());
+    assert_eq!(
+        form.submit(),
+        Err(ParseError::InvalidFormat {
+            required_type: "a 6 digit code".to_string()
+        })
+    );
+
+    form.set_input(VerificationFormField::Code, "123456".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(Verification {
+            code: "123456".to_string()
+        })
+    );
+}
+
+#[test]
+fn digits_can_be_rendered_individually() {
+    let mut form = VerificationForm::default();
+    form.set_input(VerificationFormField::Code, "1234".to_string());
+
+    // A typical one-time code UI shows one box per digit.
+    assert_eq!(form.code.code_length(), 6);
+    assert_eq!(form.code.digit(0), Some('1'));
+    assert_eq!(form.code.digit(5), None);
+    assert_eq!(
+        form.code.digits(),
+        vec![Some('1'), Some('2'), Some('3'), Some('4'), None, None]
+    );
+}
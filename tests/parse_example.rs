@@ -0,0 +1,110 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows checking what a form would submit, without
+// submitting it.
+
+// This example builds on the [list of subforms
+// example](./list_of_subforms_example.rs). This example is written
+// assuming that you're already familiar with the list of subforms
+// example, so if not please refer to that first.
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Invoice {
+    customer: String,
+    lines: Vec<InvoiceLine>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct InvoiceLine {
+    description: String,
+}
+
+// These forms don't implement Clone, which is fine for everything in
+// this example.
+
+#[derive(Default, StructForm)]
+#[structform(model = "Invoice")]
+struct InvoiceForm {
+    customer: FormTextInput<String>,
+    lines: Vec<InvoiceLineForm>,
+    #[structform(submit_attempted)]
+    submit_attempted: bool,
+}
+
+#[derive(Default, StructForm)]
+#[structform(model = "InvoiceLine")]
+struct InvoiceLineForm {
+    description: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[test]
+fn parse_does_not_change_the_form() {
+    let mut form = InvoiceForm::default();
+    form.set_input(InvoiceFormField::Customer, "Acme".to_string());
+    form.set_input(InvoiceFormField::AddLines, "".to_string());
+
+    // `parse` returns what `submit` would, so a view can enable its
+    // save button as soon as the form is valid.
+    assert_eq!(form.parse(), Err(ParseError::Required));
+
+    // Unlike `submit`, it takes the form by reference, and doesn't mark
+    // the inputs as edited. The user hasn't touched the new line yet,
+    // so it shouldn't be shown with an error.
+    assert!(!form.submit_attempted());
+    assert_eq!(form.lines[0].description.validation_error(), None);
+
+    form.set_input(
+        InvoiceFormField::Lines(0, InvoiceLineFormField::Description),
+        "Anvil".to_string(),
+    );
+    assert_eq!(
+        form.parse(),
+        Ok(Invoice {
+            customer: "Acme".to_string(),
+            lines: vec![InvoiceLine {
+                description: "Anvil".to_string(),
+            }],
+        })
+    );
+}
+
+#[test]
+fn parse_update_keeps_fields_that_are_not_on_the_form() {
+    let pristine = Invoice {
+        customer: "Acme".to_string(),
+        lines: vec![InvoiceLine {
+            description: "Anvil".to_string(),
+        }],
+    };
+    let mut form = InvoiceForm::new(&pristine);
+    assert!(!form.has_unsaved_changes(&pristine));
+
+    form.set_input(InvoiceFormField::Customer, "Wile E.".to_string());
+    assert!(form.has_unsaved_changes(&pristine));
+    assert_eq!(
+        form.parse_update(pristine.clone()),
+        Ok(Invoice {
+            customer: "Wile E.".to_string(),
+            ..pristine
+        })
+    );
+}
+
+#[test]
+fn validation_errors_are_shown_after_a_submit_is_attempted() {
+    let mut form = InvoiceForm::default();
+    assert_eq!(form.validation_error(), None);
+
+    assert_eq!(form.submit(), Err(ParseError::Required));
+    assert_eq!(form.validation_error(), Some(ParseError::Required));
+
+    form.set_input(InvoiceFormField::Customer, "Acme".to_string());
+    assert_eq!(form.validation_error(), None);
+}
@@ -0,0 +1,173 @@
+#![cfg(all(feature = "name", feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_full_name_input, impl_numeric_input_with_stringops,
+    impl_text_input_with_stringops, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows how to submit only the fields that changed, for
+// backends that update records with PATCH requests.
+
+// This example builds on the [login example](./login_example.rs) and
+// the [composite name example](./composite_name_example.rs). This
+// example is written assuming that you're already familiar with those
+// examples, so if not please refer to them first.
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct User {
+    email: String,
+    first_name: String,
+    last_name: String,
+    age: u32,
+}
+
+// The `partial_model` annotation generates a second struct, with the
+// same fields as the model except that each one is optional. The form
+// gets a `submit_partial` function, which only fills in the fields
+// whose inputs have changed from their initial value, so the backend
+// knows exactly what to update.
+//
+// Partial models can only be generated for forms without subforms.
+// Deriving one for a form with subforms is a compile error.
+
+#[derive(Clone, StructForm)]
+#[structform(model = "User", partial_model = "PartialUser")]
+struct UserForm {
+    email: FormTextInput<String>,
+    #[structform(model_fields = "first_name, last_name")]
+    full_name: FormFullNameInput<(String, String)>,
+    age: FormNumberInput<u32>,
+}
+
+// This generates:
+// ```
+// #[derive(Debug, Clone, Default, PartialEq)]
+// struct PartialUser {
+//     pub email: Option<String>,
+//     pub first_name: Option<String>,
+//     pub last_name: Option<String>,
+//     pub age: Option<u32>,
+// }
+// ```
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormFullNameInput}
+impl_full_name_input!(FormFullNameInput);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "a number", u32, u32);
+
+fn existing_user() -> User {
+    User {
+        email: "joe@example.com".to_string(),
+        first_name: "Joe".to_string(),
+        last_name: "Soap".to_string(),
+        age: 42,
+    }
+}
+
+#[test]
+fn only_changed_fields_are_submitted() {
+    let mut form = UserForm::new(&existing_user());
+    form.set_input(UserFormField::Email, "joe@example.org".to_string());
+    form.set_input(UserFormField::FullName, "Joe Bloggs".to_string());
+
+    assert_eq!(
+        form.submit_partial(),
+        Ok(PartialUser {
+            email: Some("joe@example.org".to_string()),
+            first_name: Some("Joe".to_string()),
+            last_name: Some("Bloggs".to_string()),
+            age: None,
+        })
+    );
+}
+
+#[test]
+fn changing_an_input_back_leaves_it_out() {
+    let mut form = UserForm::new(&existing_user());
+    form.set_input(UserFormField::Age, "43".to_string());
+    form.set_input(UserFormField::Age, "42".to_string());
+
+    assert_eq!(form.submit_partial(), Ok(PartialUser::default()));
+}
+
+#[test]
+fn changed_inputs_must_still_be_valid() {
+    let mut form = UserForm::new(&existing_user());
+    form.set_input(UserFormField::Age, "old".to_string());
+
+    assert!(form.submit_partial().is_err());
+    assert!(form.age.show_validation_msg());
+}
+
+// Changed inputs are checked the same way that `submit` checks them,
+// including `required_if` and `empty` annotations. Rules on the model,
+// like `at_least_one_of`, are checked against the whole form.
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Contact {
+    kind: String,
+    company: String,
+    phone: Option<String>,
+    email: Option<String>,
+}
+
+#[derive(Clone, StructForm)]
+#[structform(
+    model = "Contact",
+    partial_model = "PartialContact",
+    at_least_one_of = "phone, email"
+)]
+struct ContactForm {
+    kind: FormTextInput<String>,
+    #[structform(required_if = "is_business")]
+    company: FormTextInput<String>,
+    phone: FormTextInput<Option<String>>,
+    email: FormTextInput<Option<String>>,
+}
+
+fn is_business(form: &ContactForm) -> bool {
+    form.kind.input == "Business"
+}
+
+fn existing_contact() -> Contact {
+    Contact {
+        kind: "Business".to_string(),
+        company: "Acme".to_string(),
+        phone: Some("555 1234".to_string()),
+        email: None,
+    }
+}
+
+#[test]
+fn changed_inputs_follow_the_same_annotations_as_submit() {
+    let mut form = ContactForm::new(&existing_contact());
+    form.set_input(ContactFormField::Company, "".to_string());
+    assert_eq!(form.submit_partial(), Err(ParseError::Required));
+
+    form.set_input(ContactFormField::Kind, "Personal".to_string());
+    assert_eq!(
+        form.submit_partial(),
+        Ok(PartialContact {
+            kind: Some("Personal".to_string()),
+            company: Some(String::new()),
+            ..PartialContact::default()
+        })
+    );
+}
+
+#[test]
+fn rules_on_the_model_are_checked() {
+    let mut form = ContactForm::new(&existing_contact());
+    form.set_input(ContactFormField::Phone, "".to_string());
+
+    assert_eq!(
+        form.submit_partial(),
+        Err(ParseError::AtLeastOneRequired {
+            fields: vec!["phone".to_string(), "email".to_string()]
+        })
+    );
+}
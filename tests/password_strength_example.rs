@@ -0,0 +1,90 @@
+#![cfg(all(feature = "password", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_password_input, impl_text_input_with_stringops, ParseAndFormat,
+    ParseError, PasswordStrength, StructForm,
+};
+
+// This example shows how to give feedback on password strength while
+// someone is choosing a new password.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct SignUpData {
+    username: String,
+    password: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "SignUpData")]
+struct SignUpForm {
+    username: FormTextInput<String>,
+    password: FormNewPasswordInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// `impl_password_input` implements `ParseAndFormat` for passwords,
+// which aren't trimmed like text inputs. It can optionally be given a
+// minimum strength, which rejects passwords that are too easy to
+// guess.
+
+derive_form_input! {FormNewPasswordInput}
+impl_password_input!(FormNewPasswordInput, PasswordStrength::Fair);
+
+#[test]
+fn password_inputs_report_their_strength_while_typing() {
+    let mut form = SignUpForm::default();
+
+    // The strength of whatever is currently typed is available from
+    // the input, which is useful for showing a strength meter.
+    form.set_input(SignUpFormField::Password, "password".to_string());
+    assert_eq!(form.password.strength(), PasswordStrength::VeryWeak);
+
+    form.set_input(SignUpFormField::Password, "aaaaaaaaaaaaaaaa".to_string());
+    assert_eq!(form.password.strength(), PasswordStrength::VeryWeak);
+
+    form.set_input(SignUpFormField::Password, "hunter22".to_string());
+    assert_eq!(form.password.strength(), PasswordStrength::Fair);
+
+    form.set_input(SignUpFormField::Password, "Tr0ub4dor&3".to_string());
+    assert_eq!(form.password.strength(), PasswordStrength::Strong);
+
+    form.set_input(
+        SignUpFormField::Password,
+        "correct horse battery staple, but With Capitals & 1 number".to_string(),
+    );
+    assert_eq!(form.password.strength(), PasswordStrength::VeryStrong);
+}
+
+#[test]
+fn passwords_weaker_than_the_minimum_strength_are_rejected() {
+    let mut form = SignUpForm::default();
+    form.set_input(SignUpFormField::Username, "justin".to_string());
+
+    form.set_input(SignUpFormField::Password, "hunter".to_string());
+    assert_eq!(
+        form.submit(),
+        Err(ParseError::PasswordTooWeak {
+            min: PasswordStrength::Fair
+        })
+    );
+    assert_eq!(
+        form.password.validation_error().map(|e| e.to_string()),
+        Some("Expected a password with at least fair strength.".to_string())
+    );
+
+    // Passwords aren't trimmed, so spaces count towards the strength.
+    form.set_input(SignUpFormField::Password, " hunter22 ".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(SignUpData {
+            username: "justin".to_string(),
+            password: " hunter22 ".to_string()
+        })
+    );
+}
@@ -0,0 +1,143 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    split_pasted_row, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows how to fill in several fields at once from
+// something pasted into one of them, like a row copied from a
+// spreadsheet.
+
+// This example builds on the [list of subforms
+// example](./list_of_subforms_example.rs) and the [validation errors
+// example](./validation_errors_example.rs). This example is written
+// assuming that you're already familiar with those examples, so if not
+// please refer to them first.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "an age", u32, u32, min = 0, max = 150);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Team {
+    name: String,
+    members: Vec<Member>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Member {
+    name: String,
+    email: String,
+    age: u32,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Team")]
+struct TeamForm {
+    name: FormTextInput<String>,
+    members: Vec<MemberForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Member")]
+struct MemberForm {
+    name: FormTextInput<String>,
+    email: FormTextInput<String>,
+    age: FormNumberInput<u32>,
+}
+
+fn member_fields(i: usize) -> Vec<TeamFormField> {
+    vec![
+        TeamFormField::Members(i, MemberFormField::Name),
+        TeamFormField::Members(i, MemberFormField::Email),
+        TeamFormField::Members(i, MemberFormField::Age),
+    ]
+}
+
+// `apply_paste` splits the pasted value into pieces, and sets each
+// piece on the field in the same position. `split_pasted_row` splits
+// spreadsheet rows on tabs, and anything else on commas. Pasting into
+// the entry just after the end of a list adds it.
+
+#[test]
+fn spreadsheet_rows_fill_in_a_list_entry() {
+    let mut form = TeamForm::default();
+    form.set_input(TeamFormField::Name, "Panoptix".to_string());
+    assert_eq!(
+        form.apply_paste(
+            "Justin\tjustin@example.com\t30\n",
+            &member_fields(0),
+            split_pasted_row
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        form.submit(),
+        Ok(Team {
+            name: "Panoptix".to_string(),
+            members: vec![Member {
+                name: "Justin".to_string(),
+                email: "justin@example.com".to_string(),
+                age: 30,
+            }],
+        })
+    );
+}
+
+// Apps can split pasted values in their own way, like an email
+// address with a name in front of it.
+
+fn split_name_and_email(pasted: &str) -> Vec<String> {
+    match pasted
+        .trim()
+        .strip_suffix('>')
+        .and_then(|rest| rest.split_once('<'))
+    {
+        Some((name, email)) => vec![name.trim().to_string(), email.trim().to_string()],
+        None => vec![String::new(), pasted.trim().to_string()],
+    }
+}
+
+#[test]
+fn apps_can_split_pasted_values_themselves() {
+    let mut form = TeamForm::default();
+    form.set_input(TeamFormField::AddMembers, String::new());
+    assert_eq!(
+        form.apply_paste(
+            "Justin Wernick <justin@example.com>",
+            &member_fields(0)[..2],
+            split_name_and_email
+        ),
+        Ok(())
+    );
+    assert_eq!(form.members[0].name.input, "Justin Wernick");
+    assert_eq!(form.members[0].email.input, "justin@example.com");
+}
+
+// The errors of the pasted fields are returned, and errors elsewhere
+// on the form aren't.
+
+#[test]
+fn pieces_that_dont_parse_are_errors() {
+    let mut form = TeamForm::default();
+    let errors = form
+        .apply_paste(
+            "Justin, justin@example.com, 300",
+            &member_fields(0),
+            split_pasted_row,
+        )
+        .unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(
+        errors.get(&TeamFormField::Members(0, MemberFormField::Age)),
+        Some(&ParseError::NumberOutOfRange {
+            required_type: "an age".to_string(),
+            min: "0".to_string(),
+            max: "150".to_string(),
+        })
+    );
+    assert_eq!(form.members[0].name.input, "Justin");
+}
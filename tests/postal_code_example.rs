@@ -0,0 +1,109 @@
+#![cfg(all(feature = "postal_code", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_postal_code_input, impl_text_input_with_stringops, ParseAndFormat,
+    ParseError, StructForm,
+};
+
+// This example shows how one field's validation can depend on
+// another field, using a postal code which is checked against the
+// selected country.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Address {
+    country: String,
+    postal_code: String,
+    billing_postal_code: Option<String>,
+}
+
+// The `depends_on` annotation says that the postal code needs to be
+// validated again whenever the country changes. The `validate_with`
+// function is called with the country's input and the parsed postal
+// code, after the postal code input has parsed successfully.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    country: FormTextInput<String>,
+    #[structform(
+        depends_on = "country",
+        validate_with = "structform::validate_postal_code"
+    )]
+    postal_code: FormPostalCodeInput<String>,
+    #[structform(
+        depends_on = "country",
+        validate_with = "structform::validate_optional_postal_code"
+    )]
+    billing_postal_code: FormPostalCodeInput<Option<String>>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormPostalCodeInput}
+impl_postal_code_input!(FormPostalCodeInput);
+
+#[test]
+fn postal_codes_are_formatted_for_the_selected_country() {
+    let mut form = AddressForm::default();
+
+    form.set_input(AddressFormField::Country, "GB".to_string());
+    form.set_input(AddressFormField::PostalCode, "sw1a1aa".to_string());
+    assert_eq!(form.postal_code.value, Ok("SW1A 1AA".to_string()));
+
+    form.set_input(AddressFormField::Country, "ZA".to_string());
+    form.set_input(AddressFormField::PostalCode, "8001".to_string());
+    form.set_input(AddressFormField::BillingPostalCode, "7700".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(Address {
+            country: "ZA".to_string(),
+            postal_code: "8001".to_string(),
+            billing_postal_code: Some("7700".to_string()),
+        })
+    );
+}
+
+#[test]
+fn changing_the_country_validates_the_postal_code_again() {
+    let mut form = AddressForm::default();
+
+    form.set_input(AddressFormField::Country, "US".to_string());
+    form.set_input(AddressFormField::PostalCode, "90210".to_string());
+    assert_eq!(form.postal_code.value, Ok("90210".to_string()));
+
+    form.set_input(AddressFormField::Country, "CA".to_string());
+    assert_eq!(
+        form.postal_code.value,
+        Err(ParseError::InvalidFormat {
+            required_type: "a postal code like X9X 9X9".to_string()
+        })
+    );
+
+    form.set_input(AddressFormField::Country, "US".to_string());
+    assert_eq!(form.postal_code.value, Ok("90210".to_string()));
+}
+
+#[test]
+fn countries_without_a_known_format_accept_any_reasonable_postal_code() {
+    let mut form = AddressForm::default();
+
+    form.set_input(AddressFormField::Country, "KE".to_string());
+    form.set_input(AddressFormField::PostalCode, " 00100 ".to_string());
+    assert_eq!(form.postal_code.value, Ok("00100".to_string()));
+
+    form.set_input(AddressFormField::PostalCode, "not/a/code".to_string());
+    assert_eq!(
+        form.postal_code.value,
+        Err(ParseError::InvalidFormat {
+            required_type: "a postal code".to_string()
+        })
+    );
+
+    form.set_input(AddressFormField::BillingPostalCode, "".to_string());
+    assert_eq!(form.billing_postal_code.value, Ok(None));
+}
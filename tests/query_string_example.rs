@@ -0,0 +1,168 @@
+#![cfg(feature = "text")]
+
+use structform::{derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm};
+
+// This example shows how to fill in a form from a URL's query string,
+// for deep links that arrive with some fields already filled in.
+
+// This example builds on the [subforms example](./subforms_example.rs)
+// and the [list of subforms example](./list_of_subforms_example.rs).
+// This example is written assuming that you're already familiar with
+// those examples, so if not please refer to them first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct UserDetails {
+    username: String,
+    primary_address: Address,
+    secondary_address: Option<Address>,
+    previous_addresses: Vec<Address>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct Address {
+    city: String,
+    country: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "UserDetails")]
+struct UserDetailsForm {
+    username: FormTextInput<String>,
+    #[structform(subform)]
+    primary_address: AddressForm,
+    secondary_address: Option<AddressForm>,
+    previous_addresses: Vec<AddressForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+    country: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// Every input on a form has a field path, made of the snake case
+// field names. Subforms are separated with a `.`, and list subforms
+// include the index in square brackets, like
+// `previous_addresses[0].city`.
+
+#[test]
+fn inputs_can_be_set_by_their_field_path() {
+    let mut form = UserDetailsForm::default();
+
+    assert!(form.set_input_at_path("username", "joe".to_string()));
+    assert!(form.set_input_at_path("primary_address.city", "JHB".to_string()));
+    assert_eq!(form.username.input, "joe".to_string());
+    assert_eq!(form.primary_address.city.input, "JHB".to_string());
+
+    assert!(!form.set_input_at_path("password", "hunter2".to_string()));
+    assert!(!form.set_input_at_path("primary_address", "JHB".to_string()));
+}
+
+// `apply_query_string` sets every input named in a query string.
+// Optional subforms are turned on, and list subforms have entries
+// added one at a time. An index more than one past the end of the
+// list is ignored, so a link can't make the form allocate a huge list.
+
+#[test]
+fn a_deep_link_fills_in_the_form() {
+    let mut form = UserDetailsForm::default();
+    form.apply_query_string(
+        "?username=joe&secondary_address.city=Cape+Town\
+         &previous_addresses%5B0%5D.city=Durban&previous_addresses[1].city=Pretoria\
+         &previous_addresses[5].city=Nowhere&unknown=ignored",
+    );
+
+    assert_eq!(form.username.input, "joe".to_string());
+    assert_eq!(
+        form.secondary_address
+            .as_ref()
+            .map(|address| address.city.input.clone()),
+        Some("Cape Town".to_string())
+    );
+    let previous_cities: Vec<String> = form
+        .previous_addresses
+        .iter()
+        .map(|address| address.city.input.clone())
+        .collect();
+    assert_eq!(
+        previous_cities,
+        vec!["Durban".to_string(), "Pretoria".to_string()]
+    );
+}
+
+// Optional subforms and list entries are only added for paths that
+// lead to one of their inputs, so a link with a mistyped key leaves
+// the form as it was. Malformed `%` escapes are kept as they are.
+
+#[test]
+fn a_bad_deep_link_adds_nothing() {
+    let mut form = UserDetailsForm::default();
+    form.apply_query_string(
+        "?secondary_address.town=Cape+Town&previous_addresses[0].town=Durban\
+         &username=100%+F%ZZ%4",
+    );
+
+    assert!(form.secondary_address.is_none());
+    assert!(form.previous_addresses.is_empty());
+    assert_eq!(form.username.input, "100% F%ZZ%4".to_string());
+}
+
+// `to_urlencoded` does the opposite, and encodes every non-empty input
+// by its field path. This can be used to share a link to a partly
+// filled in form, and read back with `apply_urlencoded`.
+
+#[test]
+fn the_form_can_be_shared_as_a_link() {
+    let mut form = UserDetailsForm::default();
+    form.set_input(UserDetailsFormField::Username, "joe".to_string());
+    form.set_input(
+        UserDetailsFormField::PrimaryAddress(AddressFormField::City),
+        "Cape Town".to_string(),
+    );
+    form.set_input(UserDetailsFormField::AddPreviousAddresses, String::new());
+    form.set_input(
+        UserDetailsFormField::PreviousAddresses(0, AddressFormField::Country),
+        "ZA".to_string(),
+    );
+
+    let urlencoded = form.to_urlencoded();
+    assert_eq!(
+        urlencoded,
+        "username=joe&previous_addresses%5B0%5D.country=ZA&primary_address.city=Cape+Town"
+    );
+
+    let mut shared_form = UserDetailsForm::default();
+    shared_form.apply_urlencoded(&urlencoded);
+    assert_eq!(shared_form.inputs_by_path(), form.inputs_by_path());
+}
+
+// Inputs marked with `#[structform(sensitive)]`, like passwords, are
+// never encoded, so that they don't end up in a shared link, the
+// browser's history or a server's logs.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Login {
+    username: String,
+    password: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Login")]
+struct LoginForm {
+    username: FormTextInput<String>,
+    #[structform(sensitive)]
+    password: FormTextInput<String>,
+}
+
+#[test]
+fn sensitive_inputs_are_not_shared() {
+    let mut form = LoginForm::default();
+    form.set_input(LoginFormField::Username, "joe".to_string());
+    form.set_input(LoginFormField::Password, "hunter2".to_string());
+
+    assert_eq!(form.to_urlencoded(), "username=joe");
+}
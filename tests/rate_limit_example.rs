@@ -0,0 +1,79 @@
+#![cfg(feature = "text")]
+
+use std::time::Duration;
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, FormClock, MockClock, ParseAndFormat,
+    ParseError, StructForm, SubmitRateLimit,
+};
+
+// This example shows how to limit how often a form can be submitted,
+// which is useful for login forms.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct LoginData {
+    username: String,
+    password: String,
+}
+
+// The `rate_limit_ms` annotation marks a `structform::SubmitRateLimit`
+// field, which isn't part of the model. It remembers when the form
+// was last submitted, using the system clock, or the clock that the
+// form was given with `set_clock`. Submitting again before the
+// interval has passed fails with `ParseError::TooManyAttempts`, which
+// says how long to wait.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "LoginData")]
+struct LoginForm {
+    username: FormTextInput<String>,
+    password: FormTextInput<String>,
+    #[structform(rate_limit_ms = "2000")]
+    rate_limit: SubmitRateLimit,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[test]
+fn submits_are_refused_until_the_interval_has_passed() {
+    let clock = MockClock::new(Duration::from_secs(1_600_000_000));
+    let mut form = LoginForm::default();
+    form.set_clock(&FormClock::new(clock.clone()));
+    form.set_input(LoginFormField::Username, "joe".to_string());
+    form.set_input(LoginFormField::Password, "wrong".to_string());
+    assert!(form.submit().is_ok());
+
+    clock.advance(Duration::from_millis(500));
+    let result = form.submit();
+    assert_eq!(
+        result,
+        Err(ParseError::TooManyAttempts {
+            retry_after: Duration::from_millis(1500)
+        })
+    );
+    assert_eq!(
+        result.unwrap_err().to_string(),
+        "Too many attempts. Please wait 2 seconds and try again."
+    );
+
+    // Refused attempts don't restart the interval.
+    clock.advance(Duration::from_millis(1500));
+    assert!(form.submit().is_ok());
+}
+
+#[test]
+fn submits_are_limited_by_the_system_clock_by_default() {
+    let mut form = LoginForm::default();
+    form.set_input(LoginFormField::Username, "joe".to_string());
+    form.set_input(LoginFormField::Password, "secret".to_string());
+
+    assert!(form.submit().is_ok());
+    assert!(matches!(
+        form.submit(),
+        Err(ParseError::TooManyAttempts { .. })
+    ));
+}
@@ -0,0 +1,112 @@
+#![cfg(feature = "text")]
+
+use structform::{derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm};
+
+// This example shows how to give a form's fields different names to
+// the model fields that they fill in.
+
+// This example builds on the [subforms example](./subforms_example.rs)
+// and the [list of subforms example](./list_of_subforms_example.rs).
+// This example is written assuming that you're already familiar with
+// those examples, so if not please refer to them first.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Contact {
+    email: String,
+    address: Address,
+    phones: Vec<Phone>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Phone {
+    number: String,
+}
+
+// By default, each field on the form fills in the model field with the
+// same name. `#[structform(rename = "...")]` names a different model
+// field instead. This works for inputs, subforms and lists of
+// subforms. Field paths, labels and the field enum still use the
+// form's name for the field.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Contact")]
+struct ContactForm {
+    #[structform(rename = "email")]
+    email_address: FormTextInput<String>,
+    #[structform(subform, rename = "address")]
+    postal_address: AddressForm,
+    #[structform(rename = "phones")]
+    phone_numbers: Vec<PhoneForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Phone")]
+struct PhoneForm {
+    number: FormTextInput<String>,
+}
+
+#[test]
+fn renamed_fields_fill_in_their_model_fields() {
+    let mut form = ContactForm::default();
+    form.set_input(
+        ContactFormField::EmailAddress,
+        "justin@example.com".to_string(),
+    );
+    form.set_input(
+        ContactFormField::PostalAddress(AddressFormField::City),
+        "Johannesburg".to_string(),
+    );
+    form.set_input(ContactFormField::AddPhoneNumbers, String::new());
+    form.set_input(
+        ContactFormField::PhoneNumbers(0, PhoneFormField::Number),
+        "0115550100".to_string(),
+    );
+    assert_eq!(
+        form.submit(),
+        Ok(Contact {
+            email: "justin@example.com".to_string(),
+            address: Address {
+                city: "Johannesburg".to_string(),
+            },
+            phones: vec![Phone {
+                number: "0115550100".to_string(),
+            }],
+        })
+    );
+    assert_eq!(
+        ContactForm::field_path(&ContactFormField::EmailAddress),
+        "email_address"
+    );
+}
+
+#[test]
+fn forms_are_created_from_renamed_model_fields() {
+    let contact = Contact {
+        email: "justin@example.com".to_string(),
+        address: Address {
+            city: "Johannesburg".to_string(),
+        },
+        phones: vec![Phone {
+            number: "0115550100".to_string(),
+        }],
+    };
+    let mut form = ContactForm::new(&contact);
+    assert_eq!(form.email_address.input, "justin@example.com");
+    assert_eq!(form.postal_address.city.input, "Johannesburg");
+    assert_eq!(form.phone_numbers[0].number.input, "0115550100");
+    assert_eq!(form.submit_update(contact.clone()), Ok(contact));
+}
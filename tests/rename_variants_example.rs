@@ -0,0 +1,123 @@
+#![cfg(feature = "text")]
+
+use structform::{derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm};
+
+// This example shows how to change the naming convention of the
+// variants of a form's field enum.
+
+// This example builds on the [list of subforms
+// example](./list_of_subforms_example.rs). This example is written
+// assuming that you're already familiar with the list of subforms
+// example, so if not please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Shipment {
+    tracking_number: String,
+    parcels: Vec<Parcel>,
+    return_address: Option<Address>,
+}
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Parcel {
+    contents: String,
+}
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+// Variants are named in PascalCase by default, like `TrackingNumber`
+// and `AddParcels`. Codebases with their own naming standards can
+// choose another convention with the `rename_variants` annotation. It
+// takes the same names as serde's `rename_all`: `PascalCase`,
+// `camelCase`, `snake_case` and `SCREAMING_SNAKE_CASE`. Field names
+// stay as they are, so field paths don't change.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Shipment", rename_variants = "SCREAMING_SNAKE_CASE")]
+struct ShipmentForm {
+    tracking_number: FormTextInput<String>,
+    parcels: Vec<ParcelForm>,
+    return_address: Option<AddressForm>,
+}
+
+// Subforms can use their own convention.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Parcel", rename_variants = "snake_case")]
+struct ParcelForm {
+    contents: FormTextInput<String>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+// These derivations of StructForms generates the following field definitions:
+// ```
+// pub enum ShipmentFormField {
+//     TRACKING_NUMBER,
+//     TOGGLE_RETURN_ADDRESS,
+//     RETURN_ADDRESS(AddressFormField),
+//     ADD_PARCELS,
+//     PARCELS(usize, ParcelFormField),
+//     REMOVE_PARCELS(usize),
+//     MOVE_PARCELS(usize, usize),
+// }
+// pub enum ParcelFormField {
+//     contents,
+// }
+// pub enum AddressFormField {
+//     City,
+// }
+// ```
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[test]
+fn renamed_variants_are_used_like_any_others() {
+    let mut form = ShipmentForm::default();
+    form.set_input(
+        ShipmentFormField::TRACKING_NUMBER,
+        "ZA123456789".to_string(),
+    );
+    form.set_input(ShipmentFormField::ADD_PARCELS, "".to_string());
+    form.set_input(
+        ShipmentFormField::PARCELS(0, ParcelFormField::contents),
+        "Books".to_string(),
+    );
+    form.set_input(ShipmentFormField::TOGGLE_RETURN_ADDRESS, "".to_string());
+    form.set_input(
+        ShipmentFormField::RETURN_ADDRESS(AddressFormField::City),
+        "Johannesburg".to_string(),
+    );
+
+    assert_eq!(
+        form.submit(),
+        Ok(Shipment {
+            tracking_number: "ZA123456789".to_string(),
+            parcels: vec![Parcel {
+                contents: "Books".to_string(),
+            }],
+            return_address: Some(Address {
+                city: "Johannesburg".to_string(),
+            }),
+        })
+    );
+}
+
+#[test]
+fn field_paths_keep_the_field_names() {
+    assert_eq!(
+        ShipmentFormField::PARCELS(0, ParcelFormField::contents).to_path(),
+        "parcels[0].contents"
+    );
+    assert_eq!(
+        ShipmentFormField::from_path("tracking_number"),
+        Some(ShipmentFormField::TRACKING_NUMBER)
+    );
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows how to clear a form in place, like after it has
+// been saved and the user wants to fill in another one.
+
+// This example builds on the [subforms example](./subforms_example.rs)
+// and the [submit attempted example](./submit_attempted_example.rs).
+// This example is written assuming that you're already familiar with
+// those examples, so if not please refer to them first.
+
+// `reset` clears every input, turns optional subforms off, empties
+// lists of subforms and sets `submit_attempted` back to false. Unlike
+// replacing the form with `default()`, it works through a mutable
+// reference, so the form can stay where it is in your app's model.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Order {
+    reference: String,
+    billing_address: Address,
+    delivery_address: Option<Address>,
+    items: Vec<Item>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Item {
+    name: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Order")]
+struct OrderForm {
+    reference: FormTextInput<String>,
+    #[structform(subform)]
+    billing_address: AddressForm,
+    delivery_address: Option<AddressForm>,
+    items: Vec<ItemForm>,
+    #[structform(submit_attempted)]
+    submit_attempted: bool,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Item")]
+struct ItemForm {
+    name: FormTextInput<String>,
+}
+
+#[test]
+fn reset_clears_the_whole_form() {
+    let mut form = OrderForm::new(&Order {
+        reference: "INV-1".to_string(),
+        billing_address: Address {
+            city: "Johannesburg".to_string(),
+        },
+        delivery_address: Some(Address {
+            city: "Cape Town".to_string(),
+        }),
+        items: vec![Item {
+            name: "Widget".to_string(),
+        }],
+    });
+    assert!(form.submit().is_ok());
+    assert!(form.submit_attempted);
+
+    form.reset();
+
+    assert!(form.is_empty());
+    assert!(form.delivery_address.is_none());
+    assert!(form.items.is_empty());
+    assert!(!form.submit_attempted);
+
+    // Inputs go back to how they are on a new form, so errors aren't
+    // shown until they're edited again.
+    assert_eq!(form.reference.value, Err(ParseError::Required));
+    assert_eq!(form.reference.validation_error(), None);
+    assert_eq!(form.billing_address.city.validation_error(), None);
+}
+
+#[test]
+fn a_reset_form_can_be_filled_in_again() {
+    let mut form = OrderForm::default();
+    form.set_input(OrderFormField::Reference, "INV-1".to_string());
+    form.reset();
+
+    form.set_input(OrderFormField::Reference, "INV-2".to_string());
+    form.set_input(
+        OrderFormField::BillingAddress(AddressFormField::City),
+        "Durban".to_string(),
+    );
+    assert_eq!(
+        form.submit(),
+        Ok(Order {
+            reference: "INV-2".to_string(),
+            billing_address: Address {
+                city: "Durban".to_string(),
+            },
+            delivery_address: None,
+            items: vec![],
+        })
+    );
+}
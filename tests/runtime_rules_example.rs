@@ -0,0 +1,163 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    ParseAndFormat, ParseError, StructForm,
+};
+
+// This example builds on the [numeric bounds example](./numeric_bounds_example.rs).
+// This example is written assuming that you're already familiar with
+// the numeric bounds example, so if not please refer to that first.
+
+// Some rules can only be decided once the form is on screen, like a
+// withdrawal limit that is fetched from the server for the current
+// user. These rules can be added to an input with `add_rule` after the
+// form is built. Rules are checked in the order they were added, after
+// the input is parsed and again when the form is submitted.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "an amount", u32, u32, 1, u32::MAX);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Withdrawal {
+    account: String,
+    amount: u32,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Withdrawal")]
+struct WithdrawalForm {
+    account: FormTextInput<String>,
+    amount: FormNumberInput<u32>,
+}
+
+fn within_daily_limit(amount: &u32) -> Result<(), ParseError> {
+    if *amount > 5000 {
+        Err(ParseError::InvalidFormat {
+            required_type: "an amount within your daily limit of 5000".to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[allow(clippy::manual_is_multiple_of)]
+fn whole_hundreds(amount: &u32) -> Result<(), ParseError> {
+    if amount % 100 != 0 {
+        Err(ParseError::InvalidFormat {
+            required_type: "a multiple of 100".to_string(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn rules_are_checked_after_parsing() {
+    let mut form = WithdrawalForm::default();
+    form.amount.add_rule(within_daily_limit);
+    form.amount.add_rule(whole_hundreds);
+
+    form.set_input(WithdrawalFormField::Amount, "6000".to_string());
+    assert_eq!(
+        form.amount.validation_error(),
+        Some(&ParseError::InvalidFormat {
+            required_type: "an amount within your daily limit of 5000".to_string(),
+        })
+    );
+
+    // The first rule that fails is reported.
+    form.set_input(WithdrawalFormField::Amount, "6050".to_string());
+    assert_eq!(
+        form.amount.validation_error(),
+        Some(&ParseError::InvalidFormat {
+            required_type: "an amount within your daily limit of 5000".to_string(),
+        })
+    );
+
+    form.set_input(WithdrawalFormField::Amount, "250".to_string());
+    assert_eq!(
+        form.amount.validation_error(),
+        Some(&ParseError::InvalidFormat {
+            required_type: "a multiple of 100".to_string(),
+        })
+    );
+
+    form.set_input(WithdrawalFormField::Amount, "300".to_string());
+    form.set_input(WithdrawalFormField::Account, "Savings".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(Withdrawal {
+            account: "Savings".to_string(),
+            amount: 300,
+        })
+    );
+}
+
+#[test]
+fn adding_a_rule_checks_the_current_value() {
+    let mut form = WithdrawalForm::new(&Withdrawal {
+        account: "Savings".to_string(),
+        amount: 8000,
+    });
+    assert!(form.amount.value.is_ok());
+
+    form.amount.add_rule(within_daily_limit);
+    assert!(form.amount.value.is_err());
+    assert!(form.submit().is_err());
+}
+
+// Rules can also be closures, so they can capture what they check
+// against, like a balance that was only just fetched.
+
+#[test]
+fn rules_can_capture_their_context() {
+    let balance = 700;
+    let mut form = WithdrawalForm::default();
+    form.amount.add_rule(move |amount| {
+        if *amount > balance {
+            Err(ParseError::InvalidFormat {
+                required_type: format!("an amount up to your balance of {}", balance),
+            })
+        } else {
+            Ok(())
+        }
+    });
+
+    form.set_input(WithdrawalFormField::Amount, "800".to_string());
+    assert_eq!(
+        form.amount.validation_error(),
+        Some(&ParseError::InvalidFormat {
+            required_type: "an amount up to your balance of 700".to_string(),
+        })
+    );
+
+    // Clones of the form share the rules that were added to it.
+    let mut copy = form.clone();
+    copy.set_input(WithdrawalFormField::Amount, "900".to_string());
+    assert!(copy.amount.validation_error().is_some());
+
+    form.set_input(WithdrawalFormField::Amount, "600".to_string());
+    assert_eq!(form.amount.validation_error(), None);
+}
+
+#[test]
+fn forms_with_rules_can_be_sent_between_threads() {
+    let mut form = WithdrawalForm::default();
+    form.amount.add_rule(within_daily_limit);
+    let form = std::thread::spawn(move || {
+        form.set_input(WithdrawalFormField::Amount, "6000".to_string());
+        form
+    })
+    .join()
+    .unwrap();
+    assert!(form.amount.value.is_err());
+}
+
+#[test]
+fn parse_on_its_own_does_not_know_about_rules() {
+    assert_eq!(FormNumberInput::<u32>::parse("8000"), Ok(8000));
+}
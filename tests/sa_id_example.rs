@@ -0,0 +1,111 @@
+#![cfg(all(feature = "sa_id", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, Citizenship, Gender, ParseAndFormat,
+    ParseError, SaIdNumber, StructForm,
+};
+
+// This example shows the South African ID number input.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Debug, PartialEq, Eq)]
+struct Applicant {
+    name: String,
+    id_number: SaIdNumber,
+}
+
+impl Default for Applicant {
+    fn default() -> Applicant {
+        Applicant {
+            name: String::new(),
+            id_number: "8001015009087".parse().unwrap(),
+        }
+    }
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Applicant")]
+struct ApplicantForm {
+    name: FormTextInput<String>,
+    id_number: FormTextInput<SaIdNumber>,
+}
+
+// `SaIdNumber` implements `FromStr` and `Display`, so it works with
+// the usual text input macros.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+impl_text_input_with_stringops!(FormTextInput, SaIdNumber);
+
+#[test]
+fn id_numbers_expose_the_holders_details() {
+    let mut form = ApplicantForm::default();
+
+    form.set_input(ApplicantFormField::IdNumber, "950615 4121 08 8".to_string());
+    let id_number = form.id_number.value.clone().unwrap();
+    assert_eq!(id_number.to_string(), "9506154121088");
+    assert_eq!(id_number.date_of_birth(2024), (1995, 6, 15));
+    assert_eq!(id_number.gender(), Gender::Female);
+    assert_eq!(id_number.citizenship(), Citizenship::Citizen);
+
+    let id_number: SaIdNumber = "0402290123189".parse().unwrap();
+    assert_eq!(id_number.date_of_birth(2024), (2004, 2, 29));
+    assert_eq!(id_number.gender(), Gender::Female);
+    assert_eq!(id_number.citizenship(), Citizenship::PermanentResident);
+
+    let id_number = Applicant::default().id_number;
+    assert_eq!(id_number.date_of_birth(2024), (1980, 1, 1));
+    assert_eq!(id_number.gender(), Gender::Male);
+}
+
+// ID numbers only have the last two digits of the year. Years that
+// would be after the current year that's passed in are from the
+// previous century.
+
+#[test]
+fn the_century_of_the_birth_year_depends_on_the_current_year() {
+    let id_number: SaIdNumber = "0402290123189".parse().unwrap();
+    assert_eq!(id_number.date_of_birth(2003), (1904, 2, 29));
+    assert_eq!(id_number.date_of_birth(2004), (2004, 2, 29));
+}
+
+#[test]
+fn invalid_id_numbers_are_rejected() {
+    let mut form = ApplicantForm::default();
+
+    form.set_input(ApplicantFormField::IdNumber, "80010150090".to_string());
+    assert_eq!(
+        form.id_number.value,
+        Err(ParseError::FromStrError(
+            "Expected a 13 digit ID number".to_string()
+        ))
+    );
+
+    // 2005 wasn't a leap year.
+    form.set_input(ApplicantFormField::IdNumber, "0502290123189".to_string());
+    assert_eq!(
+        form.id_number.value,
+        Err(ParseError::FromStrError(
+            "This ID number does not start with a valid date of birth".to_string()
+        ))
+    );
+
+    form.set_input(ApplicantFormField::IdNumber, "8001015009387".to_string());
+    assert_eq!(
+        form.id_number.value,
+        Err(ParseError::FromStrError(
+            "This ID number's citizenship digit is not valid".to_string()
+        ))
+    );
+
+    form.set_input(ApplicantFormField::IdNumber, "8001015009088".to_string());
+    assert_eq!(
+        form.id_number.value,
+        Err(ParseError::FromStrError(
+            "This is not a valid ID number".to_string()
+        ))
+    );
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "text")]
+
+use structform::{derive_form_input, FormSelect, ParseAndFormat, ParseError, StructForm};
+
+// This example shows how to let users pick a value of an enum, like
+// with a dropdown.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+// The `FormSelect` derive gives an enum `Display` and `FromStr`
+// implementations which use the variant names as values, and
+// implements `ParseAndFormat` for the input named in the `formselect`
+// annotation. It also adds an `OPTIONS` list, with the value and
+// label of each variant, for rendering the dropdown's options.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FormSelect)]
+#[formselect(input = "FormSelectInput")]
+enum AccountType {
+    Personal,
+    #[formselect(label = "Business or company")]
+    Business,
+    NonProfit,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Account {
+    account_type: AccountType,
+    previous_account_type: Option<AccountType>,
+}
+
+impl Default for Account {
+    fn default() -> Account {
+        Account {
+            account_type: AccountType::Personal,
+            previous_account_type: None,
+        }
+    }
+}
+
+#[derive(Clone, StructForm)]
+#[structform(model = "Account")]
+struct AccountForm {
+    account_type: FormSelectInput<AccountType>,
+    previous_account_type: FormSelectInput<Option<AccountType>>,
+}
+
+derive_form_input! {FormSelectInput}
+
+#[test]
+fn options_list_every_variant_with_a_label() {
+    assert_eq!(
+        AccountType::OPTIONS,
+        &[
+            ("Personal", "Personal"),
+            ("Business", "Business or company"),
+            ("NonProfit", "Non profit"),
+        ][..]
+    );
+}
+
+#[test]
+fn selecting_an_option_sets_the_enum() {
+    let mut form = AccountForm::new(&Account {
+        account_type: AccountType::Personal,
+        previous_account_type: None,
+    });
+    assert_eq!(form.account_type.input, "Personal".to_string());
+
+    form.set_input(AccountFormField::AccountType, "Other".to_string());
+    assert_eq!(
+        form.account_type
+            .validation_error()
+            .map(ParseError::to_string),
+        Some("Expected one of Personal, Business or company, Non profit.".to_string())
+    );
+
+    form.set_input(AccountFormField::AccountType, "NonProfit".to_string());
+    form.set_input(
+        AccountFormField::PreviousAccountType,
+        "Business".to_string(),
+    );
+    assert_eq!(
+        form.submit(),
+        Ok(Account {
+            account_type: AccountType::NonProfit,
+            previous_account_type: Some(AccountType::Business),
+        })
+    );
+}
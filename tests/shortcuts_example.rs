@@ -0,0 +1,136 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, FormAction, KeyPress, ParseAndFormat,
+    ParseError, ShortcutMap, StructForm,
+};
+
+// This example shows how to respond to keyboard shortcuts in a form.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+// A `ShortcutMap` translates key presses into `FormAction`s. Keys are
+// named like the DOM's `KeyboardEvent.key`, and with the `web`
+// feature, `structform::key_press` reads them from a `KeyboardEvent`.
+// The app decides what each action does to its form. In this example,
+// resetting goes back to the profile that the form was opened with,
+// and undo puts back the input from before the last change.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Profile {
+    display_name: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Profile")]
+struct ProfileForm {
+    display_name: FormTextInput<String>,
+}
+
+struct App {
+    shortcuts: ShortcutMap,
+    profile: Profile,
+    form: ProfileForm,
+    history: Vec<ProfileForm>,
+    saved: Option<Result<Profile, ParseError>>,
+}
+
+impl App {
+    fn new(profile: Profile) -> App {
+        App {
+            shortcuts: ShortcutMap::default(),
+            form: ProfileForm::new(&profile),
+            profile,
+            history: Vec::new(),
+            saved: None,
+        }
+    }
+
+    fn type_display_name(&mut self, value: &str) {
+        self.history.push(self.form.clone());
+        self.form
+            .set_input(ProfileFormField::DisplayName, value.to_string());
+    }
+
+    fn key_down(&mut self, key: KeyPress) {
+        match self.shortcuts.action(&key) {
+            Some(FormAction::Submit) => self.saved = Some(self.form.submit()),
+            Some(FormAction::Reset) => {
+                self.form = ProfileForm::new(&self.profile);
+                self.history.clear();
+            }
+            Some(FormAction::Undo) => {
+                if let Some(previous) = self.history.pop() {
+                    self.form = previous;
+                }
+            }
+            None => {}
+        }
+    }
+}
+
+fn profile(display_name: &str) -> Profile {
+    Profile {
+        display_name: display_name.to_string(),
+    }
+}
+
+#[test]
+fn enter_submits() {
+    let mut app = App::new(profile("Justin"));
+    app.type_display_name("Justin W");
+    app.key_down(KeyPress::new("Enter"));
+    assert_eq!(app.saved, Some(Ok(profile("Justin W"))));
+}
+
+#[test]
+fn escape_resets() {
+    let mut app = App::new(profile("Justin"));
+    app.type_display_name("Justin W");
+    app.key_down(KeyPress::new("Escape"));
+    assert_eq!(app.form.display_name.input, "Justin");
+}
+
+#[test]
+fn ctrl_z_and_cmd_z_undo() {
+    let mut app = App::new(profile("Justin"));
+    app.type_display_name("Justin W");
+    app.type_display_name("Justin Wernick");
+
+    app.key_down(KeyPress::new("z").with_ctrl());
+    assert_eq!(app.form.display_name.input, "Justin W");
+
+    // Letters match without case, since shift changes the reported key.
+    app.key_down(KeyPress::new("Z").with_meta());
+    assert_eq!(app.form.display_name.input, "Justin");
+
+    // Other combinations aren't bound.
+    app.type_display_name("Justin W");
+    app.key_down(KeyPress::new("z"));
+    app.key_down(KeyPress::new("z").with_ctrl().with_shift());
+    assert_eq!(app.form.display_name.input, "Justin W");
+}
+
+#[test]
+fn shortcuts_can_be_changed() {
+    let mut shortcuts = ShortcutMap::default();
+    shortcuts.unbind(&KeyPress::new("Enter"));
+    shortcuts.bind(KeyPress::new("Enter").with_ctrl(), FormAction::Submit);
+    shortcuts.bind(KeyPress::new("Escape"), FormAction::Undo);
+
+    assert_eq!(shortcuts.action(&KeyPress::new("Enter")), None);
+    assert_eq!(
+        shortcuts.action(&KeyPress::new("Enter").with_ctrl()),
+        Some(FormAction::Submit)
+    );
+    assert_eq!(
+        shortcuts.action(&KeyPress::new("Escape")),
+        Some(FormAction::Undo)
+    );
+    assert_eq!(ShortcutMap::new().action(&KeyPress::new("Enter")), None);
+}
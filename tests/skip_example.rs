@@ -0,0 +1,104 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, FormFields, ParseAndFormat, StructForm,
+};
+
+// This example shows how to have fields on the model that have no
+// input, and fields on the form that aren't inputs.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Note {
+    title: String,
+    body: String,
+    revision: u32,
+    pinned: bool,
+}
+
+// The derived `submit` starts from the model's `Default`, so model
+// fields without an input are already left at their defaults. When a
+// new model should start with a different value, list the field in
+// `skip` on the container, with an expression for its value. Fields
+// listed without an expression are filled in with `Default::default()`.
+//
+// This only applies to `submit`. `submit_update` keeps the values of
+// skipped fields from the model that is being updated.
+//
+// Fields on the form marked with `#[structform(skip)]` aren't inputs.
+// They hold state that belongs with the form, like whether it's open
+// in an editor. They are filled in with `Default::default()`, or the
+// expression given with `default`, when the form is created from a
+// model.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Note", skip = "revision = 1, pinned")]
+struct NoteForm {
+    title: FormTextInput<String>,
+    body: FormTextInput<String>,
+    #[structform(skip, default = "EditorMode::Preview")]
+    mode: EditorMode,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+enum EditorMode {
+    #[default]
+    Editing,
+    Preview,
+}
+
+#[test]
+fn new_models_get_the_skipped_fields_defaults() {
+    let mut form = NoteForm::default();
+    form.set_input(NoteFormField::Title, "Groceries".to_string());
+    form.set_input(NoteFormField::Body, "Milk".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(Note {
+            title: "Groceries".to_string(),
+            body: "Milk".to_string(),
+            revision: 1,
+            pinned: false,
+        })
+    );
+}
+
+#[test]
+fn updated_models_keep_their_skipped_fields() {
+    let note = Note {
+        title: "Groceries".to_string(),
+        body: "Milk".to_string(),
+        revision: 4,
+        pinned: true,
+    };
+    let mut form = NoteForm::new(&note);
+    form.set_input(NoteFormField::Body, "Milk and eggs".to_string());
+    assert_eq!(
+        form.submit_update(note),
+        Ok(Note {
+            title: "Groceries".to_string(),
+            body: "Milk and eggs".to_string(),
+            revision: 4,
+            pinned: true,
+        })
+    );
+}
+
+#[test]
+fn skipped_form_fields_are_not_inputs() {
+    let form = NoteForm::new(&Note::default());
+    assert_eq!(form.mode, EditorMode::Preview);
+    assert_eq!(
+        NoteForm::FIELDS
+            .iter()
+            .map(|field| field.name)
+            .collect::<Vec<_>>(),
+        vec!["title", "body"]
+    );
+}
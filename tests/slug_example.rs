@@ -0,0 +1,96 @@
+#![cfg(all(feature = "slug", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_slug_input, impl_text_input_with_stringops, ParseAndFormat, ParseError,
+    StructForm,
+};
+
+// This example shows a slug input, which is filled in automatically
+// from another field.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct BlogPost {
+    title: String,
+    slug: String,
+}
+
+// The `#[structform(slug_of = "title")]` annotation tells the derive
+// macro that whenever the title changes, the slug should be updated
+// to match it. This only happens until the user edits the slug
+// themselves.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "BlogPost")]
+struct BlogPostForm {
+    title: FormTextInput<String>,
+    #[structform(slug_of = "title")]
+    slug: FormSlugInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// `impl_slug_input` implements `ParseAndFormat` for slugs, which may
+// only contain lower case letters, numbers and hyphens.
+
+derive_form_input! {FormSlugInput}
+impl_slug_input!(FormSlugInput);
+
+#[test]
+fn the_slug_follows_the_title() {
+    let mut form = BlogPostForm::default();
+
+    form.set_input(BlogPostFormField::Title, "Hello".to_string());
+    assert_eq!(form.slug.input, "hello".to_string());
+
+    form.set_input(
+        BlogPostFormField::Title,
+        "Hello, StructForm World!".to_string(),
+    );
+    assert_eq!(form.slug.input, "hello-structform-world".to_string());
+
+    // Since the user didn't edit the slug, it won't show validation
+    // errors yet.
+    assert!(!form.slug.is_edited);
+
+    assert_eq!(
+        form.submit(),
+        Ok(BlogPost {
+            title: "Hello, StructForm World!".to_string(),
+            slug: "hello-structform-world".to_string()
+        })
+    );
+}
+
+#[test]
+fn the_slug_stops_following_the_title_once_edited() {
+    let mut form = BlogPostForm::default();
+
+    form.set_input(BlogPostFormField::Title, "Hello World".to_string());
+    form.set_input(BlogPostFormField::Slug, "hi".to_string());
+    form.set_input(BlogPostFormField::Title, "Hello Everyone".to_string());
+    assert_eq!(form.slug.input, "hi".to_string());
+
+    // If the user clears the slug, it will start following the title
+    // again.
+    form.set_input(BlogPostFormField::Slug, "".to_string());
+    form.set_input(BlogPostFormField::Title, "Goodbye".to_string());
+    assert_eq!(form.slug.input, "goodbye".to_string());
+}
+
+#[test]
+fn slugs_must_be_lower_case_and_hyphenated() {
+    let mut form = BlogPostForm::default();
+
+    form.set_input(BlogPostFormField::Slug, "Not A Slug".to_string());
+    assert_eq!(
+        form.slug.value,
+        Err(ParseError::InvalidFormat {
+            required_type: "lower case letters, numbers and hyphens".to_string()
+        })
+    );
+}
@@ -0,0 +1,65 @@
+#![cfg(feature = "text")]
+
+use std::net::{IpAddr, Ipv4Addr};
+use structform::{ParseError, StdOptionalTextInput, StdTextInput, StructForm};
+
+// This example shows how to use the inputs that come with StructForm,
+// for types that don't need any customization.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+// `StdTextInput` can hold any type that implements `FromStr` and
+// `Display`, without calling `derive_form_input` or
+// `impl_text_input_with_stringops`. `StdOptionalTextInput` does the
+// same for optional values. When you need a custom error message or
+// parsing rules, derive your own input with the macros instead.
+
+#[derive(Debug, PartialEq, Eq)]
+struct Server {
+    name: String,
+    ip: IpAddr,
+    port: Option<u16>,
+}
+
+impl Default for Server {
+    fn default() -> Server {
+        Server {
+            name: String::new(),
+            ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            port: None,
+        }
+    }
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Server")]
+struct ServerForm {
+    name: StdTextInput<String>,
+    ip: StdTextInput<IpAddr>,
+    port: StdOptionalTextInput<Option<u16>>,
+}
+
+#[test]
+fn std_inputs_parse_with_from_str() {
+    let mut form = ServerForm::default();
+    form.set_input(ServerFormField::Name, " db ".to_string());
+    form.set_input(ServerFormField::Ip, "10.0.0.1".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(Server {
+            name: "db".to_string(),
+            ip: IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)),
+            port: None,
+        })
+    );
+
+    form.set_input(ServerFormField::Port, "eighty".to_string());
+    assert_eq!(
+        form.port.validation_error(),
+        Some(&ParseError::FromStrError(
+            "invalid digit found in string".to_string()
+        ))
+    );
+}
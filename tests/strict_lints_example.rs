@@ -0,0 +1,52 @@
+#![cfg(feature = "text")]
+//! This example shows that forms can be derived in crates that deny
+//! common lints, like ones that require every public item to be
+//! documented.
+
+#![deny(missing_docs, dead_code)]
+
+use structform::{derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm};
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+/// A person's name.
+#[derive(Default, Debug, PartialEq, Eq)]
+pub struct Person {
+    /// The person's first name.
+    pub first_name: String,
+    /// The person's last name.
+    pub last_name: String,
+}
+
+// The field enum, partial model and trait implementations generated
+// here don't have docs, and the field enum's variants all end in
+// `Name`, which `clippy::enum_variant_names` would usually warn about.
+// The derive allows these lints on the items that it generates, so
+// they only apply to your own code.
+
+/// A form for editing a `Person`.
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Person", partial_model = "PartialPerson")]
+pub struct PersonForm {
+    first_name: FormTextInput<String>,
+    last_name: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[test]
+fn strict_lints_are_not_tripped_by_generated_code() {
+    let mut form = PersonForm::default();
+    form.set_input(PersonFormField::FirstName, "Joe".to_string());
+    form.set_input(PersonFormField::LastName, "Soap".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(Person {
+            first_name: "Joe".to_string(),
+            last_name: "Soap".to_string(),
+        })
+    );
+}
@@ -1,3 +1,5 @@
+#![cfg(feature = "text")]
+
 use structform::{
     derive_form_input, impl_text_input_with_stringops, ParseAndFormat, ParseError, StructForm,
 };
@@ -19,7 +21,7 @@ struct UserDetails {
     secondary_address: Option<Address>,
 }
 
-#[derive(Default, Clone, Debug, PartialEq, Eq)]
+#[derive(Default, Debug, PartialEq, Eq)]
 struct Address {
     street_address: String,
     city: String,
@@ -240,3 +242,37 @@ fn the_whole_form_can_be_completed() {
         })
     );
 }
+
+#[test]
+fn existing_models_can_be_updated_without_cloning_subform_models() {
+    // `submit_update` moves the nested models into their subforms'
+    // `submit_update`, so `Address` doesn't need to implement Clone.
+
+    let model = UserDetails {
+        username: "justin".to_string(),
+        primary_address: Address {
+            street_address: "123 StructForm Drive".to_string(),
+            city: "Johannesburg".to_string(),
+            country: "South Africa".to_string(),
+        },
+        secondary_address: None,
+    };
+    let mut form = UserDetailsForm::new(&model);
+    form.set_input(
+        UserDetailsFormField::PrimaryAddress(AddressFormField::City),
+        "Pretoria".to_string(),
+    );
+
+    assert_eq!(
+        form.submit_update(model),
+        Ok(UserDetails {
+            username: "justin".to_string(),
+            primary_address: Address {
+                street_address: "123 StructForm Drive".to_string(),
+                city: "Pretoria".to_string(),
+                country: "South Africa".to_string(),
+            },
+            secondary_address: None,
+        })
+    );
+}
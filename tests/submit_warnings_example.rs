@@ -0,0 +1,91 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    CheckedSubmit, FieldWarning, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+// Some values are valid, but unusual enough that it's worth asking
+// the user "are you sure?" before saving them. `submit_checked`
+// submits the form as usual, and then runs a check for warnings on
+// the model. If there are warnings, you get the model back along with
+// them, so you can show a confirmation step without validating the
+// form again.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "an age", u32, u32, 0, 150);
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Patient {
+    name: String,
+    age: u32,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Patient")]
+struct PatientForm {
+    name: FormTextInput<String>,
+    age: FormNumberInput<u32>,
+}
+
+fn unusual_patients(patient: &Patient) -> Vec<FieldWarning> {
+    let mut warnings = Vec::new();
+    if patient.age > 110 {
+        warnings.push(FieldWarning {
+            path: "age".to_string(),
+            message: "Patients are rarely older than 110".to_string(),
+        });
+    }
+    warnings
+}
+
+#[test]
+fn errors_block_the_submit() {
+    let mut form = PatientForm::default();
+    form.set_input(PatientFormField::Name, "Joe".to_string());
+    assert_eq!(
+        form.submit_checked(unusual_patients),
+        CheckedSubmit::Err(ParseError::Required)
+    );
+}
+
+#[test]
+fn usual_values_submit_without_confirmation() {
+    let mut form = PatientForm::default();
+    form.set_input(PatientFormField::Name, "Joe".to_string());
+    form.set_input(PatientFormField::Age, "42".to_string());
+    assert_eq!(
+        form.submit_checked(unusual_patients),
+        CheckedSubmit::Ok(Patient {
+            name: "Joe".to_string(),
+            age: 42,
+        })
+    );
+}
+
+#[test]
+fn unusual_values_need_confirmation() {
+    let mut form = PatientForm::default();
+    form.set_input(PatientFormField::Name, "Joe".to_string());
+    form.set_input(PatientFormField::Age, "120".to_string());
+    assert_eq!(
+        form.submit_checked(unusual_patients),
+        CheckedSubmit::NeedsConfirmation(
+            Patient {
+                name: "Joe".to_string(),
+                age: 120,
+            },
+            vec![FieldWarning {
+                path: "age".to_string(),
+                message: "Patients are rarely older than 110".to_string(),
+            }]
+        )
+    );
+}
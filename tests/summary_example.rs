@@ -0,0 +1,133 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, find_field, impl_numeric_input_with_stringops,
+    impl_text_input_with_stringops, FieldKind, FormFields, ParseAndFormat, StructForm,
+};
+
+// This example shows how to list everything that was entered on a
+// form, for a "review before submitting" page.
+
+// This example builds on the [subforms example](./subforms_example.rs).
+// This example is written assuming that you're already familiar with
+// the subforms example, so if not please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Registration {
+    full_name: String,
+    password: String,
+    age: u32,
+    address: Address,
+    children: Vec<Child>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct Child {
+    name: String,
+}
+
+// Every form has a `summary` function, which returns a label and a
+// formatted value for each input. Labels are the field names in
+// sentence case, unless they are set with the `label` annotation.
+// Fields with the `sensitive` annotation, like passwords, are left
+// out of the summary.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Registration")]
+struct RegistrationForm {
+    full_name: FormTextInput<String>,
+    #[structform(sensitive)]
+    password: FormTextInput<String>,
+    #[structform(label = "Age in years")]
+    age: FormNumberInput<u32>,
+    #[structform(subform)]
+    address: AddressForm,
+    children: Vec<ChildForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Child")]
+struct ChildForm {
+    name: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "a number", u32, u32);
+
+#[test]
+fn the_summary_lists_every_input_except_sensitive_ones() {
+    let mut form = RegistrationForm::default();
+    form.apply_urlencoded(
+        "full_name=Joe+Soap&password=hunter2&age=+42+&address.city=Johannesburg&children[0].name=Jane&children[1].name=John",
+    );
+
+    assert_eq!(
+        form.summary(),
+        vec![
+            ("Full name".to_string(), "Joe Soap".to_string()),
+            ("Age in years".to_string(), "42".to_string()),
+            ("Address - City".to_string(), "Johannesburg".to_string()),
+            ("Children 1 - Name".to_string(), "Jane".to_string()),
+            ("Children 2 - Name".to_string(), "John".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn invalid_inputs_are_shown_as_they_were_typed() {
+    let mut form = RegistrationForm::default();
+    form.set_input(RegistrationFormField::Age, "old".to_string());
+
+    assert!(form
+        .summary()
+        .contains(&("Age in years".to_string(), "old".to_string())));
+}
+
+// Every form also has a `FIELDS` table, with the name, label and kind
+// of each of its inputs and subforms. It's a `static` slice, so a view
+// can look up labels while rendering without allocating. The table
+// comes from the `FormFields` trait, so that it can't clash with
+// anything the form defines itself.
+
+#[test]
+fn labels_can_be_looked_up_without_a_form() {
+    let names: Vec<&str> = RegistrationForm::FIELDS
+        .iter()
+        .map(|field| field.name)
+        .collect();
+    assert_eq!(
+        names,
+        vec!["full_name", "password", "age", "address", "children"]
+    );
+    assert_eq!(
+        RegistrationForm::FIELDS[3].kind,
+        FieldKind::Subform(AddressForm::FIELDS)
+    );
+
+    let age = find_field(RegistrationForm::FIELDS, "age").unwrap();
+    assert_eq!(age.label, "Age in years");
+    assert!(
+        find_field(RegistrationForm::FIELDS, "password")
+            .unwrap()
+            .sensitive
+    );
+    assert_eq!(
+        find_field(RegistrationForm::FIELDS, "children[1].name").map(|field| field.label),
+        Some("Name")
+    );
+    assert_eq!(find_field(RegistrationForm::FIELDS, "nickname"), None);
+}
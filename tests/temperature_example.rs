@@ -0,0 +1,83 @@
+#![cfg(feature = "temperature")]
+
+use structform::{
+    derive_form_input, impl_temperature_input, ParseAndFormat, ParseError, StructForm,
+    TemperatureUnit,
+};
+
+// This example shows a temperature input, which accepts temperatures
+// in Celsius or Fahrenheit and converts them to one unit.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Debug, Default, PartialEq)]
+struct Thermostat {
+    target_celsius: f64,
+    alarm_celsius: Option<f64>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Thermostat")]
+struct ThermostatForm {
+    target_celsius: FormThermostatInput<f64>,
+    alarm_celsius: FormThermostatInput<Option<f64>>,
+}
+
+// The model stores temperatures in Celsius, and only allows
+// temperatures that a thermostat can actually be set to. The range is
+// also given in Celsius.
+
+derive_form_input! {FormThermostatInput}
+impl_temperature_input!(FormThermostatInput, TemperatureUnit::Celsius, 5.0, 35.0);
+
+#[test]
+fn temperatures_are_converted_to_the_configured_unit() {
+    let mut form = ThermostatForm::default();
+
+    form.set_input(ThermostatFormField::TargetCelsius, "21.5".to_string());
+    assert_eq!(form.target_celsius.value, Ok(21.5));
+
+    form.set_input(ThermostatFormField::TargetCelsius, "68°F".to_string());
+    assert_eq!(form.target_celsius.value, Ok(20.0));
+
+    form.set_input(ThermostatFormField::AlarmCelsius, "30 c".to_string());
+    assert_eq!(
+        form.submit(),
+        Ok(Thermostat {
+            target_celsius: 20.0,
+            alarm_celsius: Some(30.0),
+        })
+    );
+
+    let form = ThermostatForm::new(&Thermostat {
+        target_celsius: 19.0,
+        alarm_celsius: None,
+    });
+    assert_eq!(form.target_celsius.input, "19°C".to_string());
+    assert_eq!(form.alarm_celsius.input, "".to_string());
+}
+
+#[test]
+fn temperatures_outside_of_the_range_are_rejected() {
+    let mut form = ThermostatForm::default();
+
+    form.set_input(ThermostatFormField::TargetCelsius, "100F".to_string());
+    assert_eq!(
+        form.target_celsius.value,
+        Err(ParseError::NumberOutOfRange {
+            required_type: "a temperature".to_string(),
+            min: "5°C".to_string(),
+            max: "35°C".to_string(),
+        })
+    );
+
+    form.set_input(ThermostatFormField::TargetCelsius, "20 K".to_string());
+    assert_eq!(
+        form.target_celsius.value,
+        Err(ParseError::InvalidFormat {
+            required_type: "a temperature".to_string()
+        })
+    );
+}
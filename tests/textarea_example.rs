@@ -0,0 +1,155 @@
+#![cfg(feature = "textarea")]
+
+use structform::{derive_form_input, impl_textarea_input, ParseAndFormat, ParseError, StructForm};
+
+// This example shows an input for long free text, like a description
+// or a bio.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Profile {
+    bio: Option<String>,
+    description: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Profile")]
+struct ProfileForm {
+    bio: FormTextArea<Option<String>>,
+    description: FormRichTextArea<String>,
+}
+
+// `impl_textarea_input` implements `ParseAndFormat` with a maximum
+// length. HTML is removed from the text when it's parsed, and
+// anything else that a browser could read as HTML is escaped, so it's
+// safe to show to other users. You can optionally allow some tags
+// through.
+
+derive_form_input! {FormTextArea}
+impl_textarea_input!(FormTextArea, 20);
+
+derive_form_input! {FormRichTextArea}
+impl_textarea_input!(FormRichTextArea, 200, &["b", "i"]);
+
+#[test]
+fn text_areas_have_a_maximum_length() {
+    let mut form = ProfileForm::default();
+
+    form.set_input(
+        ProfileFormField::Bio,
+        "This is far too long for a bio".to_string(),
+    );
+    assert_eq!(form.bio.value, Err(ParseError::TooLong { max: 20 }));
+
+    form.set_input(ProfileFormField::Bio, "  ".to_string());
+    assert_eq!(form.bio.value, Ok(None));
+}
+
+#[test]
+fn disallowed_html_is_removed_when_parsing() {
+    let mut form = ProfileForm::default();
+
+    form.set_input(
+        ProfileFormField::Description,
+        "<b onclick=\"steal()\">Hello</b> <a href=\"x\">world</a><script>alert(1)</script>!"
+            .to_string(),
+    );
+    assert_eq!(
+        form.description.value,
+        Ok("<b>Hello</b> world!".to_string())
+    );
+
+    form.set_input(ProfileFormField::Bio, "<i>Hi</i>".to_string());
+    assert_eq!(form.bio.value, Ok(Some("Hi".to_string())));
+
+    // Text that was only HTML is empty once sanitized.
+    form.set_input(ProfileFormField::Description, "<br/>".to_string());
+    assert_eq!(form.description.value, Err(ParseError::Required));
+}
+
+#[test]
+fn html_is_parsed_the_way_a_browser_would() {
+    // The text is parsed with [ammonia](https://docs.rs/ammonia), so
+    // tricks that fool a simple search for `<` and `>` don't get
+    // through.
+    let mut form = ProfileForm::default();
+
+    // A tag that's never closed is still a tag to a browser.
+    form.set_input(
+        ProfileFormField::Description,
+        "hi <img src=x onerror=alert(1) ".to_string(),
+    );
+    assert_eq!(form.description.value, Ok("hi".to_string()));
+
+    // A `>` inside an attribute doesn't end the tag.
+    form.set_input(
+        ProfileFormField::Description,
+        "<b title=\"a>b\" onclick=\"steal()\">Hello</b>".to_string(),
+    );
+    assert_eq!(form.description.value, Ok("<b>Hello</b>".to_string()));
+
+    // Links lose their URLs, including `javascript:` ones.
+    form.set_input(
+        ProfileFormField::Description,
+        "<a href=\"javascript:steal()\">click</a>".to_string(),
+    );
+    assert_eq!(form.description.value, Ok("click".to_string()));
+}
+
+#[test]
+fn text_that_looks_like_html_is_escaped() {
+    let mut form = ProfileForm::default();
+
+    // A `<` that isn't part of a tag is kept, but escaped so that a
+    // browser shows it as text.
+    form.set_input(
+        ProfileFormField::Description,
+        "Fish & chips < 5 > 3".to_string(),
+    );
+    assert_eq!(
+        form.description.value,
+        Ok("Fish &amp; chips &lt; 5 &gt; 3".to_string())
+    );
+
+    // Existing values are unescaped when they're put in the input, so
+    // the user sees what they typed.
+    let form = ProfileForm::new(&Profile {
+        bio: Some("Fish &amp; chips".to_string()),
+        description: "Fish &amp; chips".to_string(),
+    });
+    assert_eq!(form.bio.input, "Fish & chips");
+    assert_eq!(form.bio.value, Ok(Some("Fish &amp; chips".to_string())));
+}
+
+#[cfg(feature = "markdown")]
+#[test]
+fn a_markdown_preview_can_be_rendered() {
+    // With the `markdown` feature, you can render a preview of what
+    // the user has typed.
+    let mut form = ProfileForm::default();
+    form.set_input(
+        ProfileFormField::Description,
+        "# About\n\nI like **Rust** and *forms*.\n\n- [StructForm](https://crates.io/crates/structform)\n- <script>\n1. `code`"
+            .to_string(),
+    );
+    assert_eq!(
+        structform::markdown_to_html(&form.description.input),
+        "<h1>About</h1>\n\
+         <p>I like <strong>Rust</strong> and <em>forms</em>.</p>\n\
+         <ul>\n\
+         <li><a href=\"https://crates.io/crates/structform\">StructForm</a></li>\n\
+         <li>&lt;script&gt;\n</li>\n\
+         </ul>\n\
+         <ol>\n\
+         <li><code>code</code></li>\n\
+         </ol>\n"
+    );
+
+    assert_eq!(
+        structform::markdown_to_html("[click](javascript:steal)"),
+        "<p>click</p>\n"
+    );
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "text")]
+
+use std::fmt::Write;
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, DefaultTheme, FieldKind, FormFields,
+    ParseAndFormat, StructForm, Theme,
+};
+
+// This example shows how to write a renderer whose markup can be
+// styled by different design systems, without forking it.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+// Renderers take a `Theme`, and ask it for the CSS classes of inputs,
+// labels, error messages and invalid inputs. Themes only need to
+// override the classes they change, so this Bootstrap theme leaves
+// the label class as the default.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Login {
+    username: String,
+    password: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Login")]
+struct LoginForm {
+    username: FormTextInput<String>,
+    password: FormTextInput<String>,
+}
+
+struct BootstrapTheme;
+
+impl Theme for BootstrapTheme {
+    fn input_class(&self) -> &str {
+        "form-control"
+    }
+
+    fn error_class(&self) -> &str {
+        "invalid-feedback"
+    }
+
+    fn invalid_class(&self) -> &str {
+        "is-invalid"
+    }
+}
+
+// A small HTML renderer for the login form. A real renderer would be
+// generic over the form, using its `FIELDS` table.
+fn render(form: &LoginForm, theme: &dyn Theme) -> String {
+    let mut html = String::new();
+    let inputs = [
+        (&form.username.input, form.username.validation_error()),
+        (&form.password.input, form.password.validation_error()),
+    ];
+    for (field, (input, error)) in LoginForm::FIELDS.iter().zip(inputs.iter()) {
+        assert_eq!(field.kind, FieldKind::Input);
+        writeln!(
+            html,
+            "<label class=\"{}\">{}</label>",
+            theme.label_class(),
+            field.label
+        )
+        .unwrap();
+        writeln!(
+            html,
+            "<input class=\"{}\" name=\"{}\" value=\"{}\">",
+            theme.input_classes(error.is_some()),
+            field.name,
+            input
+        )
+        .unwrap();
+        if let Some(error) = error {
+            writeln!(
+                html,
+                "<div class=\"{}\">{}</div>",
+                theme.error_class(),
+                error
+            )
+            .unwrap();
+        }
+    }
+    html
+}
+
+#[test]
+fn default_theme_uses_structform_classes() {
+    let mut form = LoginForm::default();
+    form.set_input(LoginFormField::Username, "justin".to_string());
+    assert!(form.submit().is_err());
+
+    let html = render(&form, &DefaultTheme);
+    assert!(html.contains("<label class=\"structform-label\">Username</label>"));
+    assert!(html.contains("<input class=\"structform-input\" name=\"username\" value=\"justin\">"));
+    assert!(html.contains("<input class=\"structform-input structform-invalid\" name=\"password\""));
+    assert!(html.contains("<div class=\"structform-error\">This field is required.</div>"));
+}
+
+#[test]
+fn themes_override_only_the_classes_they_change() {
+    let mut form = LoginForm::default();
+    form.set_input(LoginFormField::Username, "justin".to_string());
+    assert!(form.submit().is_err());
+
+    let html = render(&form, &BootstrapTheme);
+    assert!(html.contains("<label class=\"structform-label\">Username</label>"));
+    assert!(html.contains("<input class=\"form-control\" name=\"username\""));
+    assert!(html.contains("<input class=\"form-control is-invalid\" name=\"password\""));
+    assert!(html.contains("<div class=\"invalid-feedback\">This field is required.</div>"));
+}
@@ -0,0 +1,79 @@
+#![cfg(all(feature = "ts-export", feature = "text"))]
+
+use structform::{derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm};
+
+// This example shows how to generate TypeScript types for a form, for
+// frontends that are partly written in TypeScript.
+
+// This example builds on the [manifest example](./manifest_example.rs).
+// This example is written assuming that you're already familiar with
+// the manifest example, so if not please refer to that first.
+
+// With the `ts-export` feature, a form's manifest can be turned into
+// TypeScript types for its field paths, and for its raw inputs keyed
+// by field path. The TypeScript side can then build field paths and
+// send raw inputs that are checked by its compiler, and the Rust side
+// applies them with `set_input_at_path`.
+//
+// Generate the file from a build script or a test, like
+// `std::fs::write("frontend/src/contact-form.ts", ts)`. Checking the
+// file in, and comparing it in a test like this one, catches forms
+// that changed without the TypeScript being regenerated.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Contact {
+    name: String,
+    postal_address: Option<Address>,
+    addresses: Vec<Address>,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Address {
+    street_address: String,
+    city: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Contact")]
+struct ContactForm {
+    name: FormTextInput<String>,
+    postal_address: Option<AddressForm>,
+    addresses: Vec<AddressForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    street_address: FormTextInput<String>,
+    city: FormTextInput<String>,
+}
+
+#[test]
+fn typescript_types_for_field_paths() {
+    assert_eq!(
+        ContactForm::manifest().to_typescript("ContactForm"),
+        r#"// Generated by structform. Do not edit.
+
+export type ContactFormPath =
+  | "name"
+  | "postal_address.street_address"
+  | "postal_address.city"
+  | `addresses[${number}].street_address`
+  | `addresses[${number}].city`;
+
+export type ContactFormRawValues = { [Path in ContactFormPath]?: string };
+"#
+    );
+}
+
+#[test]
+fn raw_values_are_keyed_by_field_path() {
+    let mut form = ContactForm::default();
+    assert!(form.set_input_at_path("addresses[0].city", "Johannesburg".to_string()));
+    assert!(form
+        .inputs_by_path()
+        .contains(&("addresses[0].city".to_string(), "Johannesburg".to_string())));
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows filling in a form from code with typed values,
+// rather than strings.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Default, Debug, PartialEq)]
+struct Landmark {
+    name: String,
+    latitude: f64,
+}
+
+// The `setters` annotation on the form asks for a typed setter for
+// every input. They aren't generated unless they're asked for, since
+// they're methods on the form itself, and could clash with its own
+// methods.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Landmark", setters)]
+struct LandmarkForm {
+    name: FormTextInput<String>,
+    latitude: FormCoordinateInput<f64>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// Coordinates are shown rounded to four decimal places, which is
+// plenty for someone to read, but loses precision that came from a
+// GPS.
+
+derive_form_input! {FormCoordinateInput}
+
+impl ParseAndFormat<f64> for FormCoordinateInput<f64> {
+    fn parse(value: &str) -> Result<f64, ParseError> {
+        value.trim().parse().map_err(|_| ParseError::InvalidFormat {
+            required_type: "a coordinate".to_string(),
+        })
+    }
+
+    fn format(value: &f64) -> String {
+        format!("{:.4}", value)
+    }
+}
+
+// Besides `set_input`, which takes a string like one typed by the
+// user, every input gets a setter named after it, like `set_latitude`,
+// that takes a value of the input's type. The input shows the
+// formatted value, but the value is kept as it is instead of being
+// parsed back from the input.
+
+#[test]
+fn typed_setters_keep_the_value_as_it_is() {
+    let mut form = LandmarkForm::default();
+    form.set_name("Hillbrow Tower".to_string());
+    form.set_latitude(-26.190_123_456);
+
+    assert_eq!(form.latitude.input, "-26.1901");
+    assert_eq!(
+        form.submit(),
+        Ok(Landmark {
+            name: "Hillbrow Tower".to_string(),
+            latitude: -26.190_123_456,
+        })
+    );
+
+    // Setting the input from a string still parses it, so once the
+    // user edits the coordinate, it has the precision that they typed.
+    form.set_input(LandmarkFormField::Latitude, "-26.19".to_string());
+    assert_eq!(form.latitude.value, Ok(-26.19));
+}
+
+#[test]
+fn typed_setters_are_checked_against_the_inputs_rules() {
+    let mut form = LandmarkForm::default();
+    form.latitude.add_rule(|latitude| {
+        if (-90.0..=90.0).contains(latitude) {
+            Ok(())
+        } else {
+            Err(ParseError::NumberOutOfRange {
+                required_type: "a latitude".to_string(),
+                min: "-90".to_string(),
+                max: "90".to_string(),
+            })
+        }
+    });
+
+    form.set_latitude(100.0);
+    assert!(form.latitude.value.is_err());
+}
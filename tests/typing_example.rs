@@ -0,0 +1,99 @@
+#![cfg(feature = "text")]
+
+use structform::{derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm};
+
+// This example shows how to update a form on every keystroke without
+// allocating a new string each time.
+
+// This example builds on the [subforms example](./subforms_example.rs).
+// This example is written assuming that you're already familiar with
+// the subforms example, so if not please refer to that first.
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Message {
+    subject: String,
+    recipient: Recipient,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Recipient {
+    email: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Message")]
+struct MessageForm {
+    subject: FormTextInput<String>,
+    #[structform(subform)]
+    recipient: RecipientForm,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Recipient")]
+struct RecipientForm {
+    email: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// Input events usually give you the new value as a `&str`. Passing it
+// to `set_input_str` copies it into a spare buffer that the input
+// keeps, which swaps places with the old input. Once both buffers are
+// big enough, typing doesn't allocate.
+
+#[test]
+fn typing_reuses_the_input_buffer() {
+    let mut form = MessageForm::default();
+    form.set_input_str(MessageFormField::Subject, "Hello wor");
+    let buffer = form.subject.input.as_ptr();
+
+    form.set_input_str(MessageFormField::Subject, "Hello wo");
+    form.set_input_str(MessageFormField::Subject, "Hello");
+    assert_eq!(form.subject.input.as_ptr(), buffer);
+    assert_eq!(form.subject.input, "Hello".to_string());
+
+    form.set_input_str(
+        MessageFormField::Recipient(RecipientFormField::Email),
+        "joe@example.com",
+    );
+    assert_eq!(
+        form.submit(),
+        Ok(Message {
+            subject: "Hello".to_string(),
+            recipient: Recipient {
+                email: "joe@example.com".to_string()
+            },
+        })
+    );
+}
+
+// Events often fire without the value changing, like on blur. If the
+// input is the same as before, it isn't parsed again, and it doesn't
+// count as a new edit, which shows in its timestamp with the
+// `edit-timestamps` feature.
+
+#[cfg(feature = "edit-timestamps")]
+#[test]
+fn unchanged_input_is_not_parsed_again() {
+    use std::time::Duration;
+    use structform::{FormClock, MockClock};
+
+    let clock = MockClock::new(Duration::from_secs(10));
+    let mut form = MessageForm::default();
+    form.set_clock(&FormClock::new(clock.clone()));
+
+    form.set_input_str(MessageFormField::Subject, "Hello");
+    clock.advance(Duration::from_secs(5));
+    form.set_input_str(MessageFormField::Subject, "Hello");
+    form.set_input(MessageFormField::Subject, "Hello".to_string());
+
+    assert_eq!(
+        form.subject.edit_timestamp.last_edited_at(),
+        Some(Duration::from_secs(10))
+    );
+    assert_eq!(
+        form.subject.edit_timestamp.time_since_edit(),
+        Some(Duration::from_secs(5))
+    );
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm,
+    UnsavedChangesGuard,
+};
+
+// This example shows how to stop users from navigating away from a
+// form that has unsaved changes.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Profile {
+    display_name: String,
+    bio: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Profile")]
+struct ProfileForm {
+    display_name: FormTextInput<String>,
+    bio: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// `structform::UnsavedChangesGuard` holds the form along with the
+// model it was last saved as. Call `should_block_navigation` from your
+// router's "before leave" check or the browser's `beforeunload` event.
+
+#[test]
+fn navigation_is_blocked_until_changes_are_saved() {
+    let mut guard: UnsavedChangesGuard<ProfileForm, Profile> = UnsavedChangesGuard::new(Profile {
+        display_name: "Joe".to_string(),
+        bio: "Hi".to_string(),
+    });
+    assert!(!guard.should_block_navigation());
+
+    guard
+        .form
+        .set_input(ProfileFormField::Bio, "Hello there".to_string());
+    assert!(guard.should_block_navigation());
+
+    // Typing the original value back isn't an unsaved change.
+    guard
+        .form
+        .set_input(ProfileFormField::Bio, "Hi".to_string());
+    assert!(!guard.should_block_navigation());
+
+    guard
+        .form
+        .set_input(ProfileFormField::Bio, "Hello there".to_string());
+    let saved = guard.form.submit().unwrap();
+    guard.saved(saved);
+    assert!(!guard.should_block_navigation());
+    assert_eq!(guard.pristine().bio, "Hello there".to_string());
+}
@@ -0,0 +1,138 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+
+use structform::{
+    derive_form_input, impl_numeric_input_with_stringops, impl_text_input_with_stringops,
+    ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows how to find every input on a form that doesn't
+// parse, so that they can all be shown at once.
+
+// This example builds on the [list of subforms example](./list_of_subforms_example.rs).
+// This example is written assuming that you're already familiar with
+// the list of subforms example, so if not please refer to that first.
+
+// `submit` stops at the first error that it finds. `validate` checks
+// every input instead, including inputs in subforms and lists, and
+// returns their errors keyed by field. Fields in subforms are nested
+// the same way as they are for `set_input`, so each error can be
+// matched up with the input that it belongs to.
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+derive_form_input! {FormNumberInput}
+impl_numeric_input_with_stringops!(FormNumberInput, "an age", u32, u32, 0, 150);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct UserDetails {
+    username: String,
+    age: u32,
+    primary_address: Address,
+    addresses: Vec<Address>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct Address {
+    city: String,
+    country: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "UserDetails")]
+struct UserDetailsForm {
+    username: FormTextInput<String>,
+    age: FormNumberInput<u32>,
+    #[structform(subform)]
+    primary_address: AddressForm,
+    addresses: Vec<AddressForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+    country: FormTextInput<String>,
+}
+
+fn age_error() -> ParseError {
+    ParseError::NumberOutOfRange {
+        required_type: "an age".to_string(),
+        min: "0".to_string(),
+        max: "150".to_string(),
+    }
+}
+
+#[test]
+fn every_failing_input_is_reported() {
+    let mut form = UserDetailsForm::default();
+    form.set_input(UserDetailsFormField::Age, "old".to_string());
+    form.set_input(
+        UserDetailsFormField::PrimaryAddress(AddressFormField::City),
+        "Johannesburg".to_string(),
+    );
+    form.set_input(UserDetailsFormField::AddAddresses, "".to_string());
+    form.set_input(UserDetailsFormField::AddAddresses, "".to_string());
+    form.set_input(
+        UserDetailsFormField::Addresses(1, AddressFormField::City),
+        "Pretoria".to_string(),
+    );
+    form.set_input(
+        UserDetailsFormField::Addresses(1, AddressFormField::Country),
+        "South Africa".to_string(),
+    );
+
+    let errors = form.validate().unwrap_err();
+    assert_eq!(
+        errors.into_iter().collect::<Vec<_>>(),
+        vec![
+            (UserDetailsFormField::Username, ParseError::Required),
+            (UserDetailsFormField::Age, age_error()),
+            (
+                UserDetailsFormField::PrimaryAddress(AddressFormField::Country),
+                ParseError::Required
+            ),
+            (
+                UserDetailsFormField::Addresses(0, AddressFormField::City),
+                ParseError::Required
+            ),
+            (
+                UserDetailsFormField::Addresses(0, AddressFormField::Country),
+                ParseError::Required
+            ),
+        ]
+    );
+}
+
+#[test]
+fn errors_can_be_looked_up_by_field() {
+    let mut form = UserDetailsForm::default();
+    form.set_input(UserDetailsFormField::Age, "old".to_string());
+
+    let errors = form.validate().unwrap_err();
+    assert_eq!(errors.get(&UserDetailsFormField::Age), Some(&age_error()));
+    assert_eq!(
+        errors.get(&UserDetailsFormField::PrimaryAddress(
+            AddressFormField::City
+        )),
+        Some(&ParseError::Required)
+    );
+    assert_eq!(
+        errors.get(&UserDetailsFormField::Addresses(0, AddressFormField::City)),
+        None
+    );
+}
+
+#[test]
+fn valid_forms_have_no_errors() {
+    let form = UserDetailsForm::new(&UserDetails {
+        username: "justin".to_string(),
+        age: 30,
+        primary_address: Address {
+            city: "Johannesburg".to_string(),
+            country: "South Africa".to_string(),
+        },
+        addresses: vec![],
+    });
+    assert_eq!(form.validate(), Ok(()));
+}
@@ -1,3 +1,11 @@
+#![cfg(all(feature = "numeric", feature = "text"))]
+#![allow(
+    clippy::absurd_extreme_comparisons,
+    clippy::legacy_numeric_constants,
+    clippy::manual_range_contains,
+    clippy::useless_conversion
+)]
+
 use std::convert::TryFrom;
 use std::fmt;
 use std::net::{IpAddr, Ipv4Addr};
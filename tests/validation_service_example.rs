@@ -0,0 +1,125 @@
+#![cfg(feature = "text")]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, ParseAndFormat, ParseError, StructForm,
+};
+
+// This example shows how to check a submitted model with a server
+// before saving it, and show the server's errors on the form.
+
+// This example builds on the [subforms example](./subforms_example.rs).
+// This example is written assuming that you're already familiar with
+// the subforms example, so if not please refer to that first.
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+struct Signup {
+    username: String,
+    addresses: Vec<Address>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+struct Address {
+    city: String,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Signup")]
+struct SignupForm {
+    username: FormTextInput<String>,
+    addresses: Vec<AddressForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Address")]
+struct AddressForm {
+    city: FormTextInput<String>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+// Some rules can only be checked by a server, like whether a username
+// is already taken. A `structform::ValidationService` receives the
+// submitted model, and returns errors keyed by the field path of the
+// input they belong to. Any function or closure with the right
+// signature is a validation service.
+
+fn check_with_server(signup: &Signup) -> Vec<(String, ParseError)> {
+    let mut errors = Vec::new();
+    if signup.username == "admin" {
+        errors.push((
+            "username".to_string(),
+            ParseError::External("That username is already taken.".to_string()),
+        ));
+    }
+    for (i, address) in signup.addresses.iter().enumerate() {
+        if address.city == "Atlantis" {
+            errors.push((
+                format!("addresses[{}].city", i),
+                ParseError::External("We don't deliver there.".to_string()),
+            ));
+        }
+    }
+    errors
+}
+
+#[test]
+fn errors_from_the_service_are_shown_on_their_inputs() {
+    let mut form = SignupForm::default();
+    form.apply_urlencoded("username=admin&addresses[0].city=JHB&addresses[1].city=Atlantis");
+
+    let result = form.validate_with_service(&check_with_server);
+    assert_eq!(
+        result,
+        Err(ParseError::External(
+            "That username is already taken.".to_string()
+        ))
+    );
+    assert_eq!(
+        form.username.validation_error().map(ToString::to_string),
+        Some("That username is already taken.".to_string())
+    );
+    assert_eq!(form.addresses[0].city.validation_error(), None);
+    assert_eq!(
+        form.addresses[1]
+            .city
+            .validation_error()
+            .map(ToString::to_string),
+        Some("We don't deliver there.".to_string())
+    );
+
+    // The errors stay until the inputs are changed.
+    form.set_input(SignupFormField::Username, "joe".to_string());
+    form.set_input_at_path("addresses[1].city", "CPT".to_string());
+    assert_eq!(
+        form.validate_with_service(&check_with_server),
+        Ok(Signup {
+            username: "joe".to_string(),
+            addresses: vec![
+                Address {
+                    city: "JHB".to_string()
+                },
+                Address {
+                    city: "CPT".to_string()
+                },
+            ],
+        })
+    );
+}
+
+// If the service is asynchronous, like a request to a server from the
+// browser, submit the form yourself and apply the errors from the
+// response when it arrives.
+
+#[test]
+fn errors_can_be_applied_when_they_arrive() {
+    let mut form = SignupForm::default();
+    form.set_input(SignupFormField::Username, "admin".to_string());
+    let model = form.submit().unwrap();
+
+    let response = check_with_server(&model);
+    form.apply_external_errors(response);
+
+    assert!(form.username.show_validation_msg());
+    assert!(form.submit().is_err());
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "text")]
+
+use structform::{derive_form_input, impl_text_input_with_stringops, ParseAndFormat, StructForm};
+
+// This example shows how to change the prefixes of the variants that
+// add, remove, move and toggle subforms.
+
+// This example builds on the [rename variants
+// example](./rename_variants_example.rs). This example is written
+// assuming that you're already familiar with the rename variants
+// example, so if not please refer to that first.
+
+// An order has a list of notes for the warehouse, and an input for
+// "additional notes" from the customer, which the database calls
+// `add_notes`. With the default prefixes, both would get the variant
+// `AddNotes`, so the derive macro stops with an error asking for one
+// of them to change.
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Order {
+    add_notes: String,
+    notes: Vec<Note>,
+    gift_wrap: Option<GiftWrap>,
+}
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Note {
+    text: String,
+}
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct GiftWrap {
+    message: String,
+}
+
+// The prefixes are given in snake case with `add_prefix`,
+// `remove_prefix`, `move_prefix` and `toggle_prefix`, and are cased
+// like the rest of the variant. Prefixes that aren't given keep their
+// usual names, so existing code doesn't need to change.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(
+    model = "Order",
+    add_prefix = "new",
+    remove_prefix = "delete",
+    toggle_prefix = "include"
+)]
+struct OrderForm {
+    add_notes: FormTextInput<String>,
+    notes: Vec<NoteForm>,
+    gift_wrap: Option<GiftWrapForm>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Note")]
+struct NoteForm {
+    text: FormTextInput<String>,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "GiftWrap")]
+struct GiftWrapForm {
+    message: FormTextInput<String>,
+}
+
+// This derivation of StructForm generates the following field definition:
+// ```
+// pub enum OrderFormField {
+//     AddNotes,
+//     IncludeGiftWrap,
+//     GiftWrap(GiftWrapFormField),
+//     NewNotes,
+//     Notes(usize, NoteFormField),
+//     DeleteNotes(usize),
+//     MoveNotes(usize, usize),
+// }
+// ```
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[test]
+fn prefixed_variants_act_on_subforms() {
+    let mut form = OrderForm::default();
+    form.set_input(OrderFormField::AddNotes, "Ring twice".to_string());
+    form.set_input(OrderFormField::NewNotes, "".to_string());
+    form.set_input(OrderFormField::NewNotes, "".to_string());
+    form.set_input(
+        OrderFormField::Notes(1, NoteFormField::Text),
+        "Fragile".to_string(),
+    );
+    form.set_input(OrderFormField::DeleteNotes(0), "".to_string());
+    form.set_input(OrderFormField::IncludeGiftWrap, "".to_string());
+    form.set_input(
+        OrderFormField::GiftWrap(GiftWrapFormField::Message),
+        "Happy birthday".to_string(),
+    );
+
+    assert_eq!(
+        form.submit(),
+        Ok(Order {
+            add_notes: "Ring twice".to_string(),
+            notes: vec![Note {
+                text: "Fragile".to_string(),
+            }],
+            gift_wrap: Some(GiftWrap {
+                message: "Happy birthday".to_string(),
+            }),
+        })
+    );
+}
+
+#[test]
+fn field_paths_do_not_depend_on_the_prefixes() {
+    assert_eq!(OrderFormField::NewNotes.to_path(), "notes");
+    assert_eq!(OrderFormField::AddNotes.to_path(), "add_notes");
+    assert_eq!(
+        OrderFormField::from_path("add_notes"),
+        Some(OrderFormField::AddNotes)
+    );
+}
@@ -0,0 +1,164 @@
+#![cfg(all(feature = "text", feature = "vat"))]
+
+use structform::{
+    derive_form_input, impl_text_input_with_stringops, ParseAndFormat, ParseError, StructForm,
+    VatNumber, VatNumberError,
+};
+
+// This example shows the VAT number input, which is available with
+// the `vat` feature.
+
+// This example builds on the [login example](./login_example.rs)
+// and the [postal code example](./postal_code_example.rs). This
+// example is written assuming that you're already familiar with those
+// examples, so if not please refer to them first.
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct BillingDetails {
+    company_vat_number: Option<VatNumber>,
+    branch_country: String,
+    branch_vat_number: String,
+    supplier_vat_number: Option<String>,
+}
+
+// VAT numbers usually start with a two letter country prefix. If
+// that's how your users will type them, `VatNumber` implements
+// `FromStr` and `Display`, so it works with the usual text input
+// macros.
+//
+// Sometimes, you already have a country field and your users will
+// type the VAT number without a prefix. In that case, the VAT number
+// can depend on the country field, like the postal code example.
+// `validate_vat_number` checks it against the chosen country, and
+// stores the cleaned up number without its prefix.
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "BillingDetails")]
+struct BillingDetailsForm {
+    company_vat_number: FormTextInput<Option<VatNumber>>,
+    branch_country: FormTextInput<String>,
+    #[structform(
+        depends_on = "branch_country",
+        validate_with = "structform::validate_vat_number"
+    )]
+    branch_vat_number: FormTextInput<String>,
+    #[structform(
+        depends_on = "branch_country",
+        validate_with = "structform::validate_optional_vat_number"
+    )]
+    supplier_vat_number: FormTextInput<Option<String>>,
+}
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+impl_text_input_with_stringops!(FormTextInput, VatNumber);
+
+#[test]
+fn vat_numbers_are_validated_by_country() {
+    let valid = [
+        "DE136695976",
+        "BE 0403.170.701",
+        "FR83404833048",
+        "IT00743110157",
+        "nl123456789b01",
+        "ZA4123456789",
+    ];
+    for vat_number in &valid {
+        assert!(
+            vat_number.parse::<VatNumber>().is_ok(),
+            "{} should be valid",
+            vat_number
+        );
+    }
+
+    assert_eq!(
+        "DE136695977".parse::<VatNumber>(),
+        Err(VatNumberError::InvalidChecksum {
+            country: "DE".to_string()
+        })
+    );
+    assert_eq!(
+        "ZA5123456789".parse::<VatNumber>(),
+        Err(VatNumberError::InvalidFormat {
+            country: "ZA".to_string()
+        })
+    );
+    assert_eq!(
+        "US123456789".parse::<VatNumber>(),
+        Err(VatNumberError::UnsupportedCountry("US".to_string()))
+    );
+}
+
+#[test]
+fn vat_numbers_are_formatted_with_their_country_prefix() {
+    let vat_number: VatNumber = "be 0403.170.701".parse().unwrap();
+    assert_eq!(vat_number.country(), "BE");
+    assert_eq!(vat_number.number(), "0403170701");
+    assert_eq!(vat_number.to_string(), "BE0403170701");
+}
+
+#[test]
+fn vat_numbers_can_be_validated_against_a_separate_country_field() {
+    let mut form = BillingDetailsForm::default();
+
+    form.set_input(
+        BillingDetailsFormField::CompanyVatNumber,
+        "DE136695976".to_string(),
+    );
+    form.set_input(BillingDetailsFormField::BranchCountry, "ZA".to_string());
+    form.set_input(
+        BillingDetailsFormField::BranchVatNumber,
+        "4123 456 789".to_string(),
+    );
+
+    assert_eq!(
+        form.submit(),
+        Ok(BillingDetails {
+            company_vat_number: Some("DE136695976".parse().unwrap()),
+            branch_country: "ZA".to_string(),
+            branch_vat_number: "4123456789".to_string(),
+            supplier_vat_number: None,
+        })
+    );
+
+    // The same number isn't valid for a different country. Changing
+    // the country validates the VAT numbers again.
+    form.set_input(BillingDetailsFormField::BranchCountry, "DE".to_string());
+    assert_eq!(
+        form.branch_vat_number.value,
+        Err(ParseError::FromStrError(
+            "Expected a DE VAT number".to_string()
+        ))
+    );
+
+    form.set_input(
+        BillingDetailsFormField::SupplierVatNumber,
+        "DE136695976".to_string(),
+    );
+    assert_eq!(
+        form.supplier_vat_number.value,
+        Ok(Some("136695976".to_string()))
+    );
+}
+
+#[test]
+fn vat_numbers_with_characters_outside_ascii_are_invalid() {
+    assert_eq!(
+        "CY1234567\u{e9}A".parse::<VatNumber>(),
+        Err(VatNumberError::InvalidFormat {
+            country: "CY".to_string()
+        })
+    );
+    assert_eq!(
+        "NL12345678\u{e9}B01".parse::<VatNumber>(),
+        Err(VatNumberError::InvalidFormat {
+            country: "NL".to_string()
+        })
+    );
+    assert_eq!(
+        VatNumber::new("ES", "\u{e9}1234567A"),
+        Err(VatNumberError::InvalidFormat {
+            country: "ES".to_string()
+        })
+    );
+}
@@ -0,0 +1,106 @@
+#![cfg(all(feature = "web", feature = "text", target_arch = "wasm32"))]
+
+use structform::{
+    checked_event, derive_form_input, event_selected_option, impl_text_input_with_stringops,
+    input_event, ParseAndFormat, StdTextInput, StructForm,
+};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{Event, HtmlElement, HtmlInputElement, HtmlSelectElement};
+
+// This example shows how to turn DOM events into form input, in apps
+// built on `web-sys`, like Seed and Yew apps. It needs the `web`
+// feature, and runs in a browser with
+// `wasm-pack test --headless --firefox -- --features web`.
+
+// This example builds on the [login example](./login_example.rs).
+// This example is written assuming that you're already familiar with
+// the login example, so if not please refer to that first.
+
+// `input_event`, `checked_event` and `select_event` read the value
+// from the element that an event came from, and pair it with a field
+// so that it can be passed straight to `set_input`. They return `None`
+// if the event came from an element that they don't handle.
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+derive_form_input! {FormTextInput}
+impl_text_input_with_stringops!(FormTextInput, String);
+
+#[derive(Default, Debug, PartialEq, Eq)]
+struct Subscription {
+    email: String,
+    newsletter: bool,
+}
+
+#[derive(Default, Clone, StructForm)]
+#[structform(model = "Subscription")]
+struct SubscriptionForm {
+    email: FormTextInput<String>,
+    newsletter: StdTextInput<bool>,
+}
+
+fn document() -> web_sys::Document {
+    web_sys::window().unwrap().document().unwrap()
+}
+
+fn dispatch(element: &HtmlElement) -> Event {
+    let event = Event::new("input").unwrap();
+    document().body().unwrap().append_child(element).unwrap();
+    element.dispatch_event(&event).unwrap();
+    event
+}
+
+fn create<T: JsCast>(tag: &str) -> T {
+    document()
+        .create_element(tag)
+        .unwrap()
+        .dyn_into::<T>()
+        .unwrap()
+}
+
+#[wasm_bindgen_test]
+fn events_set_inputs() {
+    let mut form = SubscriptionForm::default();
+
+    let email = create::<HtmlInputElement>("input");
+    email.set_value("justin@example.com");
+    let event = dispatch(&email);
+    if let Some((field, value)) = input_event(SubscriptionFormField::Email, &event) {
+        form.set_input(field, value);
+    }
+
+    let newsletter = create::<HtmlInputElement>("input");
+    newsletter.set_type("checkbox");
+    newsletter.set_checked(true);
+    let event = dispatch(&newsletter);
+    if let Some((field, value)) = checked_event(SubscriptionFormField::Newsletter, &event) {
+        form.set_input(field, value);
+    }
+
+    assert_eq!(
+        form.submit(),
+        Ok(Subscription {
+            email: "justin@example.com".to_string(),
+            newsletter: true,
+        })
+    );
+}
+
+#[wasm_bindgen_test]
+fn events_from_other_elements_are_ignored() {
+    let div = create::<HtmlElement>("div");
+    let event = dispatch(&div);
+    assert_eq!(input_event(SubscriptionFormField::Email, &event), None);
+    assert_eq!(
+        checked_event(SubscriptionFormField::Newsletter, &event),
+        None
+    );
+}
+
+#[wasm_bindgen_test]
+fn selects_without_a_selection_have_no_option() {
+    let select = create::<HtmlSelectElement>("select");
+    let event = dispatch(&select);
+    assert_eq!(event_selected_option(&event), None);
+}
@@ -0,0 +1,77 @@
+#![cfg(all(feature = "web", target_arch = "wasm32"))]
+
+use std::time::Duration;
+use structform::{field_id, focus_field, key_press, Clock, FormClock, KeyPress, WebClock};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{HtmlInputElement, KeyboardEvent, KeyboardEventInit};
+
+// This example shows the rest of the `web` feature's helpers, for
+// keyboard shortcuts, focusing inputs and reading the time. Like the
+// web events example, it runs in a browser with
+// `wasm-pack test --headless --firefox -- --features web`.
+
+// This example builds on the [web events
+// example](./web_events_example.rs). This example is written assuming
+// that you're already familiar with the web events example, so if not
+// please refer to that first.
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+fn document() -> web_sys::Document {
+    web_sys::window().unwrap().document().unwrap()
+}
+
+// `key_press` reads a `keydown` event into a `KeyPress`, which can be
+// looked up in a `ShortcutMap`.
+
+#[wasm_bindgen_test]
+fn key_presses_are_read_from_keyboard_events() {
+    let init = KeyboardEventInit::new();
+    init.set_key("s");
+    init.set_ctrl_key(true);
+    let event = KeyboardEvent::new_with_keyboard_event_init_dict("keydown", &init).unwrap();
+
+    assert_eq!(
+        key_press(&event),
+        KeyPress {
+            key: "s".to_string(),
+            ctrl: true,
+            shift: false,
+            alt: false,
+            meta: false,
+        }
+    );
+}
+
+// `focus_field` finds an input by the `id` from `field_id`, like when
+// an entry in an error summary is clicked.
+
+#[wasm_bindgen_test]
+fn inputs_are_focused_by_their_field_path() {
+    let input = document()
+        .create_element("input")
+        .unwrap()
+        .dyn_into::<HtmlInputElement>()
+        .unwrap();
+    input.set_id(&field_id("email"));
+    document().body().unwrap().append_child(&input).unwrap();
+
+    assert!(focus_field("email"));
+    assert_eq!(
+        document().active_element().map(|element| element.id()),
+        Some(field_id("email"))
+    );
+
+    assert!(!focus_field("phone"));
+}
+
+// The system time can't be read in the browser, so forms read the time
+// from `WebClock` there instead.
+
+#[wasm_bindgen_test]
+fn forms_read_the_time_from_the_browser() {
+    let now = FormClock::default().now().unwrap();
+    assert!(now > Duration::from_secs(1_600_000_000));
+    assert!(WebClock.now() >= now);
+}